@@ -1,4 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use jord::spherical::Sphere;
 use jord::{LatLong, NVector, Vec3};
 
 pub fn criterion_benchmark(c: &mut Criterion) {
@@ -11,6 +12,48 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         let ll = LatLong::from_degrees(45.0, 45.0);
         b.iter(|| black_box(ll.to_nvector()))
     });
+
+    c.bench_function("LatLong::distance_to", |b| {
+        let p1 = LatLong::from_degrees(54.0, 154.0);
+        let p2 = LatLong::from_degrees(54.05, 154.05);
+        let r = Sphere::EARTH.radius();
+        b.iter(|| black_box(p1.distance_to(&p2, r)))
+    });
+
+    c.bench_function("LatLong::approx_distance", |b| {
+        let p1 = LatLong::from_degrees(54.0, 154.0);
+        let p2 = LatLong::from_degrees(54.05, 154.05);
+        let r = Sphere::EARTH.radius();
+        b.iter(|| black_box(p1.approx_distance(&p2, r)))
+    });
+
+    let from: Vec<LatLong> = (0..100)
+        .map(|i| LatLong::from_degrees(i as f64 * 1.7 - 85.0, i as f64 * 3.5 - 175.0))
+        .collect();
+    let to: Vec<LatLong> = (0..100)
+        .map(|i| LatLong::from_degrees(85.0 - i as f64 * 1.3, i as f64 * 2.9 - 145.0))
+        .collect();
+
+    c.bench_function("LatLong::distance_to (100x100)", |b| {
+        let r = Sphere::EARTH.radius();
+        b.iter(|| {
+            black_box(
+                from.iter()
+                    .flat_map(|p| to.iter().map(|o| p.distance_to(o, r)))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+
+    c.bench_function("LatLong::distance_matrix (100x100)", |b| {
+        let r = Sphere::EARTH.radius();
+        b.iter(|| black_box(LatLong::distance_matrix(&from, &to, r)))
+    });
+
+    c.bench_function("LatLong::pairwise_distances (100)", |b| {
+        let r = Sphere::EARTH.radius();
+        b.iter(|| black_box(LatLong::pairwise_distances(&from, r)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
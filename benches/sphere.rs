@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use jord::spherical::Sphere;
+use jord::spherical::{GreatCircle, Sphere};
 use jord::NVector;
 
 pub fn criterion_benchmark(c: &mut Criterion) {
@@ -8,6 +8,24 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         let p2 = NVector::from_lat_long_degrees(-54.0, -154.0);
         b.iter(|| black_box(Sphere::EARTH.distance(p1, p2)))
     });
+
+    c.bench_function("Sphere::cross_track_distance", |b| {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(54.0, 154.0),
+            NVector::from_lat_long_degrees(-54.0, -154.0),
+        );
+        let p = NVector::from_lat_long_degrees(10.0, 170.0);
+        b.iter(|| black_box(Sphere::EARTH.cross_track_distance(p, gc)))
+    });
+
+    c.bench_function("GreatCircle::side", |b| {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(54.0, 154.0),
+            NVector::from_lat_long_degrees(-54.0, -154.0),
+        );
+        let p = NVector::from_lat_long_degrees(10.0, 170.0);
+        b.iter(|| black_box(gc.side(p)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,5 +1,5 @@
-use crate::{impl_measurement, Measurement};
-use std::f64::consts::PI;
+use crate::{Dimension, Length, Measurement};
+use std::{cmp::Ordering, f64::consts::PI, fmt, str::FromStr};
 
 #[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
 /// A one-dimensional angle.
@@ -49,6 +49,31 @@ impl Angle {
         self.radians
     }
 
+    /// Converts this angle to a floating point value in radians in the range (-π, π]; unlike
+    /// [as_radians](crate::Angle::as_radians), which preserves any number of turns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use jord::Angle;
+    ///
+    /// assert_eq!(PI, Angle::from_degrees(540.0).as_radians_normalised());
+    /// assert_eq!(PI, Angle::from_degrees(-180.0).as_radians_normalised());
+    /// assert_eq!(-PI / 2.0, Angle::from_degrees(270.0).as_radians_normalised());
+    /// ```
+    pub fn as_radians_normalised(&self) -> f64 {
+        // exact: the remainder of a floating point division is always representable.
+        let r = self.radians % (2.0 * PI);
+        if r > PI {
+            r - 2.0 * PI
+        } else if r <= -PI {
+            r + 2.0 * PI
+        } else {
+            r
+        }
+    }
+
     /// Creates an angle from a floating point value in degrees.
     pub fn from_degrees(degrees: f64) -> Self {
         Angle {
@@ -61,6 +86,228 @@ impl Angle {
         Angle { radians }
     }
 
+    /// Creates an angle from the given degrees, minutes and seconds.
+    ///
+    /// Minutes must be in [0, 60) and seconds in [0, 60). A negative angle is given by negative degrees, by
+    /// negative minutes if degrees are zero (e.g. `from_dms(0, -30, 0.0)` is -0.5 degrees), or by negative
+    /// seconds if both degrees and minutes are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, DmsError};
+    ///
+    /// assert_eq!(Ok(Angle::from_degrees(-45.5)), Angle::from_dms(-45, 30, 0.0));
+    /// assert_eq!(Ok(Angle::from_degrees(-0.5)), Angle::from_dms(0, -30, 0.0));
+    /// assert_eq!(Err(DmsError::InvalidMinutes(60)), Angle::from_dms(45, 60, 0.0));
+    /// ```
+    pub fn from_dms(degrees: i64, minutes: i64, seconds: f64) -> Result<Self, DmsError> {
+        match sexagesimal(degrees, minutes, seconds) {
+            Ok(d) => Ok(Angle::from_degrees(d)),
+            Err(Sexagesimal::Minutes) => Err(DmsError::InvalidMinutes(minutes)),
+            Err(Sexagesimal::Seconds) => Err(DmsError::InvalidSeconds(seconds)),
+        }
+    }
+
+    /// Creates a latitude from the given degrees, minutes and seconds: as [Angle::from_dms], but the resulting
+    /// angle must also be within [-90, 90] degrees, so that e.g. transposed or corrupt coordinates are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, DmsError};
+    ///
+    /// assert_eq!(Ok(Angle::from_degrees(-45.5)), Angle::from_dms_latitude(-45, 30, 0.0));
+    /// assert_eq!(Err(DmsError::InvalidDegrees(91)), Angle::from_dms_latitude(91, 0, 0.0));
+    /// assert_eq!(Err(DmsError::InvalidDegrees(90)), Angle::from_dms_latitude(90, 0, 1.0));
+    /// ```
+    pub fn from_dms_latitude(degrees: i64, minutes: i64, seconds: f64) -> Result<Self, DmsError> {
+        Self::from_dms_within(degrees, minutes, seconds, 90.0)
+    }
+
+    /// Creates a longitude from the given degrees, minutes and seconds: as [Angle::from_dms], but the resulting
+    /// angle must also be within [-180, 180] degrees, so that e.g. corrupt coordinates are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, DmsError};
+    ///
+    /// assert_eq!(Ok(Angle::from_degrees(-179.5)), Angle::from_dms_longitude(-179, 30, 0.0));
+    /// assert_eq!(Err(DmsError::InvalidDegrees(400)), Angle::from_dms_longitude(400, 0, 0.0));
+    /// ```
+    pub fn from_dms_longitude(degrees: i64, minutes: i64, seconds: f64) -> Result<Self, DmsError> {
+        Self::from_dms_within(degrees, minutes, seconds, 180.0)
+    }
+
+    fn from_dms_within(
+        degrees: i64,
+        minutes: i64,
+        seconds: f64,
+        limit: f64,
+    ) -> Result<Self, DmsError> {
+        match sexagesimal(degrees, minutes, seconds) {
+            Ok(d) if d.abs() > limit => Err(DmsError::InvalidDegrees(degrees)),
+            Ok(d) => Ok(Angle::from_degrees(d)),
+            Err(Sexagesimal::Minutes) => Err(DmsError::InvalidMinutes(minutes)),
+            Err(Sexagesimal::Seconds) => Err(DmsError::InvalidSeconds(seconds)),
+        }
+    }
+
+    /// Creates an angle from the given hours, minutes and seconds - as used for right ascension or hour angles -
+    /// where one hour is 15 degrees (24 hours is a full circle).
+    ///
+    /// Hours are not range checked, so that negative and large hour angles are accepted; minutes and seconds
+    /// are validated as in [Angle::from_dms].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, HmsError};
+    ///
+    /// assert_eq!(Ok(Angle::QUARTER_CIRCLE), Angle::from_hms(6, 0, 0.0));
+    /// assert_eq!(Ok(Angle::from_degrees(-37.5)), Angle::from_hms(-2, 30, 0.0));
+    /// assert_eq!(Err(HmsError::InvalidSeconds(60.0)), Angle::from_hms(6, 0, 60.0));
+    /// ```
+    pub fn from_hms(hours: i64, minutes: i64, seconds: f64) -> Result<Self, HmsError> {
+        match sexagesimal(hours, minutes, seconds) {
+            Ok(h) => Ok(Angle::from_degrees(h * 15.0)),
+            Err(Sexagesimal::Minutes) => Err(HmsError::InvalidMinutes(minutes)),
+            Err(Sexagesimal::Seconds) => Err(HmsError::InvalidSeconds(seconds)),
+        }
+    }
+
+    /// Converts this angle, normalised to the range [0, 360) degrees, to hours in [0, 24), minutes in [0, 60) and
+    /// seconds in [0, 60) - see [Angle::from_hms].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// assert_eq!((6, 0, 0.0), Angle::QUARTER_CIRCLE.as_hms());
+    /// assert_eq!((21, 30, 0.0), Angle::from_degrees(-37.5).as_hms());
+    /// ```
+    pub fn as_hms(self) -> (i64, u8, f64) {
+        let total_seconds = self.normalised().as_degrees() / 15.0 * 3600.0;
+        // a tiny negative angle normalises to (or rounds to) a full circle: that is 0 hours.
+        let total_seconds = if total_seconds >= 86_400.0 {
+            0.0
+        } else {
+            total_seconds
+        };
+        let hours = (total_seconds / 3600.0).floor();
+        let minutes = ((total_seconds - hours * 3600.0) / 60.0).floor();
+        let seconds = total_seconds - hours * 3600.0 - minutes * 60.0;
+        (hours as i64, minutes as u8, seconds)
+    }
+
+    /// Parses the given decimal degrees, tolerating surrounding whitespace and a comma as the decimal separator
+    /// (e.g. `" 48,8583 "`), as commonly found in spreadsheet exports. See also [Angle::from_str] for the strict
+    /// parser.
+    ///
+    /// Returns [ParseAngleError::AmbiguousSeparator] if the text contains more than one comma, or both
+    /// a comma and a dot, since the comma may then be a digit grouping separator (e.g. `48,123,456`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, ParseAngleError};
+    ///
+    /// assert_eq!(Ok(Angle::from_degrees(48.8583)), Angle::parse_lenient(" 48,8583\t"));
+    /// assert_eq!(Ok(Angle::from_degrees(-2.5)), Angle::parse_lenient("-2.5"));
+    /// assert_eq!(Err(ParseAngleError::AmbiguousSeparator), Angle::parse_lenient("48,123,456"));
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Self, ParseAngleError> {
+        let s = s.trim();
+        let commas = s.matches(',').count();
+        if commas > 1 || (commas == 1 && s.contains('.')) {
+            Err(ParseAngleError::AmbiguousSeparator)
+        } else if commas == 1 {
+            s.replace(',', ".").parse()
+        } else {
+            s.parse()
+        }
+    }
+
+    /// Parses the given degrees, minutes and seconds with an optional hemisphere
+    /// (e.g. `48°51'29.6"N`, `-2°21'E` or `W 2.3508°`): degrees are followed by `°`, minutes by `'` or `′` and
+    /// seconds by `"` or `″`; the degree symbol is optional when minutes and seconds are omitted. Whitespace is
+    /// allowed between components, and the hemisphere letter (`N`, `S`, `E` or `W`, case-insensitive) may
+    /// either prefix or suffix the angle: southern and western angles are negative.
+    ///
+    /// Components may also be followed by the letters `d`, `m` and `s` (e.g. `40d26m46s`), or separated by
+    /// whitespace, colons or hyphens (e.g. `40 26 46`, `40:26:46` or `40-26-46`) in which case components are
+    /// degrees, minutes and seconds in this order; a single style of separators must be used. A leading `-` is
+    /// always the sign of the angle. A trailing `s` denotes seconds only if minutes are denoted by `m`, otherwise
+    /// it denotes the southern hemisphere.
+    ///
+    /// Minutes and seconds must be in [0, 60); only the last component may have a fractional part.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, ParseAngleError};
+    ///
+    /// assert_eq!(
+    ///     Angle::from_dms(48, 51, 29.6).unwrap().round_d7(),
+    ///     Angle::parse_dms("48°51'29.6\"N").unwrap().round_d7()
+    /// );
+    /// assert_eq!(Ok(Angle::from_degrees(-2.5)), Angle::parse_dms("2°30'W"));
+    /// assert_eq!(Ok(Angle::from_degrees(-2.5)), Angle::parse_dms("-2.5"));
+    /// assert_eq!(Err(ParseAngleError::InvalidMinutes), Angle::parse_dms("48°61'N"));
+    /// assert_eq!(Ok(Angle::from_degrees(-2.5)), Angle::parse_dms("-2-30"));
+    /// assert_eq!(Ok(Angle::from_degrees(2.5)), Angle::parse_dms("2d30m"));
+    /// ```
+    pub fn parse_dms(s: &str) -> Result<Self, ParseAngleError> {
+        parse_dms_with_hemisphere(s).map(|(a, _)| a)
+    }
+
+    /// Parses the given bearing written as a whole number of degrees of 1 to 3 digits, with optional leading zeros
+    /// (e.g. `7`, `007` or `090`): `360` is accepted and normalised to `0`. Unlike [Angle::parse_dms], no sign,
+    /// fractional part, degree symbol or whitespace is accepted - see also [Angle::to_bearing_str].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, ParseAngleError};
+    ///
+    /// assert_eq!(Ok(Angle::from_degrees(7.0)), Angle::from_bearing_str("007"));
+    /// assert_eq!(Ok(Angle::ZERO), Angle::from_bearing_str("360"));
+    /// assert_eq!(Err(ParseAngleError::InvalidBearing), Angle::from_bearing_str("400"));
+    /// assert_eq!(Err(ParseAngleError::InvalidNumber), Angle::from_bearing_str("90.5"));
+    /// ```
+    pub fn from_bearing_str(s: &str) -> Result<Self, ParseAngleError> {
+        if s.is_empty() {
+            return Err(ParseAngleError::Empty);
+        }
+        if s.len() > 3 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseAngleError::InvalidNumber);
+        }
+        match s.parse::<u16>() {
+            Ok(360) => Ok(Angle::ZERO),
+            Ok(d) if d < 360 => Ok(Angle::from_degrees(d as f64)),
+            _ => Err(ParseAngleError::InvalidBearing),
+        }
+    }
+
+    /// Formats this angle as a bearing: the whole number of degrees of this angle, normalised to [0, 360) and
+    /// zero-padded to 3 digits (e.g. `007`) - see also [Angle::from_bearing_str].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// assert_eq!("007", Angle::from_degrees(7.0).to_bearing_str());
+    /// assert_eq!("270", Angle::from_degrees(-90.0).to_bearing_str());
+    /// assert_eq!("000", Angle::from_degrees(359.7).to_bearing_str());
+    /// ```
+    pub fn to_bearing_str(&self) -> String {
+        let degrees = self.normalised().as_degrees().round() as u16;
+        format!("{:03}", degrees % 360)
+    }
+
     /// Returns a new angle by normalising this angle to the range [0, 360) degrees.
     ///
     /// # Examples
@@ -107,13 +354,160 @@ impl Angle {
         } else {
             let res = self.radians % max.radians;
             if res < 0.0 {
-                Self::from_radians(res + max.radians)
+                // res + max rounds to max for tiny negative remainders: that is 0.
+                let wrapped = res + max.radians;
+                if wrapped < max.radians {
+                    Self::from_radians(wrapped)
+                } else {
+                    Self::ZERO
+                }
             } else {
                 Self::from_radians(res)
             }
         }
     }
 
+    /// Compares this bearing with the given bearing by their clockwise angular distance from the given reference
+    /// bearing: the bearing that is reached first when sweeping clockwise from the reference is the lesser one.
+    /// This is useful to sort contacts around a heading; unlike [PartialOrd], bearings are compared modulo a full
+    /// circle (e.g. 359 degrees is just before 1 degree when the reference is 358 degrees).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use jord::Angle;
+    ///
+    /// let reference = Angle::from_degrees(350.0);
+    /// assert_eq!(
+    ///     Ordering::Less,
+    ///     Angle::from_degrees(355.0).cyclic_cmp(Angle::from_degrees(10.0), reference)
+    /// );
+    /// assert_eq!(
+    ///     Ordering::Greater,
+    ///     Angle::from_degrees(340.0).cyclic_cmp(Angle::from_degrees(10.0), reference)
+    /// );
+    /// ```
+    pub fn cyclic_cmp(self, other: Angle, reference: Angle) -> Ordering {
+        let a = (self - reference).normalised().radians;
+        let b = (other - reference).normalised().radians;
+        a.total_cmp(&b)
+    }
+
+    /// Counts the given bearings in the given number of equal sectors covering [0, 360) degrees - e.g. to draw a
+    /// wind rose: sector `i` covers [`i * 360 / bins`, `(i + 1) * 360 / bins`) degrees. Each bearing is first
+    /// [normalised](crate::Angle::normalised), so that 360 degrees falls in the first sector and negative
+    /// bearings are counted in the sector of the equivalent bearing. Returns an empty vector if `bins` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// let bearings = [0.0, 10.0, 95.0, 360.0, -10.0].map(Angle::from_degrees);
+    /// assert_eq!(vec![3, 1, 0, 1], Angle::bearing_histogram(&bearings, 4));
+    /// ```
+    pub fn bearing_histogram(bearings: &[Angle], bins: usize) -> Vec<usize> {
+        let mut histogram = vec![0; bins];
+        if bins == 0 {
+            return histogram;
+        }
+        let width = Self::FULL_CIRCLE.radians / bins as f64;
+        for b in bearings {
+            // rounding may put a bearing just below 360 degrees in an extra sector.
+            let i = ((b.normalised().radians / width) as usize).min(bins - 1);
+            histogram[i] += 1;
+        }
+        histogram
+    }
+
+    /// Adds the given angle to this angle and normalises the result to the range [0, 360) degrees - e.g. to
+    /// update a heading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// assert_eq!(
+    ///     Angle::from_degrees(5.0),
+    ///     Angle::from_degrees(355.0).wrapping_add(Angle::from_degrees(10.0)).round_d7()
+    /// );
+    /// ```
+    pub fn wrapping_add(self, other: Angle) -> Angle {
+        (self + other).normalised()
+    }
+
+    /// Subtracts the given angle from this angle and normalises the result to the range [0, 360) degrees - e.g.
+    /// to update a heading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// assert_eq!(
+    ///     Angle::from_degrees(355.0),
+    ///     Angle::from_degrees(5.0).wrapping_sub(Angle::from_degrees(10.0)).round_d7()
+    /// );
+    /// ```
+    pub fn wrapping_sub(self, other: Angle) -> Angle {
+        (self - other).normalised()
+    }
+
+    /// Returns an angle with the magnitude of this angle and the sign of the given angle, like [f64::copysign]:
+    /// the sign of a zero angle is honoured (i.e. `-0.0` is negative) - e.g. to propagate the hemisphere of a
+    /// latitude to another latitude.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// let a = Angle::from_degrees(3.5);
+    /// assert_eq!(Angle::from_degrees(-3.5), a.copysign(Angle::from_degrees(-10.0)));
+    /// assert_eq!(Angle::from_degrees(3.5), (-a).copysign(Angle::from_degrees(10.0)));
+    /// assert_eq!(Angle::from_degrees(-3.5), a.copysign(Angle::from_radians(-0.0)));
+    /// ```
+    pub fn copysign(self, sign_source: Angle) -> Angle {
+        Angle::from_radians(self.radians.copysign(sign_source.radians))
+    }
+
+    /// Returns an angle with the magnitude of this angle, negative if `negative` is true, positive otherwise -
+    /// e.g. to reconstruct a latitude from its magnitude and its hemisphere (`S` being negative). A zero angle
+    /// becomes `-0.0` if `negative` is true.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    ///
+    /// let magnitude = Angle::from_degrees(48.8567);
+    /// let hemisphere = 'S';
+    /// assert_eq!(Angle::from_degrees(-48.8567), magnitude.with_sign(hemisphere == 'S'));
+    /// assert!(Angle::ZERO.with_sign(true).as_radians().is_sign_negative());
+    /// ```
+    pub fn with_sign(self, negative: bool) -> Angle {
+        let sign = if negative { -1.0 } else { 1.0 };
+        Angle::from_radians(self.radians.copysign(sign))
+    }
+
+    /// Computes the length of the arc subtended by this central angle on a circle of the given radius: the inverse
+    /// of [Length::to_central_angle].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use jord::{Angle, Length};
+    ///
+    /// let radius = Length::from_metres(2.0);
+    /// assert_eq!(Length::from_metres(2.0 * PI), Angle::HALF_CIRCLE.arc_length(radius));
+    /// ```
+    pub fn arc_length(&self, radius: Length) -> Length {
+        *self * radius
+    }
+
     /// Rounds this angle to the nearest decimal degrees with 5 decimal places - when representing
     /// an Earth latitude/longtiude this is approximately 1.11 metres at the equator.
     ///
@@ -163,7 +557,286 @@ impl Angle {
     }
 }
 
+/// Error returned when creating an [Angle] from degrees, minutes and seconds fails.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum DmsError {
+    /// The angle is out of range - e.g. not in [-90, 90] degrees for a
+    /// [latitude](crate::Angle::from_dms_latitude).
+    InvalidDegrees(i64),
+    /// The minutes are not in [0, 60), or are negative while the degrees are not zero.
+    InvalidMinutes(i64),
+    /// The seconds are not in [0, 60), or are negative while the degrees or minutes are not zero.
+    InvalidSeconds(f64),
+}
+
+impl fmt::Display for DmsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DmsError::InvalidDegrees(d) => write!(f, "degrees out of range: {}", d),
+            DmsError::InvalidMinutes(m) => write!(f, "invalid minutes of arc: {}", m),
+            DmsError::InvalidSeconds(s) => write!(f, "invalid seconds of arc: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for DmsError {}
+
+/// Error returned when creating an [Angle] from hours, minutes and seconds fails.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum HmsError {
+    /// The minutes are not in [0, 60), or are negative while the hours are not zero.
+    InvalidMinutes(i64),
+    /// The seconds are not in [0, 60), or are negative while the hours or minutes are not zero.
+    InvalidSeconds(f64),
+}
+
+impl fmt::Display for HmsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HmsError::InvalidMinutes(m) => write!(f, "invalid minutes of time: {}", m),
+            HmsError::InvalidSeconds(s) => write!(f, "invalid seconds of time: {}", s),
+        }
+    }
+}
+
+impl std::error::Error for HmsError {}
+
+/// Invalid component of a sexagesimal value.
+enum Sexagesimal {
+    Minutes,
+    Seconds,
+}
+
+/// Converts the given whole units, minutes and seconds into decimal units. The sign is given by the first
+/// non-zero component; subsequent components must be non-negative.
+fn sexagesimal(whole: i64, minutes: i64, seconds: f64) -> Result<f64, Sexagesimal> {
+    if minutes.abs() >= 60 || (minutes < 0 && whole != 0) {
+        return Err(Sexagesimal::Minutes);
+    }
+    if !seconds.is_finite()
+        || seconds.abs() >= 60.0
+        || (seconds < 0.0 && (whole != 0 || minutes != 0))
+    {
+        return Err(Sexagesimal::Seconds);
+    }
+    let magnitude = whole.abs() as f64 + minutes.abs() as f64 / 60.0 + seconds.abs() / 3600.0;
+    if whole < 0 || minutes < 0 || seconds < 0.0 {
+        Ok(-magnitude)
+    } else {
+        Ok(magnitude)
+    }
+}
+
+/// Error returned when parsing an [Angle] fails.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ParseAngleError {
+    /// The text is empty.
+    Empty,
+    /// The text is not a finite decimal number of degrees.
+    InvalidNumber,
+    /// The text contains several commas or both a comma and a dot: it is unclear whether the comma is
+    /// the decimal separator or a digit grouping separator.
+    AmbiguousSeparator,
+    /// The minutes are not in [0, 60).
+    InvalidMinutes,
+    /// The seconds are not in [0, 60).
+    InvalidSeconds,
+    /// The hemisphere is not valid for the angle, or is combined with a sign.
+    InvalidHemisphere,
+    /// The bearing is not in [0, 360].
+    InvalidBearing,
+    /// The unit following the number is not a known angle unit.
+    InvalidUnit,
+}
+
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseAngleError::Empty => write!(f, "cannot parse angle from empty string"),
+            ParseAngleError::InvalidNumber => write!(f, "invalid decimal degrees"),
+            ParseAngleError::AmbiguousSeparator => {
+                write!(f, "ambiguous decimal or digit grouping separator")
+            }
+            ParseAngleError::InvalidMinutes => write!(f, "minutes of arc not in [0, 60)"),
+            ParseAngleError::InvalidSeconds => write!(f, "seconds of arc not in [0, 60)"),
+            ParseAngleError::InvalidHemisphere => write!(f, "invalid or signed hemisphere"),
+            ParseAngleError::InvalidBearing => write!(f, "bearing not in [0, 360]"),
+            ParseAngleError::InvalidUnit => write!(
+                f,
+                "unknown angle unit (expected degrees, radians, gradians or turns)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseAngleError {}
+
+/// Parses the given degrees, minutes and seconds with an optional hemisphere - see [Angle::parse_dms]; returns
+/// the angle and the upper-case hemisphere letter if any.
+pub(crate) fn parse_dms_with_hemisphere(s: &str) -> Result<(Angle, Option<char>), ParseAngleError> {
+    let mut t = s.trim();
+    if t.is_empty() {
+        return Err(ParseAngleError::Empty);
+    }
+    let mut hemisphere = None;
+    if let Some(c) = t
+        .chars()
+        .last()
+        .filter(is_hemisphere)
+        .filter(|c| !is_seconds_letter(*c, &t[..t.len() - 1]))
+    {
+        hemisphere = Some(c.to_ascii_uppercase());
+        t = t[..t.len() - 1].trim_end();
+    } else if let Some(c) = t.chars().next().filter(is_hemisphere) {
+        hemisphere = Some(c.to_ascii_uppercase());
+        t = t[1..].trim_start();
+    }
+
+    let negative = t.starts_with('-');
+    if negative || t.starts_with('+') {
+        if hemisphere.is_some() {
+            // both a sign and a hemisphere.
+            return Err(ParseAngleError::InvalidHemisphere);
+        }
+        t = &t[1..];
+    }
+
+    // components: number followed by the symbol of its unit (0: degrees, 1: minutes, 2: seconds), or by a
+    // separator in which case the unit is given by the position of the component.
+    let mut components: Vec<(f64, bool, i32)> = Vec::with_capacity(3);
+    let mut positional = false;
+    let mut dangling_separator = false;
+    let mut rest = t.trim_start();
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number = &rest[..end];
+        let value = number
+            .parse::<f64>()
+            .map_err(|_| ParseAngleError::InvalidNumber)?;
+        let integral = !number.contains('.');
+        let after = &rest[end..];
+        rest = after.trim_start();
+        let spaced = rest.len() < after.len();
+        let position = components.len() as i32;
+        let (unit, symbol_len, separator) = match rest.chars().next() {
+            Some(c @ ('°' | 'd' | 'D')) => (0, c.len_utf8(), false),
+            Some(c @ ('\'' | '′' | 'm' | 'M')) => (1, c.len_utf8(), false),
+            Some(c @ ('"' | '″' | 's' | 'S')) => (2, c.len_utf8(), false),
+            Some(c @ (':' | '-')) => (position, c.len_utf8(), true),
+            Some(c) if spaced && c.is_ascii_digit() => (position, 0, true),
+            None if components.is_empty() => (0, 0, false),
+            None if positional => (position, 0, true),
+            _ => return Err(ParseAngleError::InvalidNumber),
+        };
+        if let Some(&(_, previous_integral, previous_unit)) = components.last() {
+            // units in order, a single style of separators, and only the last component may have a
+            // fractional part.
+            if unit <= previous_unit || separator != positional || !previous_integral {
+                return Err(ParseAngleError::InvalidNumber);
+            }
+        }
+        if unit > 2 {
+            return Err(ParseAngleError::InvalidNumber);
+        }
+        if unit == 1 && value >= 60.0 {
+            return Err(ParseAngleError::InvalidMinutes);
+        }
+        if unit == 2 && value >= 60.0 {
+            return Err(ParseAngleError::InvalidSeconds);
+        }
+        components.push((value, integral, unit));
+        positional = separator;
+        dangling_separator = separator && symbol_len > 0;
+        rest = rest[symbol_len..].trim_start();
+    }
+    if dangling_separator {
+        return Err(ParseAngleError::InvalidNumber);
+    }
+    if components.is_empty() {
+        return Err(ParseAngleError::InvalidNumber);
+    }
+
+    let degrees: f64 = components
+        .iter()
+        .map(|&(v, _, unit)| v / 60f64.powi(unit))
+        .sum();
+    if negative || matches!(hemisphere, Some('S') | Some('W')) {
+        Ok((Angle::from_degrees(-degrees), hemisphere))
+    } else {
+        Ok((Angle::from_degrees(degrees), hemisphere))
+    }
+}
+
+fn is_hemisphere(c: &char) -> bool {
+    matches!(c.to_ascii_uppercase(), 'N' | 'S' | 'E' | 'W')
+}
+
+/// Determines whether the given trailing `s` or `S` denotes seconds rather than the southern hemisphere: i.e. it
+/// follows a number and minutes are denoted by `m` or `M` in the given preceding text.
+fn is_seconds_letter(c: char, preceding: &str) -> bool {
+    c.eq_ignore_ascii_case(&'s')
+        && preceding
+            .trim_end()
+            .ends_with(|p: char| p.is_ascii_digit() || p == '.')
+        && preceding.contains(['m', 'M'])
+}
+
+impl FromStr for Angle {
+    type Err = ParseAngleError;
+
+    /// Parses the given decimal number (e.g. `48.8583` or `-2.5e1`) optionally followed by whitespace and a unit
+    /// keyword (case-insensitive):
+    /// - `degrees`, `degree` or `deg` (the default when no unit is given),
+    /// - `radians`, `radian` or `rad`,
+    /// - `gradians`, `gradian`, `grad` or `gon` (400 in a full circle),
+    /// - `turns` or `turn` (1 in a full circle).
+    ///
+    /// The dot is the only accepted decimal separator and no surrounding whitespace is allowed. Returns
+    /// [ParseAngleError::InvalidUnit] if the unit is not one of the above. See also [Angle::parse_lenient].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use jord::{Angle, ParseAngleError};
+    ///
+    /// assert_eq!(Ok(Angle::from_degrees(48.8583)), "48.8583".parse::<Angle>());
+    /// assert_eq!(Ok(Angle::from_degrees(45.0)), "45 degrees".parse::<Angle>());
+    /// assert_eq!(Ok(Angle::from_radians(0.5)), "0.5 Radians".parse::<Angle>());
+    /// assert_eq!(Ok(Angle::from_degrees(90.0)), "100 gradians".parse::<Angle>());
+    /// assert_eq!(Ok(Angle::from_radians(PI / 2.0)), "0.25 turns".parse::<Angle>());
+    /// assert_eq!(Err(ParseAngleError::InvalidNumber), "48,8583".parse::<Angle>());
+    /// assert_eq!(Err(ParseAngleError::InvalidUnit), "3 furlongs".parse::<Angle>());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseAngleError::Empty);
+        }
+        let (number, unit) = match s.rsplit_once(char::is_whitespace) {
+            Some((n, u)) if !u.is_empty() && u.chars().all(|c| c.is_ascii_alphabetic()) => {
+                (n.trim_end(), Some(u.to_ascii_lowercase()))
+            }
+            _ => (s, None),
+        };
+        let value = match number.parse::<f64>() {
+            Ok(v) if v.is_finite() => v,
+            _ => return Err(ParseAngleError::InvalidNumber),
+        };
+        match unit.as_deref() {
+            None | Some("degrees" | "degree" | "deg") => Ok(Angle::from_degrees(value)),
+            Some("radians" | "radian" | "rad") => Ok(Angle::from_radians(value)),
+            Some("gradians" | "gradian" | "grad" | "gon") => Ok(Angle::from_degrees(value * 0.9)),
+            Some("turns" | "turn") => Ok(Angle::from_radians(value * 2.0 * PI)),
+            Some(_) => Err(ParseAngleError::InvalidUnit),
+        }
+    }
+}
+
 impl Measurement for Angle {
+    const DIMENSION: Dimension = Dimension::Angle;
+
     fn from_default_unit(amount: f64) -> Self {
         Angle::from_radians(amount)
     }
@@ -176,22 +849,67 @@ impl Measurement for Angle {
 
 impl_measurement! { Angle }
 
-#[cfg(test)]
-mod tests {
+/// Multiplies an angle by a whole number, e.g. to double an angle. Angles are represented in radians, therefore
+/// the result is the correctly rounded product, which is exact when multiplying by a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use jord::Angle;
+///
+/// assert_eq!(Angle::FULL_CIRCLE, Angle::HALF_CIRCLE * 2);
+/// assert_eq!(Angle::FULL_CIRCLE, 4 * Angle::QUARTER_CIRCLE);
+/// ```
+impl ::std::ops::Mul<i64> for Angle {
+    type Output = Self;
 
-    use std::f64::consts::PI;
+    fn mul(self, rhs: i64) -> Self {
+        Angle::from_radians(self.radians * rhs as f64)
+    }
+}
 
-    use crate::Angle;
+impl ::std::ops::Mul<Angle> for i64 {
+    type Output = Angle;
 
-    #[test]
-    fn conversions() {
-        assert_eq!(PI, Angle::from_degrees(180.0).as_radians());
-        assert_eq!(180.0, Angle::from_radians(PI).as_degrees());
+    fn mul(self, rhs: Angle) -> Angle {
+        rhs * self
     }
+}
 
-    #[test]
-    fn std_ops() {
-        assert_eq!(Angle::from_degrees(2.0), 2.0 * Angle::from_degrees(1.0));
+/// Divides an angle by a whole number, e.g. to divide a full circle into equal parts. Angles are represented in
+/// radians, therefore the result is the correctly rounded quotient, which is exact when dividing by a power of 2.
+///
+/// # Examples
+///
+/// ```
+/// use jord::Angle;
+///
+/// assert_eq!(Angle::QUARTER_CIRCLE, Angle::FULL_CIRCLE / 4);
+/// ```
+impl ::std::ops::Div<i64> for Angle {
+    type Output = Self;
+
+    fn div(self, rhs: i64) -> Self {
+        Angle::from_radians(self.radians / rhs as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::{cmp::Ordering, f64::consts::PI};
+
+    use crate::{Angle, DmsError, HmsError, Length, ParseAngleError};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(PI, Angle::from_degrees(180.0).as_radians());
+        assert_eq!(180.0, Angle::from_radians(PI).as_degrees());
+    }
+
+    #[test]
+    fn std_ops() {
+        assert_eq!(Angle::from_degrees(2.0), 2.0 * Angle::from_degrees(1.0));
         assert_eq!(
             Angle::from_degrees(2.0),
             Angle::from_degrees(1.0) + Angle::from_degrees(1.0)
@@ -242,4 +960,816 @@ mod tests {
             Angle::from_degrees(180.0).normalised_to(Angle::HALF_CIRCLE)
         );
     }
+
+    // bearing_histogram
+
+    #[test]
+    fn bearing_histogram_cardinals() {
+        let d = Angle::from_degrees;
+        let bearings = [d(0.0), d(90.0), d(180.0), d(270.0), d(45.0), d(315.0)];
+        assert_eq!(
+            vec![1, 1, 1, 0, 1, 0, 1, 1],
+            Angle::bearing_histogram(&bearings, 8)
+        );
+    }
+
+    #[test]
+    fn bearing_histogram_edges() {
+        let d = Angle::from_degrees;
+        let h = Angle::bearing_histogram(&[d(360.0), d(720.0), d(-360.0)], 8);
+        assert_eq!(vec![3, 0, 0, 0, 0, 0, 0, 0], h);
+        // just below 360 degrees.
+        let h = Angle::bearing_histogram(
+            &[
+                Angle::from_radians(f64::from_bits((2.0 * PI).to_bits() - 1)),
+                d(359.999),
+            ],
+            8,
+        );
+        assert_eq!(2, h[7]);
+        // sector boundaries belong to the next sector.
+        assert_eq!(
+            vec![0, 1, 0, 0, 0, 0, 0, 0],
+            Angle::bearing_histogram(&[d(45.0)], 8)
+        );
+        // negative bearings.
+        assert_eq!(
+            vec![0, 0, 0, 0, 0, 0, 0, 1],
+            Angle::bearing_histogram(&[d(-20.0)], 8)
+        );
+    }
+
+    #[test]
+    fn bearing_histogram_degenerate() {
+        let d = Angle::from_degrees;
+        assert!(Angle::bearing_histogram(&[d(10.0)], 0).is_empty());
+        assert_eq!(vec![0, 0, 0], Angle::bearing_histogram(&[], 3));
+        assert_eq!(
+            vec![3],
+            Angle::bearing_histogram(&[d(10.0), d(200.0), d(359.0)], 1)
+        );
+    }
+
+    // cyclic_cmp
+
+    fn sorted_around(reference: f64, bearings: &[f64]) -> Vec<f64> {
+        let mut angles: Vec<Angle> = bearings.iter().map(|d| Angle::from_degrees(*d)).collect();
+        angles.sort_by(|a, b| a.cyclic_cmp(*b, Angle::from_degrees(reference)));
+        angles.iter().map(|a| a.round_d7().as_degrees()).collect()
+    }
+
+    #[test]
+    fn cyclic_cmp_around_zero() {
+        assert_eq!(
+            vec![0.0, 10.0, 90.0, 180.0, 270.0, 359.0],
+            sorted_around(0.0, &[270.0, 10.0, 359.0, 180.0, 0.0, 90.0])
+        );
+        // raw angles outside [0, 360) are normalised.
+        assert_eq!(
+            vec![10.0, 90.0, -90.0, -1.0],
+            sorted_around(0.0, &[-1.0, -90.0, 90.0, 10.0])
+        );
+    }
+
+    #[test]
+    fn cyclic_cmp_around_half_circle() {
+        assert_eq!(
+            vec![180.0, 270.0, 359.0, 0.0, 10.0, 90.0],
+            sorted_around(180.0, &[270.0, 10.0, 359.0, 180.0, 0.0, 90.0])
+        );
+    }
+
+    #[test]
+    fn cyclic_cmp_equal() {
+        assert_eq!(
+            Ordering::Equal,
+            Angle::from_degrees(10.0).cyclic_cmp(Angle::from_degrees(370.0), Angle::HALF_CIRCLE)
+        );
+    }
+
+    // wrapping_add, wrapping_sub
+
+    #[test]
+    fn wrapping_add() {
+        let d = Angle::from_degrees;
+        assert_eq!(d(5.0), d(355.0).wrapping_add(d(10.0)).round_d7());
+        assert_eq!(Angle::ZERO, d(350.0).wrapping_add(d(10.0)).round_d7());
+        assert_eq!(d(30.0), d(10.0).wrapping_add(d(20.0)));
+        // adding a negative angle across 0.
+        assert_eq!(d(350.0), d(5.0).wrapping_add(d(-15.0)).round_d7());
+        // several turns.
+        assert_eq!(d(10.0), d(5.0).wrapping_add(d(725.0)).round_d7());
+    }
+
+    #[test]
+    fn wrapping_sub() {
+        let d = Angle::from_degrees;
+        assert_eq!(d(355.0), d(5.0).wrapping_sub(d(10.0)).round_d7());
+        assert_eq!(Angle::ZERO, d(10.0).wrapping_sub(d(10.0)));
+        // subtracting a negative angle across 360.
+        assert_eq!(d(5.0), d(355.0).wrapping_sub(d(-10.0)).round_d7());
+        assert_eq!(d(340.0), d(10.0).wrapping_sub(d(390.0)).round_d7());
+    }
+
+    #[test]
+    fn wrapping_tiny_negative() {
+        let d = Angle::from_degrees;
+        let just_above = Angle::from_radians(10f64.to_radians() + 1e-16);
+        assert_eq!(Angle::ZERO, d(10.0).wrapping_sub(just_above));
+        assert_eq!(
+            Angle::ZERO,
+            Angle::ZERO.wrapping_add(Angle::from_radians(-1e-16))
+        );
+        assert_eq!(Angle::ZERO, Angle::from_radians(-1e-16).normalised());
+        assert_eq!(
+            Angle::ZERO,
+            Angle::from_radians(-1e-17).normalised_to(Angle::HALF_CIRCLE)
+        );
+    }
+
+    #[test]
+    fn wrapping_range() {
+        let mut heading = Angle::ZERO;
+        for _ in 0..1000 {
+            heading = heading.wrapping_add(Angle::from_degrees(17.3));
+            assert!(heading >= Angle::ZERO && heading < Angle::FULL_CIRCLE);
+            heading = heading.wrapping_sub(Angle::from_degrees(29.1));
+            assert!(heading >= Angle::ZERO && heading < Angle::FULL_CIRCLE);
+        }
+    }
+
+    // copysign, with_sign
+
+    #[test]
+    fn copysign() {
+        let r = Angle::from_radians;
+        for (a, b) in [
+            (1.5, 2.0),
+            (1.5, -2.0),
+            (-1.5, 2.0),
+            (-1.5, -2.0),
+            (1.5, 0.0),
+            (1.5, -0.0),
+            (0.0, -1.0),
+            (-0.0, 1.0),
+            (0.0, -0.0),
+            (-0.0, 0.0),
+            (f64::INFINITY, -1.0),
+        ] {
+            let actual = r(a).copysign(r(b)).as_radians();
+            let expected = a.copysign(b);
+            assert_eq!(expected, actual);
+            assert_eq!(expected.is_sign_negative(), actual.is_sign_negative());
+        }
+    }
+
+    #[test]
+    fn copysign_nan() {
+        assert!(Angle::from_radians(f64::NAN)
+            .copysign(Angle::from_radians(-1.0))
+            .as_radians()
+            .is_nan());
+    }
+
+    #[test]
+    fn with_sign() {
+        let d = Angle::from_degrees;
+        assert_eq!(d(-10.0), d(10.0).with_sign(true));
+        assert_eq!(d(-10.0), d(-10.0).with_sign(true));
+        assert_eq!(d(10.0), d(10.0).with_sign(false));
+        assert_eq!(d(10.0), d(-10.0).with_sign(false));
+        assert!(Angle::ZERO.with_sign(true).as_radians().is_sign_negative());
+        assert!(Angle::from_radians(-0.0)
+            .with_sign(false)
+            .as_radians()
+            .is_sign_positive());
+    }
+
+    // from_str
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Ok(Angle::from_degrees(48.8583)), "48.8583".parse::<Angle>());
+        assert_eq!(Ok(Angle::from_degrees(-2.35)), "-2.35".parse::<Angle>());
+        assert_eq!(Ok(Angle::from_degrees(150.0)), "1.5e2".parse::<Angle>());
+        assert_eq!(Ok(Angle::from_degrees(48.0)), "+48".parse::<Angle>());
+    }
+
+    #[test]
+    fn from_str_strict() {
+        assert_eq!(Err(ParseAngleError::Empty), "".parse::<Angle>());
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            "48,8583".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            " 48.8583".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            "48.8583 ".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            "48.8583N".parse::<Angle>()
+        );
+        assert_eq!(Err(ParseAngleError::InvalidNumber), "NaN".parse::<Angle>());
+        assert_eq!(Err(ParseAngleError::InvalidNumber), "inf".parse::<Angle>());
+    }
+
+    #[test]
+    fn from_str_units() {
+        let d = Angle::from_degrees;
+        assert_eq!(Ok(d(45.0)), "45 degrees".parse::<Angle>());
+        assert_eq!(Ok(d(1.0)), "1 degree".parse::<Angle>());
+        assert_eq!(Ok(d(-12.5)), "-12.5 DEG".parse::<Angle>());
+        assert_eq!(Ok(Angle::from_radians(0.5)), "0.5 radians".parse::<Angle>());
+        assert_eq!(Ok(Angle::from_radians(1.0)), "1 radian".parse::<Angle>());
+        assert_eq!(
+            Ok(Angle::from_radians(PI)),
+            "3.141592653589793 rad".parse::<Angle>()
+        );
+        assert_eq!(Ok(d(90.0)), "100 gradians".parse::<Angle>());
+        assert_eq!(Ok(d(180.0)), "200 Gradian".parse::<Angle>());
+        assert_eq!(Ok(d(0.9)), "1 grad".parse::<Angle>());
+        assert_eq!(Ok(d(360.0)), "400 gon".parse::<Angle>());
+        assert_eq!(
+            Ok(Angle::from_radians(PI / 2.0)),
+            "0.25 turns".parse::<Angle>()
+        );
+        assert_eq!(Ok(Angle::FULL_CIRCLE), "1 TURN".parse::<Angle>());
+        // several whitespace characters between the number and the unit.
+        assert_eq!(Ok(d(45.0)), "45 \tdegrees".parse::<Angle>());
+    }
+
+    #[test]
+    fn from_str_no_unit() {
+        assert_eq!(
+            "48.8583 degrees".parse::<Angle>(),
+            "48.8583".parse::<Angle>()
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_unit() {
+        assert_eq!(
+            Err(ParseAngleError::InvalidUnit),
+            "45 furlongs".parse::<Angle>()
+        );
+        assert_eq!(Err(ParseAngleError::InvalidUnit), "45 N".parse::<Angle>());
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            "degrees".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            " 45 degrees".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            "45 degrees ".parse::<Angle>()
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            "45,5 degrees".parse::<Angle>()
+        );
+    }
+
+    // parse_lenient
+
+    #[test]
+    fn parse_lenient_comma_decimal() {
+        assert_eq!(
+            Ok(Angle::from_degrees(48.8583)),
+            Angle::parse_lenient("48,8583")
+        );
+        assert_eq!(
+            Ok(Angle::from_degrees(-2.2945)),
+            Angle::parse_lenient("-2,2945")
+        );
+        assert_eq!(Ok(Angle::from_degrees(0.5)), Angle::parse_lenient(",5"));
+    }
+
+    #[test]
+    fn parse_lenient_whitespace() {
+        assert_eq!(
+            Ok(Angle::from_degrees(48.8583)),
+            Angle::parse_lenient("  48.8583 ")
+        );
+        assert_eq!(
+            Ok(Angle::from_degrees(48.8583)),
+            Angle::parse_lenient("\t48,8583\n")
+        );
+        assert_eq!(Err(ParseAngleError::Empty), Angle::parse_lenient("   "));
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            Angle::parse_lenient("48 8583")
+        );
+    }
+
+    #[test]
+    fn parse_lenient_ambiguous_grouping() {
+        assert_eq!(
+            Err(ParseAngleError::AmbiguousSeparator),
+            Angle::parse_lenient("48,123,456")
+        );
+        assert_eq!(
+            Err(ParseAngleError::AmbiguousSeparator),
+            Angle::parse_lenient("1,234.5")
+        );
+        assert_eq!(
+            Err(ParseAngleError::AmbiguousSeparator),
+            Angle::parse_lenient("1.234,5")
+        );
+    }
+
+    // from_dms
+
+    #[test]
+    fn from_dms() {
+        assert_eq!(Ok(Angle::from_degrees(45.5)), Angle::from_dms(45, 30, 0.0));
+        assert_eq!(
+            Angle::from_degrees(48.858222).round_d6(),
+            Angle::from_dms(48, 51, 29.6).unwrap().round_d6()
+        );
+        assert_eq!(
+            Ok(Angle::from_degrees(-45.5)),
+            Angle::from_dms(-45, 30, 0.0)
+        );
+        assert_eq!(Ok(Angle::from_degrees(-0.5)), Angle::from_dms(0, -30, 0.0));
+        assert_eq!(
+            Ok(Angle::from_degrees(-1.0 / 120.0)),
+            Angle::from_dms(0, 0, -30.0)
+        );
+    }
+
+    #[test]
+    fn from_dms_invalid_minutes() {
+        assert_eq!(
+            Err(DmsError::InvalidMinutes(60)),
+            Angle::from_dms(45, 60, 0.0)
+        );
+        assert_eq!(
+            Err(DmsError::InvalidMinutes(-1)),
+            Angle::from_dms(45, -1, 0.0)
+        );
+        assert_eq!(
+            Err(DmsError::InvalidMinutes(-60)),
+            Angle::from_dms(0, -60, 0.0)
+        );
+    }
+
+    #[test]
+    fn from_dms_invalid_seconds() {
+        assert_eq!(
+            Err(DmsError::InvalidSeconds(60.0)),
+            Angle::from_dms(45, 0, 60.0)
+        );
+        assert_eq!(
+            Err(DmsError::InvalidSeconds(-1.0)),
+            Angle::from_dms(0, 1, -1.0)
+        );
+        assert!(Angle::from_dms(0, 0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn from_dms_latitude() {
+        assert_eq!(
+            Angle::from_degrees(89.5),
+            Angle::from_dms_latitude(89, 30, 0.0).unwrap()
+        );
+        assert_eq!(
+            Ok(Angle::QUARTER_CIRCLE),
+            Angle::from_dms_latitude(90, 0, 0.0)
+        );
+        assert_eq!(
+            Ok(Angle::NEG_QUARTER_CIRCLE),
+            Angle::from_dms_latitude(-90, 0, 0.0)
+        );
+        assert_eq!(
+            Err(DmsError::InvalidDegrees(91)),
+            Angle::from_dms_latitude(91, 0, 0.0)
+        );
+        assert_eq!(
+            Err(DmsError::InvalidDegrees(-90)),
+            Angle::from_dms_latitude(-90, 1, 0.0)
+        );
+        // minutes and seconds are still validated.
+        assert_eq!(
+            Err(DmsError::InvalidMinutes(60)),
+            Angle::from_dms_latitude(45, 60, 0.0)
+        );
+    }
+
+    #[test]
+    fn from_dms_longitude() {
+        assert_eq!(
+            Ok(Angle::from_degrees(91.0)),
+            Angle::from_dms_longitude(91, 0, 0.0)
+        );
+        assert_eq!(
+            Ok(Angle::HALF_CIRCLE),
+            Angle::from_dms_longitude(180, 0, 0.0)
+        );
+        assert_eq!(
+            Err(DmsError::InvalidDegrees(180)),
+            Angle::from_dms_longitude(180, 0, 0.5)
+        );
+        assert_eq!(
+            Err(DmsError::InvalidDegrees(-400)),
+            Angle::from_dms_longitude(-400, 0, 0.0)
+        );
+    }
+
+    // from_hms
+
+    #[test]
+    fn from_hms_conversion_factor() {
+        assert_eq!(Ok(Angle::QUARTER_CIRCLE), Angle::from_hms(6, 0, 0.0));
+        assert_eq!(Ok(Angle::from_degrees(15.0)), Angle::from_hms(1, 0, 0.0));
+        assert_eq!(Ok(Angle::from_degrees(0.25)), Angle::from_hms(0, 1, 0.0));
+        assert_eq!(
+            Ok(Angle::from_degrees(15.0 / 3600.0)),
+            Angle::from_hms(0, 0, 1.0)
+        );
+    }
+
+    #[test]
+    fn from_hms_negative_and_large() {
+        assert_eq!(Ok(Angle::NEG_QUARTER_CIRCLE), Angle::from_hms(-6, 0, 0.0));
+        assert_eq!(Ok(Angle::from_degrees(-7.5)), Angle::from_hms(0, -30, 0.0));
+        assert_eq!(Ok(Angle::from_degrees(450.0)), Angle::from_hms(30, 0, 0.0));
+    }
+
+    #[test]
+    fn from_hms_fractional_seconds() {
+        // right ascension of Sirius: 06h 45m 08.917s.
+        let a = Angle::from_hms(6, 45, 8.917).unwrap();
+        assert_eq!(Angle::from_degrees(101.287154).round_d6(), a.round_d6());
+        let (h, m, s) = a.as_hms();
+        assert_eq!((6, 45), (h, m));
+        assert!((s - 8.917).abs() < 1e-9);
+    }
+
+    #[test]
+    fn from_hms_invalid_minutes() {
+        assert_eq!(
+            Err(HmsError::InvalidMinutes(60)),
+            Angle::from_hms(6, 60, 0.0)
+        );
+        assert_eq!(
+            Err(HmsError::InvalidMinutes(-5)),
+            Angle::from_hms(6, -5, 0.0)
+        );
+    }
+
+    #[test]
+    fn from_hms_invalid_seconds() {
+        assert_eq!(
+            Err(HmsError::InvalidSeconds(60.5)),
+            Angle::from_hms(6, 0, 60.5)
+        );
+        assert!(Angle::from_hms(6, 0, f64::INFINITY).is_err());
+    }
+
+    // as_hms
+
+    #[test]
+    fn as_hms() {
+        assert_eq!((0, 0, 0.0), Angle::ZERO.as_hms());
+        assert_eq!((6, 0, 0.0), Angle::QUARTER_CIRCLE.as_hms());
+        assert_eq!((18, 0, 0.0), Angle::NEG_QUARTER_CIRCLE.as_hms());
+        assert_eq!((6, 0, 0.0), Angle::from_degrees(450.0).as_hms());
+    }
+
+    #[test]
+    fn as_hms_tiny_negative() {
+        assert_eq!((0, 0, 0.0), Angle::from_radians(-1e-16).as_hms());
+        assert_eq!(
+            (0, 0, 0.0),
+            Angle::from_radians(-f64::MIN_POSITIVE).as_hms()
+        );
+        let (h, m, s) = Angle::from_radians(-1e-9).as_hms();
+        assert_eq!((23, 59), (h, m));
+        assert!(s < 60.0);
+    }
+
+    #[test]
+    fn as_hms_round_trip() {
+        for h in 0..24 {
+            for m in (0..60).step_by(7) {
+                let (ah, am, as_) = Angle::from_hms(h, m, 12.5).unwrap().as_hms();
+                assert_eq!((h, m as u8), (ah, am));
+                assert!((as_ - 12.5).abs() < 1e-9);
+            }
+        }
+    }
+
+    // as_radians_normalised
+
+    #[test]
+    fn as_radians_normalised() {
+        assert_eq!(0.0, Angle::ZERO.as_radians_normalised());
+        assert_eq!(PI, Angle::from_degrees(540.0).as_radians_normalised());
+        assert_eq!(PI, Angle::from_degrees(180.0).as_radians_normalised());
+        assert_eq!(PI, Angle::from_degrees(-180.0).as_radians_normalised());
+        assert_eq!(PI, Angle::from_degrees(-540.0).as_radians_normalised());
+        assert_eq!(
+            -PI / 2.0,
+            Angle::from_degrees(270.0).as_radians_normalised()
+        );
+        assert_eq!(
+            PI / 2.0,
+            Angle::from_degrees(-270.0).as_radians_normalised()
+        );
+        assert_eq!(0.0, Angle::FULL_CIRCLE.as_radians_normalised());
+    }
+
+    #[test]
+    fn as_radians_normalised_range() {
+        for d in -1440..=1440 {
+            let r = Angle::from_degrees(d as f64 + 0.5).as_radians_normalised();
+            assert!(r > -PI && r <= PI);
+        }
+    }
+
+    // from_bearing_str
+
+    #[test]
+    fn from_bearing_str() {
+        assert_eq!(Ok(Angle::from_degrees(7.0)), Angle::from_bearing_str("007"));
+        assert_eq!(Ok(Angle::from_degrees(7.0)), Angle::from_bearing_str("7"));
+        assert_eq!(
+            Ok(Angle::from_degrees(90.0)),
+            Angle::from_bearing_str("090")
+        );
+        assert_eq!(
+            Ok(Angle::from_degrees(359.0)),
+            Angle::from_bearing_str("359")
+        );
+        assert_eq!(Ok(Angle::ZERO), Angle::from_bearing_str("000"));
+        assert_eq!(Ok(Angle::ZERO), Angle::from_bearing_str("360"));
+    }
+
+    #[test]
+    fn from_bearing_str_invalid() {
+        assert_eq!(Err(ParseAngleError::Empty), Angle::from_bearing_str(""));
+        assert_eq!(
+            Err(ParseAngleError::InvalidBearing),
+            Angle::from_bearing_str("400")
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidBearing),
+            Angle::from_bearing_str("361")
+        );
+        for s in ["0090", "-90", "+90", " 90", "90°", "90.0", "9O", "１"] {
+            assert_eq!(
+                Err(ParseAngleError::InvalidNumber),
+                Angle::from_bearing_str(s),
+                "{}",
+                s
+            );
+        }
+    }
+
+    // to_bearing_str
+
+    #[test]
+    fn to_bearing_str() {
+        assert_eq!("007", Angle::from_degrees(7.0).to_bearing_str());
+        assert_eq!("090", Angle::from_degrees(90.4).to_bearing_str());
+        assert_eq!("000", Angle::FULL_CIRCLE.to_bearing_str());
+        assert_eq!("000", Angle::from_degrees(359.5).to_bearing_str());
+        assert_eq!("359", Angle::from_degrees(-1.0).to_bearing_str());
+        for d in 0..360 {
+            let a = Angle::from_degrees(d as f64);
+            assert_eq!(Ok(a), Angle::from_bearing_str(&a.to_bearing_str()));
+        }
+    }
+
+    // parse_dms
+
+    #[test]
+    fn parse_dms_decimal_degrees() {
+        assert_eq!(
+            Ok(Angle::from_degrees(48.8567)),
+            Angle::parse_dms("48.8567")
+        );
+        assert_eq!(
+            Ok(Angle::from_degrees(-48.8567)),
+            Angle::parse_dms("-48.8567°")
+        );
+        assert_eq!(
+            Ok(Angle::from_degrees(48.8567)),
+            Angle::parse_dms("+48.8567")
+        );
+        assert_eq!(
+            Ok(Angle::from_degrees(-48.8567)),
+            Angle::parse_dms("48.8567 S")
+        );
+    }
+
+    #[test]
+    fn parse_dms_components() {
+        let e = Angle::from_dms(48, 51, 29.6).unwrap().round_d7();
+        for s in [
+            "48°51'29.6\"",
+            "48° 51' 29.6\"",
+            "48°51′29.6″",
+            "48°51'29.6\"N",
+            "N 48°51'29.6\"",
+            "48°51'29.6\"e",
+        ] {
+            assert_eq!(e, Angle::parse_dms(s).unwrap().round_d7(), "{}", s);
+        }
+        assert_eq!(
+            Angle::from_degrees(-(2.0 + 21.5 / 60.0)).round_d7(),
+            Angle::parse_dms("2°21.5'W").unwrap().round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(-30.0 / 3600.0).round_d7(),
+            Angle::parse_dms("-30\"").unwrap().round_d7()
+        );
+    }
+
+    #[test]
+    fn parse_dms_separators() {
+        let e = Angle::from_dms(40, 26, 46.0).unwrap().round_d7();
+        for s in [
+            "40 26 46",
+            "40:26:46",
+            "40-26-46",
+            "40d26m46s",
+            "40D 26M 46S",
+            "40 d 26 m 46 s",
+            " 40  26  46 ",
+            "40 : 26 : 46",
+            "40-26-46N",
+            "40d26m46sN",
+            "40 26 46 E",
+        ] {
+            assert_eq!(e, Angle::parse_dms(s).unwrap().round_d7(), "{}", s);
+        }
+        assert_eq!(
+            Angle::from_degrees(40.0 + 26.5 / 60.0).round_d7(),
+            Angle::parse_dms("40:26.5").unwrap().round_d7()
+        );
+    }
+
+    #[test]
+    fn parse_dms_separators_negative() {
+        let e = -Angle::from_dms(40, 26, 46.0).unwrap().round_d7();
+        for s in [
+            "-40-26-46",
+            "-40 26 46",
+            "-40:26:46",
+            "-40d26m46s",
+            "40-26-46S",
+            "40 26 46 s",
+            "40d26m46s S",
+            "40°26'46\"s",
+        ] {
+            assert_eq!(e, Angle::parse_dms(s).unwrap().round_d7(), "{}", s);
+        }
+        // 46 degrees south: seconds must be given after minutes with m.
+        assert_eq!(Angle::from_degrees(-46.0), Angle::parse_dms("46s").unwrap());
+    }
+
+    #[test]
+    fn parse_dms_separators_invalid() {
+        for s in [
+            "40--26-46",
+            "40-26-",
+            "40:26:46:10",
+            "40:26'46\"",
+            "40°26 46",
+            "40 26'",
+            "40m26d",
+            "40 - -26",
+        ] {
+            assert_eq!(
+                Err(ParseAngleError::InvalidNumber),
+                Angle::parse_dms(s),
+                "{}",
+                s
+            );
+        }
+        assert_eq!(
+            Err(ParseAngleError::InvalidMinutes),
+            Angle::parse_dms("40:60:00")
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidSeconds),
+            Angle::parse_dms("40-59-60")
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidHemisphere),
+            Angle::parse_dms("-40-26-46N")
+        );
+    }
+
+    #[test]
+    fn parse_dms_invalid() {
+        assert_eq!(Err(ParseAngleError::Empty), Angle::parse_dms(" "));
+        assert_eq!(Err(ParseAngleError::InvalidNumber), Angle::parse_dms("N"));
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            Angle::parse_dms("48°51")
+        );
+        // components out of order.
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            Angle::parse_dms("51'48°")
+        );
+        // fractional minutes followed by seconds.
+        assert_eq!(
+            Err(ParseAngleError::InvalidNumber),
+            Angle::parse_dms("48°51.5'30\"")
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidMinutes),
+            Angle::parse_dms("48°60'")
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidSeconds),
+            Angle::parse_dms("48°59'60\"")
+        );
+        assert_eq!(
+            Err(ParseAngleError::InvalidHemisphere),
+            Angle::parse_dms("-48°N")
+        );
+    }
+
+    // arc_length
+
+    #[test]
+    fn arc_length_round_trip() {
+        for radius in [
+            Length::from_metres(1.0),
+            Length::from_kilometres(1_737.4),
+            Length::from_metres(6_371_000.8),
+            Length::from_kilometres(69_911.0),
+        ] {
+            for d in [-270.0, -45.0, 0.0, 0.001, 1.0, 90.0, 180.0, 359.9] {
+                let a = Angle::from_degrees(d);
+                assert_eq!(
+                    a.round_d7(),
+                    a.arc_length(radius).to_central_angle(radius).round_d7()
+                );
+            }
+            for m in [0.0, 1.0, 1852.0, 1e6] {
+                let l = Length::from_metres(m);
+                assert_eq!(
+                    l.round_mm(),
+                    l.to_central_angle(radius).arc_length(radius).round_mm()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn arc_length_zero_radius() {
+        assert_eq!(
+            Length::ZERO,
+            Angle::from_degrees(10.0).arc_length(Length::ZERO)
+        );
+        assert_eq!(
+            Angle::ZERO,
+            Length::from_metres(10.0).to_central_angle(Length::ZERO)
+        );
+    }
+
+    // Mul<i64>, Div<i64>
+
+    #[test]
+    fn mul_i64() {
+        assert_eq!(Angle::FULL_CIRCLE, Angle::HALF_CIRCLE * 2);
+        assert_eq!(Angle::FULL_CIRCLE, 2 * Angle::HALF_CIRCLE);
+        assert_eq!(Angle::NEG_HALF_CIRCLE, Angle::QUARTER_CIRCLE * -2);
+        for i in -1000..1000 {
+            let a = Angle::from_degrees(1.2345);
+            assert_eq!(a * (i as f64), a * i);
+        }
+    }
+
+    #[test]
+    fn div_i64() {
+        assert_eq!(Angle::QUARTER_CIRCLE, Angle::FULL_CIRCLE / 4);
+        assert_eq!(Angle::HALF_CIRCLE, Angle::FULL_CIRCLE / 2);
+        assert_eq!(Angle::NEG_QUARTER_CIRCLE, Angle::HALF_CIRCLE / -2);
+        assert_eq!(Angle::from_degrees(45.0), Angle::FULL_CIRCLE / 8);
+        for i in 1..1000 {
+            assert_eq!(Angle::FULL_CIRCLE / (i as f64), Angle::FULL_CIRCLE / i);
+        }
+    }
+
+    #[test]
+    fn div_i64_equal_parts() {
+        // dividing a full circle into n parts and summing them back gives a full circle (within rounding).
+        for n in [3, 5, 6, 7, 12, 360] {
+            let part = Angle::FULL_CIRCLE / n;
+            assert!((part * n - Angle::FULL_CIRCLE).as_radians().abs() <= f64::EPSILON * 8.0);
+        }
+    }
 }
@@ -0,0 +1,201 @@
+use crate::Angle;
+
+/// A range of bearings swept clockwise from a start bearing to an end bearing, both inclusive - e.g. the arc of
+/// visibility of a light or the sector of an antenna.
+///
+/// Both bounds are [normalised](crate::Angle::normalised) to [0, 360) degrees: if the start is less than or equal
+/// to the end the range does not wrap, otherwise it wraps across 0 degrees (e.g. from 350 to 10 degrees).
+///
+/// # Examples
+///
+/// ```
+/// use jord::{Angle, AngleRange};
+///
+/// let range = AngleRange::new(Angle::from_degrees(350.0), Angle::from_degrees(10.0));
+/// assert!(range.contains(Angle::from_degrees(355.0)));
+/// assert!(range.contains(Angle::from_degrees(5.0)));
+/// assert!(!range.contains(Angle::from_degrees(180.0)));
+/// assert_eq!(Angle::from_degrees(20.0), range.width().round_d7());
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct AngleRange {
+    start: Angle,
+    end: Angle,
+}
+
+impl AngleRange {
+    /// Creates a new range from the given start and end bearings: the range covers the bearings swept
+    /// clockwise from the start to the end.
+    pub fn new(start: Angle, end: Angle) -> Self {
+        AngleRange {
+            start: start.normalised(),
+            end: end.normalised(),
+        }
+    }
+
+    /// Returns the start bearing of this range, within [0, 360) degrees.
+    #[inline]
+    pub fn start(&self) -> Angle {
+        self.start
+    }
+
+    /// Returns the end bearing of this range, within [0, 360) degrees.
+    #[inline]
+    pub fn end(&self) -> Angle {
+        self.end
+    }
+
+    /// Determines whether this range wraps across 0 degrees - i.e. its start is greater than its end.
+    #[inline]
+    pub fn is_wrapping(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Determines whether the given bearing is within this range, bounds included. The bearing is
+    /// [normalised](crate::Angle::normalised) first, so e.g. -5 degrees is within [350, 10] degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, AngleRange};
+    ///
+    /// let range = AngleRange::new(Angle::from_degrees(90.0), Angle::from_degrees(180.0));
+    /// assert!(range.contains(Angle::from_degrees(90.0)));
+    /// assert!(range.contains(Angle::from_degrees(-225.0)));
+    /// assert!(!range.contains(Angle::from_degrees(181.0)));
+    /// ```
+    pub fn contains(&self, bearing: Angle) -> bool {
+        let b = bearing.normalised();
+        if self.is_wrapping() {
+            b >= self.start || b <= self.end
+        } else {
+            b >= self.start && b <= self.end
+        }
+    }
+
+    /// Returns the size of the arc swept clockwise from the start to the end of this range, within
+    /// [0, 360) degrees.
+    pub fn width(&self) -> Angle {
+        self.end.wrapping_sub(self.start)
+    }
+
+    /// Returns the given bearing [normalised](crate::Angle::normalised) if it is within this range, otherwise
+    /// the bound of this range that is the closest to the bearing (the start if both bounds are equally close).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, AngleRange};
+    ///
+    /// let range = AngleRange::new(Angle::from_degrees(350.0), Angle::from_degrees(10.0));
+    /// assert_eq!(Angle::from_degrees(5.0), range.clamp(Angle::from_degrees(5.0)).round_d7());
+    /// assert_eq!(Angle::from_degrees(10.0), range.clamp(Angle::from_degrees(30.0)));
+    /// assert_eq!(Angle::from_degrees(350.0), range.clamp(Angle::from_degrees(300.0)));
+    /// ```
+    pub fn clamp(&self, bearing: Angle) -> Angle {
+        if self.contains(bearing) {
+            return bearing.normalised();
+        }
+        // the bearing is outside the range: clockwise from the end then to the start.
+        let after_end = bearing.wrapping_sub(self.end);
+        let before_start = self.start.wrapping_sub(bearing);
+        if before_start <= after_end {
+            self.start
+        } else {
+            self.end
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Angle, AngleRange};
+
+    fn range(start: f64, end: f64) -> AngleRange {
+        AngleRange::new(Angle::from_degrees(start), Angle::from_degrees(end))
+    }
+
+    fn d(degrees: f64) -> Angle {
+        Angle::from_degrees(degrees)
+    }
+
+    #[test]
+    fn non_wrapping() {
+        let r = range(30.0, 120.0);
+        assert!(!r.is_wrapping());
+        assert!(r.contains(d(30.0)));
+        assert!(r.contains(d(75.0)));
+        assert!(r.contains(d(120.0)));
+        assert!(r.contains(d(435.0)));
+        assert!(!r.contains(d(29.9)));
+        assert!(!r.contains(d(120.1)));
+        assert!(!r.contains(d(300.0)));
+        assert_eq!(d(90.0), r.width().round_d7());
+    }
+
+    #[test]
+    fn wrapping() {
+        let r = range(300.0, 60.0);
+        assert!(r.is_wrapping());
+        assert!(r.contains(d(300.0)));
+        assert!(r.contains(d(0.0)));
+        assert!(r.contains(d(360.0)));
+        assert!(r.contains(d(-30.0)));
+        assert!(r.contains(d(60.0)));
+        assert!(!r.contains(d(299.9)));
+        assert!(!r.contains(d(60.1)));
+        assert!(!r.contains(d(180.0)));
+        assert_eq!(d(120.0), r.width().round_d7());
+    }
+
+    #[test]
+    fn normalised_bounds() {
+        let r = range(-60.0, 420.0);
+        assert_eq!(d(300.0), r.start().round_d7());
+        assert_eq!(d(60.0), r.end().round_d7());
+        assert!(r.is_wrapping());
+    }
+
+    #[test]
+    fn single_bearing() {
+        let r = range(45.0, 45.0);
+        assert_eq!(Angle::ZERO, r.width());
+        assert!(r.contains(d(45.0)));
+        assert!(!r.contains(d(46.0)));
+        assert_eq!(d(45.0), r.clamp(d(200.0)));
+    }
+
+    #[test]
+    fn width_within_full_circle() {
+        let just_above = Angle::from_radians(10f64.to_radians() + 1e-16);
+        let width = AngleRange::new(just_above, d(10.0)).width();
+        assert!(width >= Angle::ZERO && width < Angle::FULL_CIRCLE);
+    }
+
+    #[test]
+    fn clamp_non_wrapping() {
+        let r = range(30.0, 120.0);
+        assert_eq!(d(75.0), r.clamp(d(75.0)));
+        // just outside each bound.
+        assert_eq!(d(30.0), r.clamp(d(29.0)));
+        assert_eq!(d(120.0), r.clamp(d(121.0)));
+        // across 0: closer to the start.
+        assert_eq!(d(30.0), r.clamp(d(350.0)));
+        // closer to the end, on the other side of the circle.
+        assert_eq!(d(120.0), r.clamp(d(200.0)));
+        // equally close to both bounds.
+        assert_eq!(d(30.0), r.clamp(d(255.0)));
+    }
+
+    #[test]
+    fn clamp_wrapping() {
+        let r = range(300.0, 60.0);
+        assert_eq!(d(350.0), r.clamp(d(-10.0)).round_d7());
+        // just outside each bound.
+        assert_eq!(d(300.0), r.clamp(d(299.0)));
+        assert_eq!(d(60.0), r.clamp(d(61.0)));
+        assert_eq!(d(60.0), r.clamp(d(179.0)));
+        assert_eq!(d(300.0), r.clamp(d(181.0)));
+    }
+}
@@ -0,0 +1,157 @@
+use std::time::Duration;
+
+use crate::{Angle, Dimension, Measurement};
+
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
+/// An angular velocity (e.g. a rate of turn).
+///
+/// It primarily exists to unambiguously represent an angular velocity as opposed to a bare
+/// [f64] (which could be anything and in any unit).
+/// It allows conversion to or from radians/second, degrees/second and degrees/minute.
+///
+/// # Examples
+///
+/// ```
+/// use jord::AngularVelocity;
+///
+/// assert_eq!(1.0, AngularVelocity::from_radians_per_second(1.0).as_radians_per_second());
+/// assert_eq!(180.0, AngularVelocity::from_degrees_per_second(180.0).as_degrees_per_second());
+/// assert_eq!(60.0, AngularVelocity::from_radians_per_second(1.0f64.to_radians()).as_degrees_per_minute());
+/// ```
+///
+/// [AngularVelocity] implements many traits, including [Add](::std::ops::Add), [Sub](::std::ops::Sub),
+/// [Mul](::std::ops::Mul) and [Div](::std::ops::Div), among others.
+///
+/// # Angular velocity from angle and time
+///
+/// ```
+/// use jord::{Angle, AngularVelocity};
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     AngularVelocity::from_radians_per_second(0.5),
+///     Angle::from_radians(10.0) / Duration::from_secs(20)
+/// );
+/// ```
+///
+/// # Angle turned at angular velocity over time
+///
+/// ```
+/// use jord::{Angle, AngularVelocity};
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///     Angle::from_radians(10.0),
+///     AngularVelocity::from_radians_per_second(0.5) * Duration::from_secs(20)
+/// );
+/// ```
+pub struct AngularVelocity {
+    rps: f64,
+}
+
+impl AngularVelocity {
+    /// Zero angular velocity.
+    pub const ZERO: AngularVelocity = AngularVelocity { rps: 0.0 };
+
+    /// Creates an angular velocity from a floating point value in radians per second.
+    pub const fn from_radians_per_second(rps: f64) -> Self {
+        AngularVelocity { rps }
+    }
+
+    /// Creates an angular velocity from a floating point value in degrees per second.
+    pub fn from_degrees_per_second(dps: f64) -> Self {
+        AngularVelocity::from_radians_per_second(dps.to_radians())
+    }
+
+    /// Converts this angular velocity to a floating point value in radians per second.
+    #[inline]
+    pub const fn as_radians_per_second(&self) -> f64 {
+        self.rps
+    }
+
+    /// Converts this angular velocity to a floating point value in degrees per second.
+    pub fn as_degrees_per_second(&self) -> f64 {
+        self.rps.to_degrees()
+    }
+
+    /// Converts this angular velocity to a floating point value in degrees per minute.
+    pub fn as_degrees_per_minute(&self) -> f64 {
+        self.as_degrees_per_second() * 60.0
+    }
+}
+
+impl Measurement for AngularVelocity {
+    const DIMENSION: Dimension = Dimension::AngularVelocity;
+
+    fn from_default_unit(amount: f64) -> Self {
+        AngularVelocity::from_radians_per_second(amount)
+    }
+
+    #[inline]
+    fn as_default_unit(&self) -> f64 {
+        self.rps
+    }
+}
+
+impl_measurement! { AngularVelocity }
+
+impl ::std::ops::Div<Duration> for Angle {
+    type Output = AngularVelocity;
+
+    fn div(self, rhs: Duration) -> AngularVelocity {
+        let rps = self.as_radians() / rhs.as_secs_f64();
+        AngularVelocity::from_radians_per_second(rps)
+    }
+}
+
+impl ::std::ops::Mul<Duration> for AngularVelocity {
+    type Output = Angle;
+
+    fn mul(self, rhs: Duration) -> Angle {
+        let radians = self.as_radians_per_second() * rhs.as_secs_f64();
+        Angle::from_radians(radians)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Angle, AngularVelocity};
+    use std::time::Duration;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            1.0,
+            AngularVelocity::from_degrees_per_second(1.0).as_degrees_per_second()
+        );
+        assert_eq!(
+            60.0,
+            AngularVelocity::from_degrees_per_second(1.0).as_degrees_per_minute()
+        );
+        assert_eq!(
+            1.0f64.to_radians(),
+            AngularVelocity::from_degrees_per_second(1.0).as_radians_per_second()
+        );
+    }
+
+    #[test]
+    fn std_ops() {
+        assert_eq!(
+            AngularVelocity::from_radians_per_second(2.0),
+            2.0 * AngularVelocity::from_radians_per_second(1.0)
+        );
+        assert_eq!(
+            AngularVelocity::from_radians_per_second(-1.0),
+            -AngularVelocity::from_radians_per_second(1.0)
+        );
+        assert_eq!(
+            AngularVelocity::from_radians_per_second(1.0),
+            Angle::from_radians(2.0) / Duration::from_secs(2)
+        );
+        assert_eq!(
+            Angle::from_radians(2.0),
+            AngularVelocity::from_radians_per_second(1.0) * Duration::from_secs(2)
+        );
+    }
+}
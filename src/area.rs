@@ -0,0 +1,236 @@
+use std::fmt;
+
+use crate::{Dimension, Length, Measurement};
+
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
+/// An area (e.g. the area of a polygon).
+///
+/// It primarily exists to unambiguously represent an area as opposed to a bare
+/// [f64] (which could be anything and in any unit).
+/// It allows conversion to or from square metres, square kilometres, hectares and acres.
+///
+/// # Examples
+///
+/// ```
+/// use jord::Area;
+///
+/// assert_eq!(1.0, Area::from_square_metres(1.0).as_square_metres());
+/// assert_eq!(1_000_000.0, Area::from_square_kilometres(1.0).as_square_metres());
+/// assert_eq!(10_000.0, Area::from_hectares(1.0).as_square_metres());
+/// assert_eq!(4_046.8564224, Area::from_acres(1.0).as_square_metres());
+/// ```
+///
+/// [Area] implements many traits, including [Add](::std::ops::Add), [Sub](::std::ops::Sub),
+/// [Mul](::std::ops::Mul) and [Div](::std::ops::Div), among others.
+///
+/// # Area from lengths
+///
+/// ```
+/// use jord::{Area, Length};
+///
+/// assert_eq!(
+///     Area::from_square_kilometres(1.0),
+///     Length::from_kilometres(1.0) * Length::from_kilometres(1.0)
+/// );
+/// ```
+pub struct Area {
+    square_metres: f64,
+}
+
+impl Area {
+    const KM2_TO_M2: f64 = 1_000_000.0;
+
+    const HA_TO_M2: f64 = 10_000.0;
+
+    // international acre: 1 chain (66 ft) by 1 furlong (660 ft).
+    const AC_TO_M2: f64 = 4_046.856_422_4;
+
+    /// Zero area.
+    pub const ZERO: Area = Area { square_metres: 0.0 };
+
+    /// Creates an area from a floating point value in square metres.
+    pub const fn from_square_metres(square_metres: f64) -> Self {
+        Area { square_metres }
+    }
+
+    /// Creates an area from a floating point value in square kilometres.
+    pub fn from_square_kilometres(square_kilometres: f64) -> Self {
+        Area::from_square_metres(square_kilometres * Self::KM2_TO_M2)
+    }
+
+    /// Creates an area from a floating point value in hectares.
+    pub fn from_hectares(hectares: f64) -> Self {
+        Area::from_square_metres(hectares * Self::HA_TO_M2)
+    }
+
+    /// Creates an area from a floating point value in (international) acres.
+    pub fn from_acres(acres: f64) -> Self {
+        Area::from_square_metres(acres * Self::AC_TO_M2)
+    }
+
+    /// Converts this area to a floating point value in square metres.
+    #[inline]
+    pub const fn as_square_metres(&self) -> f64 {
+        self.square_metres
+    }
+
+    /// Converts this area to a floating point value in square kilometres.
+    pub fn as_square_kilometres(&self) -> f64 {
+        self.square_metres / Self::KM2_TO_M2
+    }
+
+    /// Converts this area to a floating point value in hectares.
+    pub fn as_hectares(&self) -> f64 {
+        self.square_metres / Self::HA_TO_M2
+    }
+
+    /// Converts this area to a floating point value in (international) acres.
+    pub fn as_acres(&self) -> f64 {
+        self.square_metres / Self::AC_TO_M2
+    }
+
+    /// Computes the absolute value of this area - e.g. to discard the sign of a
+    /// [signed polygon area](crate::spherical::Sphere::signed_polygon_area).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Area;
+    ///
+    /// assert_eq!(Area::from_square_metres(3.0), Area::from_square_metres(-3.0).abs());
+    /// ```
+    pub fn abs(&self) -> Self {
+        Self::from_square_metres(self.square_metres.abs())
+    }
+
+    /// Rounds this area to the nearest square metre.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Area;
+    ///
+    /// assert_eq!(Area::from_square_metres(3.0), Area::from_square_metres(3.4).round_m2());
+    /// assert_eq!(Area::from_square_metres(4.0), Area::from_square_metres(3.5).round_m2());
+    /// ```
+    pub fn round_m2(&self) -> Self {
+        Self {
+            square_metres: self.square_metres.round(),
+        }
+    }
+
+    /// Rounds this area to the nearest square kilometre.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Area;
+    ///
+    /// assert_eq!(Area::from_square_kilometres(3.0), Area::from_square_metres(3_400_000.0).round_km2());
+    /// ```
+    pub fn round_km2(&self) -> Self {
+        Area::from_square_kilometres(self.as_square_kilometres().round())
+    }
+}
+
+impl Measurement for Area {
+    const DIMENSION: Dimension = Dimension::Area;
+
+    fn from_default_unit(amount: f64) -> Self {
+        Area::from_square_metres(amount)
+    }
+
+    #[inline]
+    fn as_default_unit(&self) -> f64 {
+        self.square_metres
+    }
+}
+
+impl_measurement! { Area }
+
+/// Formats this area in square metres, honouring the precision if any (e.g. `{:.1}`).
+///
+/// # Examples
+///
+/// ```
+/// use jord::Area;
+///
+/// assert_eq!("12.5 m²", Area::from_square_metres(12.5).to_string());
+/// assert_eq!("12.35 m²", format!("{:.2}", Area::from_square_metres(12.3456)));
+/// ```
+impl fmt::Display for Area {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.square_metres, f)?;
+        write!(f, " m²")
+    }
+}
+
+impl ::std::ops::Mul<Length> for Length {
+    type Output = Area;
+
+    fn mul(self, rhs: Length) -> Area {
+        Area::from_square_metres(self.as_metres() * rhs.as_metres())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Area, Length};
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            1.0,
+            Area::from_square_kilometres(1.0).as_square_kilometres()
+        );
+        assert_eq!(100.0, Area::from_square_kilometres(1.0).as_hectares());
+        assert_eq!(0.01, Area::from_hectares(1.0).as_square_kilometres());
+        // 1 hectare is ~ 2.4710538 acres.
+        assert_eq!(
+            2.4710538,
+            (Area::from_hectares(1.0).as_acres() * 1e7).round() / 1e7
+        );
+        // 640 acres in a square mile.
+        assert_eq!(
+            Area::from_acres(640.0).round_m2(),
+            (Length::from_feet(5280.0) * Length::from_feet(5280.0)).round_m2()
+        );
+    }
+
+    #[test]
+    fn square() {
+        let side = Length::from_kilometres(1.0);
+        let a = side * side;
+        assert_eq!(1_000_000.0, a.as_square_metres());
+        assert_eq!(Area::from_square_metres(1_000_000.0), a);
+        assert_eq!(100.0, a.as_hectares());
+    }
+
+    #[test]
+    fn std_ops() {
+        assert_eq!(
+            Area::from_square_metres(3.0),
+            Area::from_square_metres(1.0) + Area::from_square_metres(2.0)
+        );
+        assert_eq!(
+            Area::from_square_metres(-1.0),
+            -Area::from_square_metres(1.0)
+        );
+        assert_eq!(
+            Area::from_square_metres(6.0),
+            2.0 * Area::from_square_metres(3.0)
+        );
+        assert_eq!(
+            2.0,
+            Area::from_square_metres(6.0) / Area::from_square_metres(3.0)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("0 m²", Area::ZERO.to_string());
+        assert_eq!("1000000 m²", Area::from_square_kilometres(1.0).to_string());
+        assert_eq!("4046.9 m²", format!("{:.1}", Area::from_acres(1.0)));
+    }
+}
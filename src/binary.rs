@@ -0,0 +1,268 @@
+//! Compact binary encoding of sequences of positions (e.g. tracks).
+//!
+//! The number of positions is written first as a little-endian `u32`, so that a truncated buffer is detected.
+//! Each position is then quantised to whole microdegrees and written as 2 little-endian `i32`: latitude then
+//! longitude. The first position is written as absolute values, every following position as the difference with
+//! the previous (quantised) position, which keeps values small for tracks sampled at a high rate and makes the
+//! encoding lossless within one microdegree (about 0.11 metres at the equator).
+
+use std::fmt;
+
+use crate::LatLong;
+
+/// Error returned when a sequence of positions cannot be encoded or decoded.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum BinaryError {
+    /// The number of bytes does not match the number of positions written at the start of the bytes (4 bytes
+    /// followed by 8 bytes per position).
+    Truncated,
+    /// The number of positions to encode does not fit in a `u32`.
+    TooManyPositions(usize),
+    /// A latitude (in degrees) to encode or decoded is outside of [-90, 90] or not a number.
+    LatitudeOutOfRange(f64),
+    /// A longitude (in degrees) to encode or decoded is outside of [-180, 180] or not a number.
+    LongitudeOutOfRange(f64),
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryError::Truncated => write!(f, "truncated binary positions"),
+            BinaryError::TooManyPositions(n) => write!(f, "too many positions to encode: {}", n),
+            BinaryError::LatitudeOutOfRange(lat) => {
+                write!(f, "latitude out of range [-90, 90]: {}", lat)
+            }
+            BinaryError::LongitudeOutOfRange(lng) => {
+                write!(f, "longitude out of range [-180, 180]: {}", lng)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {}
+
+/// Number of microdegrees in a degree.
+const MICRODEGREES: f64 = 1e6;
+
+/// Size in bytes of the encoded number of positions.
+const COUNT_SIZE: usize = 4;
+
+/// Size in bytes of an encoded position.
+const POSITION_SIZE: usize = 8;
+
+/// Encodes the given positions: see the [module](crate::binary) documentation for the format. The returned
+/// vector contains 4 bytes followed by 8 bytes per position.
+///
+/// Returns [BinaryError::TooManyPositions] if the number of positions does not fit in a `u32`, or an error if
+/// any latitude is outside of [-90, 90] degrees or any longitude is outside of [-180, 180]
+/// degrees (e.g. a [LatLong] created from degrees that were not normalised), since such positions could not be
+/// decoded.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::binary;
+///
+/// let ps = vec![LatLong::from_degrees(48.8567, 2.3508), LatLong::from_degrees(48.8568, 2.3510)];
+///
+/// let bytes = binary::encode_positions(&ps).unwrap();
+/// assert_eq!(20, bytes.len());
+///
+/// let decoded: Vec<LatLong> = binary::decode_positions(&bytes)
+///     .unwrap()
+///     .iter()
+///     .map(|p| p.round_d6())
+///     .collect();
+/// assert_eq!(ps, decoded);
+/// ```
+pub fn encode_positions(ps: &[LatLong]) -> Result<Vec<u8>, BinaryError> {
+    let count = u32::try_from(ps.len()).map_err(|_| BinaryError::TooManyPositions(ps.len()))?;
+    let mut bytes = Vec::with_capacity(COUNT_SIZE + ps.len() * POSITION_SIZE);
+    bytes.extend_from_slice(&count.to_le_bytes());
+    let mut previous = (0i32, 0i32);
+    for p in ps {
+        let lat_degrees = p.latitude().as_degrees();
+        if !(-90.0..=90.0).contains(&lat_degrees) {
+            return Err(BinaryError::LatitudeOutOfRange(lat_degrees));
+        }
+        let lng_degrees = p.longitude().as_degrees();
+        if !(-180.0..=180.0).contains(&lng_degrees) {
+            return Err(BinaryError::LongitudeOutOfRange(lng_degrees));
+        }
+        let lat = to_microdegrees(lat_degrees);
+        let lng = to_microdegrees(lng_degrees);
+        // latitudes are within [-90e6, 90e6] and longitudes within [-180e6, 180e6]: no overflow.
+        bytes.extend_from_slice(&(lat - previous.0).to_le_bytes());
+        bytes.extend_from_slice(&(lng - previous.1).to_le_bytes());
+        previous = (lat, lng);
+    }
+    Ok(bytes)
+}
+
+/// Decodes the positions from the given bytes: see the [module](crate::binary) documentation for the format.
+///
+/// Returns [BinaryError::Truncated] if the number of bytes does not match the number of positions written at the
+/// start of the bytes, or an error if any decoded latitude or longitude is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::binary::{self, BinaryError};
+///
+/// let bytes = binary::encode_positions(&[LatLong::from_degrees(48.8567, 2.3508)]).unwrap();
+/// assert_eq!(Err(BinaryError::Truncated), binary::decode_positions(&bytes[..7]));
+/// assert_eq!(Err(BinaryError::Truncated), binary::decode_positions(&bytes[..4]));
+/// ```
+pub fn decode_positions(bytes: &[u8]) -> Result<Vec<LatLong>, BinaryError> {
+    if bytes.len() < COUNT_SIZE {
+        return Err(BinaryError::Truncated);
+    }
+    let (count, positions) = bytes.split_at(COUNT_SIZE);
+    let count = read_u32(count) as usize;
+    // compared by division so that a corrupted count cannot overflow.
+    if positions.len() % POSITION_SIZE != 0 || positions.len() / POSITION_SIZE != count {
+        return Err(BinaryError::Truncated);
+    }
+    let mut ps = Vec::with_capacity(count);
+    let mut previous = (0i64, 0i64);
+    for chunk in positions.chunks_exact(POSITION_SIZE) {
+        let lat = previous.0 + i64::from(read_i32(&chunk[..4]));
+        let lng = previous.1 + i64::from(read_i32(&chunk[4..]));
+        let lat_degrees = lat as f64 / MICRODEGREES;
+        if !(-90.0..=90.0).contains(&lat_degrees) {
+            return Err(BinaryError::LatitudeOutOfRange(lat_degrees));
+        }
+        let lng_degrees = lng as f64 / MICRODEGREES;
+        if !(-180.0..=180.0).contains(&lng_degrees) {
+            return Err(BinaryError::LongitudeOutOfRange(lng_degrees));
+        }
+        ps.push(LatLong::from_degrees(lat_degrees, lng_degrees));
+        previous = (lat, lng);
+    }
+    Ok(ps)
+}
+
+fn to_microdegrees(degrees: f64) -> i32 {
+    (degrees * MICRODEGREES).round() as i32
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_i32(bytes: &[u8]) -> i32 {
+    i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{
+        binary::{decode_positions, encode_positions, BinaryError},
+        LatLong,
+    };
+
+    fn track() -> Vec<LatLong> {
+        vec![
+            LatLong::from_degrees(55.605, 13.0038),
+            LatLong::from_degrees(55.4295, 13.82),
+            LatLong::from_degrees(-33.8688, 151.2093),
+            LatLong::from_degrees(-16.5, 179.9999),
+            LatLong::from_degrees(-16.5, -179.9999),
+            LatLong::from_degrees(90.0, 180.0),
+            LatLong::from_degrees(-90.0, -180.0),
+            LatLong::from_degrees(0.0000004, -0.0000006),
+        ]
+    }
+
+    /// Returns the given number of positions followed by the given latitudes and longitudes as written by
+    /// [encode_positions].
+    fn encoded(count: u32, values: &[i32]) -> Vec<u8> {
+        let mut bytes = count.to_le_bytes().to_vec();
+        for v in values {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Ok(vec![0, 0, 0, 0]), encode_positions(&[]));
+        assert_eq!(Ok(Vec::new()), decode_positions(&[0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn round_trip() {
+        let ps = track();
+        let bytes = encode_positions(&ps).unwrap();
+        assert_eq!(4 + ps.len() * 8, bytes.len());
+        let decoded = decode_positions(&bytes).unwrap();
+        assert_eq!(ps.len(), decoded.len());
+        for (p, d) in ps.iter().zip(decoded.iter()) {
+            assert_eq!(p.round_d6(), *d);
+            assert!((p.latitude() - d.latitude()).as_degrees().abs() <= 0.5e-6);
+            assert!((p.longitude() - d.longitude()).as_degrees().abs() <= 0.5e-6);
+        }
+        // encoding the decoded positions is lossless.
+        assert_eq!(Ok(bytes), encode_positions(&decoded));
+    }
+
+    #[test]
+    fn deltas() {
+        let bytes = encode_positions(&[
+            LatLong::from_degrees(1.0, 2.0),
+            LatLong::from_degrees(1.000001, 1.999998),
+        ])
+        .unwrap();
+        assert_eq!(encoded(2, &[1_000_000, 2_000_000, 1, -2]), bytes);
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = encode_positions(&track()).unwrap();
+        for len in [0, 1, 3, 4, 7, 12, 20, bytes.len() - 8, bytes.len() - 1] {
+            assert_eq!(Err(BinaryError::Truncated), decode_positions(&bytes[..len]));
+        }
+        assert!(decode_positions(&bytes).is_ok());
+
+        // trailing bytes.
+        let mut longer = bytes.clone();
+        longer.extend_from_slice(&[0; 8]);
+        assert_eq!(Err(BinaryError::Truncated), decode_positions(&longer));
+
+        // count which would overflow the expected number of bytes.
+        assert_eq!(
+            Err(BinaryError::Truncated),
+            decode_positions(&encoded(u32::MAX, &[0, 0]))
+        );
+    }
+
+    #[test]
+    fn encode_out_of_range() {
+        let ok = LatLong::from_degrees(10.0, 20.0);
+        assert_eq!(
+            Err(BinaryError::LatitudeOutOfRange(-3000.0)),
+            encode_positions(&[ok, LatLong::from_degrees(-3000.0, 3000.0)])
+        );
+        assert_eq!(
+            Err(BinaryError::LongitudeOutOfRange(-180.5)),
+            encode_positions(&[LatLong::from_degrees(0.0, -180.5), ok])
+        );
+        assert!(encode_positions(&[LatLong::from_degrees(f64::NAN, 0.0)]).is_err());
+        assert!(encode_positions(&[ok, LatLong::from_degrees(0.0, f64::INFINITY)]).is_err());
+    }
+
+    #[test]
+    fn decode_out_of_range() {
+        assert_eq!(
+            Err(BinaryError::LatitudeOutOfRange(90.000001)),
+            decode_positions(&encoded(2, &[90_000_000, 0, 1, 0]))
+        );
+        assert_eq!(
+            Err(BinaryError::LongitudeOutOfRange(i32::MIN as f64 / 1e6)),
+            decode_positions(&encoded(1, &[0, i32::MIN]))
+        );
+    }
+}
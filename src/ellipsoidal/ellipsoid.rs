@@ -44,6 +44,31 @@ impl Ellipsoid {
         flattening: 0.005889281507656065f64,
     };
 
+    /// [Airy 1830](https://en.wikipedia.org/wiki/Ordnance_Survey_National_Grid) Ellipsoid (Ordnance Survey
+    /// Great Britain 1936).
+    pub const AIRY1830: Ellipsoid = Ellipsoid {
+        equatorial_radius: Length::from_metres(6_377_563.396f64),
+        polar_radius: Length::from_metres(6_356_256.909237285f64),
+        eccentricity: 0.08167337387414043f64,
+        flattening: 0.0033408506414970775f64,
+    };
+
+    /// International 1924 (Hayford) Ellipsoid (European Datum 1950).
+    pub const INTERNATIONAL1924: Ellipsoid = Ellipsoid {
+        equatorial_radius: Length::from_metres(6_378_388.0f64),
+        polar_radius: Length::from_metres(6_356_911.9461279465f64),
+        eccentricity: 0.08199188997902888f64,
+        flattening: 0.003367003367003367f64,
+    };
+
+    /// Clarke 1866 Ellipsoid (North American Datum 1927).
+    pub const CLARKE1866: Ellipsoid = Ellipsoid {
+        equatorial_radius: Length::from_metres(6_378_206.4f64),
+        polar_radius: Length::from_metres(6_356_583.800000007f64),
+        eccentricity: 0.08227185422298973f64,
+        flattening: 0.0033900753039276207f64,
+    };
+
     /// Creates a new ellipsoid from the given equatorial radius (semi-major axis A) and
     /// inverse (or reciprocal) flattening.
     pub fn new(equatorial_radius: Length, inverse_flattening: f64) -> Self {
@@ -59,6 +84,29 @@ impl Ellipsoid {
         }
     }
 
+    /// Creates a new spherical model - i.e. an ellipsoid with a flattening of 0 - of the given radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Length;
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// let s = Ellipsoid::sphere(Length::from_metres(6_371_000.8));
+    /// assert_eq!(s.equatorial_radius(), s.polar_radius());
+    /// assert_eq!(0.0, s.flattening());
+    /// assert_eq!(0.0, s.eccentricity());
+    /// assert!(s.inverse_flattening().is_infinite());
+    /// ```
+    pub const fn sphere(radius: Length) -> Self {
+        Ellipsoid {
+            equatorial_radius: radius,
+            polar_radius: radius,
+            eccentricity: 0.0,
+            flattening: 0.0,
+        }
+    }
+
     /// Returns the equatorial radius (or semi-major axis A) of this ellipsoid.
     #[inline]
     pub fn equatorial_radius(&self) -> Length {
@@ -71,6 +119,18 @@ impl Ellipsoid {
         self.polar_radius
     }
 
+    /// Returns the semi-major axis (or equatorial radius A) of this ellipsoid.
+    #[inline]
+    pub fn semi_major_axis(&self) -> Length {
+        self.equatorial_radius
+    }
+
+    /// Returns the semi-minor axis (or polar radius B) of this ellipsoid.
+    #[inline]
+    pub fn semi_minor_axis(&self) -> Length {
+        self.polar_radius
+    }
+
     /// Returns the eccentricity of this ellipsoid.
     #[inline]
     pub fn eccentricity(&self) -> f64 {
@@ -83,9 +143,35 @@ impl Ellipsoid {
         self.flattening
     }
 
+    /// Returns the inverse (or reciprocal) flattening of this ellipsoid; infinite for a sphere.
+    #[inline]
+    pub fn inverse_flattening(&self) -> f64 {
+        1.0 / self.flattening
+    }
+
+    /// Returns the first eccentricity squared (`e²`) of this ellipsoid, computed from the flattening
+    /// as `f * (2 - f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// assert!((Ellipsoid::WGS84.eccentricity_squared() - 0.00669437999014).abs() < 1e-14);
+    /// ```
+    #[inline]
+    pub fn eccentricity_squared(&self) -> f64 {
+        self.flattening * (2.0 - self.flattening)
+    }
+
     /// Returns the geocentric radius at the given geodetic latitude: the distance from the Earth's center
     /// to a point on the spheroid surface at geodetic latitude.
     ///
+    /// This is the radius at latitude to use for a spherical approximation of this ellipsoid local to the given
+    /// latitude - e.g. `Sphere::new(Ellipsoid::WGS84.geocentric_radius(latitude))`; see also
+    /// [mean_radius](crate::ellipsoidal::Ellipsoid::mean_radius) and
+    /// [authalic_radius](crate::ellipsoidal::Ellipsoid::authalic_radius) for global approximations.
+    ///
     /// See: [Location-dependent radii](https://en.wikipedia.org/wiki/Earth_radius#Location-dependent_radii)
     ///
     /// # Examples
@@ -188,6 +274,26 @@ impl Ellipsoid {
         (2.0 * a + b) / 3.0
     }
 
+    /// Returns the authalic radius of this ellipsoid: the radius of sphere of same surface area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Length;
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// assert_eq!(Length::from_metres(6_371_007.2), Ellipsoid::WGS84.authalic_radius().round_dm());
+    /// ```
+    pub fn authalic_radius(&self) -> Length {
+        let a = self.equatorial_radius().as_metres();
+        let e = self.eccentricity;
+        if e == 0.0 {
+            return self.equatorial_radius;
+        }
+        let r2 = a * a / 2.0 * (1.0 + (1.0 - e * e) / (2.0 * e) * ((1.0 + e) / (1.0 - e)).ln());
+        Length::from_metres(r2.sqrt())
+    }
+
     /// Returns the volumetric radius of this ellipsoid: the radius of sphere of same volume.
     ///
     /// # Examples
@@ -309,6 +415,71 @@ mod tests {
         assert_eq!(Ellipsoid::MOLA.flattening(), mola.flattening());
     }
 
+    #[test]
+    fn airy1830() {
+        let airy1830 = Ellipsoid::new(Length::from_metres(6_377_563.396), 299.3249646);
+        assert_eq!(Ellipsoid::AIRY1830, airy1830);
+    }
+
+    #[test]
+    fn international1924() {
+        let international1924 = Ellipsoid::new(Length::from_metres(6_378_388.0), 297.0);
+        assert_eq!(Ellipsoid::INTERNATIONAL1924, international1924);
+    }
+
+    #[test]
+    fn clarke1866() {
+        let clarke1866 = Ellipsoid::new(Length::from_metres(6_378_206.4), 294.978698214);
+        assert_eq!(Ellipsoid::CLARKE1866, clarke1866);
+    }
+
+    #[test]
+    fn sphere() {
+        let r = Length::from_metres(6_371_000.8);
+        let s = Ellipsoid::sphere(r);
+        assert_eq!(r, s.semi_major_axis());
+        assert_eq!(r, s.semi_minor_axis());
+        assert_eq!(0.0, s.eccentricity());
+        assert_eq!(0.0, s.eccentricity_squared());
+        assert_eq!(r, s.mean_radius());
+        assert_eq!(r, s.geocentric_radius(Angle::from_degrees(45.0)));
+    }
+
+    // eccentricity_squared
+
+    #[test]
+    fn eccentricity_squared_wgs84() {
+        assert!((Ellipsoid::WGS84.eccentricity_squared() - 0.00669437999014).abs() < 1e-14);
+        let e = Ellipsoid::WGS84.eccentricity();
+        assert!((Ellipsoid::WGS84.eccentricity_squared() - e * e).abs() < 1e-15);
+    }
+
+    #[test]
+    fn eccentricity_squared_grs80() {
+        assert!((Ellipsoid::GRS80.eccentricity_squared() - 0.00669438002290).abs() < 1e-14);
+        let e = Ellipsoid::GRS80.eccentricity();
+        assert!((Ellipsoid::GRS80.eccentricity_squared() - e * e).abs() < 1e-15);
+    }
+
+    #[test]
+    fn inverse_flattening() {
+        assert!((Ellipsoid::WGS84.inverse_flattening() - 298.257223563).abs() < 1e-9);
+        assert!((Ellipsoid::GRS80.inverse_flattening() - 298.257222101).abs() < 1e-9);
+        assert!((Ellipsoid::AIRY1830.inverse_flattening() - 299.3249646).abs() < 1e-9);
+    }
+
+    #[test]
+    fn semi_axes() {
+        assert_eq!(
+            Ellipsoid::WGS84.equatorial_radius(),
+            Ellipsoid::WGS84.semi_major_axis()
+        );
+        assert_eq!(
+            Ellipsoid::WGS84.polar_radius(),
+            Ellipsoid::WGS84.semi_minor_axis()
+        );
+    }
+
     #[test]
     fn geocentric_radius() {
         assert_eq!(
@@ -365,6 +536,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mean_radius_equals_arithmetic_mean_of_axes() {
+        let a = Ellipsoid::GRS80.semi_major_axis().as_metres();
+        let b = Ellipsoid::GRS80.semi_minor_axis().as_metres();
+        let expected = (2.0 * a + b) / 3.0;
+        assert!((Ellipsoid::GRS80.mean_radius().as_metres() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn geocentric_radius_at_equator() {
+        for e in [
+            Ellipsoid::WGS84,
+            Ellipsoid::GRS80,
+            Ellipsoid::AIRY1830,
+            Ellipsoid::CLARKE1866,
+        ] {
+            assert_eq!(e.semi_major_axis(), e.geocentric_radius(Angle::ZERO));
+        }
+    }
+
+    #[test]
+    fn authalic_radius() {
+        assert_eq!(
+            Length::from_metres(6_371_007.2),
+            Ellipsoid::WGS84.authalic_radius().round_dm()
+        );
+        let r = Length::from_metres(1_000.0);
+        assert_eq!(r, Ellipsoid::sphere(r).authalic_radius());
+    }
+
+    #[test]
+    fn authalic_radius_between_polar_and_equatorial() {
+        let r = Ellipsoid::WGS84.authalic_radius();
+        assert!(r < Ellipsoid::WGS84.semi_major_axis());
+        assert!(r > Ellipsoid::WGS84.semi_minor_axis());
+        assert!(r < Ellipsoid::WGS84.mean_radius());
+    }
+
     #[test]
     fn volumetric_radius() {
         let r = (Ellipsoid::WGS84.volumetric_radius().as_metres() * 10.0).round() / 10.0;
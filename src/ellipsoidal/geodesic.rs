@@ -0,0 +1,749 @@
+use std::f64::consts::PI;
+
+use crate::{spherical::Sphere, Angle, LatLong, Length, NavigationError};
+
+use super::Ellipsoid;
+
+/// Maximum number of iterations of Vincenty's inverse formula.
+const VINCENTY_MAX_ITERATIONS: usize = 200;
+
+/// Convergence threshold of the longitude on the auxiliary sphere in radians (about 0.006 millimetres).
+const VINCENTY_EPSILON: f64 = 1e-12;
+
+/// Method used to compute a [geodesic distance](crate::LatLong::geodesic_distance).
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum GeodesicMethod {
+    /// Vincenty's inverse formula on the ellipsoid.
+    Vincenty,
+    /// Great circle distance on a sphere whose radius is the [mean radius](crate::ellipsoidal::Ellipsoid::mean_radius)
+    /// of the ellipsoid: used when Vincenty's inverse formula does not converge.
+    Spherical,
+}
+
+impl LatLong {
+    /// Computes the length of the geodesic between this position and the given position on the given ellipsoid
+    /// using [Vincenty's inverse formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae), which is
+    /// accurate to within 0.5 millimetres on the Earth ellipsoid.
+    ///
+    /// Vincenty's inverse formula fails to converge for nearly antipodal positions: see
+    /// [geodesic_distance](crate::LatLong::geodesic_distance) for a function which always returns a distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length, NavigationError};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// // Flinders Peak to Buninyong.
+    /// let p1 = LatLong::new(
+    ///     Angle::from_dms(-37, 57, 3.7203).unwrap(),
+    ///     Angle::from_dms(144, 25, 29.5244).unwrap(),
+    /// );
+    /// let p2 = LatLong::new(
+    ///     Angle::from_dms(-37, 39, 10.1561).unwrap(),
+    ///     Angle::from_dms(143, 55, 35.3839).unwrap(),
+    /// );
+    /// assert_eq!(
+    ///     Ok(Length::from_metres(54_972.271)),
+    ///     p1.vincenty_distance(&p2, &Ellipsoid::WGS84).map(|d| d.round_mm())
+    /// );
+    ///
+    /// let p3 = LatLong::from_degrees(0.0, 0.0);
+    /// let p4 = LatLong::from_degrees(0.5, 179.7);
+    /// assert_eq!(
+    ///     Err(NavigationError::NonConvergence),
+    ///     p3.vincenty_distance(&p4, &Ellipsoid::WGS84)
+    /// );
+    /// ```
+    pub fn vincenty_distance(
+        &self,
+        other: &LatLong,
+        ellipsoid: &Ellipsoid,
+    ) -> Result<Length, NavigationError> {
+        self.vincenty_inverse(other, ellipsoid).map(|(d, _, _)| d)
+    }
+
+    /// Solves the inverse geodesic problem between this position and the given position on the given ellipsoid
+    /// using [Vincenty's inverse formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae): returns the length
+    /// of the geodesic, the initial azimuth (at this position) and the final azimuth (at the given position),
+    /// both azimuths being within [0, 360) degrees. Both azimuths are zero if the positions are coincident.
+    ///
+    /// Returns [NavigationError::NonConvergence] if Vincenty's inverse formula fails to converge, which happens
+    /// for nearly antipodal positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// // Flinders Peak to Buninyong.
+    /// let p1 = LatLong::new(
+    ///     Angle::from_dms(-37, 57, 3.7203).unwrap(),
+    ///     Angle::from_dms(144, 25, 29.5244).unwrap(),
+    /// );
+    /// let p2 = LatLong::new(
+    ///     Angle::from_dms(-37, 39, 10.1561).unwrap(),
+    ///     Angle::from_dms(143, 55, 35.3839).unwrap(),
+    /// );
+    /// let (d, a1, a2) = p1.vincenty_inverse(&p2, &Ellipsoid::WGS84).unwrap();
+    /// assert_eq!(Length::from_metres(54_972.271), d.round_mm());
+    /// // within 0.01 arcseconds of 306°52'05.37" and 127°10'25.07" + 180°.
+    /// let arcsec = |a: Angle, b: Angle| (a - b).as_degrees().abs() * 3600.0;
+    /// assert!(arcsec(Angle::from_dms(306, 52, 5.37).unwrap(), a1) < 0.01);
+    /// assert!(arcsec(Angle::from_dms(307, 10, 25.07).unwrap(), a2) < 0.01);
+    /// ```
+    pub fn vincenty_inverse(
+        &self,
+        other: &LatLong,
+        ellipsoid: &Ellipsoid,
+    ) -> Result<(Length, Angle, Angle), NavigationError> {
+        let a = ellipsoid.equatorial_radius().as_metres();
+        let b = ellipsoid.polar_radius().as_metres();
+        let f = ellipsoid.flattening();
+
+        // difference of longitudes within [-π, π]: across the antimeridian the shortest way round.
+        let l = (other.longitude() - self.longitude()).as_radians_normalised();
+        // reduced latitudes.
+        let u1 = ((1.0 - f) * self.latitude().as_radians().tan()).atan();
+        let u2 = ((1.0 - f) * other.latitude().as_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        for _ in 0..VINCENTY_MAX_ITERATIONS {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let x = cos_u2 * sin_lambda;
+            let y = cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda;
+            let sin_sigma = (x * x + y * y).sqrt();
+            if sin_sigma == 0.0 {
+                // coincident positions.
+                return Ok((Length::ZERO, Angle::ZERO, Angle::ZERO));
+            }
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+            // on the equator cos2_alpha is 0.
+            let cos_2sigma_m = if cos2_alpha == 0.0 {
+                0.0
+            } else {
+                cos_sigma - 2.0 * sin_u1 * sin_u2 / cos2_alpha
+            };
+            let c = f / 16.0 * cos2_alpha * (4.0 + f * (4.0 - 3.0 * cos2_alpha));
+            let lambda_prev = lambda;
+            lambda = l
+                + (1.0 - c)
+                    * f
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m
+                                + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+            if lambda.abs() > PI + VINCENTY_EPSILON {
+                // the iteration diverges: nearly antipodal positions.
+                return Err(NavigationError::NonConvergence);
+            }
+            if (lambda - lambda_prev).abs() <= VINCENTY_EPSILON {
+                let u_sq = cos2_alpha * (a * a - b * b) / (b * b);
+                let big_a = 1.0
+                    + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+                let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+                let delta_sigma = big_b
+                    * sin_sigma
+                    * (cos_2sigma_m
+                        + big_b / 4.0
+                            * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                                - big_b / 6.0
+                                    * cos_2sigma_m
+                                    * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                                    * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+                let distance = Length::from_metres(b * big_a * (sigma - delta_sigma));
+                let (sin_lambda, cos_lambda) = lambda.sin_cos();
+                let initial =
+                    (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+                let final_ =
+                    (cos_u1 * sin_lambda).atan2(-sin_u1 * cos_u2 + cos_u1 * sin_u2 * cos_lambda);
+                return Ok((
+                    distance,
+                    Angle::from_radians(initial).normalised(),
+                    Angle::from_radians(final_).normalised(),
+                ));
+            }
+        }
+        Err(NavigationError::NonConvergence)
+    }
+
+    /// Solves the direct geodesic problem on the given ellipsoid using
+    /// [Vincenty's direct formula](https://en.wikipedia.org/wiki/Vincenty%27s_formulae): returns the position
+    /// reached from this position after travelling the given distance along the geodesic starting at the given
+    /// azimuth, and the final azimuth at that position (within [0, 360) degrees).
+    ///
+    /// Unlike the inverse formula, the direct formula converges for any distance, including distances longer than
+    /// half of the circumference of the ellipsoid. When starting from a pole, the azimuth is interpreted as in
+    /// [vincenty_inverse](crate::LatLong::vincenty_inverse): the destination longitude is 180 degrees minus the
+    /// azimuth from the north pole and the azimuth from the south pole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// // Flinders Peak to Buninyong.
+    /// let p1 = LatLong::new(
+    ///     Angle::from_dms(-37, 57, 3.7203).unwrap(),
+    ///     Angle::from_dms(144, 25, 29.5244).unwrap(),
+    /// );
+    /// let (p2, a2) = p1.vincenty_direct(
+    ///     Angle::from_dms(306, 52, 5.37).unwrap(),
+    ///     Length::from_metres(54_972.271),
+    ///     &Ellipsoid::WGS84,
+    /// );
+    /// // within 0.0001 arcseconds of -37°39'10.1561" and 143°55'35.3839".
+    /// let arcsec = |a: Angle, b: Angle| (a - b).as_degrees().abs() * 3600.0;
+    /// assert!(arcsec(Angle::from_dms(-37, 39, 10.1561).unwrap(), p2.latitude()) < 1e-4);
+    /// assert!(arcsec(Angle::from_dms(143, 55, 35.3839).unwrap(), p2.longitude()) < 1e-4);
+    /// // within 0.01 arcseconds of 127°10'25.07" + 180°.
+    /// assert!(arcsec(Angle::from_dms(307, 10, 25.07).unwrap(), a2) < 0.01);
+    /// ```
+    pub fn vincenty_direct(
+        &self,
+        azimuth: Angle,
+        distance: Length,
+        ellipsoid: &Ellipsoid,
+    ) -> (LatLong, Angle) {
+        let a = ellipsoid.equatorial_radius().as_metres();
+        let b = ellipsoid.polar_radius().as_metres();
+        let f = ellipsoid.flattening();
+        let s = distance.as_metres();
+
+        let (sin_alpha1, cos_alpha1) = azimuth.as_radians().sin_cos();
+        // reduced latitude.
+        let u1 = ((1.0 - f) * self.latitude().as_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        // angular distance on the auxiliary sphere from the equator to this position.
+        let sigma1 = sin_u1.atan2(cos_u1 * cos_alpha1);
+        // azimuth of the geodesic at the equator.
+        let sin_alpha = cos_u1 * sin_alpha1;
+        let cos2_alpha = 1.0 - sin_alpha * sin_alpha;
+        let u_sq = cos2_alpha * (a * a - b * b) / (b * b);
+        let big_a =
+            1.0 + u_sq / 16384.0 * (4096.0 + u_sq * (-768.0 + u_sq * (320.0 - 175.0 * u_sq)));
+        let big_b = u_sq / 1024.0 * (256.0 + u_sq * (-128.0 + u_sq * (74.0 - 47.0 * u_sq)));
+
+        let mut sigma = s / (b * big_a);
+        let mut sin_sigma = sigma.sin();
+        let mut cos_sigma = sigma.cos();
+        let mut cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+        for _ in 0..VINCENTY_MAX_ITERATIONS {
+            let delta_sigma = big_b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + big_b / 4.0
+                        * (cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)
+                            - big_b / 6.0
+                                * cos_2sigma_m
+                                * (-3.0 + 4.0 * sin_sigma * sin_sigma)
+                                * (-3.0 + 4.0 * cos_2sigma_m * cos_2sigma_m)));
+            let sigma_prev = sigma;
+            sigma = s / (b * big_a) + delta_sigma;
+            sin_sigma = sigma.sin();
+            cos_sigma = sigma.cos();
+            cos_2sigma_m = (2.0 * sigma1 + sigma).cos();
+            if (sigma - sigma_prev).abs() <= VINCENTY_EPSILON {
+                break;
+            }
+        }
+
+        let x = sin_u1 * sin_sigma - cos_u1 * cos_sigma * cos_alpha1;
+        let lat = (sin_u1 * cos_sigma + cos_u1 * sin_sigma * cos_alpha1)
+            .atan2((1.0 - f) * (sin_alpha * sin_alpha + x * x).sqrt());
+        let lambda =
+            (sin_sigma * sin_alpha1).atan2(cos_u1 * cos_sigma - sin_u1 * sin_sigma * cos_alpha1);
+        let c = f / 16.0 * cos2_alpha * (4.0 + f * (4.0 - 3.0 * cos2_alpha));
+        let l = lambda
+            - (1.0 - c)
+                * f
+                * sin_alpha
+                * (sigma
+                    + c * sin_sigma
+                        * (cos_2sigma_m
+                            + c * cos_sigma * (-1.0 + 2.0 * cos_2sigma_m * cos_2sigma_m)));
+        // longitude within [-180, 180).
+        let lng = (self.longitude().as_radians() + l + PI).rem_euclid(2.0 * PI) - PI;
+        let final_ = Angle::from_radians(sin_alpha.atan2(-x)).normalised();
+        (
+            LatLong::new(Angle::from_radians(lat), Angle::from_radians(lng)),
+            final_,
+        )
+    }
+
+    /// Computes the length of the geodesic between this position and the given position on the given ellipsoid:
+    /// this function first tries [Vincenty's inverse formula](crate::LatLong::vincenty_distance) and, if it does
+    /// not converge (nearly antipodal positions), falls back to the great circle distance on a sphere whose radius
+    /// is the [mean radius](crate::ellipsoidal::Ellipsoid::mean_radius) of the ellipsoid. The returned
+    /// [GeodesicMethod] tells which method was used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::ellipsoidal::{Ellipsoid, GeodesicMethod};
+    ///
+    /// let p1 = LatLong::from_degrees(0.0, 0.0);
+    ///
+    /// let (d, m) = p1.geodesic_distance(&LatLong::from_degrees(0.0, 1.0), &Ellipsoid::WGS84);
+    /// assert_eq!(GeodesicMethod::Vincenty, m);
+    /// assert_eq!(Length::from_metres(111_319.491), d.round_mm());
+    ///
+    /// let (_, m) = p1.geodesic_distance(&LatLong::from_degrees(0.5, 179.7), &Ellipsoid::WGS84);
+    /// assert_eq!(GeodesicMethod::Spherical, m);
+    /// ```
+    pub fn geodesic_distance(
+        &self,
+        other: &LatLong,
+        ellipsoid: &Ellipsoid,
+    ) -> (Length, GeodesicMethod) {
+        match self.vincenty_distance(other, ellipsoid) {
+            Ok(d) => (d, GeodesicMethod::Vincenty),
+            Err(_) => {
+                let sphere = Sphere::new(ellipsoid.mean_radius());
+                let d = sphere.distance(self.to_nvector(), other.to_nvector());
+                (d, GeodesicMethod::Spherical)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ellipsoidal::{Ellipsoid, GeodesicMethod},
+        spherical::Sphere,
+        Angle, LatLong, Length, NavigationError,
+    };
+
+    #[test]
+    fn vincenty_coincident() {
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        assert_eq!(Ok(Length::ZERO), p.vincenty_distance(&p, &Ellipsoid::WGS84));
+    }
+
+    #[test]
+    fn vincenty_equator() {
+        // along the equator the geodesic is the equator: a quarter of the circumference.
+        let d = LatLong::from_degrees(0.0, 0.0)
+            .vincenty_distance(&LatLong::from_degrees(0.0, 90.0), &Ellipsoid::WGS84)
+            .unwrap();
+        let e = Ellipsoid::WGS84.equatorial_radius() * std::f64::consts::PI / 2.0;
+        assert_eq!(e.round_mm(), d.round_mm());
+    }
+
+    #[test]
+    fn vincenty_meridian() {
+        // pole to pole: twice the meridian arc, which is ~ 20_003_931.4586 metres on WGS84.
+        let d = LatLong::from_degrees(90.0, 0.0)
+            .vincenty_distance(&LatLong::from_degrees(-90.0, 0.0), &Ellipsoid::WGS84)
+            .unwrap();
+        assert_eq!(Length::from_metres(20_003_931.459), d.round_mm());
+    }
+
+    #[test]
+    fn vincenty_symmetric() {
+        // Flinders Peak to Buninyong.
+        let p1 = LatLong::new(
+            Angle::from_dms(-37, 57, 3.7203).unwrap(),
+            Angle::from_dms(144, 25, 29.5244).unwrap(),
+        );
+        let p2 = LatLong::new(
+            Angle::from_dms(-37, 39, 10.1561).unwrap(),
+            Angle::from_dms(143, 55, 35.3839).unwrap(),
+        );
+        let d12 = p1.vincenty_distance(&p2, &Ellipsoid::WGS84).unwrap();
+        let d21 = p2.vincenty_distance(&p1, &Ellipsoid::WGS84).unwrap();
+        assert_eq!(Length::from_metres(54_972.271), d12.round_mm());
+        assert_eq!(d12.round_mm(), d21.round_mm());
+    }
+
+    // Vincenty (1975), test lines (a) to (e): Bessel ellipsoid for (a), International ellipsoid otherwise.
+
+    fn bessel() -> Ellipsoid {
+        Ellipsoid::new(Length::from_metres(6_377_397.155), 299.1528128)
+    }
+
+    fn dms(d: i64, m: i64, s: f64) -> Angle {
+        Angle::from_dms(d, m, s).unwrap()
+    }
+
+    fn assert_vincenty_line(
+        ellipsoid: Ellipsoid,
+        p1: LatLong,
+        p2: LatLong,
+        distance: f64,
+        initial: Angle,
+        final_: Angle,
+        arcsec_tolerance: f64,
+    ) {
+        let (d, a1, a2) = p1.vincenty_inverse(&p2, &ellipsoid).unwrap();
+        assert!(
+            (d.as_metres() - distance).abs() < 0.001,
+            "expected {} but got {}",
+            distance,
+            d.as_metres()
+        );
+        let arcsec = |a: Angle, b: Angle| (a - b).as_degrees().abs() * 3600.0;
+        assert!(
+            arcsec(initial, a1) < arcsec_tolerance,
+            "initial: {}",
+            arcsec(initial, a1)
+        );
+        assert!(
+            arcsec(final_, a2) < arcsec_tolerance,
+            "final: {}",
+            arcsec(final_, a2)
+        );
+    }
+
+    #[test]
+    fn vincenty_inverse_line_a() {
+        assert_vincenty_line(
+            bessel(),
+            LatLong::new(dms(55, 45, 0.0), Angle::ZERO),
+            LatLong::new(-dms(33, 26, 0.0), dms(108, 13, 0.0)),
+            14_110_526.170,
+            dms(96, 36, 8.79960),
+            dms(137, 52, 22.01454),
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn vincenty_inverse_line_b() {
+        assert_vincenty_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(37, 19, 54.95367), Angle::ZERO),
+            LatLong::new(dms(26, 7, 42.83946), dms(41, 28, 35.50729)),
+            4_085_966.703,
+            dms(95, 27, 59.63089),
+            dms(118, 5, 58.96161),
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn vincenty_inverse_line_c() {
+        assert_vincenty_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(35, 16, 11.24862), Angle::ZERO),
+            LatLong::new(dms(67, 22, 14.77638), dms(137, 47, 28.31435)),
+            8_084_823.839,
+            dms(15, 44, 23.74850),
+            dms(144, 55, 39.92147),
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn vincenty_inverse_line_d() {
+        assert_vincenty_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(1, 0, 0.0), Angle::ZERO),
+            LatLong::new(-dms(0, 59, 53.83076), dms(179, 17, 48.02997)),
+            19_960_000.000,
+            dms(89, 0, 0.0),
+            dms(91, 0, 6.11733),
+            // nearly antipodal: the azimuths are very sensitive to the rounding of the published positions.
+            2e-3,
+        );
+    }
+
+    #[test]
+    fn vincenty_inverse_line_e() {
+        assert_vincenty_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(1, 0, 0.0), Angle::ZERO),
+            LatLong::new(dms(1, 1, 15.18952), dms(179, 46, 17.84244)),
+            19_780_006.558,
+            dms(4, 59, 59.99995),
+            dms(174, 59, 59.88481),
+            1e-4,
+        );
+    }
+
+    fn assert_vincenty_direct_line(
+        ellipsoid: Ellipsoid,
+        p1: LatLong,
+        p2: LatLong,
+        distance: f64,
+        initial: Angle,
+        final_: Angle,
+    ) {
+        let (p, a2) = p1.vincenty_direct(initial, Length::from_metres(distance), &ellipsoid);
+        let arcsec = |a: Angle, b: Angle| (a - b).as_degrees().abs() * 3600.0;
+        // 1e-4 arcseconds is about 3 millimetres.
+        assert!(
+            arcsec(p2.latitude(), p.latitude()) < 1e-4,
+            "latitude: {}",
+            arcsec(p2.latitude(), p.latitude())
+        );
+        assert!(
+            arcsec(p2.longitude(), p.longitude()) < 1e-4,
+            "longitude: {}",
+            arcsec(p2.longitude(), p.longitude())
+        );
+        assert!(arcsec(final_, a2) < 1e-4, "final: {}", arcsec(final_, a2));
+    }
+
+    #[test]
+    fn vincenty_direct_lines() {
+        assert_vincenty_direct_line(
+            bessel(),
+            LatLong::new(dms(55, 45, 0.0), Angle::ZERO),
+            LatLong::new(-dms(33, 26, 0.0), dms(108, 13, 0.0)),
+            14_110_526.170,
+            dms(96, 36, 8.79960),
+            dms(137, 52, 22.01454),
+        );
+        assert_vincenty_direct_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(37, 19, 54.95367), Angle::ZERO),
+            LatLong::new(dms(26, 7, 42.83946), dms(41, 28, 35.50729)),
+            4_085_966.703,
+            dms(95, 27, 59.63089),
+            dms(118, 5, 58.96161),
+        );
+        assert_vincenty_direct_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(35, 16, 11.24862), Angle::ZERO),
+            LatLong::new(dms(67, 22, 14.77638), dms(137, 47, 28.31435)),
+            8_084_823.839,
+            dms(15, 44, 23.74850),
+            dms(144, 55, 39.92147),
+        );
+        assert_vincenty_direct_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(1, 0, 0.0), Angle::ZERO),
+            LatLong::new(-dms(0, 59, 53.83076), dms(179, 17, 48.02997)),
+            19_960_000.000,
+            dms(89, 0, 0.0),
+            dms(91, 0, 6.11733),
+        );
+        assert_vincenty_direct_line(
+            Ellipsoid::INTERNATIONAL1924,
+            LatLong::new(dms(1, 0, 0.0), Angle::ZERO),
+            LatLong::new(dms(1, 1, 15.18952), dms(179, 46, 17.84244)),
+            19_780_006.558,
+            dms(4, 59, 59.99995),
+            dms(174, 59, 59.88481),
+        );
+    }
+
+    #[test]
+    fn vincenty_direct_zero_distance() {
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        let (d, a) = p.vincenty_direct(Angle::from_degrees(45.0), Length::ZERO, &Ellipsoid::WGS84);
+        assert_eq!(p.round_d7(), d.round_d7());
+        assert_eq!(Angle::from_degrees(45.0), a.round_d7());
+    }
+
+    #[test]
+    fn vincenty_direct_from_poles() {
+        let meridian = LatLong::from_degrees(90.0, 0.0)
+            .vincenty_distance(&LatLong::from_degrees(0.0, 0.0), &Ellipsoid::WGS84)
+            .unwrap();
+        // from the north pole the longitude reached is 180 degrees minus the azimuth.
+        let (p, a) = LatLong::from_degrees(90.0, 0.0).vincenty_direct(
+            Angle::from_degrees(150.0),
+            meridian,
+            &Ellipsoid::WGS84,
+        );
+        assert_eq!(LatLong::from_degrees(0.0, 30.0), p.round_d7());
+        assert_eq!(Angle::HALF_CIRCLE, a.round_d7());
+        // from the south pole the longitude reached is the azimuth.
+        let (p, a) = LatLong::from_degrees(-90.0, 0.0).vincenty_direct(
+            Angle::from_degrees(30.0),
+            meridian,
+            &Ellipsoid::WGS84,
+        );
+        assert_eq!(LatLong::from_degrees(0.0, 30.0), p.round_d7());
+        assert_eq!(Angle::ZERO, a.round_d7());
+    }
+
+    #[test]
+    fn vincenty_direct_longer_than_half_circumference() {
+        // along the equator, three quarters of the circumference.
+        let d = Ellipsoid::WGS84.equatorial_radius() * 1.5 * std::f64::consts::PI;
+        let (p, a) = LatLong::from_degrees(0.0, 0.0).vincenty_direct(
+            Angle::from_degrees(90.0),
+            d,
+            &Ellipsoid::WGS84,
+        );
+        assert_eq!(LatLong::from_degrees(0.0, -90.0), p.round_d7());
+        assert_eq!(Angle::from_degrees(90.0), a.round_d7());
+    }
+
+    #[test]
+    fn vincenty_direct_inverse_round_trip() {
+        // xorshift: deterministic pseudo-random positions.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        for _ in 0..1000 {
+            let p1 = LatLong::from_degrees(next() * 180.0 - 90.0, next() * 360.0 - 180.0);
+            let azimuth = Angle::from_degrees(next() * 360.0);
+            // up to 19,000 km: the inverse formula does not converge for nearly antipodal positions.
+            let distance = Length::from_metres(next() * 19_000_000.0);
+            let (p2, _) = p1.vincenty_direct(azimuth, distance, &Ellipsoid::WGS84);
+            let (d, a1, _) = match p1.vincenty_inverse(&p2, &Ellipsoid::WGS84) {
+                Ok(r) => r,
+                Err(_) => continue,
+            };
+            assert!(
+                (d - distance).abs().as_metres() < 0.001,
+                "{:?} {:?} {:?}: {:?}",
+                p1,
+                azimuth,
+                distance,
+                d
+            );
+            // re-solve the direct problem from the computed azimuth: same destination within a millimetre.
+            let (p3, _) = p1.vincenty_direct(a1, d, &Ellipsoid::WGS84);
+            let e = Sphere::new(Ellipsoid::WGS84.mean_radius());
+            assert!(e.distance(p2.to_nvector(), p3.to_nvector()).as_metres() < 0.001);
+        }
+    }
+
+    #[test]
+    fn vincenty_inverse_coincident() {
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        assert_eq!(
+            Ok((Length::ZERO, Angle::ZERO, Angle::ZERO)),
+            p.vincenty_inverse(&p, &Ellipsoid::WGS84)
+        );
+    }
+
+    #[test]
+    fn vincenty_inverse_equator() {
+        let (d, a1, a2) = LatLong::from_degrees(0.0, 10.0)
+            .vincenty_inverse(&LatLong::from_degrees(0.0, -20.0), &Ellipsoid::WGS84)
+            .unwrap();
+        let e = Ellipsoid::WGS84.equatorial_radius() * 30.0f64.to_radians();
+        assert_eq!(e.round_mm(), d.round_mm());
+        assert_eq!(Angle::from_degrees(270.0), a1.round_d7());
+        assert_eq!(Angle::from_degrees(270.0), a2.round_d7());
+    }
+
+    #[test]
+    fn vincenty_inverse_meridian() {
+        let (d, a1, a2) = LatLong::from_degrees(-10.0, 30.0)
+            .vincenty_inverse(&LatLong::from_degrees(50.0, 30.0), &Ellipsoid::WGS84)
+            .unwrap();
+        assert_eq!(Angle::ZERO, a1.round_d7());
+        assert_eq!(Angle::ZERO, a2.round_d7());
+        let (s, b1, b2) = LatLong::from_degrees(50.0, 30.0)
+            .vincenty_inverse(&LatLong::from_degrees(-10.0, 30.0), &Ellipsoid::WGS84)
+            .unwrap();
+        assert_eq!(d.round_mm(), s.round_mm());
+        assert_eq!(Angle::HALF_CIRCLE, b1.round_d7());
+        assert_eq!(Angle::HALF_CIRCLE, b2.round_d7());
+    }
+
+    #[test]
+    fn vincenty_inverse_antimeridian() {
+        let (d, a1, a2) = LatLong::from_degrees(0.0, 179.0)
+            .vincenty_inverse(&LatLong::from_degrees(0.0, -179.0), &Ellipsoid::WGS84)
+            .unwrap();
+        let e = Ellipsoid::WGS84.equatorial_radius() * 2.0f64.to_radians();
+        assert_eq!(e.round_mm(), d.round_mm());
+        assert_eq!(Angle::QUARTER_CIRCLE, a1.round_d7());
+        assert_eq!(Angle::QUARTER_CIRCLE, a2.round_d7());
+
+        // same as the positions shifted by 180 degrees of longitude.
+        let (d, a1, a2) = LatLong::from_degrees(10.0, 170.0)
+            .vincenty_inverse(&LatLong::from_degrees(12.0, -170.0), &Ellipsoid::WGS84)
+            .unwrap();
+        let (e, b1, b2) = LatLong::from_degrees(10.0, -10.0)
+            .vincenty_inverse(&LatLong::from_degrees(12.0, 10.0), &Ellipsoid::WGS84)
+            .unwrap();
+        assert_eq!(e.round_mm(), d.round_mm());
+        assert_eq!(b1.round_d7(), a1.round_d7());
+        assert_eq!(b2.round_d7(), a2.round_d7());
+    }
+
+    #[test]
+    fn vincenty_distance_antimeridian() {
+        let p1 = LatLong::from_degrees(10.0, 170.0);
+        let p2 = LatLong::from_degrees(12.0, -170.0);
+        let d = p1.vincenty_distance(&p2, &Ellipsoid::WGS84).unwrap();
+        assert_eq!(
+            LatLong::from_degrees(10.0, -10.0)
+                .vincenty_distance(&LatLong::from_degrees(12.0, 10.0), &Ellipsoid::WGS84)
+                .unwrap()
+                .round_mm(),
+            d.round_mm()
+        );
+        assert_eq!(
+            d.round_mm(),
+            p2.vincenty_distance(&p1, &Ellipsoid::WGS84)
+                .unwrap()
+                .round_mm()
+        );
+    }
+
+    #[test]
+    fn vincenty_inverse_non_convergence() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.5, 179.7);
+        assert_eq!(
+            Err(NavigationError::NonConvergence),
+            p1.vincenty_inverse(&p2, &Ellipsoid::WGS84)
+        );
+    }
+
+    #[test]
+    fn vincenty_non_convergence() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.5, 179.7);
+        assert_eq!(
+            Err(NavigationError::NonConvergence),
+            p1.vincenty_distance(&p2, &Ellipsoid::WGS84)
+        );
+    }
+
+    #[test]
+    fn geodesic_distance_fallback() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.5, 179.7);
+        let (d, m) = p1.geodesic_distance(&p2, &Ellipsoid::WGS84);
+        assert_eq!(GeodesicMethod::Spherical, m);
+        let e =
+            Sphere::new(Ellipsoid::WGS84.mean_radius()).distance(p1.to_nvector(), p2.to_nvector());
+        assert_eq!(e, d);
+        // reasonable: slightly less than half the circumference.
+        assert!(d > Length::from_kilometres(19_900.0) && d < Length::from_kilometres(20_040.0));
+    }
+
+    #[test]
+    fn geodesic_distance_antimeridian() {
+        let p1 = LatLong::from_degrees(0.0, 179.0);
+        let p2 = LatLong::from_degrees(0.0, -179.0);
+        let (d, m) = p1.geodesic_distance(&p2, &Ellipsoid::WGS84);
+        assert_eq!(GeodesicMethod::Vincenty, m);
+        let e = Ellipsoid::WGS84.equatorial_radius() * 2.0f64.to_radians();
+        assert_eq!(e.round_mm(), d.round_mm());
+    }
+
+    #[test]
+    fn geodesic_distance_vincenty() {
+        let p1 = LatLong::from_degrees(50.066, -5.715);
+        let p2 = LatLong::from_degrees(58.644, -3.07);
+        let (d, m) = p1.geodesic_distance(&p2, &Ellipsoid::WGS84);
+        assert_eq!(GeodesicMethod::Vincenty, m);
+        assert_eq!(Ok(d), p1.vincenty_distance(&p2, &Ellipsoid::WGS84));
+    }
+}
@@ -0,0 +1,573 @@
+use std::{fmt, str::FromStr};
+
+use crate::{LatLong, Length};
+
+use super::{Ellipsoid, Hemisphere, Utm, UtmError};
+
+/// Latitude bands C..X, each 8° high except X which is 12° high (72°N to 84°N).
+const LATITUDE_BANDS: &[u8; 20] = b"CDEFGHJKLMNPQRSTUVWX";
+
+/// 100 km square column letters, repeating every 3 zones.
+const COLUMN_LETTERS: [&[u8; 8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+
+/// 100 km square row letters, repeating every 2,000 km; even zones are offset by 5 letters (AA/AL scheme).
+const ROW_LETTERS: [&[u8; 20]; 2] = [b"ABCDEFGHJKLMNPQRSTUV", b"FGHJKLMNPQRSTUVABCDE"];
+
+/// Maximum number of digits of each of the easting and northing: 5 digits is 1 metre.
+const MAX_DIGITS: usize = 5;
+
+/// Error returned when a MGRS grid reference cannot be created or parsed.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MgrsError {
+    /// The latitude is outside of the MGRS coverage (excluding the polar UPS areas): 80°S to 84°N.
+    LatitudeOutOfRange,
+    /// The zone is not in [1, 60].
+    InvalidZone(u8),
+    /// The latitude band letter is not in C..X (excluding I and O).
+    InvalidBand(char),
+    /// The 100 km square letter is not valid for the zone.
+    InvalidSquare(char),
+    /// The grid reference is not of the form zone, band, 100 km square letters and between 1 and 5
+    /// digits for both the easting and the northing.
+    InvalidFormat,
+}
+
+impl fmt::Display for MgrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MgrsError::LatitudeOutOfRange => {
+                write!(f, "latitude outside of MGRS coverage (80°S to 84°N)")
+            }
+            MgrsError::InvalidZone(zone) => {
+                write!(f, "invalid MGRS zone: {} (expected 1 to 60)", zone)
+            }
+            MgrsError::InvalidBand(c) => write!(f, "invalid MGRS latitude band: {:?}", c),
+            MgrsError::InvalidSquare(c) => write!(f, "invalid MGRS 100 km square letter: {:?}", c),
+            MgrsError::InvalidFormat => write!(f, "invalid MGRS grid reference"),
+        }
+    }
+}
+
+impl std::error::Error for MgrsError {}
+
+impl From<UtmError> for MgrsError {
+    fn from(e: UtmError) -> Self {
+        match e {
+            UtmError::LatitudeOutOfRange => MgrsError::LatitudeOutOfRange,
+            UtmError::InvalidZone(zone) => MgrsError::InvalidZone(zone),
+        }
+    }
+}
+
+/// A [Military Grid Reference System](https://en.wikipedia.org/wiki/Military_Grid_Reference_System) (MGRS)
+/// grid reference: UTM zone, latitude band, 100 km square letters (column and row) and easting/northing
+/// within the 100 km square.
+///
+/// The polar regions (UPS) are not supported.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Mgrs {
+    zone: u8,
+    band: char,
+    column: char,
+    row: char,
+    easting: Length,
+    northing: Length,
+}
+
+impl Mgrs {
+    /// Converts the given UTM coordinate to a MGRS grid reference; the given ellipsoid is used to
+    /// determine the latitude band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Length;
+    /// use jord::ellipsoidal::{Ellipsoid, Hemisphere, Mgrs, Utm};
+    ///
+    /// let utm = Utm::new(
+    ///     31,
+    ///     Hemisphere::North,
+    ///     Length::from_metres(448_251.795),
+    ///     Length::from_metres(5_411_932.678)
+    /// ).unwrap();
+    /// let mgrs = Mgrs::from_utm(&utm, &Ellipsoid::WGS84).unwrap();
+    /// assert_eq!("31U DQ 48251 11932", mgrs.to_string());
+    /// ```
+    pub fn from_utm(utm: &Utm, ellipsoid: &Ellipsoid) -> Result<Self, MgrsError> {
+        let lat = utm.to_lat_long(ellipsoid).latitude().as_degrees();
+        Mgrs::from_utm_at_latitude(utm, lat)
+    }
+
+    /// Converts the given UTM coordinate at the given latitude in degrees to a MGRS grid reference.
+    fn from_utm_at_latitude(utm: &Utm, lat: f64) -> Result<Self, MgrsError> {
+        let band = band(lat).ok_or(MgrsError::LatitudeOutOfRange)?;
+
+        let e = utm.easting().as_metres();
+        let n = utm.northing().as_metres();
+        let col = (e / 100_000.0).floor() as i64;
+        let row = ((n / 100_000.0).floor() as i64).rem_euclid(20);
+        let zi = (utm.zone() - 1) as usize;
+        let column = COLUMN_LETTERS[zi % 3]
+            .get((col - 1) as usize)
+            .ok_or(MgrsError::InvalidFormat)?;
+        let row = ROW_LETTERS[zi % 2][row as usize];
+
+        Ok(Mgrs {
+            zone: utm.zone(),
+            band,
+            column: *column as char,
+            row: row as char,
+            easting: Length::from_metres(e.rem_euclid(100_000.0)),
+            northing: Length::from_metres(n.rem_euclid(100_000.0)),
+        })
+    }
+
+    /// Converts the given position on the given ellipsoid to a MGRS grid reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    /// use jord::ellipsoidal::{Ellipsoid, Mgrs};
+    ///
+    /// let mgrs = Mgrs::from_lat_long(&LatLong::from_degrees(48.8582, 2.2945), &Ellipsoid::WGS84).unwrap();
+    /// assert_eq!("31U DQ 48251 11932", mgrs.to_string());
+    /// ```
+    pub fn from_lat_long(pos: &LatLong, ellipsoid: &Ellipsoid) -> Result<Self, MgrsError> {
+        let utm = Utm::from_lat_long(pos, ellipsoid)?;
+        Mgrs::from_utm_at_latitude(&utm, pos.latitude().as_degrees())
+    }
+
+    /// Parses the given MGRS grid reference, e.g. `31U DQ 48251 11932` or `31UDQ4825111932`, and returns the
+    /// south-west corner of the referenced cell and the size of the cell: 1 digit for the easting and northing
+    /// is a 10 km cell, 5 digits is a 1 m cell.
+    ///
+    /// Parsing is case-insensitive and whitespace is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Length;
+    /// use jord::ellipsoidal::Mgrs;
+    ///
+    /// let (mgrs, size) = Mgrs::parse("31U DQ 482 119").unwrap();
+    /// assert_eq!(Length::from_metres(100.0), size);
+    /// assert_eq!(Length::from_metres(48_200.0), mgrs.easting());
+    /// assert_eq!(Length::from_metres(11_900.0), mgrs.northing());
+    /// ```
+    pub fn parse(s: &str) -> Result<(Self, Length), MgrsError> {
+        let chars: Vec<char> = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        let zone_len = chars.iter().take_while(|c| c.is_ascii_digit()).count();
+        if zone_len == 0 || zone_len > 2 || chars.len() < zone_len + 3 {
+            return Err(MgrsError::InvalidFormat);
+        }
+        let zone: u8 = chars[..zone_len]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .map_err(|_| MgrsError::InvalidFormat)?;
+        if !(1..=60).contains(&zone) {
+            return Err(MgrsError::InvalidZone(zone));
+        }
+
+        let band = chars[zone_len];
+        if !LATITUDE_BANDS.contains(&(band as u8)) || !band.is_ascii() {
+            return Err(MgrsError::InvalidBand(band));
+        }
+
+        let zi = (zone - 1) as usize;
+        let column = chars[zone_len + 1];
+        if !column.is_ascii() || !COLUMN_LETTERS[zi % 3].contains(&(column as u8)) {
+            return Err(MgrsError::InvalidSquare(column));
+        }
+        let row = chars[zone_len + 2];
+        if !row.is_ascii() || !ROW_LETTERS[zi % 2].contains(&(row as u8)) {
+            return Err(MgrsError::InvalidSquare(row));
+        }
+
+        let digits = &chars[zone_len + 3..];
+        if digits.is_empty()
+            || digits.len() % 2 != 0
+            || digits.len() > 2 * MAX_DIGITS
+            || !digits.iter().all(|c| c.is_ascii_digit())
+        {
+            return Err(MgrsError::InvalidFormat);
+        }
+        let half = digits.len() / 2;
+        let size = 10f64.powi((MAX_DIGITS - half) as i32);
+        let parse_digits = |ds: &[char]| -> f64 {
+            ds.iter()
+                .fold(0.0, |acc, d| acc * 10.0 + d.to_digit(10).unwrap() as f64)
+        };
+        let easting = parse_digits(&digits[..half]) * size;
+        let northing = parse_digits(&digits[half..]) * size;
+
+        let mgrs = Mgrs {
+            zone,
+            band,
+            column,
+            row,
+            easting: Length::from_metres(easting),
+            northing: Length::from_metres(northing),
+        };
+        Ok((mgrs, Length::from_metres(size)))
+    }
+
+    /// Converts this MGRS grid reference to a UTM coordinate; the given ellipsoid is used to resolve the
+    /// 2,000 km ambiguity of the 100 km square row letter within the latitude band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Length;
+    /// use jord::ellipsoidal::{Ellipsoid, Hemisphere, Mgrs};
+    ///
+    /// let mgrs: Mgrs = "31U DQ 48251 11932".parse().unwrap();
+    /// let utm = mgrs.to_utm(&Ellipsoid::WGS84);
+    /// assert_eq!(31, utm.zone());
+    /// assert_eq!(Hemisphere::North, utm.hemisphere());
+    /// assert_eq!(Length::from_metres(448_251.0), utm.easting());
+    /// assert_eq!(Length::from_metres(5_411_932.0), utm.northing());
+    /// ```
+    pub fn to_utm(&self, ellipsoid: &Ellipsoid) -> Utm {
+        let hemisphere = if self.band >= 'N' {
+            Hemisphere::North
+        } else {
+            Hemisphere::South
+        };
+        let zi = (self.zone - 1) as usize;
+        // letters are validated on construction.
+        let col = COLUMN_LETTERS[zi % 3]
+            .iter()
+            .position(|&c| c == self.column as u8)
+            .unwrap_or(0)
+            + 1;
+        let row = ROW_LETTERS[zi % 2]
+            .iter()
+            .position(|&c| c == self.row as u8)
+            .unwrap_or(0);
+
+        let e = col as f64 * 100_000.0 + self.easting.as_metres();
+        let n100k = row as f64 * 100_000.0 + self.northing.as_metres();
+
+        // northing of the bottom of the latitude band (rounded down to 100 km), the northing of the
+        // grid reference is the first value above it (modulo 2,000 km).
+        let band_lat = band_southern_latitude(self.band);
+        let band_northing = Utm::from_lat_long_in_zone(band_lat, 3.0, 31, ellipsoid)
+            .northing()
+            .as_metres();
+        let band_northing = (band_northing / 100_000.0).floor() * 100_000.0;
+        let mut n2m = 0.0;
+        while n2m + n100k < band_northing {
+            n2m += 2_000_000.0;
+        }
+
+        // zone is validated on construction.
+        Utm::new(
+            self.zone,
+            hemisphere,
+            Length::from_metres(e),
+            Length::from_metres(n2m + n100k),
+        )
+        .unwrap()
+    }
+
+    /// Converts this MGRS grid reference to a position on the given ellipsoid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    /// use jord::ellipsoidal::{Ellipsoid, Mgrs};
+    ///
+    /// let mgrs: Mgrs = "56H LH 34873 52266".parse().unwrap();
+    /// assert_eq!(LatLong::from_degrees(-33.857, 151.215), mgrs.to_lat_long(&Ellipsoid::WGS84).round_d5());
+    /// ```
+    pub fn to_lat_long(&self, ellipsoid: &Ellipsoid) -> LatLong {
+        self.to_utm(ellipsoid).to_lat_long(ellipsoid)
+    }
+
+    /// Formats this MGRS grid reference with the given number of digits for both the easting and northing
+    /// (truncated, not rounded). The number of digits is clamped to the range [1, 5]: 1 digit is a 10 km cell,
+    /// 5 digits is a 1 m cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::ellipsoidal::Mgrs;
+    ///
+    /// let mgrs: Mgrs = "31U DQ 48251 11932".parse().unwrap();
+    /// assert_eq!("31U DQ 482 119", mgrs.to_string_with_digits(3));
+    /// assert_eq!("31U DQ 4 1", mgrs.to_string_with_digits(1));
+    /// ```
+    pub fn to_string_with_digits(&self, digits: usize) -> String {
+        let digits = digits.clamp(1, MAX_DIGITS);
+        let size = 10f64.powi((MAX_DIGITS - digits) as i32);
+        let e = (self.easting.as_metres() / size).floor() as u64;
+        let n = (self.northing.as_metres() / size).floor() as u64;
+        format!(
+            "{:02}{} {}{} {:0w$} {:0w$}",
+            self.zone,
+            self.band,
+            self.column,
+            self.row,
+            e,
+            n,
+            w = digits
+        )
+    }
+
+    /// Returns the UTM zone of this grid reference: in [1, 60].
+    #[inline]
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Returns the latitude band letter of this grid reference.
+    #[inline]
+    pub fn band(&self) -> char {
+        self.band
+    }
+
+    /// Returns the 100 km square column (easting) letter of this grid reference.
+    #[inline]
+    pub fn column(&self) -> char {
+        self.column
+    }
+
+    /// Returns the 100 km square row (northing) letter of this grid reference.
+    #[inline]
+    pub fn row(&self) -> char {
+        self.row
+    }
+
+    /// Returns the easting within the 100 km square of this grid reference.
+    #[inline]
+    pub fn easting(&self) -> Length {
+        self.easting
+    }
+
+    /// Returns the northing within the 100 km square of this grid reference.
+    #[inline]
+    pub fn northing(&self) -> Length {
+        self.northing
+    }
+}
+
+impl fmt::Display for Mgrs {
+    /// Formats this MGRS grid reference with 1 metre precision, e.g. `31U DQ 48251 11932`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with_digits(MAX_DIGITS))
+    }
+}
+
+impl FromStr for Mgrs {
+    type Err = MgrsError;
+
+    /// Parses the given MGRS grid reference and returns the south-west corner of the referenced cell:
+    /// see [Mgrs::parse].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Mgrs::parse(s).map(|(mgrs, _)| mgrs)
+    }
+}
+
+/// Returns the latitude band letter of the given latitude in degrees, if in [-80, 84].
+fn band(lat: f64) -> Option<char> {
+    if !(-80.0..=84.0).contains(&lat) {
+        return None;
+    }
+    let i = ((lat / 8.0).floor() + 10.0).clamp(0.0, 19.0) as usize;
+    Some(LATITUDE_BANDS[i] as char)
+}
+
+/// Returns the southern latitude in degrees of the given (valid) latitude band.
+fn band_southern_latitude(band: char) -> f64 {
+    let i = LATITUDE_BANDS
+        .iter()
+        .position(|&c| c == band as u8)
+        .unwrap_or(0);
+    (i as f64 - 10.0) * 8.0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ellipsoidal::Ellipsoid, LatLong, Length};
+
+    use super::{Mgrs, MgrsError};
+
+    fn to_mgrs(lat: f64, lng: f64) -> String {
+        Mgrs::from_lat_long(&LatLong::from_degrees(lat, lng), &Ellipsoid::WGS84)
+            .unwrap()
+            .to_string()
+    }
+
+    fn assert_round_trip(p: LatLong) {
+        let mgrs = Mgrs::from_lat_long(&p, &Ellipsoid::WGS84).unwrap();
+        let parsed: Mgrs = mgrs.to_string().parse().unwrap();
+        assert_eq!(mgrs.zone(), parsed.zone());
+        assert_eq!(mgrs.band(), parsed.band());
+        assert_eq!(mgrs.column(), parsed.column());
+        assert_eq!(mgrs.row(), parsed.row());
+        let actual = parsed.to_lat_long(&Ellipsoid::WGS84);
+        // truncation to 1 metre: less than 2 metres, i.e. 2e-5 degree of latitude.
+        assert!((p.latitude().as_degrees() - actual.latitude().as_degrees()).abs() < 2e-5);
+        let dlng = (p.longitude().as_degrees() - actual.longitude().as_degrees()).abs();
+        assert!(dlng * p.latitude().as_radians().cos() < 2e-5);
+    }
+
+    // from_lat_long
+
+    #[test]
+    fn from_lat_long() {
+        assert_eq!("31U DQ 48251 11932", to_mgrs(48.8582, 2.2945));
+        assert_eq!("56H LH 34873 52266", to_mgrs(-33.857, 151.215));
+        assert_eq!("18S UJ 23394 07395", to_mgrs(38.8976944, -77.0365));
+        assert_eq!("23K PQ 83466 60687", to_mgrs(-22.9519, -43.2106));
+        assert_eq!("32V KN 97508 00645", to_mgrs(60.39135, 5.3249));
+    }
+
+    #[test]
+    fn from_lat_long_zone_padding() {
+        assert!(to_mgrs(21.3, -157.8).starts_with("04Q "));
+    }
+
+    #[test]
+    fn from_lat_long_latitude_out_of_range() {
+        assert_eq!(
+            Err(MgrsError::LatitudeOutOfRange),
+            Mgrs::from_lat_long(&LatLong::from_degrees(85.0, 0.0), &Ellipsoid::WGS84)
+        );
+        assert_eq!(
+            Err(MgrsError::LatitudeOutOfRange),
+            Mgrs::from_lat_long(&LatLong::from_degrees(-81.0, 0.0), &Ellipsoid::WGS84)
+        );
+    }
+
+    #[test]
+    fn from_lat_long_bands() {
+        assert!(to_mgrs(-80.0, 0.0).starts_with("31C "));
+        assert!(to_mgrs(-0.0001, 0.0).starts_with("31M "));
+        assert!(to_mgrs(0.0, 0.0).starts_with("31N "));
+        assert!(to_mgrs(72.0, 0.0).starts_with("31X "));
+        assert!(to_mgrs(84.0, 0.0).starts_with("31X "));
+    }
+
+    // parse
+
+    #[test]
+    fn parse_precision() {
+        let expected_sizes = [10_000.0, 1_000.0, 100.0, 10.0, 1.0];
+        let refs = [
+            "31U DQ 4 1",
+            "31U DQ 48 11",
+            "31U DQ 482 119",
+            "31U DQ 4825 1193",
+            "31U DQ 48251 11932",
+        ];
+        for (r, s) in refs.iter().zip(expected_sizes) {
+            let (mgrs, size) = Mgrs::parse(r).unwrap();
+            assert_eq!(Length::from_metres(s), size);
+            assert_eq!(*r, mgrs.to_string_with_digits(r.len() / 2 - 4));
+        }
+    }
+
+    #[test]
+    fn parse_truncated_sw_corner() {
+        let (mgrs, size) = Mgrs::parse("31U DQ 4 1").unwrap();
+        assert_eq!(Length::from_metres(10_000.0), size);
+        assert_eq!(Length::from_metres(40_000.0), mgrs.easting());
+        assert_eq!(Length::from_metres(10_000.0), mgrs.northing());
+        assert_eq!("31U DQ 40000 10000", mgrs.to_string());
+    }
+
+    #[test]
+    fn parse_compact_and_lower_case() {
+        assert_eq!(
+            Mgrs::parse("31U DQ 48251 11932"),
+            Mgrs::parse("31udq4825111932")
+        );
+        assert_eq!(Mgrs::parse("04Q FJ 1 2"), Mgrs::parse("4QFJ12"));
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(Err(MgrsError::InvalidFormat), Mgrs::parse(""));
+        assert_eq!(Err(MgrsError::InvalidFormat), Mgrs::parse("UDQ4811"));
+        assert_eq!(Err(MgrsError::InvalidFormat), Mgrs::parse("31UDQ"));
+        assert_eq!(Err(MgrsError::InvalidFormat), Mgrs::parse("31UDQ481"));
+        assert_eq!(
+            Err(MgrsError::InvalidFormat),
+            Mgrs::parse("31UDQ482511193211")
+        );
+        assert_eq!(Err(MgrsError::InvalidFormat), Mgrs::parse("31UDQ4A"));
+        assert_eq!(Err(MgrsError::InvalidZone(0)), Mgrs::parse("0UDQ11"));
+        assert_eq!(Err(MgrsError::InvalidZone(61)), Mgrs::parse("61UDQ11"));
+        assert_eq!(Err(MgrsError::InvalidBand('I')), Mgrs::parse("31IDQ11"));
+        assert_eq!(Err(MgrsError::InvalidBand('Y')), Mgrs::parse("31YDQ11"));
+        // zone 31: columns A..H.
+        assert_eq!(Err(MgrsError::InvalidSquare('S')), Mgrs::parse("31USQ11"));
+        assert_eq!(Err(MgrsError::InvalidSquare('W')), Mgrs::parse("31UDW11"));
+    }
+
+    // to_utm
+
+    #[test]
+    fn to_utm_row_letter_alternation() {
+        // same position, odd and even zones use row letters offset by 5.
+        let odd =
+            Mgrs::from_lat_long(&LatLong::from_degrees(0.5, 5.99), &Ellipsoid::WGS84).unwrap();
+        let even =
+            Mgrs::from_lat_long(&LatLong::from_degrees(0.5, 6.01), &Ellipsoid::WGS84).unwrap();
+        assert_eq!(31, odd.zone());
+        assert_eq!(32, even.zone());
+        assert_eq!('A', odd.row());
+        assert_eq!('F', even.row());
+    }
+
+    #[test]
+    fn to_utm_southern_hemisphere() {
+        let mgrs: Mgrs = "56H LH 34873 52266".parse().unwrap();
+        let utm = mgrs.to_utm(&Ellipsoid::WGS84);
+        assert_eq!(Length::from_metres(334_873.0), utm.easting());
+        assert_eq!(Length::from_metres(6_252_266.0), utm.northing());
+    }
+
+    // round trip
+
+    #[test]
+    fn round_trip_zone_boundaries() {
+        for lat in [
+            -79.9, -45.0, -0.1, 0.1, 45.0, 55.9, 56.1, 63.9, 71.9, 72.1, 83.9,
+        ] {
+            for lng in [
+                -180.0, -0.01, 0.0, 2.99, 3.0, 5.99, 6.0, 8.99, 9.0, 11.99, 12.0, 179.99,
+            ] {
+                assert_round_trip(LatLong::from_degrees(lat, lng));
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_polar_limits() {
+        for lng in [-177.0, -90.0, 0.0, 15.0, 27.0, 39.0, 90.0, 177.0] {
+            assert_round_trip(LatLong::from_degrees(84.0, lng));
+            assert_round_trip(LatLong::from_degrees(83.999, lng));
+            assert_round_trip(LatLong::from_degrees(-80.0, lng));
+            assert_round_trip(LatLong::from_degrees(-79.999, lng));
+        }
+    }
+
+    #[test]
+    fn round_trip_band_boundaries() {
+        for i in -9..=10 {
+            let lat = (i as f64 * 8.0).min(84.0);
+            assert_round_trip(LatLong::from_degrees(lat, 1.0));
+            assert_round_trip(LatLong::from_degrees(lat - 1e-6, 1.0));
+        }
+    }
+}
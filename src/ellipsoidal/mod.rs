@@ -2,3 +2,12 @@
 
 mod ellipsoid;
 pub use ellipsoid::Ellipsoid;
+
+mod geodesic;
+pub use geodesic::GeodesicMethod;
+
+mod utm;
+pub use utm::{Hemisphere, Utm, UtmError};
+
+mod mgrs;
+pub use mgrs::{Mgrs, MgrsError};
@@ -0,0 +1,545 @@
+use std::fmt;
+
+use crate::{Angle, LatLong, Length};
+
+use super::Ellipsoid;
+
+/// UTM scale factor on the central meridian.
+const K0: f64 = 0.9996;
+
+/// UTM false easting in metres.
+const FALSE_EASTING: f64 = 500_000.0;
+
+/// UTM false northing in metres (southern hemisphere only).
+const FALSE_NORTHING: f64 = 10_000_000.0;
+
+/// Northernmost latitude covered by UTM in degrees.
+const MAX_LATITUDE: f64 = 84.0;
+
+/// Southernmost latitude covered by UTM in degrees.
+const MIN_LATITUDE: f64 = -80.0;
+
+/// Latitude hemisphere of a UTM coordinate.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum Hemisphere {
+    /// Northern hemisphere: latitude >= 0.
+    North,
+    /// Southern hemisphere: latitude < 0.
+    South,
+}
+
+/// Error returned when a UTM coordinate cannot be created.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum UtmError {
+    /// The latitude is outside of the UTM coverage: 80°S to 84°N.
+    LatitudeOutOfRange,
+    /// The zone is not in [1, 60].
+    InvalidZone(u8),
+}
+
+impl fmt::Display for UtmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UtmError::LatitudeOutOfRange => {
+                write!(f, "latitude outside of UTM coverage (80°S to 84°N)")
+            }
+            UtmError::InvalidZone(zone) => {
+                write!(f, "invalid UTM zone: {} (expected 1 to 60)", zone)
+            }
+        }
+    }
+}
+
+impl std::error::Error for UtmError {}
+
+/// A [Universal Transverse Mercator](https://en.wikipedia.org/wiki/Universal_Transverse_Mercator_coordinate_system)
+/// (UTM) coordinate: zone, hemisphere, easting and northing.
+///
+/// Conversions use the Krüger series to order n⁶ as given by
+/// [Karney (2011)](https://arxiv.org/abs/1002.1417) which is accurate to a few nanometres within a zone.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Utm {
+    zone: u8,
+    hemisphere: Hemisphere,
+    easting: Length,
+    northing: Length,
+}
+
+impl Utm {
+    /// Creates a new UTM coordinate from the given zone (in [1, 60]), hemisphere, easting and northing
+    /// (including false easting and northing).
+    pub fn new(
+        zone: u8,
+        hemisphere: Hemisphere,
+        easting: Length,
+        northing: Length,
+    ) -> Result<Self, UtmError> {
+        if !(1..=60).contains(&zone) {
+            return Err(UtmError::InvalidZone(zone));
+        }
+        Ok(Utm {
+            zone,
+            hemisphere,
+            easting,
+            northing,
+        })
+    }
+
+    /// Converts the given position on the given ellipsoid to a UTM coordinate.
+    ///
+    /// The zone follows the standard exceptions for south-west Norway (zone 32V is widened to cover
+    /// longitudes 3° to 12°) and Svalbard (zones 31X, 33X, 35X and 37X).
+    ///
+    /// Returns [UtmError::LatitudeOutOfRange] if the latitude of the given position is outside of 80°S to 84°N.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::ellipsoidal::{Ellipsoid, Hemisphere, Utm};
+    ///
+    /// let utm = Utm::from_lat_long(&LatLong::from_degrees(48.8582, 2.2945), &Ellipsoid::WGS84).unwrap();
+    /// assert_eq!(31, utm.zone());
+    /// assert_eq!(Hemisphere::North, utm.hemisphere());
+    /// assert_eq!(Length::from_metres(448_252.0), utm.easting().round_m());
+    /// assert_eq!(Length::from_metres(5_411_933.0), utm.northing().round_m());
+    /// ```
+    pub fn from_lat_long(pos: &LatLong, ellipsoid: &Ellipsoid) -> Result<Self, UtmError> {
+        let lat = pos.latitude().as_degrees();
+        if !(MIN_LATITUDE..=MAX_LATITUDE).contains(&lat) {
+            return Err(UtmError::LatitudeOutOfRange);
+        }
+        let lng = (pos.longitude().as_degrees() + 180.0).rem_euclid(360.0) - 180.0;
+        let zone = zone(lat, lng);
+        Ok(Self::from_lat_long_in_zone(lat, lng, zone, ellipsoid))
+    }
+
+    /// Converts the given latitude and longitude (degrees) to a UTM coordinate in the given zone.
+    pub(crate) fn from_lat_long_in_zone(
+        lat: f64,
+        lng: f64,
+        zone: u8,
+        ellipsoid: &Ellipsoid,
+    ) -> Self {
+        let e = ellipsoid.eccentricity_squared().sqrt();
+        let n = ellipsoid.flattening() / (2.0 - ellipsoid.flattening());
+        let a = rectifying_radius(ellipsoid.semi_major_axis().as_metres(), n);
+        let alpha = alpha(n);
+
+        let phi = lat.to_radians();
+        let lambda = (lng - central_meridian(zone)).to_radians();
+
+        let cos_lambda = lambda.cos();
+        let sin_lambda = lambda.sin();
+
+        // conformal latitude.
+        let tau = phi.tan();
+        let sigma = (e * (e * tau / (1.0 + tau * tau).sqrt()).atanh()).sinh();
+        let tau_p = tau * (1.0 + sigma * sigma).sqrt() - sigma * (1.0 + tau * tau).sqrt();
+
+        let xi_p = tau_p.atan2(cos_lambda);
+        let eta_p = (sin_lambda / (tau_p * tau_p + cos_lambda * cos_lambda).sqrt()).asinh();
+
+        let mut xi = xi_p;
+        let mut eta = eta_p;
+        for (j, alpha_j) in alpha.iter().enumerate() {
+            let j2 = 2.0 * (j + 1) as f64;
+            xi += alpha_j * (j2 * xi_p).sin() * (j2 * eta_p).cosh();
+            eta += alpha_j * (j2 * xi_p).cos() * (j2 * eta_p).sinh();
+        }
+
+        let x = K0 * a * eta + FALSE_EASTING;
+        let mut y = K0 * a * xi;
+        let hemisphere = if lat >= 0.0 {
+            Hemisphere::North
+        } else {
+            y += FALSE_NORTHING;
+            Hemisphere::South
+        };
+
+        Utm {
+            zone,
+            hemisphere,
+            easting: Length::from_metres(x),
+            northing: Length::from_metres(y),
+        }
+    }
+
+    /// Converts this UTM coordinate to a position on the given ellipsoid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::ellipsoidal::{Ellipsoid, Hemisphere, Utm};
+    ///
+    /// let utm = Utm::new(
+    ///     31,
+    ///     Hemisphere::North,
+    ///     Length::from_metres(448_251.795),
+    ///     Length::from_metres(5_411_932.678)
+    /// ).unwrap();
+    /// assert_eq!(LatLong::from_degrees(48.8582, 2.2945), utm.to_lat_long(&Ellipsoid::WGS84).round_d5());
+    /// ```
+    pub fn to_lat_long(&self, ellipsoid: &Ellipsoid) -> LatLong {
+        let e = ellipsoid.eccentricity_squared().sqrt();
+        let e2 = e * e;
+        let n = ellipsoid.flattening() / (2.0 - ellipsoid.flattening());
+        let a = rectifying_radius(ellipsoid.semi_major_axis().as_metres(), n);
+        let beta = beta(n);
+
+        let x = self.easting.as_metres() - FALSE_EASTING;
+        let y = match self.hemisphere {
+            Hemisphere::North => self.northing.as_metres(),
+            Hemisphere::South => self.northing.as_metres() - FALSE_NORTHING,
+        };
+
+        let xi = y / (K0 * a);
+        let eta = x / (K0 * a);
+
+        let mut xi_p = xi;
+        let mut eta_p = eta;
+        for (j, beta_j) in beta.iter().enumerate() {
+            let j2 = 2.0 * (j + 1) as f64;
+            xi_p -= beta_j * (j2 * xi).sin() * (j2 * eta).cosh();
+            eta_p -= beta_j * (j2 * xi).cos() * (j2 * eta).sinh();
+        }
+
+        let sinh_eta_p = eta_p.sinh();
+        let sin_xi_p = xi_p.sin();
+        let cos_xi_p = xi_p.cos();
+
+        let tau_p = sin_xi_p / (sinh_eta_p * sinh_eta_p + cos_xi_p * cos_xi_p).sqrt();
+
+        // Newton-Raphson iteration to recover the geodetic latitude from the conformal latitude.
+        let mut tau_i = tau_p;
+        for _ in 0..10 {
+            let sigma_i = (e * (e * tau_i / (1.0 + tau_i * tau_i).sqrt()).atanh()).sinh();
+            let tau_i_p =
+                tau_i * (1.0 + sigma_i * sigma_i).sqrt() - sigma_i * (1.0 + tau_i * tau_i).sqrt();
+            let delta_tau_i = (tau_p - tau_i_p) / (1.0 + tau_i_p * tau_i_p).sqrt()
+                * (1.0 + (1.0 - e2) * tau_i * tau_i)
+                / ((1.0 - e2) * (1.0 + tau_i * tau_i).sqrt());
+            tau_i += delta_tau_i;
+            if delta_tau_i.abs() <= 1e-12 {
+                break;
+            }
+        }
+
+        let phi = tau_i.atan();
+        let lambda = sinh_eta_p.atan2(cos_xi_p);
+
+        LatLong::new(
+            Angle::from_radians(phi),
+            Angle::from_degrees(lambda.to_degrees() + central_meridian(self.zone)),
+        )
+    }
+
+    /// Returns the zone of this UTM coordinate: in [1, 60].
+    #[inline]
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Returns the hemisphere of this UTM coordinate.
+    #[inline]
+    pub fn hemisphere(&self) -> Hemisphere {
+        self.hemisphere
+    }
+
+    /// Returns the easting of this UTM coordinate (including the false easting of 500 km).
+    #[inline]
+    pub fn easting(&self) -> Length {
+        self.easting
+    }
+
+    /// Returns the northing of this UTM coordinate (including the false northing of 10,000 km in the
+    /// southern hemisphere).
+    #[inline]
+    pub fn northing(&self) -> Length {
+        self.northing
+    }
+}
+
+/// Returns the UTM zone of the given latitude (in [-80, 84]) and longitude (in [-180, 180)) in degrees.
+fn zone(lat: f64, lng: f64) -> u8 {
+    let mut zone = ((lng + 180.0) / 6.0).floor() as u8 + 1;
+    // south-west Norway: band V.
+    if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lng) {
+        zone = 32;
+    }
+    // Svalbard: band X.
+    if lat >= 72.0 {
+        if (0.0..9.0).contains(&lng) {
+            zone = 31;
+        } else if (9.0..21.0).contains(&lng) {
+            zone = 33;
+        } else if (21.0..33.0).contains(&lng) {
+            zone = 35;
+        } else if (33.0..42.0).contains(&lng) {
+            zone = 37;
+        }
+    }
+    zone.min(60)
+}
+
+/// Returns the longitude of the central meridian of the given zone in degrees.
+fn central_meridian(zone: u8) -> f64 {
+    (zone as f64 - 1.0) * 6.0 - 180.0 + 3.0
+}
+
+/// Returns the radius of the rectifying sphere (2πA is the circumference of a meridian) for the given
+/// semi-major axis and third flattening.
+fn rectifying_radius(a: f64, n: f64) -> f64 {
+    let n2 = n * n;
+    a / (1.0 + n) * (1.0 + n2 / 4.0 + n2 * n2 / 64.0 + n2 * n2 * n2 / 256.0)
+}
+
+/// Returns the Krüger series coefficients used to convert from conformal latitude to the transverse
+/// Mercator projection.
+fn alpha(n: f64) -> [f64; 6] {
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+    [
+        1.0 / 2.0 * n - 2.0 / 3.0 * n2 + 5.0 / 16.0 * n3 + 41.0 / 180.0 * n4 - 127.0 / 288.0 * n5
+            + 7891.0 / 37800.0 * n6,
+        13.0 / 48.0 * n2 - 3.0 / 5.0 * n3 + 557.0 / 1440.0 * n4 + 281.0 / 630.0 * n5
+            - 1983433.0 / 1935360.0 * n6,
+        61.0 / 240.0 * n3 - 103.0 / 140.0 * n4 + 15061.0 / 26880.0 * n5 + 167603.0 / 181440.0 * n6,
+        49561.0 / 161280.0 * n4 - 179.0 / 168.0 * n5 + 6601661.0 / 7257600.0 * n6,
+        34729.0 / 80640.0 * n5 - 3418889.0 / 1995840.0 * n6,
+        212378941.0 / 319334400.0 * n6,
+    ]
+}
+
+/// Returns the Krüger series coefficients used to convert from the transverse Mercator projection to
+/// conformal latitude.
+fn beta(n: f64) -> [f64; 6] {
+    let n2 = n * n;
+    let n3 = n2 * n;
+    let n4 = n3 * n;
+    let n5 = n4 * n;
+    let n6 = n5 * n;
+    [
+        1.0 / 2.0 * n - 2.0 / 3.0 * n2 + 37.0 / 96.0 * n3 - 1.0 / 360.0 * n4 - 81.0 / 512.0 * n5
+            + 96199.0 / 604800.0 * n6,
+        1.0 / 48.0 * n2 + 1.0 / 15.0 * n3 - 437.0 / 1440.0 * n4 + 46.0 / 105.0 * n5
+            - 1118711.0 / 3870720.0 * n6,
+        17.0 / 480.0 * n3 - 37.0 / 840.0 * n4 - 209.0 / 4480.0 * n5 + 5569.0 / 90720.0 * n6,
+        4397.0 / 161280.0 * n4 - 11.0 / 504.0 * n5 - 830251.0 / 7257600.0 * n6,
+        4583.0 / 161280.0 * n5 - 108847.0 / 3991680.0 * n6,
+        20648693.0 / 638668800.0 * n6,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ellipsoidal::Ellipsoid, LatLong, Length};
+
+    use super::{Hemisphere, Utm, UtmError};
+
+    fn assert_utm(
+        expected_zone: u8,
+        expected_hemisphere: Hemisphere,
+        expected_easting: f64,
+        expected_northing: f64,
+        actual: Utm,
+    ) {
+        assert_eq!(expected_zone, actual.zone());
+        assert_eq!(expected_hemisphere, actual.hemisphere());
+        assert_eq!(
+            Length::from_metres(expected_easting),
+            actual.easting().round_m()
+        );
+        assert_eq!(
+            Length::from_metres(expected_northing),
+            actual.northing().round_m()
+        );
+    }
+
+    fn to_utm(lat: f64, lng: f64) -> Utm {
+        Utm::from_lat_long(&LatLong::from_degrees(lat, lng), &Ellipsoid::WGS84).unwrap()
+    }
+
+    // from_lat_long
+
+    #[test]
+    fn from_lat_long_north_east() {
+        // Eiffel Tower.
+        assert_utm(
+            31,
+            Hemisphere::North,
+            448_252.0,
+            5_411_933.0,
+            to_utm(48.8582, 2.2945),
+        );
+    }
+
+    #[test]
+    fn from_lat_long_south_east() {
+        // Sydney Opera House.
+        assert_utm(
+            56,
+            Hemisphere::South,
+            334_873.0,
+            6_252_266.0,
+            to_utm(-33.857, 151.215),
+        );
+    }
+
+    #[test]
+    fn from_lat_long_north_west() {
+        // White House: 38°53′51.7″N, 77°02′11.4″W.
+        assert_utm(
+            18,
+            Hemisphere::North,
+            323_394.0,
+            4_307_395.0,
+            to_utm(38.897694, -77.036500),
+        );
+    }
+
+    #[test]
+    fn from_lat_long_south_west() {
+        // Christ the Redeemer.
+        assert_utm(
+            23,
+            Hemisphere::South,
+            683_466.0,
+            7_460_687.0,
+            to_utm(-22.9519, -43.2106),
+        );
+    }
+
+    #[test]
+    fn from_lat_long_equator_central_meridian() {
+        assert_utm(31, Hemisphere::North, 500_000.0, 0.0, to_utm(0.0, 3.0));
+        assert_utm(
+            31,
+            Hemisphere::South,
+            500_000.0,
+            10_000_000.0,
+            to_utm(-1e-12, 3.0),
+        );
+    }
+
+    #[test]
+    fn from_lat_long_norway() {
+        // Bergen: 32V instead of 31V.
+        assert_utm(
+            32,
+            Hemisphere::North,
+            297_508.0,
+            6_700_645.0,
+            to_utm(60.39135, 5.3249),
+        );
+        assert_eq!(31, to_utm(60.0, 2.9).zone());
+        assert_eq!(32, to_utm(60.0, 3.0).zone());
+        assert_eq!(32, to_utm(60.0, 11.9).zone());
+        assert_eq!(33, to_utm(60.0, 12.0).zone());
+        // band W: no exception.
+        assert_eq!(31, to_utm(64.0, 5.0).zone());
+    }
+
+    #[test]
+    fn from_lat_long_svalbard() {
+        assert_eq!(31, to_utm(78.0, 8.9).zone());
+        assert_eq!(33, to_utm(78.0, 9.0).zone());
+        assert_eq!(33, to_utm(78.0, 20.9).zone());
+        assert_eq!(35, to_utm(78.0, 21.0).zone());
+        assert_eq!(35, to_utm(78.0, 32.9).zone());
+        assert_eq!(37, to_utm(78.0, 33.0).zone());
+        assert_eq!(37, to_utm(78.0, 41.9).zone());
+        assert_eq!(38, to_utm(78.0, 42.0).zone());
+        // band W: no exception.
+        assert_eq!(32, to_utm(71.9, 9.0).zone());
+    }
+
+    #[test]
+    fn from_lat_long_antimeridian() {
+        assert_eq!(1, to_utm(0.0, -180.0).zone());
+        assert_eq!(1, to_utm(0.0, 180.0).zone());
+        assert_eq!(60, to_utm(0.0, 179.9).zone());
+    }
+
+    #[test]
+    fn from_lat_long_latitude_out_of_range() {
+        assert_eq!(
+            Err(UtmError::LatitudeOutOfRange),
+            Utm::from_lat_long(&LatLong::from_degrees(84.1, 0.0), &Ellipsoid::WGS84)
+        );
+        assert_eq!(
+            Err(UtmError::LatitudeOutOfRange),
+            Utm::from_lat_long(&LatLong::from_degrees(-80.1, 0.0), &Ellipsoid::WGS84)
+        );
+        assert!(Utm::from_lat_long(&LatLong::from_degrees(84.0, 0.0), &Ellipsoid::WGS84).is_ok());
+        assert!(Utm::from_lat_long(&LatLong::from_degrees(-80.0, 0.0), &Ellipsoid::WGS84).is_ok());
+    }
+
+    // new
+
+    #[test]
+    fn new_invalid_zone() {
+        let e = Length::from_metres(500_000.0);
+        let n = Length::ZERO;
+        assert_eq!(
+            Err(UtmError::InvalidZone(0)),
+            Utm::new(0, Hemisphere::North, e, n)
+        );
+        assert_eq!(
+            Err(UtmError::InvalidZone(61)),
+            Utm::new(61, Hemisphere::North, e, n)
+        );
+        assert!(Utm::new(60, Hemisphere::North, e, n).is_ok());
+    }
+
+    // to_lat_long
+
+    #[test]
+    fn to_lat_long() {
+        let utm = Utm::new(
+            56,
+            Hemisphere::South,
+            Length::from_metres(334_873.0),
+            Length::from_metres(6_252_266.0),
+        )
+        .unwrap();
+        assert_eq!(
+            LatLong::from_degrees(-33.857, 151.215),
+            utm.to_lat_long(&Ellipsoid::WGS84).round_d5()
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let ps = vec![
+            LatLong::from_degrees(48.8582, 2.2945),
+            LatLong::from_degrees(-33.857, 151.215),
+            LatLong::from_degrees(38.8977, -77.0365),
+            LatLong::from_degrees(-22.9519, -43.2106),
+            LatLong::from_degrees(60.39135, 5.3249),
+            LatLong::from_degrees(78.2232, 15.6267),
+            LatLong::from_degrees(-79.9, -179.9),
+            LatLong::from_degrees(83.9, 179.9),
+            LatLong::from_degrees(0.0, 0.0),
+        ];
+        let ellipsoids = [
+            Ellipsoid::WGS84,
+            Ellipsoid::GRS80,
+            Ellipsoid::INTERNATIONAL1924,
+        ];
+        for p in ps {
+            for ellipsoid in ellipsoids.iter() {
+                let utm = Utm::from_lat_long(&p, ellipsoid).unwrap();
+                let actual = utm.to_lat_long(ellipsoid);
+                assert!((p.latitude().as_degrees() - actual.latitude().as_degrees()).abs() < 1e-9);
+                assert!(
+                    (p.longitude().as_degrees() - actual.longitude().as_degrees()).abs() < 1e-9
+                );
+            }
+        }
+    }
+}
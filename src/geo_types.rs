@@ -0,0 +1,210 @@
+//! Conversions to and from the [geo-types](https://docs.rs/geo-types) crate (requires the `geo-types` feature).
+//!
+//! geo-types coordinates are planar: `x` is the longitude and `y` is the latitude, both in decimal degrees -
+//! i.e. the order is longitude first, the opposite of [LatLong]. Converting a [LatLong] into a geo-types
+//! [Point] or [Coord] never fails; the reverse conversion fails if the latitude is not within [-90, 90] degrees
+//! or if the longitude is not within [-180, 180] degrees, which includes NaN and infinite coordinates.
+//! Coordinates are not rounded: converting to and from degrees may change the last bit of a coordinate.
+//!
+//! A [Path] converts into a geo-types [LineString] and back, and [line_string] and [parse_line_string] convert
+//! any slice of positions.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo_types::Point;
+//! use jord::LatLong;
+//!
+//! let paris = LatLong::from_degrees(48.8567, 2.3508);
+//! let p: Point<f64> = paris.into();
+//! assert_eq!(2.3508, p.x());
+//! assert_eq!(48.8567, p.y());
+//! assert_eq!(Ok(paris), LatLong::try_from(p));
+//! ```
+
+use ::geo_types::{Coord, LineString, Point};
+
+use crate::{spherical::Path, LatLong, ParseLatLongError};
+
+impl From<LatLong> for Coord<f64> {
+    /// Converts the given [LatLong] into a [Coord] whose `x` is the longitude and `y` is the latitude, in decimal
+    /// degrees.
+    fn from(p: LatLong) -> Self {
+        Coord {
+            x: p.longitude().as_degrees(),
+            y: p.latitude().as_degrees(),
+        }
+    }
+}
+
+impl From<LatLong> for Point<f64> {
+    /// Converts the given [LatLong] into a [Point] whose `x` is the longitude and `y` is the latitude, in decimal
+    /// degrees.
+    fn from(p: LatLong) -> Self {
+        Point(p.into())
+    }
+}
+
+impl TryFrom<Coord<f64>> for LatLong {
+    type Error = ParseLatLongError;
+
+    /// Converts the given [Coord] - `x` is the longitude and `y` is the latitude, in decimal degrees - into a
+    /// [LatLong].
+    ///
+    /// Returns [LatitudeOutOfRange](ParseLatLongError::LatitudeOutOfRange) if `y` is not within [-90, 90] and
+    /// [LongitudeOutOfRange](ParseLatLongError::LongitudeOutOfRange) if `x` is not within [-180, 180] (including
+    /// NaN and infinite coordinates).
+    fn try_from(c: Coord<f64>) -> Result<Self, Self::Error> {
+        if !(-90.0..=90.0).contains(&c.y) {
+            Err(ParseLatLongError::LatitudeOutOfRange(c.y))
+        } else if !(-180.0..=180.0).contains(&c.x) {
+            Err(ParseLatLongError::LongitudeOutOfRange(c.x))
+        } else {
+            Ok(LatLong::from_degrees(c.y, c.x))
+        }
+    }
+}
+
+impl TryFrom<Point<f64>> for LatLong {
+    type Error = ParseLatLongError;
+
+    /// Converts the given [Point] - `x` is the longitude and `y` is the latitude, in decimal degrees - into a
+    /// [LatLong], failing if either coordinate is out of range: see the conversion from [Coord].
+    fn try_from(p: Point<f64>) -> Result<Self, Self::Error> {
+        LatLong::try_from(p.0)
+    }
+}
+
+impl From<Path> for LineString<f64> {
+    /// Converts the given [Path] into a [LineString] of the same positions, in the same order.
+    fn from(path: Path) -> Self {
+        line_string(&path)
+    }
+}
+
+impl TryFrom<LineString<f64>> for Path {
+    type Error = ParseLatLongError;
+
+    /// Converts the given [LineString] into a [Path]: see [parse_line_string].
+    fn try_from(ls: LineString<f64>) -> Result<Self, Self::Error> {
+        parse_line_string(&ls).map(Path::new)
+    }
+}
+
+/// Returns the [LineString] of the given positions, in the same order.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::line_string;
+/// use jord::LatLong;
+///
+/// let ps = vec![LatLong::from_degrees(48.8567, 2.3508), LatLong::from_degrees(51.5074, -0.1278)];
+/// assert_eq!(
+///     line_string![(x: 2.3508, y: 48.8567), (x: -0.1278, y: 51.5074)],
+///     jord::geo_types::line_string(&ps)
+/// );
+/// ```
+pub fn line_string(ps: &[LatLong]) -> LineString<f64> {
+    LineString(ps.iter().map(|p| Coord::from(*p)).collect())
+}
+
+/// Returns the positions of the given [LineString], in the same order.
+///
+/// Returns the error of the first coordinate which is not a valid [LatLong], i.e. whose latitude (`y`) is not
+/// within [-90, 90] degrees or whose longitude (`x`) is not within [-180, 180] degrees.
+pub fn parse_line_string(ls: &LineString<f64>) -> Result<Vec<LatLong>, ParseLatLongError> {
+    ls.coords().map(|c| LatLong::try_from(*c)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ::geo_types::{coord, line_string, point, Coord, LineString, Point};
+
+    use crate::{spherical::Path, LatLong, ParseLatLongError};
+
+    use super::{line_string, parse_line_string};
+
+    #[test]
+    fn point_axis_order() {
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        assert_eq!(point!(x: 2.3508, y: 48.8567), Point::from(p));
+        assert_eq!(coord! { x: 2.3508, y: 48.8567 }, Coord::from(p));
+    }
+
+    #[test]
+    fn point_round_trip() {
+        for p in [
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(90.0, 0.0),
+            LatLong::from_degrees(-90.0, 180.0),
+            LatLong::from_degrees(-33.8688, -180.0),
+        ] {
+            assert_eq!(Ok(p), LatLong::try_from(Point::from(p)));
+        }
+    }
+
+    #[test]
+    fn point_out_of_range() {
+        assert_eq!(
+            Err(ParseLatLongError::LatitudeOutOfRange(90.5)),
+            LatLong::try_from(point!(x: 0.0, y: 90.5))
+        );
+        assert_eq!(
+            Err(ParseLatLongError::LongitudeOutOfRange(-180.5)),
+            LatLong::try_from(point!(x: -180.5, y: 0.0))
+        );
+        assert_eq!(
+            Err(ParseLatLongError::LongitudeOutOfRange(f64::INFINITY)),
+            LatLong::try_from(point!(x: f64::INFINITY, y: 0.0))
+        );
+    }
+
+    #[test]
+    fn point_nan() {
+        assert!(matches!(
+            LatLong::try_from(point!(x: 0.0, y: f64::NAN)),
+            Err(ParseLatLongError::LatitudeOutOfRange(lat)) if lat.is_nan()
+        ));
+        assert!(matches!(
+            LatLong::try_from(point!(x: f64::NAN, y: 0.0)),
+            Err(ParseLatLongError::LongitudeOutOfRange(lng)) if lng.is_nan()
+        ));
+    }
+
+    #[test]
+    fn line_string_round_trip() {
+        let ps = vec![
+            LatLong::from_degrees(48.8567, 2.3508),
+            LatLong::from_degrees(51.5074, -0.1278),
+        ];
+        let ls = line_string(&ps);
+        assert_eq!(
+            line_string![(x: 2.3508, y: 48.8567), (x: -0.1278, y: 51.5074)],
+            ls
+        );
+        assert_eq!(Ok(ps.clone()), parse_line_string(&ls));
+
+        let path = Path::new(ps);
+        assert_eq!(Ok(path.clone()), Path::try_from(LineString::from(path)));
+    }
+
+    #[test]
+    fn line_string_empty() {
+        assert!(line_string(&[]).0.is_empty());
+        assert_eq!(Ok(vec![]), parse_line_string(&LineString(vec![])));
+    }
+
+    #[test]
+    fn line_string_invalid() {
+        let ls = line_string![(x: 0.0, y: 0.0), (x: 200.0, y: 0.0), (x: 0.0, y: 100.0)];
+        assert_eq!(
+            Err(ParseLatLongError::LongitudeOutOfRange(200.0)),
+            parse_line_string(&ls)
+        );
+        assert_eq!(
+            Err(ParseLatLongError::LongitudeOutOfRange(200.0)),
+            Path::try_from(ls)
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::fmt;
+
+use crate::{Angle, LatLong};
+
+/// Geohash base-32 alphabet: digits and lower-case letters excluding "a", "i", "l" and "o".
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// Maximum number of characters of a geohash: 12 characters is a cell of about 37 by 19 millimetres.
+const MAX_PRECISION: usize = 12;
+
+/// Error returned when decoding an invalid [geohash](https://en.wikipedia.org/wiki/Geohash).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GeohashError {
+    /// The geohash is either empty or has more than 12 characters.
+    InvalidLength(usize),
+    /// The geohash contains a character which is not part of the geohash base-32 alphabet.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeohashError::InvalidLength(len) => write!(
+                f,
+                "invalid geohash length: {} (expected 1 to {} characters)",
+                len, MAX_PRECISION
+            ),
+            GeohashError::InvalidCharacter(c) => write!(f, "invalid geohash character: {:?}", c),
+        }
+    }
+}
+
+impl std::error::Error for GeohashError {}
+
+impl LatLong {
+    /// Encodes this [LatLong] into a [geohash](https://en.wikipedia.org/wiki/Geohash) of the given
+    /// precision (number of characters). The precision is clamped to the range [1, 12].
+    ///
+    /// This [LatLong] is assumed to have a latitude in [-90, 90] degrees and a longitude in [-180, 180]
+    /// degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    ///
+    /// assert_eq!("ezs42", LatLong::from_degrees(42.605, -5.603).to_geohash(5));
+    /// assert_eq!("u4pruydqqvj", LatLong::from_degrees(57.64911, 10.40744).to_geohash(11));
+    /// ```
+    pub fn to_geohash(&self, precision: usize) -> String {
+        let precision = precision.clamp(1, MAX_PRECISION);
+        let lat = self.latitude().as_degrees();
+        let lng = self.longitude().as_degrees();
+
+        let mut lat_interval = (-90.0, 90.0);
+        let mut lng_interval = (-180.0, 180.0);
+        let mut geohash = String::with_capacity(precision);
+        // bits are interleaved starting with the longitude.
+        let mut even_bit = true;
+        for _ in 0..precision {
+            let mut index = 0;
+            for _ in 0..5 {
+                let (value, interval) = if even_bit {
+                    (lng, &mut lng_interval)
+                } else {
+                    (lat, &mut lat_interval)
+                };
+                let mid = (interval.0 + interval.1) / 2.0;
+                index <<= 1;
+                if value >= mid {
+                    index |= 1;
+                    interval.0 = mid;
+                } else {
+                    interval.1 = mid;
+                }
+                even_bit = !even_bit;
+            }
+            geohash.push(ALPHABET[index] as char);
+        }
+        geohash
+    }
+
+    /// Decodes the given [geohash](https://en.wikipedia.org/wiki/Geohash) into the cell it represents: returns the
+    /// south-west (lowest latitude and longitude) and north-east (highest latitude and longitude) corners of the cell.
+    ///
+    /// The geohash must have between 1 and 12 characters; decoding is case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{GeohashError, LatLong};
+    ///
+    /// let (sw, ne) = LatLong::from_geohash("ezs42").unwrap();
+    /// assert_eq!(LatLong::from_degrees(42.58301, -5.625), sw.round_d5());
+    /// assert_eq!(LatLong::from_degrees(42.62695, -5.58105), ne.round_d5());
+    ///
+    /// assert_eq!(Err(GeohashError::InvalidCharacter('a')), LatLong::from_geohash("ezsa2"));
+    /// ```
+    pub fn from_geohash(geohash: &str) -> Result<(LatLong, LatLong), GeohashError> {
+        let len = geohash.chars().count();
+        if len == 0 || len > MAX_PRECISION {
+            return Err(GeohashError::InvalidLength(len));
+        }
+
+        let mut lat_interval = (-90.0, 90.0);
+        let mut lng_interval = (-180.0, 180.0);
+        let mut even_bit = true;
+        for c in geohash.chars() {
+            let index = decode_char(c).ok_or(GeohashError::InvalidCharacter(c))?;
+            for shift in (0..5).rev() {
+                let interval = if even_bit {
+                    &mut lng_interval
+                } else {
+                    &mut lat_interval
+                };
+                let mid = (interval.0 + interval.1) / 2.0;
+                if (index >> shift) & 1 == 1 {
+                    interval.0 = mid;
+                } else {
+                    interval.1 = mid;
+                }
+                even_bit = !even_bit;
+            }
+        }
+
+        let sw = LatLong::new(
+            Angle::from_degrees(lat_interval.0),
+            Angle::from_degrees(lng_interval.0),
+        );
+        let ne = LatLong::new(
+            Angle::from_degrees(lat_interval.1),
+            Angle::from_degrees(lng_interval.1),
+        );
+        Ok((sw, ne))
+    }
+}
+
+/// Returns the index of the given character in the geohash alphabet (case-insensitive).
+fn decode_char(c: char) -> Option<u8> {
+    if !c.is_ascii() {
+        return None;
+    }
+    let lc = c.to_ascii_lowercase() as u8;
+    ALPHABET.iter().position(|&a| a == lc).map(|i| i as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GeohashError, LatLong};
+
+    #[test]
+    fn to_geohash() {
+        assert_eq!("ezs42", LatLong::from_degrees(42.605, -5.603).to_geohash(5));
+        assert_eq!(
+            "u4pruydqqvj",
+            LatLong::from_degrees(57.64911, 10.40744).to_geohash(11)
+        );
+    }
+
+    #[test]
+    fn to_geohash_clamped_precision() {
+        let p = LatLong::from_degrees(57.64911, 10.40744);
+        assert_eq!("u", p.to_geohash(0));
+        assert_eq!(p.to_geohash(12), p.to_geohash(20));
+        assert_eq!(12, p.to_geohash(20).len());
+    }
+
+    #[test]
+    fn to_geohash_extremes() {
+        assert_eq!("000000", LatLong::from_degrees(-90.0, -180.0).to_geohash(6));
+        assert_eq!("zzzzzz", LatLong::from_degrees(90.0, 180.0).to_geohash(6));
+        assert_eq!("s00000", LatLong::from_degrees(0.0, 0.0).to_geohash(6));
+    }
+
+    #[test]
+    fn from_geohash_contains_encoded() {
+        let ps = vec![
+            LatLong::from_degrees(42.605, -5.603),
+            LatLong::from_degrees(57.64911, 10.40744),
+            LatLong::from_degrees(-33.8688, 151.2093),
+            LatLong::from_degrees(-54.8019, -68.303),
+            LatLong::from_degrees(89.9999, 179.9999),
+        ];
+        for p in ps {
+            for precision in 1..=12 {
+                let (sw, ne) = LatLong::from_geohash(&p.to_geohash(precision)).unwrap();
+                assert!(sw.latitude() <= p.latitude() && p.latitude() <= ne.latitude());
+                assert!(sw.longitude() <= p.longitude() && p.longitude() <= ne.longitude());
+            }
+        }
+    }
+
+    #[test]
+    fn from_geohash_cell_size() {
+        let (sw, ne) = LatLong::from_geohash("u4pruydqqvj").unwrap();
+        // 11 characters: 55 bits, 28 for longitude and 27 for latitude.
+        let lat_size = 180.0 / (1u64 << 27) as f64;
+        let lng_size = 360.0 / (1u64 << 28) as f64;
+        let d_lat = ne.latitude().as_degrees() - sw.latitude().as_degrees();
+        let d_lng = ne.longitude().as_degrees() - sw.longitude().as_degrees();
+        assert!((d_lat - lat_size).abs() < 1e-12);
+        assert!((d_lng - lng_size).abs() < 1e-12);
+    }
+
+    #[test]
+    fn from_geohash_case_insensitive() {
+        assert_eq!(
+            LatLong::from_geohash("ezs42"),
+            LatLong::from_geohash("EZS42")
+        );
+    }
+
+    #[test]
+    fn from_geohash_invalid_character() {
+        for c in ['a', 'i', 'l', 'o', ' ', '-', 'é'] {
+            let geohash = format!("u4{}", c);
+            assert_eq!(
+                Err(GeohashError::InvalidCharacter(c)),
+                LatLong::from_geohash(&geohash)
+            );
+        }
+    }
+
+    #[test]
+    fn from_geohash_invalid_length() {
+        assert_eq!(
+            Err(GeohashError::InvalidLength(0)),
+            LatLong::from_geohash("")
+        );
+        assert_eq!(
+            Err(GeohashError::InvalidLength(13)),
+            LatLong::from_geohash("u4pruydqqvjuu")
+        );
+    }
+}
@@ -0,0 +1,560 @@
+//! Conversions between positions and [GeoJSON](https://datatracker.ietf.org/doc/html/rfc7946) geometries
+//! (requires the `geojson` feature).
+//!
+//! GeoJSON positions are ordered longitude first, then latitude and optionally height in metres: all functions
+//! of this module take care of this ordering, e.g. Paris (48.8567°N, 2.3508°E) is written `[2.3508, 48.8567]`.
+//!
+//! Coordinates are written in decimal degrees rounded to 9 decimal places (about 0.1 millimetre at the equator).
+
+use std::fmt;
+
+use serde_json::{json, Map, Value};
+
+use crate::{spherical::Path, Angle, GeodeticPos, LatLong, Length};
+
+/// Error returned when a GeoJSON geometry cannot be parsed.
+#[derive(PartialEq, Clone, Debug)]
+pub enum GeoJsonError {
+    /// The value is not a GeoJSON object of the expected type ("Point", "LineString", "Polygon", "Feature" or
+    /// "FeatureCollection").
+    UnexpectedType(&'static str),
+    /// The coordinates are not an array with the expected structure or a position does not have 2 or 3
+    /// numbers.
+    InvalidCoordinates,
+    /// The latitude (in degrees) is outside of [-90, 90].
+    LatitudeOutOfRange(f64),
+    /// The longitude (in degrees) is outside of [-180, 180].
+    LongitudeOutOfRange(f64),
+    /// A polygon ring is not closed (first and last positions differ) or has less than 4 positions.
+    InvalidRing,
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoJsonError::UnexpectedType(t) => write!(f, "expected a GeoJSON {}", t),
+            GeoJsonError::InvalidCoordinates => write!(f, "invalid GeoJSON coordinates"),
+            GeoJsonError::LatitudeOutOfRange(lat) => {
+                write!(f, "latitude out of range [-90, 90]: {}", lat)
+            }
+            GeoJsonError::LongitudeOutOfRange(lng) => {
+                write!(f, "longitude out of range [-180, 180]: {}", lng)
+            }
+            GeoJsonError::InvalidRing => write!(
+                f,
+                "invalid GeoJSON polygon ring (expected at least 4 positions, first equal to last)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+/// Returns the GeoJSON Point geometry of the given position.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::geojson;
+/// use serde_json::json;
+///
+/// let paris = LatLong::from_degrees(48.8567, 2.3508);
+/// assert_eq!(
+///     json!({ "type": "Point", "coordinates": [2.3508, 48.8567] }),
+///     geojson::point(paris)
+/// );
+/// ```
+pub fn point(p: LatLong) -> Value {
+    json!({ "type": "Point", "coordinates": position(p) })
+}
+
+/// Returns the GeoJSON Point geometry of the given position; the height is written as the third coordinate
+/// in metres.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{GeodeticPos, Length, NVector};
+/// use jord::geojson;
+/// use serde_json::json;
+///
+/// let p = GeodeticPos::new(NVector::from_lat_long_degrees(48.8567, 2.3508), Length::from_metres(35.0));
+/// assert_eq!(
+///     json!({ "type": "Point", "coordinates": [2.3508, 48.8567, 35.0] }),
+///     geojson::point_with_height(p)
+/// );
+/// ```
+pub fn point_with_height(p: GeodeticPos) -> Value {
+    let ll = LatLong::from_nvector(p.horizontal_position());
+    json!({
+        "type": "Point",
+        "coordinates": [
+            round_d9(ll.longitude()),
+            round_d9(ll.latitude()),
+            p.height().as_metres()
+        ]
+    })
+}
+
+/// Returns the GeoJSON LineString geometry of the given positions.
+pub fn line_string(ps: &[LatLong]) -> Value {
+    let coordinates: Vec<Value> = ps.iter().map(|p| position(*p)).collect();
+    json!({ "type": "LineString", "coordinates": coordinates })
+}
+
+/// Returns the GeoJSON Polygon geometry of the given rings: the first ring is the exterior ring, any
+/// other ring is an interior ring (hole). Each ring is closed if required, i.e. the first position is
+/// repeated at the end of the ring unless it is already equal to the last position.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::geojson;
+/// use serde_json::json;
+///
+/// let ring = vec![
+///     LatLong::from_degrees(0.0, 0.0),
+///     LatLong::from_degrees(0.0, 1.0),
+///     LatLong::from_degrees(1.0, 1.0),
+/// ];
+/// assert_eq!(
+///     json!({ "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]] }),
+///     geojson::polygon(&[ring])
+/// );
+/// ```
+pub fn polygon(rings: &[Vec<LatLong>]) -> Value {
+    let coordinates: Vec<Value> = rings
+        .iter()
+        .map(|ring| {
+            let mut r: Vec<Value> = ring.iter().map(|p| position(*p)).collect();
+            if let (Some(first), Some(last)) = (r.first(), r.last()) {
+                if r.len() == 1 || first != last {
+                    r.push(first.clone());
+                }
+            }
+            Value::Array(r)
+        })
+        .collect();
+    json!({ "type": "Polygon", "coordinates": coordinates })
+}
+
+/// A LineString feature: the path and the properties (if any) of the feature.
+pub type LineStringFeature = (Path, Option<Map<String, Value>>);
+
+/// Returns the GeoJSON FeatureCollection of the given paths: each path is a Feature whose geometry is the
+/// LineString of the path and whose properties are the given properties (`null` if [None]).
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::geojson;
+/// use jord::spherical::Path;
+/// use serde_json::json;
+///
+/// let track = Path::new(vec![LatLong::from_degrees(48.8567, 2.3508), LatLong::from_degrees(51.5074, -0.1278)]);
+/// let properties = json!({ "name": "AF1680" }).as_object().cloned();
+/// assert_eq!(
+///     json!({
+///         "type": "FeatureCollection",
+///         "features": [{
+///             "type": "Feature",
+///             "geometry": { "type": "LineString", "coordinates": [[2.3508, 48.8567], [-0.1278, 51.5074]] },
+///             "properties": { "name": "AF1680" }
+///         }]
+///     }),
+///     geojson::feature_collection(&[(track, properties)])
+/// );
+/// ```
+pub fn feature_collection(paths: &[LineStringFeature]) -> Value {
+    let features: Vec<Value> = paths
+        .iter()
+        .map(|(path, properties)| {
+            json!({
+                "type": "Feature",
+                "geometry": line_string(path),
+                "properties": properties
+            })
+        })
+        .collect();
+    json!({ "type": "FeatureCollection", "features": features })
+}
+
+/// Parses the given GeoJSON FeatureCollection of LineString features: returns the path and the properties
+/// ([None] if `null` or absent) of each feature. Any other member of the features (e.g. `id` or `bbox`) is
+/// ignored.
+pub fn parse_feature_collection(v: &Value) -> Result<Vec<LineStringFeature>, GeoJsonError> {
+    if v.get("type").and_then(Value::as_str) != Some("FeatureCollection") {
+        return Err(GeoJsonError::UnexpectedType("FeatureCollection"));
+    }
+    v.get("features")
+        .and_then(Value::as_array)
+        .ok_or(GeoJsonError::UnexpectedType("FeatureCollection"))?
+        .iter()
+        .map(|f| {
+            if f.get("type").and_then(Value::as_str) != Some("Feature") {
+                return Err(GeoJsonError::UnexpectedType("Feature"));
+            }
+            let geometry = f
+                .get("geometry")
+                .ok_or(GeoJsonError::UnexpectedType("LineString"))?;
+            let path = Path::new(parse_line_string(geometry)?);
+            let properties = f.get("properties").and_then(Value::as_object).cloned();
+            Ok((path, properties))
+        })
+        .collect()
+}
+
+/// Parses the given GeoJSON Point geometry; the height - if any - is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::geojson;
+/// use serde_json::json;
+///
+/// let p = geojson::parse_point(&json!({ "type": "Point", "coordinates": [2.3508, 48.8567] })).unwrap();
+/// assert_eq!(LatLong::from_degrees(48.8567, 2.3508), p);
+/// ```
+pub fn parse_point(v: &Value) -> Result<LatLong, GeoJsonError> {
+    parse_position(coordinates(v, "Point")?).map(|(p, _)| p)
+}
+
+/// Parses the given GeoJSON Point geometry; the height is 0 if the position has no third coordinate.
+pub fn parse_point_with_height(v: &Value) -> Result<GeodeticPos, GeoJsonError> {
+    let (p, h) = parse_position(coordinates(v, "Point")?)?;
+    Ok(GeodeticPos::new(p.to_nvector(), h))
+}
+
+/// Parses the given GeoJSON LineString geometry.
+pub fn parse_line_string(v: &Value) -> Result<Vec<LatLong>, GeoJsonError> {
+    parse_positions(coordinates(v, "LineString")?)
+}
+
+/// Parses the given GeoJSON Polygon geometry: returns the exterior ring followed by the interior rings (if any).
+/// Each returned ring omits the closing position, i.e. the last position of each GeoJSON ring.
+pub fn parse_polygon(v: &Value) -> Result<Vec<Vec<LatLong>>, GeoJsonError> {
+    coordinates(v, "Polygon")?
+        .as_array()
+        .ok_or(GeoJsonError::InvalidCoordinates)?
+        .iter()
+        .map(|r| {
+            let mut ring = parse_positions(r)?;
+            if ring.len() < 4 || ring.first() != ring.last() {
+                return Err(GeoJsonError::InvalidRing);
+            }
+            ring.pop();
+            Ok(ring)
+        })
+        .collect()
+}
+
+/// Returns the GeoJSON position - [longitude, latitude] - of the given position.
+fn position(p: LatLong) -> Value {
+    json!([round_d9(p.longitude()), round_d9(p.latitude())])
+}
+
+/// Returns the given angle in decimal degrees rounded to 9 decimal places.
+fn round_d9(a: Angle) -> f64 {
+    (a.as_degrees() * 1e9).round() / 1e9
+}
+
+/// Returns the coordinates of the given geometry if of the given type.
+fn coordinates<'a>(v: &'a Value, geometry_type: &'static str) -> Result<&'a Value, GeoJsonError> {
+    if v.get("type").and_then(Value::as_str) != Some(geometry_type) {
+        return Err(GeoJsonError::UnexpectedType(geometry_type));
+    }
+    v.get("coordinates").ok_or(GeoJsonError::InvalidCoordinates)
+}
+
+/// Parses the given array of GeoJSON positions ignoring heights.
+fn parse_positions(v: &Value) -> Result<Vec<LatLong>, GeoJsonError> {
+    v.as_array()
+        .ok_or(GeoJsonError::InvalidCoordinates)?
+        .iter()
+        .map(|p| parse_position(p).map(|(ll, _)| ll))
+        .collect()
+}
+
+/// Parses the given GeoJSON position: [longitude, latitude] or [longitude, latitude, height].
+fn parse_position(v: &Value) -> Result<(LatLong, Length), GeoJsonError> {
+    let cs = v.as_array().ok_or(GeoJsonError::InvalidCoordinates)?;
+    if cs.len() != 2 && cs.len() != 3 {
+        return Err(GeoJsonError::InvalidCoordinates);
+    }
+    let cs: Vec<f64> = cs
+        .iter()
+        .map(|c| c.as_f64().ok_or(GeoJsonError::InvalidCoordinates))
+        .collect::<Result<_, _>>()?;
+    let (lng, lat) = (cs[0], cs[1]);
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(GeoJsonError::LatitudeOutOfRange(lat));
+    }
+    if !(-180.0..=180.0).contains(&lng) {
+        return Err(GeoJsonError::LongitudeOutOfRange(lng));
+    }
+    let h = cs.get(2).copied().unwrap_or(0.0);
+    Ok((LatLong::from_degrees(lat, lng), Length::from_metres(h)))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{spherical::Path, GeodeticPos, LatLong, Length, NVector};
+
+    use super::{
+        feature_collection, line_string, parse_feature_collection, parse_line_string, parse_point,
+        parse_point_with_height, parse_polygon, point, point_with_height, polygon, GeoJsonError,
+    };
+
+    fn paris() -> LatLong {
+        LatLong::from_degrees(48.8567, 2.3508)
+    }
+
+    // point
+
+    #[test]
+    fn point_lon_lat_order() {
+        let v = point(paris());
+        assert_eq!(json!([2.3508, 48.8567]), v["coordinates"]);
+        assert_ne!(json!([48.8567, 2.3508]), v["coordinates"]);
+        assert_eq!(
+            r#"{"coordinates":[2.3508,48.8567],"type":"Point"}"#,
+            v.to_string()
+        );
+    }
+
+    #[test]
+    fn point_with_height_third_coordinate() {
+        let p = GeodeticPos::new(
+            NVector::from_lat_long_degrees(48.8567, 2.3508),
+            Length::from_metres(-12.5),
+        );
+        assert_eq!(
+            json!([2.3508, 48.8567, -12.5]),
+            point_with_height(p)["coordinates"]
+        );
+    }
+
+    // line_string
+
+    #[test]
+    fn line_string_lon_lat_order() {
+        let ps = vec![paris(), LatLong::from_degrees(51.5074, -0.1278)];
+        assert_eq!(
+            json!({ "type": "LineString", "coordinates": [[2.3508, 48.8567], [-0.1278, 51.5074]] }),
+            line_string(&ps)
+        );
+    }
+
+    // polygon
+
+    #[test]
+    fn polygon_already_closed() {
+        let ring = vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 1.0),
+            LatLong::from_degrees(1.0, 1.0),
+            LatLong::from_degrees(0.0, 0.0),
+        ];
+        assert_eq!(
+            json!([[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]]),
+            polygon(&[ring])["coordinates"]
+        );
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let exterior = vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 10.0),
+            LatLong::from_degrees(10.0, 10.0),
+            LatLong::from_degrees(10.0, 0.0),
+        ];
+        let hole = vec![
+            LatLong::from_degrees(4.0, 4.0),
+            LatLong::from_degrees(6.0, 4.0),
+            LatLong::from_degrees(6.0, 6.0),
+        ];
+        let v = polygon(&[exterior.clone(), hole.clone()]);
+        assert_eq!(Ok(vec![exterior, hole]), parse_polygon(&v));
+    }
+
+    // parse_point
+
+    #[test]
+    fn parse_point_lon_lat_order() {
+        let v = json!({ "type": "Point", "coordinates": [2.3508, 48.8567] });
+        assert_eq!(Ok(paris()), parse_point(&v));
+    }
+
+    #[test]
+    fn parse_point_round_trip() {
+        let ps = vec![
+            paris(),
+            LatLong::from_degrees(-33.8688, 151.2093),
+            LatLong::from_degrees(90.0, 0.0),
+            LatLong::from_degrees(-90.0, -180.0),
+        ];
+        for p in ps {
+            assert_eq!(p.round_d7(), parse_point(&point(p)).unwrap().round_d7());
+        }
+    }
+
+    #[test]
+    fn parse_point_with_height_default() {
+        let v = json!({ "type": "Point", "coordinates": [2.3508, 48.8567] });
+        assert_eq!(Length::ZERO, parse_point_with_height(&v).unwrap().height());
+        let v = json!({ "type": "Point", "coordinates": [2.3508, 48.8567, 35.0] });
+        assert_eq!(
+            Length::from_metres(35.0),
+            parse_point_with_height(&v).unwrap().height()
+        );
+    }
+
+    #[test]
+    fn parse_point_out_of_range() {
+        // latitude and longitude swapped.
+        let v = json!({ "type": "Point", "coordinates": [48.8567, 122.3508] });
+        assert_eq!(
+            Err(GeoJsonError::LatitudeOutOfRange(122.3508)),
+            parse_point(&v)
+        );
+        let v = json!({ "type": "Point", "coordinates": [180.5, 48.8567] });
+        assert_eq!(
+            Err(GeoJsonError::LongitudeOutOfRange(180.5)),
+            parse_point(&v)
+        );
+    }
+
+    #[test]
+    fn parse_point_invalid() {
+        assert_eq!(
+            Err(GeoJsonError::UnexpectedType("Point")),
+            parse_point(&json!({ "type": "LineString", "coordinates": [] }))
+        );
+        assert_eq!(
+            Err(GeoJsonError::UnexpectedType("Point")),
+            parse_point(&json!([2.3508, 48.8567]))
+        );
+        assert_eq!(
+            Err(GeoJsonError::InvalidCoordinates),
+            parse_point(&json!({ "type": "Point" }))
+        );
+        assert_eq!(
+            Err(GeoJsonError::InvalidCoordinates),
+            parse_point(&json!({ "type": "Point", "coordinates": [2.3508] }))
+        );
+        assert_eq!(
+            Err(GeoJsonError::InvalidCoordinates),
+            parse_point(&json!({ "type": "Point", "coordinates": [2.3508, "48.8567"] }))
+        );
+    }
+
+    // parse_line_string
+
+    #[test]
+    fn parse_line_string_round_trip() {
+        let ps = vec![paris(), LatLong::from_degrees(51.5074, -0.1278)];
+        assert_eq!(Ok(ps.clone()), parse_line_string(&line_string(&ps)));
+    }
+
+    // feature_collection, parse_feature_collection
+
+    #[test]
+    fn feature_collection_round_trip() {
+        let tracks = vec![
+            (
+                Path::new(vec![paris(), LatLong::from_degrees(51.5074, -0.1278)]),
+                json!({ "name": "CDG-LHR", "speed": 450 })
+                    .as_object()
+                    .cloned(),
+            ),
+            (
+                Path::new(vec![
+                    LatLong::from_degrees(-33.8688, 151.2093),
+                    LatLong::from_degrees(-37.8136, 144.9631),
+                    LatLong::from_degrees(-34.9285, 138.6007),
+                ]),
+                json!({ "name": "SYD-MEL-ADL" }).as_object().cloned(),
+            ),
+        ];
+        let s = serde_json::to_string(&feature_collection(&tracks)).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&s).unwrap();
+        assert_eq!(Ok(tracks), parse_feature_collection(&v));
+    }
+
+    #[test]
+    fn feature_collection_null_properties() {
+        let tracks = vec![(Path::new(vec![paris(), paris()]), None)];
+        let v = feature_collection(&tracks);
+        assert_eq!(serde_json::Value::Null, v["features"][0]["properties"]);
+        assert_eq!(Ok(tracks), parse_feature_collection(&v));
+        assert_eq!(
+            json!({ "type": "FeatureCollection", "features": [] }),
+            feature_collection(&[])
+        );
+    }
+
+    #[test]
+    fn parse_feature_collection_ignores_unknown_members() {
+        let v = json!({
+            "type": "FeatureCollection",
+            "bbox": [-0.1278, 48.8567, 2.3508, 51.5074],
+            "features": [{
+                "type": "Feature",
+                "id": 42,
+                "geometry": { "type": "LineString", "coordinates": [[2.3508, 48.8567], [-0.1278, 51.5074]] },
+                "properties": { "name": "CDG-LHR", "extra": { "nested": [1, 2] } }
+            }, {
+                "type": "Feature",
+                "geometry": { "type": "LineString", "coordinates": [] }
+            }]
+        });
+        let features = parse_feature_collection(&v).unwrap();
+        assert_eq!(2, features.len());
+        assert_eq!(
+            vec![paris(), LatLong::from_degrees(51.5074, -0.1278)],
+            features[0].0.clone().into_inner()
+        );
+        assert_eq!(
+            Some(&json!("CDG-LHR")),
+            features[0].1.as_ref().unwrap().get("name")
+        );
+        assert!(features[1].0.is_empty());
+        assert_eq!(None, features[1].1);
+    }
+
+    #[test]
+    fn parse_feature_collection_invalid() {
+        assert_eq!(
+            Err(GeoJsonError::UnexpectedType("FeatureCollection")),
+            parse_feature_collection(&line_string(&[paris()]))
+        );
+        assert_eq!(
+            Err(GeoJsonError::UnexpectedType("Feature")),
+            parse_feature_collection(&json!({ "type": "FeatureCollection", "features": [{}] }))
+        );
+        assert_eq!(
+            Err(GeoJsonError::UnexpectedType("LineString")),
+            parse_feature_collection(&json!({
+                "type": "FeatureCollection",
+                "features": [{ "type": "Feature", "geometry": point(paris()), "properties": null }]
+            }))
+        );
+    }
+
+    // parse_polygon
+
+    #[test]
+    fn parse_polygon_unclosed_ring() {
+        let v = json!({ "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]] });
+        assert_eq!(Err(GeoJsonError::InvalidRing), parse_polygon(&v));
+        let v = json!({ "type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 0.0], [0.0, 0.0]]] });
+        assert_eq!(Err(GeoJsonError::InvalidRing), parse_polygon(&v));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{impl_measurement, Angle, Measurement};
+use crate::{Angle, Dimension, Measurement};
 
 #[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
 /// A length.
@@ -93,7 +93,18 @@ impl Length {
         self.metres / Self::NM_TO_M
     }
 
-    /// Computes the absolute value of this length.
+    /// Computes the absolute value of this length - e.g. to discard the sign of a
+    /// [cross-track distance](crate::spherical::Sphere::cross_track_distance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Length;
+    ///
+    /// let l = -Length::from_metres(3.0);
+    /// assert_eq!(Length::from_metres(-3.0), l);
+    /// assert_eq!(Length::from_metres(3.0), l.abs());
+    /// ```
     pub fn abs(&self) -> Self {
         if self.metres >= 0.0 {
             *self
@@ -102,6 +113,27 @@ impl Length {
         }
     }
 
+    /// Converts this length - i.e. a distance along a circle of the given radius - to the central angle it
+    /// subtends: the inverse of [Angle::arc_length]. Returns a zero angle if the given radius is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use jord::{Angle, Length};
+    ///
+    /// let radius = Length::from_metres(2.0);
+    /// assert_eq!(Angle::HALF_CIRCLE, Length::from_metres(2.0 * PI).to_central_angle(radius));
+    /// assert_eq!(Angle::ZERO, Length::from_metres(1.0).to_central_angle(Length::ZERO));
+    /// ```
+    pub fn to_central_angle(&self, radius: Length) -> Angle {
+        if radius.metres == 0.0 {
+            Angle::ZERO
+        } else {
+            Angle::from_radians(self.metres / radius.metres)
+        }
+    }
+
     /// Rounds this length to the nearest metre.
     ///
     /// # Examples
@@ -165,6 +197,8 @@ impl Length {
 }
 
 impl Measurement for Length {
+    const DIMENSION: Dimension = Dimension::Length;
+
     fn from_default_unit(amount: f64) -> Self {
         Length::from_metres(amount)
     }
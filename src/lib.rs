@@ -1,7 +1,6 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 #![forbid(
     anonymous_parameters,
-    illegal_floating_point_literal_pattern,
     late_bound_lifetime_arguments,
     path_statements,
     patterns_in_fns_without_body,
@@ -21,34 +20,75 @@ pub use crate::mat33::Mat33;
 
 #[macro_use]
 mod measurement;
-pub use crate::measurement::Measurement;
+pub use crate::measurement::{Dimension, Measurement};
 
 mod angle;
-pub use crate::angle::Angle;
+pub use crate::angle::{Angle, DmsError, HmsError, ParseAngleError};
+
+mod angle_range;
+pub use crate::angle_range::AngleRange;
+
+mod area;
+pub use crate::area::Area;
+
+mod angular_velocity;
+pub use crate::angular_velocity::AngularVelocity;
+
+pub mod binary;
 
 pub mod ellipsoidal;
 
+#[cfg(feature = "geo-types")]
+pub mod geo_types;
+
+mod geohash;
+pub use crate::geohash::GeohashError;
+
+#[cfg(feature = "geojson")]
+pub mod geojson;
+
 mod local_frame;
 pub use crate::local_frame::{r2xyz, r2zyx, xyz2r, zyx2r, LocalFrame, LocalPositionVector};
 
 mod length;
 pub use crate::length::Length;
 
+mod magnetic;
+pub use crate::magnetic::{ConstantDeclination, DeclinationModel};
+
+mod maidenhead;
+pub use crate::maidenhead::MaidenheadError;
+
+mod navigation_error;
+pub use crate::navigation_error::NavigationError;
+
 mod numbers;
 
 mod positions;
-pub use crate::positions::{Cartesian3DVector, GeocentricPos, GeodeticPos, LatLong, NVector};
+pub use crate::positions::{
+    Cartesian3DVector, GeocentricPos, GeodeticPos, LatLong, NVector, ParseLatLongError,
+};
 
 mod speed;
 pub use crate::speed::Speed;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
 pub mod spherical;
 
 mod surface;
 pub use crate::surface::Surface;
 
+mod tile;
+pub use crate::tile::Tile;
+
 mod vec3;
 pub use crate::vec3::Vec3;
 
 mod vehicle;
-pub use crate::vehicle::Vehicle;
+pub use crate::vehicle::{Vehicle, VehicleError};
+
+mod web_mercator;
+
+pub mod wkt;
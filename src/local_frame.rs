@@ -18,6 +18,7 @@ enum Orientation {
 /// The orientation of the x, y and z axis depends on the [local Cartesian coordinate frame](crate::LocalFrame):
 /// - x = north (or forward), y = east (or right), z = down: [NED](crate::LocalFrame::ned), [Body](crate::LocalFrame::body) and [Local Level](crate::LocalFrame::local_level),
 /// - x = east, y = north, z = up: [ENU](crate::LocalFrame::enu).
+///
 /// However, the [azimuth](crate::LocalPositionVector::azimuth) is always relative to 'north' and the elevation is always positive if above the local
 /// tangent plane and negative if below.
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
@@ -200,9 +201,25 @@ where
     /// East-North-Up (local level) frame. This frame is usefull for many targeting and tracking applications.
     ///
     /// - Orientation: The x-axis points towards east, the y-axis points towards north (both are
-    /// horizontal), and the z-axis is pointing up.
+    ///   horizontal), and the z-axis is pointing up.
     ///
     /// See also [NED](crate::LocalFrame::ned)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Cartesian3DVector, GeodeticPos, LatLong, Length, LocalFrame};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// let origin = GeodeticPos::from_lat_long(LatLong::from_degrees(46.017, 7.750), Length::from_metres(1673.0));
+    /// let point = GeodeticPos::from_lat_long(LatLong::from_degrees(45.976, 7.658), Length::from_metres(4531.0));
+    ///
+    /// let enu = LocalFrame::enu(origin, Ellipsoid::WGS84);
+    /// let local = enu.geodetic_to_local_pos(point);
+    /// assert_eq!(Length::from_metres(-7134.757), local.x().round_mm()); // east
+    /// assert_eq!(Length::from_metres(-4556.322), local.y().round_mm()); // north
+    /// assert_eq!(Length::from_metres(2852.39), local.z().round_mm()); // up
+    /// ```
     pub fn enu(origin: GeodeticPos, surface: S) -> Self {
         let vo = origin.horizontal_position().as_vec3();
         // up - just the n-vector.
@@ -228,7 +245,7 @@ where
     ///
     /// - The origin is directly beneath or above the vehicle (B), at Earth’s surface.
     /// - Orientation: The x-axis points towards north, the y-axis points towards east (both are
-    /// horizontal), and the z-axis is pointing down.
+    ///   horizontal), and the z-axis is pointing down.
     ///
     /// Note: When moving relative to the Earth, the frame rotates about its z-axis to allow the
     /// x-axis to always point towards north. When getting close to the poles this rotation rate
@@ -279,10 +296,10 @@ where
     ///
     /// - The origin is directly beneath or above the vehicle (B), at Earth’s surface.
     /// - Orientation: The z-axis is pointing down. Initially, the x-axis points towards north, and the
-    /// y-axis points towards east, but as the vehicle moves they are not rotating about the z-axis
-    /// (their angular velocity relative to the Earth has zero component along the z-axis).
-    /// (Note: Any initial horizontal direction of the x- and y-axes is valid for L, but if the
-    /// initial position is outside the poles, north and east are usually chosen for convenience.)
+    ///   y-axis points towards east, but as the vehicle moves they are not rotating about the z-axis
+    ///   (their angular velocity relative to the Earth has zero component along the z-axis).
+    ///   (Note: Any initial horizontal direction of the x- and y-axes is valid for L, but if the
+    ///   initial position is outside the poles, north and east are usually chosen for convenience.)
     ///
     /// Notes: The L-frame is equal to the N-frame except for the rotation about the z-axis,
     /// which is always zero for this frame (relative to Earth). Hence, at a given time, the only
@@ -648,6 +665,57 @@ mod tests {
         )
     }
 
+    #[test]
+    fn enu_high_latitude() {
+        // across the north pole: the point is due north of the origin.
+        let origin = GeodeticPos::new(
+            NVector::from_lat_long_degrees(89.5, 0.0),
+            Length::from_metres(100.0),
+        );
+        let point = GeodeticPos::new(
+            NVector::from_lat_long_degrees(89.5, 180.0),
+            Length::from_metres(100.0),
+        );
+
+        let enu = LocalFrame::enu(origin, Ellipsoid::WGS84);
+        let local = enu.geodetic_to_local_pos(point);
+
+        assert_eq!(Length::ZERO, local.x().round_mm().abs());
+        assert!(local.y() > Length::from_metres(111_000.0));
+        assert!(local.z() < Length::ZERO);
+        assert_eq!(Angle::ZERO, local.azimuth().round_d7());
+        assert_eq!(
+            origin
+                .straight_line_distance(&point, &Ellipsoid::WGS84)
+                .round_mm(),
+            local.slant_range().round_mm()
+        );
+        assert_geod_eq_d7_mm(point, enu.local_to_geodetic_pos(local));
+    }
+
+    #[test]
+    fn enu_high_latitude_east() {
+        // 1 km east of an origin at 85 degrees: a naive equirectangular approximation would not account for the
+        // convergence of the meridians.
+        let origin = GeodeticPos::new(NVector::from_lat_long_degrees(85.0, 30.0), Length::ZERO);
+        let enu = LocalFrame::enu(origin, Ellipsoid::WGS84);
+        let east =
+            LocalPositionVector::new(Length::ZERO, Length::from_metres(1_000.0), Length::ZERO);
+        let p = enu.local_to_geodetic_pos(east);
+        let ll = LatLong::from_nvector(p.horizontal_position());
+        // ~ 1000 / (N(85) * cos(85)) radians of longitude.
+        let n = Ellipsoid::WGS84
+            .prime_vertical_radius(Angle::from_degrees(85.0))
+            .as_metres();
+        let dlng = (1_000.0 / (n * 85f64.to_radians().cos())).to_degrees();
+        assert!((ll.longitude().as_degrees() - 30.0 - dlng).abs() < 1e-6);
+        assert!(p.height() > Length::ZERO);
+        assert_eq!(
+            Length::from_metres(1_000.0),
+            enu.geodetic_to_local_pos(p).x().round_mm()
+        );
+    }
+
     #[test]
     fn transitiviy_ned() {
         let point_a = GeodeticPos::new(
@@ -0,0 +1,119 @@
+use crate::{Angle, LatLong};
+
+/// A model of the magnetic declination (a.k.a. magnetic variation): the angle between true north and magnetic
+/// north, positive if magnetic north is east of true north.
+///
+/// jord does not bundle any magnetic model: implement this trait to plug in a model such as the World Magnetic
+/// Model (WMM), or use [ConstantDeclination] when the declination is known.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{Angle, ConstantDeclination, DeclinationModel, LatLong};
+///
+/// let model = ConstantDeclination(Angle::from_degrees(10.0));
+/// let p = LatLong::from_degrees(55.6050, 13.0038);
+///
+/// let magnetic = model.to_magnetic(Angle::from_degrees(5.0), &p, 2024.5);
+/// assert_eq!(Angle::from_degrees(355.0), magnetic.round_d7());
+/// assert_eq!(Angle::from_degrees(5.0), model.to_true(magnetic, &p, 2024.5).round_d7());
+/// ```
+pub trait DeclinationModel {
+    /// Returns the magnetic declination at the given position and date (decimal year, e.g. `2024.5` for the
+    /// 1st of July 2024): positive if magnetic north is east of true north, negative if it is west.
+    fn declination(&self, position: &LatLong, date: f64) -> Angle;
+
+    /// Converts the given true bearing at the given position and date to a magnetic bearing, within
+    /// [0, 360) degrees: magnetic bearing = true bearing - declination.
+    fn to_magnetic(&self, true_bearing: Angle, position: &LatLong, date: f64) -> Angle {
+        true_bearing.wrapping_sub(self.declination(position, date))
+    }
+
+    /// Converts the given magnetic bearing at the given position and date to a true bearing, within
+    /// [0, 360) degrees: true bearing = magnetic bearing + declination.
+    fn to_true(&self, magnetic_bearing: Angle, position: &LatLong, date: f64) -> Angle {
+        magnetic_bearing.wrapping_add(self.declination(position, date))
+    }
+}
+
+/// A [DeclinationModel] returning the same declination at every position and date - e.g. the declination
+/// printed on a chart, or for testing.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct ConstantDeclination(pub Angle);
+
+impl DeclinationModel for ConstantDeclination {
+    fn declination(&self, _position: &LatLong, _date: f64) -> Angle {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Angle, ConstantDeclination, DeclinationModel, LatLong};
+
+    #[test]
+    fn east_declination() {
+        let model = ConstantDeclination(Angle::from_degrees(3.5));
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        assert_eq!(
+            Angle::from_degrees(86.5),
+            model
+                .to_magnetic(Angle::from_degrees(90.0), &p, 2020.0)
+                .round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(90.0),
+            model
+                .to_true(Angle::from_degrees(86.5), &p, 2020.0)
+                .round_d7()
+        );
+    }
+
+    #[test]
+    fn west_declination() {
+        let model = ConstantDeclination(Angle::from_degrees(-14.0));
+        let p = LatLong::from_degrees(40.7128, -74.006);
+        assert_eq!(
+            Angle::from_degrees(4.0),
+            model
+                .to_magnetic(Angle::from_degrees(350.0), &p, 2020.0)
+                .round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(346.0),
+            model.to_true(Angle::ZERO, &p, 2020.0).round_d7()
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let model = ConstantDeclination(Angle::from_degrees(-7.25));
+        let p = LatLong::from_degrees(-33.8688, 151.2093);
+        for i in 0..36 {
+            let b = Angle::from_degrees(i as f64 * 10.0);
+            let m = model.to_magnetic(b, &p, 2030.0);
+            assert!(m >= Angle::ZERO && m < Angle::FULL_CIRCLE);
+            assert_eq!(b.round_d7(), model.to_true(m, &p, 2030.0).round_d7());
+        }
+    }
+
+    #[test]
+    fn custom_model() {
+        // declination varying with longitude and date.
+        struct Linear;
+        impl DeclinationModel for Linear {
+            fn declination(&self, position: &LatLong, date: f64) -> Angle {
+                Angle::from_degrees(
+                    position.longitude().as_degrees() / 10.0 + (date - 2000.0) / 10.0,
+                )
+            }
+        }
+        let p = LatLong::from_degrees(0.0, 20.0);
+        assert_eq!(Angle::from_degrees(4.0), Linear.declination(&p, 2020.0));
+        assert_eq!(
+            Angle::from_degrees(356.0),
+            Linear.to_magnetic(Angle::ZERO, &p, 2020.0).round_d7()
+        );
+    }
+}
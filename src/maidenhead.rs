@@ -0,0 +1,317 @@
+use std::fmt;
+
+use crate::{Angle, LatLong};
+
+/// Minimum number of characters of a Maidenhead locator: a field of 20 by 10 degrees.
+const MIN_PRECISION: usize = 2;
+
+/// Maximum number of characters of a Maidenhead locator: an extended subsquare of 12.5 by 6.25 arcseconds.
+const MAX_PRECISION: usize = 10;
+
+/// Error returned when decoding an invalid [Maidenhead locator](https://en.wikipedia.org/wiki/Maidenhead_Locator_System).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum MaidenheadError {
+    /// The locator has an odd number of characters, or is either shorter than 2 or longer than 10 characters.
+    InvalidLength(usize),
+    /// The locator contains a character which is not valid at its position: fields are letters A to R,
+    /// squares are digits and subsquares are letters A to X.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for MaidenheadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaidenheadError::InvalidLength(len) => write!(
+                f,
+                "invalid Maidenhead locator length: {} (expected an even number of {} to {} characters)",
+                len, MIN_PRECISION, MAX_PRECISION
+            ),
+            MaidenheadError::InvalidCharacter(c) => {
+                write!(f, "invalid Maidenhead locator character: {:?}", c)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MaidenheadError {}
+
+impl LatLong {
+    /// Encodes this [LatLong] into a [Maidenhead locator](https://en.wikipedia.org/wiki/Maidenhead_Locator_System)
+    /// of the given precision (number of characters). The precision is clamped to the range [2, 10] and rounded
+    /// down to an even number of characters.
+    ///
+    /// Fields (first pair) are upper-case, subsquares (third and fifth pairs) are lower-case.
+    ///
+    /// This [LatLong] is assumed to have a latitude in [-90, 90] degrees and a longitude in [-180, 180]
+    /// degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    ///
+    /// assert_eq!("JN58td", LatLong::from_degrees(48.14666, 11.60833).to_maidenhead(6));
+    /// assert_eq!("FM18", LatLong::from_degrees(38.92, -77.065).to_maidenhead(4));
+    /// ```
+    pub fn to_maidenhead(&self, precision: usize) -> String {
+        let pairs = precision.clamp(MIN_PRECISION, MAX_PRECISION) / 2;
+        let mut lng = self.longitude().as_degrees() + 180.0;
+        let mut lat = self.latitude().as_degrees() + 90.0;
+        let mut lng_size = 360.0;
+        let mut lat_size = 180.0;
+
+        let mut locator = String::with_capacity(pairs * 2);
+        for pair in 0..pairs {
+            let base = base(pair);
+            lng_size /= base as f64;
+            lat_size /= base as f64;
+            let lng_index = cell_index(lng, lng_size, base);
+            let lat_index = cell_index(lat, lat_size, base);
+            lng -= lng_index as f64 * lng_size;
+            lat -= lat_index as f64 * lat_size;
+            locator.push(encode_char(pair, lng_index));
+            locator.push(encode_char(pair, lat_index));
+        }
+        locator
+    }
+
+    /// Decodes the given [Maidenhead locator](https://en.wikipedia.org/wiki/Maidenhead_Locator_System) into the
+    /// cell it represents: returns the south-west (lowest latitude and longitude) and north-east (highest latitude
+    /// and longitude) corners of the cell.
+    ///
+    /// The locator must have an even number of characters between 2 and 10; decoding is case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, MaidenheadError};
+    ///
+    /// let (sw, ne) = LatLong::from_maidenhead("JN58td").unwrap();
+    /// assert_eq!(LatLong::from_degrees(48.125, 11.58333), sw.round_d5());
+    /// assert_eq!(LatLong::from_degrees(48.16667, 11.66667), ne.round_d5());
+    ///
+    /// assert_eq!(Err(MaidenheadError::InvalidCharacter('S')), LatLong::from_maidenhead("SN58"));
+    /// ```
+    pub fn from_maidenhead(locator: &str) -> Result<(LatLong, LatLong), MaidenheadError> {
+        let chars: Vec<char> = locator.chars().collect();
+        let len = chars.len();
+        if len % 2 != 0 || !(MIN_PRECISION..=MAX_PRECISION).contains(&len) {
+            return Err(MaidenheadError::InvalidLength(len));
+        }
+
+        let mut lng = 0.0;
+        let mut lat = 0.0;
+        let mut lng_size = 360.0;
+        let mut lat_size = 180.0;
+        for (pair, c) in chars.chunks(2).enumerate() {
+            let base = base(pair);
+            lng_size /= base as f64;
+            lat_size /= base as f64;
+            let lng_index =
+                decode_char(pair, c[0]).ok_or(MaidenheadError::InvalidCharacter(c[0]))?;
+            let lat_index =
+                decode_char(pair, c[1]).ok_or(MaidenheadError::InvalidCharacter(c[1]))?;
+            lng += lng_index as f64 * lng_size;
+            lat += lat_index as f64 * lat_size;
+        }
+
+        let sw = LatLong::new(
+            Angle::from_degrees(lat - 90.0),
+            Angle::from_degrees(lng - 180.0),
+        );
+        let ne = LatLong::new(
+            Angle::from_degrees(lat + lat_size - 90.0),
+            Angle::from_degrees(lng + lng_size - 180.0),
+        );
+        Ok((sw, ne))
+    }
+}
+
+/// Returns the number of divisions of the given pair: 18 for the field, 10 for (extended) squares and
+/// 24 for (extended) subsquares.
+fn base(pair: usize) -> u32 {
+    if pair == 0 {
+        18
+    } else if pair % 2 == 1 {
+        10
+    } else {
+        24
+    }
+}
+
+/// Returns the index of the cell of the given size containing the given offset, clamped to the given base
+/// so that the north pole and the antimeridian fall in the last cell.
+fn cell_index(offset: f64, size: f64, base: u32) -> u32 {
+    let index = (offset / size).floor();
+    if index <= 0.0 {
+        0
+    } else {
+        (index as u32).min(base - 1)
+    }
+}
+
+/// Returns the character encoding the given index for the given pair.
+fn encode_char(pair: usize, index: u32) -> char {
+    let first = match pair {
+        0 => b'A',
+        _ if pair % 2 == 1 => b'0',
+        _ => b'a',
+    };
+    (first + index as u8) as char
+}
+
+/// Returns the index encoded by the given character for the given pair (case-insensitive).
+fn decode_char(pair: usize, c: char) -> Option<u32> {
+    let index = if pair % 2 == 1 {
+        c.to_digit(10)?
+    } else if c.is_ascii_alphabetic() {
+        (c.to_ascii_uppercase() as u32) - ('A' as u32)
+    } else {
+        return None;
+    };
+    if index < base(pair) {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LatLong, MaidenheadError};
+
+    // to_maidenhead
+
+    #[test]
+    fn to_maidenhead() {
+        assert_eq!(
+            "JN58td",
+            LatLong::from_degrees(48.14666, 11.60833).to_maidenhead(6)
+        );
+        assert_eq!(
+            "GF15vc",
+            LatLong::from_degrees(-34.91, -56.21166).to_maidenhead(6)
+        );
+        assert_eq!(
+            "FM18lw",
+            LatLong::from_degrees(38.92, -77.065).to_maidenhead(6)
+        );
+        assert_eq!(
+            "RE78ir",
+            LatLong::from_degrees(-41.28333, 174.745).to_maidenhead(6)
+        );
+    }
+
+    #[test]
+    fn to_maidenhead_precision() {
+        let p = LatLong::from_degrees(48.14666, 11.60833);
+        assert_eq!("JN", p.to_maidenhead(0));
+        assert_eq!("JN", p.to_maidenhead(2));
+        assert_eq!("JN", p.to_maidenhead(3));
+        assert_eq!("JN58", p.to_maidenhead(4));
+        assert_eq!(10, p.to_maidenhead(10).len());
+        assert_eq!(p.to_maidenhead(10), p.to_maidenhead(20));
+        assert!(p.to_maidenhead(10).starts_with("JN58td"));
+    }
+
+    #[test]
+    fn to_maidenhead_extremes() {
+        assert_eq!(
+            "AA00aa00aa",
+            LatLong::from_degrees(-90.0, -180.0).to_maidenhead(10)
+        );
+        assert_eq!(
+            "RR99xx99xx",
+            LatLong::from_degrees(90.0, 180.0).to_maidenhead(10)
+        );
+        assert_eq!("JJ00aa", LatLong::from_degrees(0.0, 0.0).to_maidenhead(6));
+    }
+
+    // from_maidenhead
+
+    #[test]
+    fn from_maidenhead_contains_encoded() {
+        let ps = vec![
+            LatLong::from_degrees(48.14666, 11.60833),
+            LatLong::from_degrees(-34.91, -56.21166),
+            LatLong::from_degrees(-33.8688, 151.2093),
+            LatLong::from_degrees(-54.8019, -68.303),
+            LatLong::from_degrees(89.9999, 179.9999),
+            LatLong::from_degrees(-89.9999, -179.9999),
+        ];
+        for p in ps {
+            for precision in (2..=10).step_by(2) {
+                let (sw, ne) = LatLong::from_maidenhead(&p.to_maidenhead(precision)).unwrap();
+                assert!(sw.latitude() <= p.latitude() && p.latitude() <= ne.latitude());
+                assert!(sw.longitude() <= p.longitude() && p.longitude() <= ne.longitude());
+            }
+        }
+    }
+
+    #[test]
+    fn from_maidenhead_cell_size() {
+        let sizes = [
+            (2, 20.0, 10.0),
+            (4, 2.0, 1.0),
+            (6, 5.0 / 60.0, 2.5 / 60.0),
+            (8, 0.5 / 60.0, 0.25 / 60.0),
+            (10, 1.25 / 3600.0, 0.625 / 3600.0),
+        ];
+        let locator = "JN58td25ab";
+        for (len, lng_size, lat_size) in sizes {
+            let (sw, ne) = LatLong::from_maidenhead(&locator[..len]).unwrap();
+            let d_lat = ne.latitude().as_degrees() - sw.latitude().as_degrees();
+            let d_lng = ne.longitude().as_degrees() - sw.longitude().as_degrees();
+            assert!((d_lat - lat_size).abs() < 1e-12);
+            assert!((d_lng - lng_size).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn from_maidenhead_case_insensitive() {
+        assert_eq!(
+            LatLong::from_maidenhead("JN58td"),
+            LatLong::from_maidenhead("jn58TD")
+        );
+    }
+
+    #[test]
+    fn from_maidenhead_invalid_character() {
+        assert_eq!(
+            Err(MaidenheadError::InvalidCharacter('S')),
+            LatLong::from_maidenhead("JS58")
+        );
+        assert_eq!(
+            Err(MaidenheadError::InvalidCharacter('A')),
+            LatLong::from_maidenhead("JNA8")
+        );
+        assert_eq!(
+            Err(MaidenheadError::InvalidCharacter('y')),
+            LatLong::from_maidenhead("JN58ty")
+        );
+        assert_eq!(
+            Err(MaidenheadError::InvalidCharacter('1')),
+            LatLong::from_maidenhead("1N58")
+        );
+        assert_eq!(
+            Err(MaidenheadError::InvalidCharacter('é')),
+            LatLong::from_maidenhead("JN58té")
+        );
+    }
+
+    #[test]
+    fn from_maidenhead_invalid_length() {
+        assert_eq!(
+            Err(MaidenheadError::InvalidLength(0)),
+            LatLong::from_maidenhead("")
+        );
+        assert_eq!(
+            Err(MaidenheadError::InvalidLength(3)),
+            LatLong::from_maidenhead("JN5")
+        );
+        assert_eq!(
+            Err(MaidenheadError::InvalidLength(12)),
+            LatLong::from_maidenhead("JN58td25ab00")
+        );
+    }
+}
@@ -1,9 +1,123 @@
+/// The physical dimension of a [Measurement]: quantities can only be converted to one another if they share the
+/// same dimension.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum Dimension {
+    /// Plane angle (default unit: radian).
+    Angle,
+    /// Angular velocity (default unit: radian per second).
+    AngularVelocity,
+    /// Area (default unit: square metre).
+    Area,
+    /// Length (default unit: metre).
+    Length,
+    /// Speed (default unit: metre per second).
+    Speed,
+}
+
 /// Trait implemented by all measurable quantities.
 pub trait Measurement {
+    /// The physical dimension of this quantity.
+    const DIMENSION: Dimension;
+
     /// Creates a new quantity from the given amount expressed in the default unit.
     fn from_default_unit(amount: f64) -> Self;
     /// Returns this quantity in the default unit.
     fn as_default_unit(&self) -> f64;
+
+    /// Converts this quantity to the given type of quantity through the default unit, if both types share the
+    /// same [Dimension]; returns [None] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, Measurement};
+    ///
+    /// let l = Length::from_metres(1.0);
+    /// assert_eq!(Some(l), l.convert::<Length>());
+    /// assert_eq!(None, l.convert::<Angle>());
+    /// ```
+    fn convert<T: Measurement>(&self) -> Option<T> {
+        if Self::DIMENSION == T::DIMENSION {
+            Some(T::from_default_unit(self.as_default_unit()))
+        } else {
+            None
+        }
+    }
+
+    /// Computes the quantity halfway between this quantity and the given quantity, without overflowing even if
+    /// the sum of both quantities exceeds [f64::MAX] in the default unit.
+    ///
+    /// Note: for an [Angle](crate::Angle) this is the arithmetic mean, which is not the angle halfway along the
+    /// shortest rotation (e.g. the midpoint of 350 and 10 degrees is 180 degrees).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, Measurement, Speed};
+    ///
+    /// assert_eq!(
+    ///     Length::from_metres(15.0),
+    ///     Length::from_metres(10.0).midpoint(&Length::from_metres(20.0))
+    /// );
+    /// assert_eq!(Speed::from_knots(-5.0), Speed::from_knots(10.0).midpoint(&Speed::from_knots(-20.0)));
+    /// assert_eq!(Length::MAX, Length::MAX.midpoint(&Length::MAX));
+    /// ```
+    fn midpoint(&self, other: &Self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_default_unit(self.as_default_unit() / 2.0 + other.as_default_unit() / 2.0)
+    }
+
+    /// Returns the lesser of this quantity and the given quantity, comparing amounts in the default unit. This
+    /// has the semantics of [std::cmp::min]: this quantity is returned if both are equal (e.g. `0.0` and
+    /// `-0.0`), or if they cannot be compared (NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Measurement};
+    ///
+    /// assert_eq!(
+    ///     Angle::from_degrees(-10.0),
+    ///     Angle::from_degrees(5.0).min(Angle::from_degrees(-10.0))
+    /// );
+    /// ```
+    fn min(self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        if other.as_default_unit() < self.as_default_unit() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the greater of this quantity and the given quantity, comparing amounts in the default unit. This
+    /// has the semantics of [std::cmp::max]: the given quantity is returned if both are equal (e.g. `0.0` and
+    /// `-0.0`), and this quantity if they cannot be compared (NaN).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, Measurement};
+    ///
+    /// assert_eq!(
+    ///     Length::from_metres(5.0),
+    ///     Length::from_metres(5.0).max(Length::from_metres(-10.0))
+    /// );
+    /// ```
+    fn max(self, other: Self) -> Self
+    where
+        Self: Sized,
+    {
+        if other.as_default_unit() >= self.as_default_unit() {
+            other
+        } else {
+            self
+        }
+    }
 }
 
 /// Macro that creates the code to implement operator overrides.
@@ -69,3 +183,117 @@ macro_rules! impl_measurement {
 
     )*)
 }
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{Angle, AngularVelocity, Area, Dimension, Length, Measurement, Speed};
+
+    #[test]
+    fn dimensions() {
+        assert_eq!(Dimension::Angle, Angle::DIMENSION);
+        assert_eq!(Dimension::AngularVelocity, AngularVelocity::DIMENSION);
+        assert_eq!(Dimension::Area, Area::DIMENSION);
+        assert_eq!(Dimension::Length, Length::DIMENSION);
+        assert_eq!(Dimension::Speed, Speed::DIMENSION);
+    }
+
+    #[test]
+    fn convert_same_dimension() {
+        let a = Angle::from_degrees(12.5);
+        assert_eq!(Some(a), a.convert::<Angle>());
+        let l = Length::from_nautical_miles(3.0);
+        assert_eq!(Some(l), l.convert::<Length>());
+        let s = Speed::from_knots(400.0);
+        assert_eq!(Some(s), s.convert::<Speed>());
+        let v = AngularVelocity::from_degrees_per_second(3.0);
+        assert_eq!(Some(v), v.convert::<AngularVelocity>());
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(
+            Angle::from_degrees(15.0),
+            Angle::from_degrees(10.0).midpoint(&Angle::from_degrees(20.0))
+        );
+        assert_eq!(
+            Area::from_square_metres(2.0),
+            Area::from_square_metres(4.0).midpoint(&Area::ZERO)
+        );
+        assert_eq!(
+            AngularVelocity::from_radians_per_second(-1.0),
+            AngularVelocity::from_radians_per_second(-1.0)
+                .midpoint(&AngularVelocity::from_radians_per_second(-1.0))
+        );
+        // commutative.
+        let a = Length::from_metres(3.0);
+        let b = Length::from_metres(-7.5);
+        assert_eq!(a.midpoint(&b), b.midpoint(&a));
+    }
+
+    #[test]
+    fn midpoint_no_overflow() {
+        let max = Length::MAX;
+        let near_max = Length::from_metres(f64::MAX / 2.0 * 1.5);
+        // the sum overflows.
+        assert!((max + near_max).as_metres().is_infinite());
+        let m = max.midpoint(&near_max);
+        assert!(m.as_metres().is_finite());
+        assert_eq!(Length::from_metres(f64::MAX / 8.0 * 7.0), m);
+        assert_eq!(Length::ZERO, max.midpoint(&-max));
+        assert_eq!(-max, (-max).midpoint(&-max));
+    }
+
+    #[test]
+    fn min_max() {
+        let d = Angle::from_degrees;
+        assert_eq!(d(10.0), d(10.0).min(d(20.0)));
+        assert_eq!(d(10.0), d(20.0).min(d(10.0)));
+        assert_eq!(d(-20.0), d(-10.0).min(d(-20.0)));
+        assert_eq!(d(-10.0), d(5.0).min(d(-10.0)));
+        assert_eq!(d(20.0), d(10.0).max(d(20.0)));
+        assert_eq!(d(20.0), d(20.0).max(d(10.0)));
+        assert_eq!(d(-10.0), d(-10.0).max(d(-20.0)));
+        assert_eq!(d(5.0), d(5.0).max(d(-10.0)));
+        assert_eq!(
+            Length::from_metres(1.0),
+            Length::from_metres(1.0).min(Length::MAX)
+        );
+        assert_eq!(
+            Speed::from_knots(3.0),
+            Speed::ZERO.max(Speed::from_knots(3.0))
+        );
+    }
+
+    #[test]
+    fn min_max_agree_with_std_cmp() {
+        let cmp = |a: &Angle, b: &Angle| a.partial_cmp(b).unwrap();
+        let values = [-360.0, -90.0, -1.5, -0.0, 0.0, 0.0, 1.5, 90.0, 360.0];
+        for a in values {
+            for b in values {
+                let (a, b) = (Angle::from_degrees(a), Angle::from_degrees(b));
+                let min = a.min(b);
+                let max = a.max(b);
+                let std_min = std::cmp::min_by(a, b, cmp);
+                let std_max = std::cmp::max_by(a, b, cmp);
+                // compare bits so that the sign of a zero is checked.
+                assert_eq!(std_min.as_radians().to_bits(), min.as_radians().to_bits());
+                assert_eq!(std_max.as_radians().to_bits(), max.as_radians().to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn convert_other_dimension() {
+        assert_eq!(None, Angle::from_degrees(1.0).convert::<Length>());
+        assert_eq!(None, Length::from_metres(1.0).convert::<Angle>());
+        assert_eq!(None, Length::from_metres(1.0).convert::<Speed>());
+        assert_eq!(None, Length::from_metres(1.0).convert::<Area>());
+        assert_eq!(None, Speed::from_knots(1.0).convert::<Length>());
+        assert_eq!(None, Angle::from_degrees(1.0).convert::<AngularVelocity>());
+        assert_eq!(
+            None,
+            AngularVelocity::from_radians_per_second(1.0).convert::<Angle>()
+        );
+    }
+}
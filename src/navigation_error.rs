@@ -0,0 +1,183 @@
+use std::fmt;
+
+use crate::{ellipsoidal::UtmError, spherical::TriangleError, VehicleError};
+
+/// Error returned by fallible navigation functions: a single error type that can be matched on across
+/// calculations.
+///
+/// Errors of the individual modules (e.g. [UtmError] or [VehicleError]) convert into [NavigationError], so
+/// that `?` can be used to propagate them: the original error is kept and returned by
+/// [source](std::error::Error::source).
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum NavigationError {
+    /// The calculation is undefined for antipodal positions (e.g. an infinity of great circles exist).
+    AntipodalPositions,
+    /// An iterative calculation failed to converge.
+    NonConvergence,
+    /// The geometry is degenerate (e.g. coincidental positions or equal great circles).
+    DegenerateGeometry,
+    /// An input value is out of the range accepted by the calculation.
+    OutOfRange,
+    /// A UTM coordinate could not be created.
+    Utm(UtmError),
+    /// A vehicle could not be created or updated.
+    Vehicle(VehicleError),
+    /// A spherical triangle could not be solved.
+    Triangle(TriangleError),
+}
+
+impl fmt::Display for NavigationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NavigationError::AntipodalPositions => write!(f, "undefined for antipodal positions"),
+            NavigationError::NonConvergence => write!(f, "iterative calculation did not converge"),
+            NavigationError::DegenerateGeometry => write!(f, "degenerate geometry"),
+            NavigationError::OutOfRange => write!(f, "input value out of range"),
+            NavigationError::Utm(e) => write!(f, "{}", e),
+            NavigationError::Vehicle(e) => write!(f, "{}", e),
+            NavigationError::Triangle(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for NavigationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NavigationError::Utm(e) => Some(e),
+            NavigationError::Vehicle(e) => Some(e),
+            NavigationError::Triangle(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<UtmError> for NavigationError {
+    fn from(e: UtmError) -> Self {
+        NavigationError::Utm(e)
+    }
+}
+
+impl From<VehicleError> for NavigationError {
+    fn from(e: VehicleError) -> Self {
+        NavigationError::Vehicle(e)
+    }
+}
+
+impl From<TriangleError> for NavigationError {
+    fn from(e: TriangleError) -> Self {
+        NavigationError::Triangle(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::{
+        ellipsoidal::{Ellipsoid, Utm, UtmError},
+        spherical::{GreatCircle, MinorArc, SphericalTriangle, TriangleError},
+        Angle, LatLong, NVector, NavigationError, Speed, Vehicle, VehicleError,
+    };
+
+    fn utm(pos: LatLong) -> Result<Utm, NavigationError> {
+        Ok(Utm::from_lat_long(&pos, &Ellipsoid::WGS84)?)
+    }
+
+    #[test]
+    fn from_utm_error() {
+        assert!(utm(LatLong::from_degrees(45.0, 0.0)).is_ok());
+        assert_eq!(
+            Err(NavigationError::Utm(UtmError::LatitudeOutOfRange)),
+            utm(LatLong::from_degrees(85.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn from_vehicle_error() {
+        let v = Vehicle::checked_new(
+            LatLong::from_degrees(0.0, 0.0).to_nvector(),
+            Angle::ZERO,
+            Speed::from_knots(-1.0),
+        )
+        .map_err(NavigationError::from);
+        assert_eq!(Err(NavigationError::Vehicle(VehicleError::InvalidSpeed)), v);
+    }
+
+    #[test]
+    fn from_triangle_error() {
+        let t = SphericalTriangle::solve_sss(
+            Angle::from_degrees(10.0),
+            Angle::from_degrees(20.0),
+            Angle::from_degrees(40.0),
+        )
+        .map_err(NavigationError::from);
+        assert_eq!(
+            Err(NavigationError::Triangle(TriangleError::InvalidSides)),
+            t
+        );
+    }
+
+    #[test]
+    fn source() {
+        let e = NavigationError::from(VehicleError::InvalidSpeed);
+        assert_eq!(VehicleError::InvalidSpeed.to_string(), e.to_string());
+        assert_eq!(
+            Some(VehicleError::InvalidSpeed.to_string()),
+            e.source().map(|s| s.to_string())
+        );
+        assert!(NavigationError::OutOfRange.source().is_none());
+    }
+
+    #[test]
+    fn vincenty_non_convergence() {
+        // nearly antipodal positions.
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.5, 179.7);
+        assert_eq!(
+            Err(NavigationError::NonConvergence),
+            p1.vincenty_distance(&p2, &Ellipsoid::WGS84)
+        );
+    }
+
+    #[test]
+    fn parallel_circles_intersection() {
+        // the same great circle, defined by different positions and in opposite directions.
+        let gc1 = GreatCircle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        let gc2 = GreatCircle::new(
+            NVector::from_lat_long_degrees(0.0, 50.0),
+            NVector::from_lat_long_degrees(0.0, 20.0),
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            gc1.checked_intersections(gc2)
+        );
+        // overlapping minor arcs.
+        let ma1 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        let ma2 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 15.0),
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            ma1.checked_intersection(ma2)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "degenerate geometry",
+            NavigationError::DegenerateGeometry.to_string()
+        );
+        assert_eq!(
+            "iterative calculation did not converge",
+            NavigationError::NonConvergence.to_string()
+        );
+    }
+}
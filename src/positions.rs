@@ -1,4 +1,18 @@
-use crate::Length;
+use std::{
+    f64::consts::PI,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use crate::{
+    angle::parse_dms_with_hemisphere,
+    spherical::{
+        base::{angle_radians_between, weighted_mean_vec3},
+        MinorArc, Sphere,
+    },
+    Length, NavigationError, ParseAngleError, Surface,
+};
 
 use {crate::Angle, crate::Vec3};
 
@@ -103,6 +117,9 @@ impl Cartesian3DVector for GeocentricPos {
 }
 
 /// A geodetic position: the horiztonal coordinates (as a [NVector]) and height above the surface.
+///
+/// [GeodeticPos] implements [Eq] and [Hash] (e.g. to be used as a [HashMap](std::collections::HashMap) key):
+/// equality is exact, only positions created with finite values shall be compared or hashed.
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
 pub struct GeodeticPos {
     hp: NVector,
@@ -115,6 +132,31 @@ impl GeodeticPos {
         Self { hp, height }
     }
 
+    /// Creates a new [GeodeticPos] from the given latitude-longitude and height above the surface.
+    pub fn from_lat_long(coordinate: LatLong, height: Length) -> Self {
+        Self::new(coordinate.to_nvector(), height)
+    }
+
+    /// Converts the given [GeocentricPos] into a [GeodeticPos] on the given surface.
+    ///
+    /// See also: [Surface::geocentric_to_geodetic].
+    pub fn from_geocentric<S: Surface>(pos: GeocentricPos, surface: &S) -> Self {
+        surface.geocentric_to_geodetic(pos)
+    }
+
+    /// Converts this [GeodeticPos] into a [GeocentricPos] on the given surface: the height is along the normal
+    /// to the surface.
+    ///
+    /// See also: [Surface::geodetic_to_geocentric].
+    pub fn to_geocentric<S: Surface>(&self, surface: &S) -> GeocentricPos {
+        surface.geodetic_to_geocentric(*self)
+    }
+
+    /// Returns the latitude-longitude of the horizontal coordinates of this [GeodeticPos].
+    pub fn lat_long(&self) -> LatLong {
+        LatLong::from_nvector(self.hp)
+    }
+
     /// Returns the [NVector] representing the horizontal coordinates of this [GeodeticPos].
     #[inline]
     pub fn horizontal_position(&self) -> NVector {
@@ -126,9 +168,70 @@ impl GeodeticPos {
     pub fn height(&self) -> Length {
         self.height
     }
+
+    /// Computes the surface distance between the horizontal coordinates of this [GeodeticPos] and the given
+    /// [GeodeticPos] on the given sphere: heights are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{GeodeticPos, LatLong, Length};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = GeodeticPos::from_lat_long(LatLong::from_degrees(0.0, 0.0), Length::ZERO);
+    /// let p2 = GeodeticPos::from_lat_long(LatLong::from_degrees(0.0, 1.0), Length::from_metres(10_000.0));
+    /// assert_eq!(
+    ///     Length::from_metres(111_195.0),
+    ///     p1.horizontal_distance(&p2, &Sphere::EARTH).round_m()
+    /// );
+    /// ```
+    pub fn horizontal_distance(&self, other: &GeodeticPos, sphere: &Sphere) -> Length {
+        sphere.distance(self.hp, other.hp)
+    }
+
+    /// Computes the straight-line (chord) distance between this [GeodeticPos] and the given [GeodeticPos]: both
+    /// positions are converted to [GeocentricPos] on the given surface, so heights are accounted for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{GeodeticPos, LatLong, Length};
+    /// use jord::ellipsoidal::Ellipsoid;
+    ///
+    /// let ll = LatLong::from_degrees(45.0, 45.0);
+    /// let p1 = GeodeticPos::from_lat_long(ll, Length::from_metres(100.0));
+    /// let p2 = GeodeticPos::from_lat_long(ll, Length::from_metres(1_100.0));
+    /// assert_eq!(
+    ///     Length::from_metres(1_000.0),
+    ///     p1.straight_line_distance(&p2, &Ellipsoid::WGS84).round_mm()
+    /// );
+    /// ```
+    pub fn straight_line_distance<S: Surface>(&self, other: &GeodeticPos, surface: &S) -> Length {
+        let c1 = self.to_geocentric(surface).as_metres();
+        let c2 = other.to_geocentric(surface).as_metres();
+        Length::from_metres((c1 - c2).norm())
+    }
+}
+
+impl Eq for GeodeticPos {}
+
+impl Hash for GeodeticPos {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let v = self.hp.as_vec3();
+        hash_f64(v.x(), state);
+        hash_f64(v.y(), state);
+        hash_f64(v.z(), state);
+        hash_f64(self.height.as_metres(), state);
+    }
 }
 
 /// An horizontal position represented by a pair of latitude-longitude.
+///
+/// [LatLong] implements [Eq] and [Hash] (e.g. to be used as a [HashMap](std::collections::HashMap) key):
+/// equality is exact and longitudes are not normalised, so a longitude of -180 degrees is not equal to a
+/// longitude of 180 degrees - and positions at the poles with different longitudes are not equal either.
+/// Longitudes should be normalised first if needed (e.g. by mapping -180 degrees to 180 degrees) and only
+/// positions created with finite values shall be compared or hashed.
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
 pub struct LatLong {
     latitude: Angle,
@@ -215,6 +318,528 @@ impl LatLong {
             longitude: self.longitude.round_d7(),
         }
     }
+
+    /// Determines whether the great circle distance between this [LatLong] and the given [LatLong] is at most
+    /// the given tolerance, on a sphere of the given radius.
+    ///
+    /// Unlike comparing the latitudes and longitudes one by one, this accounts for the convergence of the
+    /// meridians towards the poles and for the wrapping of the longitude at the antimeridian.
+    ///
+    /// See also: [is_within_angular](crate::LatLong::is_within_angular).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(0.0, 179.99999);
+    /// let p2 = LatLong::from_degrees(0.0, -179.99999);
+    /// // ~2.2 metres apart.
+    /// assert!(p1.is_within(&p2, Length::from_metres(3.0), Sphere::EARTH.radius()));
+    /// assert!(!p1.is_within(&p2, Length::from_metres(2.0), Sphere::EARTH.radius()));
+    /// ```
+    pub fn is_within(&self, other: &LatLong, tolerance: Length, radius: Length) -> bool {
+        self.is_within_angular(
+            other,
+            Angle::from_radians(tolerance.as_metres() / radius.as_metres()),
+        )
+    }
+
+    /// Determines whether the central angle between this [LatLong] and the given [LatLong] is at most the given
+    /// tolerance.
+    ///
+    /// The angle itself is not computed: the chord between both n-vectors is compared with the chord subtended
+    /// by the tolerance, which is cheaper and accurate for small tolerances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    ///
+    /// // at latitude 89.9999 degrees, 180 degrees of longitude are only 0.0002 degrees of arc.
+    /// let p1 = LatLong::from_degrees(89.9999, 0.0);
+    /// let p2 = LatLong::from_degrees(89.9999, 180.0);
+    /// assert!(p1.is_within_angular(&p2, Angle::from_degrees(0.00021)));
+    /// assert!(!p1.is_within_angular(&p2, Angle::from_degrees(0.00019)));
+    /// ```
+    pub fn is_within_angular(&self, other: &LatLong, tolerance: Angle) -> bool {
+        let t = tolerance.as_radians();
+        if t < 0.0 {
+            return false;
+        }
+        if t >= std::f64::consts::PI {
+            return true;
+        }
+        let chord = (self.to_nvector().0 - other.to_nvector().0).norm();
+        chord <= 2.0 * (t / 2.0).sin()
+    }
+
+    /// Computes the central angle between this [LatLong] and the given [LatLong]: the angle subtended at the
+    /// centre of the sphere by the great circle arc joining both positions, which does not depend on the
+    /// radius of the sphere. The returned angle is within [0, 180] degrees.
+    ///
+    /// See also: [Sphere::angle](crate::spherical::Sphere::angle).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    ///
+    /// let p1 = LatLong::from_degrees(0.0, 0.0);
+    /// assert_eq!(Angle::from_degrees(90.0), p1.angular_distance_to(&LatLong::from_degrees(0.0, 90.0)));
+    /// assert_eq!(Angle::HALF_CIRCLE, p1.angular_distance_to(&LatLong::from_degrees(0.0, 180.0)));
+    /// ```
+    pub fn angular_distance_to(&self, other: &LatLong) -> Angle {
+        Sphere::angle(self.to_nvector(), other.to_nvector())
+    }
+
+    /// Computes the great circle distance between this [LatLong] and the given [LatLong], on a sphere of the
+    /// given radius: this is the [central angle](crate::LatLong::angular_distance_to) between both positions
+    /// times the radius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(0.0, 0.0);
+    /// let p2 = LatLong::from_degrees(0.0, 90.0);
+    /// let d = p1.distance_to(&p2, Sphere::EARTH.radius());
+    /// assert_eq!(Length::from_metres(10_007_545.0), d.round_m());
+    /// ```
+    pub fn distance_to(&self, other: &LatLong, radius: Length) -> Length {
+        self.angular_distance_to(other).arc_length(radius)
+    }
+
+    /// Computes the [great circle distances](crate::LatLong::distance_to) from each of the given `from` positions
+    /// to each of the given `to` positions, on a sphere of the given radius, in row-major order: the distance
+    /// from `from[i]` to `to[j]` is at index `i * to.len() + j`.
+    ///
+    /// Each position is converted to an n-vector only once, which makes this significantly faster than calling
+    /// [distance_to](crate::LatLong::distance_to) for each pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    ///
+    /// let from = [LatLong::from_degrees(0.0, 0.0), LatLong::from_degrees(0.0, 90.0)];
+    /// let to = [LatLong::from_degrees(0.0, 0.0), LatLong::from_degrees(90.0, 0.0), LatLong::from_degrees(0.0, 180.0)];
+    /// let d = LatLong::distance_matrix(&from, &to, Length::from_metres(2.0));
+    ///
+    /// assert_eq!(6, d.len());
+    /// // from (0, 90) to (90, 0).
+    /// assert_eq!(Length::from_metres(std::f64::consts::PI), d[4]);
+    /// ```
+    pub fn distance_matrix(from: &[LatLong], to: &[LatLong], radius: Length) -> Vec<Length> {
+        let vs_to: Vec<Vec3> = to.iter().map(|p| p.to_nvector().as_vec3()).collect();
+        let mut distances = Vec::with_capacity(from.len() * to.len());
+        for p in from {
+            let v = p.to_nvector().as_vec3();
+            distances.extend(
+                vs_to
+                    .iter()
+                    .map(|o| radius * angle_radians_between(v, *o, None)),
+            );
+        }
+        distances
+    }
+
+    /// Computes the [great circle distances](crate::LatLong::distance_to) between each pair of the given
+    /// positions, on a sphere of the given radius: this is the upper triangle (diagonal excluded) of the
+    /// [distance matrix](crate::LatLong::distance_matrix) of the positions with themselves, in row-major order -
+    /// i.e. the distances from `points[0]` to `points[1..]`, then from `points[1]` to `points[2..]`, and so on,
+    /// `n * (n - 1) / 2` distances for `n` positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    ///
+    /// let points = [
+    ///     LatLong::from_degrees(0.0, 0.0),
+    ///     LatLong::from_degrees(0.0, 90.0),
+    ///     LatLong::from_degrees(0.0, 180.0),
+    /// ];
+    /// let d = LatLong::pairwise_distances(&points, Length::from_metres(2.0));
+    ///
+    /// let pi = Length::from_metres(std::f64::consts::PI);
+    /// assert_eq!(vec![pi, 2.0 * pi, pi], d);
+    /// ```
+    pub fn pairwise_distances(points: &[LatLong], radius: Length) -> Vec<Length> {
+        let vs: Vec<Vec3> = points.iter().map(|p| p.to_nvector().as_vec3()).collect();
+        let n = vs.len();
+        let mut distances = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+        for (i, v) in vs.iter().enumerate() {
+            distances.extend(
+                vs[i + 1..]
+                    .iter()
+                    .map(|o| radius * angle_radians_between(*v, *o, None)),
+            );
+        }
+        distances
+    }
+
+    /// Computes an approximation of the [great circle distance](crate::LatLong::distance_to) between this
+    /// [LatLong] and the given [LatLong], on a sphere of the given radius, using the equirectangular projection:
+    /// `R * sqrt(Δlat² + (Δlon * cos(mean lat))²)`, where the difference of longitudes is wrapped across the
+    /// antimeridian.
+    ///
+    /// No n-vector nor inverse trigonometric function is computed, which makes this suitable for tight loops -
+    /// e.g. to prefilter candidates by rough proximity before refining with [distance_to](crate::LatLong::distance_to).
+    ///
+    /// The error grows with the square of the separation and rapidly with latitude; for separations below 100
+    /// kilometres (on Earth) the relative error is below:
+    /// - 0.002% if both latitudes are within [-45, 45] degrees,
+    /// - 0.01% if both latitudes are within [-70, 70] degrees,
+    /// - 0.04% if both latitudes are within [-80, 80] degrees.
+    ///
+    /// Closer to the poles the approximation breaks down (e.g. ~3% at 89 degrees) and the exact distance should
+    /// be used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(48.8567, 2.3508);
+    /// let p2 = LatLong::from_degrees(48.8, 2.4);
+    /// let r = Sphere::EARTH.radius();
+    /// let exact = p1.distance_to(&p2, r);
+    /// assert!((p1.approx_distance(&p2, r) - exact).abs() < Length::from_metres(0.01));
+    /// ```
+    pub fn approx_distance(&self, other: &LatLong, radius: Length) -> Length {
+        let lat1 = self.latitude.as_radians();
+        let lat2 = other.latitude.as_radians();
+        let dlat = lat2 - lat1;
+        let dlon = (other.longitude.as_radians() - self.longitude.as_radians() + PI)
+            .rem_euclid(2.0 * PI)
+            - PI;
+        let x = dlon * ((lat1 + lat2) / 2.0).cos();
+        radius * x.hypot(dlat)
+    }
+
+    /// Computes the [minor arc](crate::spherical::MinorArc) from this [LatLong] to the
+    /// [destination](crate::spherical::Sphere::destination_pos) reached after travelling the given distance on the
+    /// given initial bearing (compass angle), on a sphere of the given radius - e.g. to render a leg by
+    /// [interpolating](crate::spherical::MinorArc::interpolate) positions along it.
+    ///
+    /// Returns [OutOfRange](crate::NavigationError::OutOfRange) if the distance is negative, NaN or not less than
+    /// half the circumference of the sphere (the destination would not be reached along a minor arc).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p = LatLong::from_degrees(0.0, 0.0);
+    /// let leg = p
+    ///     .leg_to(Angle::from_degrees(90.0), Length::from_kilometres(1000.0), Sphere::EARTH.radius())
+    ///     .unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 8.9932149), LatLong::from_nvector(leg.end()).round_d7());
+    /// assert_eq!(
+    ///     LatLong::from_degrees(0.0, 4.496607),
+    ///     LatLong::from_nvector(leg.interpolate(0.5).unwrap()).round_d6()
+    /// );
+    /// ```
+    pub fn leg_to(
+        &self,
+        bearing: Angle,
+        distance: Length,
+        radius: Length,
+    ) -> Result<MinorArc, NavigationError> {
+        if distance.as_metres().is_nan() || distance < Length::ZERO || distance >= radius * PI {
+            return Err(NavigationError::OutOfRange);
+        }
+        let start = self.to_nvector();
+        let end = Sphere::new(radius).destination_pos(start, bearing, distance);
+        Ok(MinorArc::new(start, end))
+    }
+
+    /// Computes the mean position of the given positions: the normalised sum of the n-vectors of the given
+    /// positions, which - unlike the arithmetic mean of latitudes and longitudes - is correct across the
+    /// antimeridian and around the poles.
+    ///
+    /// The mean position is undefined if no position are given or if the sum of the n-vectors is effectively
+    /// zero (e.g. 2 antipodal positions). See also [Sphere::mean_position](crate::spherical::Sphere::mean_position),
+    /// which also returns [None] if any 2 positions are antipodal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    ///
+    /// let m = LatLong::mean([LatLong::from_degrees(0.0, 179.0), LatLong::from_degrees(0.0, -179.0)]);
+    /// assert_eq!(Some(LatLong::from_degrees(0.0, 180.0)), m.map(|p| p.round_d7()));
+    /// ```
+    pub fn mean<I: IntoIterator<Item = LatLong>>(positions: I) -> Option<LatLong> {
+        Self::weighted_mean(positions.into_iter().map(|p| (p, 1.0)))
+    }
+
+    /// Computes the weighted mean position of the given positions: the normalised sum of the n-vectors of the
+    /// given positions, each scaled by its weight (e.g. the confidence of an observation).
+    ///
+    /// With equal weights, this is the [mean position](crate::LatLong::mean).
+    ///
+    /// The weighted mean position is undefined if:
+    /// - no position are given, or
+    /// - the total weight is zero, negative or NaN, or
+    /// - the weighted sum of the n-vectors is effectively zero (e.g. 2 antipodal positions with equal weights).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    ///
+    /// let m = LatLong::weighted_mean([
+    ///     (LatLong::from_degrees(0.0, 0.0), 3.0),
+    ///     (LatLong::from_degrees(0.0, 90.0), 1.0),
+    /// ]);
+    /// assert_eq!(
+    ///     Some(LatLong::from_degrees(0.0, 18.4349488)),
+    ///     m.map(|p| p.round_d7())
+    /// );
+    /// ```
+    pub fn weighted_mean<I: IntoIterator<Item = (LatLong, f64)>>(items: I) -> Option<LatLong> {
+        weighted_mean_vec3(
+            items
+                .into_iter()
+                .map(|(p, w)| (p.to_nvector().as_vec3(), w)),
+        )
+        .map(|v| LatLong::from_nvector(NVector::new(v)))
+    }
+}
+
+impl Eq for LatLong {}
+
+impl Hash for LatLong {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_f64(self.latitude.as_radians(), state);
+        hash_f64(self.longitude.as_radians(), state);
+    }
+}
+
+/// Feeds the given float into the given hasher: 0 and -0 - which are equal - hash identically.
+fn hash_f64<H: Hasher>(f: f64, state: &mut H) {
+    if f == 0.0 {
+        0f64.to_bits().hash(state)
+    } else {
+        f.to_bits().hash(state)
+    }
+}
+
+/// Error returned when parsing a [LatLong] fails.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ParseLatLongError {
+    /// The text does not contain exactly 2 components separated by a comma, a semicolon or whitespace, and is not
+    /// an ISO 6709 string.
+    InvalidFormat,
+    /// The latitude (first component) cannot be parsed.
+    InvalidLatitude(ParseAngleError),
+    /// The longitude (second component) cannot be parsed.
+    InvalidLongitude(ParseAngleError),
+    /// The latitude (in degrees) is not in [-90, 90].
+    LatitudeOutOfRange(f64),
+    /// The longitude (in degrees) is not in [-180, 180].
+    LongitudeOutOfRange(f64),
+}
+
+impl fmt::Display for ParseLatLongError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLatLongError::InvalidFormat => write!(
+                f,
+                "expected latitude and longitude (decimal degrees, with or without hemisphere, or degrees, minutes \
+                 and seconds) separated by a comma, a semicolon or whitespace, or an ISO 6709 string"
+            ),
+            ParseLatLongError::InvalidLatitude(e) => write!(f, "invalid latitude: {}", e),
+            ParseLatLongError::InvalidLongitude(e) => write!(f, "invalid longitude: {}", e),
+            ParseLatLongError::LatitudeOutOfRange(lat) => {
+                write!(f, "latitude out of range [-90, 90]: {}", lat)
+            }
+            ParseLatLongError::LongitudeOutOfRange(lng) => {
+                write!(f, "longitude out of range [-180, 180]: {}", lng)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseLatLongError {}
+
+impl FromStr for LatLong {
+    type Err = ParseLatLongError;
+
+    /// Parses the given latitude and longitude (in this order - latitude always comes first) separated by a
+    /// comma, a semicolon or whitespace, and optionally surrounded by parentheses or brackets (e.g.
+    /// `48.8567, 2.3508` or `(48.8567; 2.3508)`).
+    ///
+    /// Each component is either decimal degrees or degrees, minutes and seconds with an optional hemisphere - see
+    /// [Angle::parse_dms]: the hemisphere of the latitude must be `N` or `S` and the hemisphere of the longitude
+    /// must be `E` or `W`. When using whitespace as the separator, components must not contain whitespace.
+    ///
+    /// The text may also be an [ISO 6709](https://en.wikipedia.org/wiki/ISO_6709) string - detected by a leading
+    /// sign and no separator: signed latitude (`±DD.D`, `±DDMM.M` or `±DDMMSS.S`) immediately followed by signed
+    /// longitude (`±DDD.D`, `±DDDMM.M` or `±DDDMMSS.S`), an optional altitude which is ignored, and an optional
+    /// terminating `/` (e.g. `+48.8583+002.2945/` or `+485130+0021740/`).
+    ///
+    /// The latitude must be within [-90, 90] degrees and the longitude within [-180, 180] degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, ParseAngleError, ParseLatLongError};
+    ///
+    /// assert_eq!(Ok(LatLong::from_degrees(48.8567, 2.3508)), "48.8567, 2.3508".parse::<LatLong>());
+    /// assert_eq!(Ok(LatLong::from_degrees(48.8567, 2.3508)), "[48.8567 2.3508]".parse::<LatLong>());
+    /// assert_eq!(
+    ///     LatLong::from_degrees(48.85, -2.35),
+    ///     "48°51'N 2°21'W".parse::<LatLong>().unwrap().round_d7()
+    /// );
+    /// assert_eq!(
+    ///     Err(ParseLatLongError::InvalidLongitude(ParseAngleError::InvalidHemisphere)),
+    ///     "48°51'N 2°21'S".parse::<LatLong>()
+    /// );
+    /// assert_eq!(
+    ///     LatLong::from_degrees(48.8583333, 2.2944444),
+    ///     "+485130+0021740/".parse::<LatLong>().unwrap().round_d7()
+    /// );
+    /// assert_eq!(Err(ParseLatLongError::LatitudeOutOfRange(91.0)), "91, 0".parse::<LatLong>());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut t = s.trim();
+        for (open, close) in [('(', ')'), ('[', ']')] {
+            if let Some(inner) = t.strip_prefix(open).and_then(|r| r.strip_suffix(close)) {
+                t = inner.trim();
+                break;
+            }
+        }
+
+        let (latitude, longitude) = if is_iso6709(t) {
+            parse_iso6709(t)?
+        } else {
+            let components: Vec<&str> = if t.contains(';') {
+                t.split(';').collect()
+            } else if t.contains(',') {
+                t.split(',').collect()
+            } else {
+                t.split_whitespace().collect()
+            };
+            if components.len() != 2 {
+                return Err(ParseLatLongError::InvalidFormat);
+            }
+            let latitude = parse_component(components[0], ['N', 'S'])
+                .map_err(ParseLatLongError::InvalidLatitude)?;
+            let longitude = parse_component(components[1], ['E', 'W'])
+                .map_err(ParseLatLongError::InvalidLongitude)?;
+            (latitude, longitude)
+        };
+
+        let lat = latitude.as_degrees();
+        if !(-90.0..=90.0).contains(&lat) {
+            return Err(ParseLatLongError::LatitudeOutOfRange(lat));
+        }
+        let lng = longitude.as_degrees();
+        if !(-180.0..=180.0).contains(&lng) {
+            return Err(ParseLatLongError::LongitudeOutOfRange(lng));
+        }
+        Ok(LatLong::new(latitude, longitude))
+    }
+}
+
+/// Determines whether the given text looks like an ISO 6709 string: signed components with no separator between
+/// them (e.g. `+48.8583+002.2945/`).
+fn is_iso6709(t: &str) -> bool {
+    t.starts_with(['+', '-'])
+        && t[1..].contains(['+', '-'])
+        && !t.contains([',', ';'])
+        && !t.contains(char::is_whitespace)
+}
+
+/// Parses the given ISO 6709 string: latitude and longitude, optionally followed by an altitude (ignored), and
+/// an optional terminating solidus.
+fn parse_iso6709(t: &str) -> Result<(Angle, Angle), ParseLatLongError> {
+    let t = t.strip_suffix('/').unwrap_or(t);
+    let mut components = Vec::new();
+    let mut start = 0;
+    for (i, c) in t.char_indices().skip(1) {
+        if c == '+' || c == '-' {
+            components.push(&t[start..i]);
+            start = i;
+        }
+    }
+    components.push(&t[start..]);
+    if components.len() != 2 && components.len() != 3 {
+        return Err(ParseLatLongError::InvalidFormat);
+    }
+    let latitude =
+        parse_iso6709_component(components[0], 2).map_err(ParseLatLongError::InvalidLatitude)?;
+    let longitude =
+        parse_iso6709_component(components[1], 3).map_err(ParseLatLongError::InvalidLongitude)?;
+    if let Some(altitude) = components.get(2) {
+        if altitude[1..].parse::<f64>().is_err() {
+            return Err(ParseLatLongError::InvalidFormat);
+        }
+    }
+    Ok((latitude, longitude))
+}
+
+/// Parses the given signed ISO 6709 latitude or longitude: `±D[.D]`, `±DMM[.M]` or `±DMMSS[.S]` where `D` has the
+/// given number of digits - the fraction, if any, applying to the last unit.
+fn parse_iso6709_component(s: &str, degree_digits: usize) -> Result<Angle, ParseAngleError> {
+    let (sign, unsigned) = match s.split_at(1) {
+        ("+", u) => (1.0, u),
+        ("-", u) => (-1.0, u),
+        _ => return Err(ParseAngleError::InvalidNumber),
+    };
+    let (int, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if !int.bytes().all(|b| b.is_ascii_digit()) || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseAngleError::InvalidNumber);
+    }
+    let value = |digits: &str| -> f64 { digits.parse::<f64>().unwrap_or(0.0) };
+    let fraction = if fraction.is_empty() {
+        0.0
+    } else {
+        value(&format!("0.{}", fraction))
+    };
+    let len = int.len();
+    let degrees = if len == degree_digits {
+        value(int) + fraction
+    } else if len == degree_digits + 2 || len == degree_digits + 4 {
+        let minutes = value(&int[degree_digits..degree_digits + 2]);
+        let seconds = if len == degree_digits + 4 {
+            value(&int[degree_digits + 2..]) + fraction
+        } else {
+            0.0
+        };
+        let minutes = if len == degree_digits + 2 {
+            minutes + fraction
+        } else {
+            minutes
+        };
+        if minutes >= 60.0 {
+            return Err(ParseAngleError::InvalidMinutes);
+        }
+        if seconds >= 60.0 {
+            return Err(ParseAngleError::InvalidSeconds);
+        }
+        value(&int[..degree_digits]) + minutes / 60.0 + seconds / 3600.0
+    } else {
+        return Err(ParseAngleError::InvalidNumber);
+    };
+    Ok(Angle::from_degrees(sign * degrees))
+}
+
+/// Parses the given latitude or longitude component, checking its hemisphere if any.
+fn parse_component(s: &str, hemispheres: [char; 2]) -> Result<Angle, ParseAngleError> {
+    let (angle, hemisphere) = parse_dms_with_hemisphere(s)?;
+    match hemisphere {
+        Some(h) if !hemispheres.contains(&h) => Err(ParseAngleError::InvalidHemisphere),
+        _ => Ok(angle),
+    }
 }
 
 /// An horizontal position represented by a n-vector: the unit and normal vector to the surface.
@@ -312,7 +937,683 @@ pub(crate) fn assert_geod_eq_d7_mm(expected: GeodeticPos, actual: GeodeticPos) {
 
 #[cfg(test)]
 mod tests {
-    use crate::{LatLong, NVector, Vec3};
+    use std::{collections::HashSet, f64::consts::PI};
+
+    use crate::{
+        ellipsoidal::Ellipsoid, positions::assert_geod_eq_d7_mm, spherical::Sphere, Angle,
+        GeodeticPos, LatLong, Length, NVector, NavigationError, ParseAngleError, ParseLatLongError,
+        Vec3,
+    };
+
+    // hash
+
+    #[test]
+    fn hash_lat_long() {
+        let mut set = HashSet::new();
+        set.insert(LatLong::from_degrees(48.8567, 2.3508));
+        set.insert(LatLong::new(
+            Angle::from_degrees(48.8567),
+            Angle::from_degrees(2.3508),
+        ));
+        set.insert("48.8567, 2.3508".parse::<LatLong>().unwrap());
+        assert_eq!(1, set.len());
+        set.insert(LatLong::from_degrees(48.8567, 2.3509));
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn hash_lat_long_signed_zero() {
+        let mut set = HashSet::new();
+        set.insert(LatLong::from_degrees(0.0, 0.0));
+        set.insert(LatLong::from_degrees(-0.0, -0.0));
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn hash_lat_long_antimeridian() {
+        // longitudes are not normalised.
+        let a = LatLong::from_degrees(10.0, 180.0);
+        let b = LatLong::from_degrees(10.0, -180.0);
+        let mut set = HashSet::new();
+        set.insert(a);
+        set.insert(b);
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn hash_geodetic_pos() {
+        let mut set = HashSet::new();
+        let h = Length::from_metres(100.0);
+        set.insert(GeodeticPos::new(
+            NVector::from_lat_long_degrees(48.8567, 2.3508),
+            h,
+        ));
+        set.insert(GeodeticPos::new(
+            LatLong::from_degrees(48.8567, 2.3508).to_nvector(),
+            h,
+        ));
+        assert_eq!(1, set.len());
+        set.insert(GeodeticPos::new(
+            NVector::from_lat_long_degrees(48.8567, 2.3508),
+            Length::ZERO,
+        ));
+        assert_eq!(2, set.len());
+    }
+
+    // mean
+
+    #[test]
+    fn mean_across_antimeridian() {
+        let ps = [
+            LatLong::from_degrees(10.0, 179.0),
+            LatLong::from_degrees(-10.0, 179.0),
+            LatLong::from_degrees(10.0, -179.0),
+            LatLong::from_degrees(-10.0, -179.0),
+        ];
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, 180.0)),
+            LatLong::mean(ps).map(|p| p.round_d7())
+        );
+    }
+
+    #[test]
+    fn mean_around_pole() {
+        let ps = (0..6).map(|i| LatLong::from_degrees(80.0, i as f64 * 60.0));
+        // the longitude of the pole is arbitrary.
+        assert_eq!(
+            Some(Angle::QUARTER_CIRCLE),
+            LatLong::mean(ps).map(|p| p.latitude().round_d7())
+        );
+    }
+
+    #[test]
+    fn mean_undefined() {
+        assert_eq!(None, LatLong::mean([]));
+        let p = LatLong::from_degrees(45.0, 45.0);
+        assert_eq!(
+            None,
+            LatLong::mean([p, LatLong::from_nvector(p.to_nvector().antipode())])
+        );
+        assert_eq!(Some(p), LatLong::mean([p]).map(|m| m.round_d7()));
+    }
+
+    // weighted_mean
+
+    #[test]
+    fn weighted_mean_equal_weights() {
+        let ps = [
+            LatLong::from_degrees(10.0, 179.0),
+            LatLong::from_degrees(-10.0, 179.0),
+            LatLong::from_degrees(12.0, -179.0),
+            LatLong::from_degrees(-3.0, -178.0),
+        ];
+        let nvs: Vec<NVector> = ps.iter().map(|p| p.to_nvector()).collect();
+        let e = Sphere::mean_position(&nvs).map(LatLong::from_nvector);
+        assert!(e.is_some());
+        for w in [1.0, 2.0, 0.5, 1024.0] {
+            assert_eq!(e, LatLong::weighted_mean(ps.iter().map(|p| (*p, w))));
+        }
+        assert_eq!(
+            e.map(|p| p.round_d7()),
+            LatLong::weighted_mean(ps.iter().map(|p| (*p, 0.3))).map(|p| p.round_d7())
+        );
+    }
+
+    #[test]
+    fn weighted_mean_weights() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.0, 90.0);
+        // tan(lon) = w2 / w1.
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, 3f64.atan().to_degrees()).round_d7()),
+            LatLong::weighted_mean([(p1, 1.0), (p2, 3.0)]).map(|p| p.round_d7())
+        );
+        // zero weight is ignored.
+        assert_eq!(
+            Some(p2),
+            LatLong::weighted_mean([(p1, 0.0), (p2, 1.0)]).map(|p| p.round_d7())
+        );
+    }
+
+    #[test]
+    fn weighted_mean_undefined() {
+        let p = LatLong::from_degrees(45.0, 45.0);
+        assert_eq!(None, LatLong::weighted_mean(Vec::new()));
+        assert_eq!(None, LatLong::weighted_mean([(p, 0.0)]));
+        assert_eq!(None, LatLong::weighted_mean([(p, -1.0)]));
+        assert_eq!(None, LatLong::weighted_mean([(p, f64::NAN)]));
+        let a = LatLong::from_nvector(p.to_nvector().antipode());
+        assert_eq!(None, LatLong::weighted_mean([(p, 2.0), (a, 2.0)]));
+        assert!(LatLong::weighted_mean([(p, 2.0), (a, 1.0)]).is_some());
+    }
+
+    // geodetic <-> geocentric
+
+    #[test]
+    fn geodetic_zero_height_round_trip() {
+        for ll in [
+            LatLong::from_degrees(48.8567, 2.3508),
+            LatLong::from_degrees(-33.8688, 151.2093),
+            LatLong::from_degrees(0.0, -180.0),
+            LatLong::from_degrees(89.5, 45.0),
+        ] {
+            let p = GeodeticPos::from_lat_long(ll, Length::ZERO);
+            let a =
+                GeodeticPos::from_geocentric(p.to_geocentric(&Ellipsoid::WGS84), &Ellipsoid::WGS84);
+            assert_geod_eq_d7_mm(p, a);
+            assert_eq!(ll.round_d7(), a.lat_long().round_d7());
+        }
+    }
+
+    #[test]
+    fn straight_line_distance_same_coordinate() {
+        let ll = LatLong::from_degrees(48.8567, 2.3508);
+        let p1 = GeodeticPos::from_lat_long(ll, Length::from_metres(35.0));
+        let p2 = GeodeticPos::from_lat_long(ll, Length::from_metres(11_035.0));
+        assert_eq!(
+            Length::from_metres(11_000.0),
+            p1.straight_line_distance(&p2, &Ellipsoid::WGS84).round_mm()
+        );
+        assert_eq!(
+            Length::from_metres(11_000.0),
+            p2.straight_line_distance(&p1, &Sphere::EARTH).round_mm()
+        );
+        assert_eq!(Length::ZERO, p1.horizontal_distance(&p2, &Sphere::EARTH));
+    }
+
+    #[test]
+    fn straight_line_distance_is_chord() {
+        // points on the equator 90 degrees apart: chord is radius * sqrt(2).
+        let p1 = GeodeticPos::from_lat_long(LatLong::from_degrees(0.0, 0.0), Length::ZERO);
+        let p2 = GeodeticPos::from_lat_long(LatLong::from_degrees(0.0, 90.0), Length::ZERO);
+        let r = Sphere::EARTH.radius().as_metres();
+        assert_eq!(
+            Length::from_metres(r * 2f64.sqrt()).round_mm(),
+            p1.straight_line_distance(&p2, &Sphere::EARTH).round_mm()
+        );
+        assert!(
+            p1.straight_line_distance(&p2, &Sphere::EARTH)
+                < p1.horizontal_distance(&p2, &Sphere::EARTH)
+        );
+    }
+
+    // from_str
+
+    #[test]
+    fn from_str_separators() {
+        let e = Ok(LatLong::from_degrees(48.8567, 2.3508));
+        assert_eq!(e, "48.8567, 2.3508".parse::<LatLong>());
+        assert_eq!(e, "48.8567,2.3508".parse::<LatLong>());
+        assert_eq!(e, "48.8567; 2.3508".parse::<LatLong>());
+        assert_eq!(e, "48.8567 2.3508".parse::<LatLong>());
+        assert_eq!(e, " 48.8567\t2.3508 ".parse::<LatLong>());
+    }
+
+    #[test]
+    fn from_str_enclosed() {
+        let e = Ok(LatLong::from_degrees(-48.8567, -2.3508));
+        assert_eq!(e, "(-48.8567, -2.3508)".parse::<LatLong>());
+        assert_eq!(e, "[ -48.8567 -2.3508 ]".parse::<LatLong>());
+        // mismatched brackets are not stripped.
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLatitude(
+                ParseAngleError::InvalidNumber
+            )),
+            "(-48.8567, -2.3508]".parse::<LatLong>()
+        );
+    }
+
+    #[test]
+    fn from_str_dms() {
+        assert_eq!(
+            LatLong::from_degrees(48.8582222, 2.2945),
+            "48°51'29.6\"N 2°17'40.2\"E"
+                .parse::<LatLong>()
+                .unwrap()
+                .round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(-33.8568, -151.2153),
+            "33°51.408'S; 151°12.918'W"
+                .parse::<LatLong>()
+                .unwrap()
+                .round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(-33.86, 151.21),
+            "S33.86, E151.21".parse::<LatLong>().unwrap().round_d7()
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_format() {
+        for s in ["", "48.8567", "48.8567, 2.3508, 10", "1 2 3", "1;2;3"] {
+            assert_eq!(Err(ParseLatLongError::InvalidFormat), s.parse::<LatLong>());
+        }
+    }
+
+    #[test]
+    fn from_str_iso6709() {
+        let expected = LatLong::from_degrees(48.8583, 2.2945);
+        for s in [
+            "+48.8583+002.2945/",
+            "+48.8583+002.2945",
+            "+48.8583+002.2945+330/",
+            "+4851.498+00217.67/",
+        ] {
+            assert_eq!(expected, s.parse::<LatLong>().unwrap().round_d7(), "{}", s);
+        }
+        assert_eq!(
+            LatLong::from_degrees(48.8583333, 2.2944444),
+            "+485130+0021740/".parse::<LatLong>().unwrap().round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(-33.8568, -151.2153),
+            "-33.8568-151.2153/".parse::<LatLong>().unwrap().round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(-33.8568333, -151.2152778),
+            "-335124.6-1511255.0-10.5/"
+                .parse::<LatLong>()
+                .unwrap()
+                .round_d7()
+        );
+    }
+
+    #[test]
+    fn from_str_all_formats() {
+        let expected = LatLong::from_degrees(48.8583, 2.2945);
+        for s in [
+            "48.8583, 2.2945",
+            "48.8583N, 2.2945E",
+            "48°51'29.88\"N, 2°17'40.2\"E",
+            "+48.8583+002.2945/",
+        ] {
+            assert_eq!(expected, s.parse::<LatLong>().unwrap().round_d7(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn from_str_iso6709_invalid() {
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLatitude(
+                ParseAngleError::InvalidNumber
+            )),
+            "+488+002.2945/".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLongitude(
+                ParseAngleError::InvalidMinutes
+            )),
+            "+48.8583+00260/".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLatitude(
+                ParseAngleError::InvalidSeconds
+            )),
+            "+485160+0021740/".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::LatitudeOutOfRange(91.0)),
+            "+91+002/".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::InvalidFormat),
+            "+48+002+10+5/".parse::<LatLong>()
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_format_message() {
+        let message = "48.8583".parse::<LatLong>().unwrap_err().to_string();
+        for format in ["decimal degrees", "hemisphere", "minutes", "ISO 6709"] {
+            assert!(message.contains(format), "{}", message);
+        }
+    }
+
+    #[test]
+    fn from_str_invalid_component() {
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLatitude(ParseAngleError::Empty)),
+            ", 2.3508".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLongitude(
+                ParseAngleError::InvalidNumber
+            )),
+            "48.8567, abc".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLatitude(
+                ParseAngleError::InvalidHemisphere
+            )),
+            "48.8567E, 2.3508E".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::InvalidLongitude(
+                ParseAngleError::InvalidMinutes
+            )),
+            "48°51'N, 2°60'E".parse::<LatLong>()
+        );
+    }
+
+    #[test]
+    fn from_str_out_of_range() {
+        assert_eq!(
+            Err(ParseLatLongError::LatitudeOutOfRange(-90.5)),
+            "-90.5, 0".parse::<LatLong>()
+        );
+        assert_eq!(
+            Err(ParseLatLongError::LongitudeOutOfRange(180.5)),
+            "0, 180.5".parse::<LatLong>()
+        );
+        assert_eq!(
+            Ok(LatLong::from_degrees(90.0, -180.0)),
+            "90, -180".parse::<LatLong>()
+        );
+    }
+
+    // angular_distance_to
+
+    #[test]
+    fn angular_distance_to_same_position() {
+        let p = LatLong::from_degrees(45.0, 45.0);
+        assert_eq!(Angle::ZERO, p.angular_distance_to(&p));
+    }
+
+    #[test]
+    fn angular_distance_to_antipode() {
+        let p = LatLong::from_degrees(45.0, 45.0);
+        let a = LatLong::from_nvector(p.to_nvector().antipode());
+        assert_eq!(Angle::HALF_CIRCLE, p.angular_distance_to(&a));
+        assert_eq!(
+            Angle::HALF_CIRCLE,
+            LatLong::from_degrees(90.0, 0.0)
+                .angular_distance_to(&LatLong::from_degrees(-90.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn angular_distance_to() {
+        // pole to equator.
+        assert_eq!(
+            Angle::from_degrees(90.0),
+            LatLong::from_degrees(90.0, 0.0)
+                .angular_distance_to(&LatLong::from_degrees(0.0, 123.0))
+                .round_d7()
+        );
+        // along a meridian.
+        assert_eq!(
+            Angle::from_degrees(25.0),
+            LatLong::from_degrees(-10.0, 30.0)
+                .angular_distance_to(&LatLong::from_degrees(15.0, 30.0))
+                .round_d7()
+        );
+        // across the antimeridian.
+        assert_eq!(
+            Angle::from_degrees(2.0),
+            LatLong::from_degrees(0.0, 179.0)
+                .angular_distance_to(&LatLong::from_degrees(0.0, -179.0))
+                .round_d7()
+        );
+        // symmetric.
+        let p1 = LatLong::from_degrees(51.5, 0.0);
+        let p2 = LatLong::from_degrees(40.0, -74.0);
+        assert_eq!(p1.angular_distance_to(&p2), p2.angular_distance_to(&p1));
+    }
+
+    #[test]
+    fn distance_to() {
+        let p1 = LatLong::from_degrees(51.5, 0.0);
+        let p2 = LatLong::from_degrees(40.0, -74.0);
+        let r = Sphere::EARTH.radius();
+        assert_eq!(p1.angular_distance_to(&p2) * r, p1.distance_to(&p2, r));
+        assert_eq!(
+            Sphere::EARTH.distance(p1.to_nvector(), p2.to_nvector()),
+            p1.distance_to(&p2, r)
+        );
+        assert_eq!(
+            Length::from_metres(PI),
+            LatLong::from_degrees(0.0, 0.0)
+                .distance_to(&LatLong::from_degrees(0.0, 180.0), Length::from_metres(1.0))
+        );
+    }
+
+    // distance_matrix, pairwise_distances
+
+    fn scattered_positions(n: usize) -> Vec<LatLong> {
+        (0..n)
+            .map(|i| {
+                let f = i as f64;
+                LatLong::from_degrees((f * 37.0) % 180.0 - 90.0, (f * 71.0) % 360.0 - 180.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn distance_matrix() {
+        let from = scattered_positions(7);
+        let to = scattered_positions(11)[4..].to_vec();
+        let r = Sphere::EARTH.radius();
+        let d = LatLong::distance_matrix(&from, &to, r);
+        assert_eq!(from.len() * to.len(), d.len());
+        for (i, p) in from.iter().enumerate() {
+            for (j, o) in to.iter().enumerate() {
+                assert_eq!(p.distance_to(o, r), d[i * to.len() + j]);
+            }
+        }
+    }
+
+    #[test]
+    fn distance_matrix_empty() {
+        let ps = scattered_positions(3);
+        let r = Length::from_metres(1.0);
+        assert!(LatLong::distance_matrix(&[], &ps, r).is_empty());
+        assert!(LatLong::distance_matrix(&ps, &[], r).is_empty());
+    }
+
+    #[test]
+    fn pairwise_distances() {
+        let ps = scattered_positions(9);
+        let r = Sphere::EARTH.radius();
+        let d = LatLong::pairwise_distances(&ps, r);
+        assert_eq!(36, d.len());
+        let full = LatLong::distance_matrix(&ps, &ps, r);
+        let mut k = 0;
+        for i in 0..ps.len() {
+            for j in (i + 1)..ps.len() {
+                assert_eq!(ps[i].distance_to(&ps[j], r), d[k]);
+                assert_eq!(full[i * ps.len() + j], d[k]);
+                k += 1;
+            }
+        }
+        assert!(LatLong::pairwise_distances(&ps[..1], r).is_empty());
+        assert!(LatLong::pairwise_distances(&[], r).is_empty());
+    }
+
+    // approx_distance
+
+    #[test]
+    fn approx_distance_short_range() {
+        let r = Sphere::EARTH.radius();
+        // separations below 10 km in all directions, latitudes up to 60 degrees.
+        for lat in [-60.0, -30.0, 0.0, 30.0, 60.0] {
+            let p1 = LatLong::from_degrees(lat, 179.99);
+            for i in 0..16 {
+                let b = i as f64 * PI / 8.0;
+                let p2 = LatLong::from_degrees(lat + 0.06 * b.cos(), 179.99 + 0.06 * b.sin());
+                let exact = p1.distance_to(&p2, r);
+                let approx = p1.approx_distance(&p2, r);
+                assert!(exact < Length::from_kilometres(10.0));
+                assert!((approx - exact).abs() < Length::from_metres(0.01));
+                assert_eq!(approx, p2.approx_distance(&p1, r));
+            }
+        }
+    }
+
+    #[test]
+    fn approx_distance_error_bounds() {
+        let r = Sphere::EARTH.radius();
+        // (maximum absolute latitude, maximum relative error).
+        let bounds = [(45.0, 0.00002), (70.0, 0.0001), (80.0, 0.0004)];
+        for (max_lat, max_error) in bounds {
+            let mut lat = -max_lat;
+            while lat <= max_lat {
+                let p1 = LatLong::from_degrees(lat, 10.0);
+                for d in [0.1, 1.0, 10.0, 50.0, 99.9] {
+                    for b in (0..24).map(|i| Angle::from_degrees(i as f64 * 15.0)) {
+                        let p2 = LatLong::from_nvector(Sphere::EARTH.destination_pos(
+                            p1.to_nvector(),
+                            b,
+                            Length::from_kilometres(d),
+                        ));
+                        if p2.latitude().as_degrees().abs() > max_lat {
+                            continue;
+                        }
+                        let exact = p1.distance_to(&p2, r);
+                        let approx = p1.approx_distance(&p2, r);
+                        let error = ((approx - exact) / exact).abs();
+                        assert!(
+                            error < max_error,
+                            "error {} at latitude {}, distance {} km, bearing {:?}",
+                            error,
+                            lat,
+                            d,
+                            b
+                        );
+                    }
+                }
+                lat += 5.0;
+            }
+        }
+    }
+
+    #[test]
+    fn approx_distance_exact_along_meridian_and_equator() {
+        let r = Sphere::EARTH.radius();
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.0, 1.0);
+        let p3 = LatLong::from_degrees(1.0, 0.0);
+        assert_eq!(
+            p1.distance_to(&p2, r).round_mm(),
+            p1.approx_distance(&p2, r).round_mm()
+        );
+        assert_eq!(
+            p1.distance_to(&p3, r).round_mm(),
+            p1.approx_distance(&p3, r).round_mm()
+        );
+    }
+
+    #[test]
+    fn approx_distance_same_position() {
+        let p = LatLong::from_degrees(12.0, -45.0);
+        assert_eq!(Length::ZERO, p.approx_distance(&p, Sphere::EARTH.radius()));
+    }
+
+    // leg_to
+
+    #[test]
+    fn leg_to() {
+        let p = LatLong::from_degrees(51.5, -0.1);
+        let b = Angle::from_degrees(60.0);
+        let d = Length::from_kilometres(5_000.0);
+        let leg = p.leg_to(b, d, Sphere::EARTH.radius()).unwrap();
+        assert_eq!(p.to_nvector(), leg.start());
+        assert_eq!(
+            Sphere::EARTH.destination_pos(p.to_nvector(), b, d),
+            leg.end()
+        );
+        assert_eq!(d.round_mm(), leg.length(Sphere::EARTH.radius()).round_mm());
+        assert_eq!(
+            b.round_d7(),
+            Sphere::initial_bearing(leg.start(), leg.end()).round_d7()
+        );
+    }
+
+    #[test]
+    fn leg_to_sampling() {
+        let p = LatLong::from_degrees(-33.9, 18.4);
+        let d = Length::from_kilometres(3_000.0);
+        let leg = p
+            .leg_to(Angle::from_degrees(250.0), d, Sphere::EARTH.radius())
+            .unwrap();
+        let samples: Vec<NVector> = (0..=10)
+            .map(|i| leg.interpolate(i as f64 / 10.0).unwrap())
+            .collect();
+        assert_eq!(leg.start(), samples[0]);
+        assert_eq!(leg.end(), samples[10]);
+        for w in samples.windows(2) {
+            assert_eq!(
+                Length::from_kilometres(300.0).round_mm(),
+                Sphere::EARTH.distance(w[0], w[1]).round_mm()
+            );
+        }
+    }
+
+    #[test]
+    fn leg_to_out_of_range() {
+        let p = LatLong::from_degrees(0.0, 0.0);
+        let r = Length::from_metres(1.0);
+        assert!(p.leg_to(Angle::ZERO, Length::ZERO, r).is_ok());
+        assert!(p
+            .leg_to(Angle::ZERO, Length::from_metres(PI - 1e-9), r)
+            .is_ok());
+        for d in [-1.0, PI, 4.0, f64::NAN] {
+            assert_eq!(
+                Err(NavigationError::OutOfRange),
+                p.leg_to(Angle::ZERO, Length::from_metres(d), r)
+            );
+        }
+    }
+
+    // is_within
+
+    #[test]
+    fn is_within_same_position() {
+        let p = LatLong::from_degrees(45.0, 10.0);
+        assert!(p.is_within(&p, Length::ZERO, Sphere::EARTH.radius()));
+        assert!(p.is_within_angular(&p, Angle::ZERO));
+    }
+
+    #[test]
+    fn is_within_negative_tolerance() {
+        let p = LatLong::from_degrees(45.0, 10.0);
+        assert!(!p.is_within(&p, Length::from_metres(-1.0), Sphere::EARTH.radius()));
+    }
+
+    #[test]
+    fn is_within_antimeridian() {
+        let p1 = LatLong::from_degrees(10.0, 180.0);
+        let p2 = LatLong::from_degrees(10.0, -180.0);
+        // longitudes 360 degrees apart, but same position.
+        assert!(p1.is_within(&p2, Length::from_metres(1e-6), Sphere::EARTH.radius()));
+
+        let p3 = LatLong::from_degrees(10.0, -179.9999);
+        let d = Sphere::EARTH.distance(p1.to_nvector(), p3.to_nvector());
+        assert!(p1.is_within(&p3, d + Length::from_metres(0.01), Sphere::EARTH.radius()));
+        assert!(!p1.is_within(&p3, d - Length::from_metres(0.01), Sphere::EARTH.radius()));
+    }
+
+    #[test]
+    fn is_within_high_latitude() {
+        // 1 degree of longitude at 89.99 degrees of latitude is ~19 metres, not ~111 kilometres.
+        let p1 = LatLong::from_degrees(89.99, 0.0);
+        let p2 = LatLong::from_degrees(89.99, 1.0);
+        assert!(p1.is_within(&p2, Length::from_metres(20.0), Sphere::EARTH.radius()));
+        assert!(!p1.is_within(&p2, Length::from_metres(19.0), Sphere::EARTH.radius()));
+        assert!(!p1.is_within_angular(&p2, Angle::from_degrees(0.0001)));
+        assert!(p1.is_within_angular(&p2, Angle::from_degrees(0.0002)));
+    }
+
+    #[test]
+    fn is_within_poles() {
+        let n1 = LatLong::from_degrees(90.0, 0.0);
+        let n2 = LatLong::from_degrees(90.0, 123.0);
+        assert!(n1.is_within_angular(&n2, Angle::ZERO));
+        let s = LatLong::from_degrees(-90.0, 0.0);
+        assert!(!n1.is_within_angular(&s, Angle::from_degrees(179.9)));
+        assert!(n1.is_within_angular(&s, Angle::HALF_CIRCLE));
+    }
 
     #[test]
     fn nvector_from_north_pole() {
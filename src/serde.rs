@@ -0,0 +1,450 @@
+//! [Serde](https://serde.rs) support (requires the `serde` feature).
+//!
+//! A [LatLong] is serialized as an object with explicitly named fields in decimal degrees, so that the order of
+//! the latitude and longitude is never ambiguous, e.g. Paris (48.8567°N, 2.3508°E) is written
+//! `{"latitude": 48.8567, "longitude": 2.3508}`. It is deserialized from either an object with named fields:
+//! `lat` (or `latitude`) and `lon` (or `lng`, `long`, `longitude`), or a two-element array ordered latitude
+//! first, then longitude, e.g. `[48.8567, 2.3508]`. Formats which are not self-describing (e.g. bincode) read
+//! back exactly what was written: the latitude followed by the longitude.
+//!
+//! The following modules can be used with `#[serde(with = "...")]` to serialize a [LatLong] as a compact
+//! two-element array instead:
+//! - [lat_lon]: ordered latitude first, e.g. `[48.8567, 2.3508]`,
+//! - [lon_lat]: ordered longitude first, as used by GeoJSON and related formats, e.g. `[2.3508, 48.8567]`.
+//!
+//! In all cases deserialization fails if the latitude is outside of [-90, 90] degrees or if the longitude is
+//! outside of [-180, 180] degrees.
+
+use std::fmt;
+
+use ::serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{LatLong, ParseLatLongError};
+
+impl Serialize for LatLong {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_struct("LatLong", 2)?;
+        s.serialize_field("latitude", &self.latitude().as_degrees())?;
+        s.serialize_field("longitude", &self.longitude().as_degrees())?;
+        s.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for LatLong {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("LatLong", SERIALIZED_FIELDS, LatLongVisitor(Order::LatLon))
+    }
+}
+
+/// Serializes and deserializes a [LatLong] as a two-element array of decimal degrees ordered latitude first,
+/// then longitude, e.g. `[48.8567, 2.3508]`. Objects with named fields are also accepted on deserialization.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Airport {
+///     #[serde(with = "jord::serde::lat_lon")]
+///     position: LatLong,
+/// }
+///
+/// let airport = Airport { position: LatLong::from_degrees(59.5, 17.75) };
+/// let json = serde_json::to_string(&airport).unwrap();
+/// assert_eq!(r#"{"position":[59.5,17.75]}"#, json);
+/// assert_eq!(airport, serde_json::from_str(&json).unwrap());
+/// ```
+pub mod lat_lon {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{deserialize_ordered, serialize_ordered, Order};
+    use crate::LatLong;
+
+    /// Serializes the given [LatLong] as `[latitude, longitude]` in decimal degrees.
+    pub fn serialize<S>(p: &LatLong, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_ordered(p, Order::LatLon, serializer)
+    }
+
+    /// Deserializes a [LatLong] from `[latitude, longitude]` in decimal degrees or from an object with named
+    /// fields.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<LatLong, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_ordered(Order::LatLon, deserializer)
+    }
+}
+
+/// Serializes and deserializes a [LatLong] as a two-element array of decimal degrees ordered longitude first,
+/// then latitude, e.g. `[2.3508, 48.8567]`. Objects with named fields are also accepted on deserialization.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Airport {
+///     #[serde(with = "jord::serde::lon_lat")]
+///     position: LatLong,
+/// }
+///
+/// let airport = Airport { position: LatLong::from_degrees(59.5, 17.75) };
+/// let json = serde_json::to_string(&airport).unwrap();
+/// assert_eq!(r#"{"position":[17.75,59.5]}"#, json);
+/// assert_eq!(airport, serde_json::from_str(&json).unwrap());
+/// ```
+pub mod lon_lat {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::{deserialize_ordered, serialize_ordered, Order};
+    use crate::LatLong;
+
+    /// Serializes the given [LatLong] as `[longitude, latitude]` in decimal degrees.
+    pub fn serialize<S>(p: &LatLong, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_ordered(p, Order::LonLat, serializer)
+    }
+
+    /// Deserializes a [LatLong] from `[longitude, latitude]` in decimal degrees or from an object with named
+    /// fields.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<LatLong, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_ordered(Order::LonLat, deserializer)
+    }
+}
+
+/// Order of the elements of the array representation of a [LatLong].
+#[derive(Clone, Copy)]
+enum Order {
+    LatLon,
+    LonLat,
+}
+
+fn serialize_ordered<S>(p: &LatLong, order: Order, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let lat = p.latitude().as_degrees();
+    let lng = p.longitude().as_degrees();
+    let (first, second) = match order {
+        Order::LatLon => (lat, lng),
+        Order::LonLat => (lng, lat),
+    };
+    let mut tuple = serializer.serialize_tuple(2)?;
+    tuple.serialize_element(&first)?;
+    tuple.serialize_element(&second)?;
+    tuple.end()
+}
+
+/// Deserializes a [LatLong] from a two-element array in the given order. Human-readable formats (e.g. JSON)
+/// describe their data, so an object with named fields is accepted as well; other formats (e.g. bincode) can
+/// only be read as the tuple written by [serialize_ordered].
+fn deserialize_ordered<'de, D>(order: Order, deserializer: D) -> Result<LatLong, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(LatLongVisitor(order))
+    } else {
+        deserializer.deserialize_tuple(2, LatLongVisitor(order))
+    }
+}
+
+/// Returns the [LatLong] at the given latitude and longitude in degrees, if both are within range.
+fn checked_lat_long<E>(lat: f64, lng: f64) -> Result<LatLong, E>
+where
+    E: de::Error,
+{
+    if !(-90.0..=90.0).contains(&lat) {
+        Err(E::custom(ParseLatLongError::LatitudeOutOfRange(lat)))
+    } else if !(-180.0..=180.0).contains(&lng) {
+        Err(E::custom(ParseLatLongError::LongitudeOutOfRange(lng)))
+    } else {
+        Ok(LatLong::from_degrees(lat, lng))
+    }
+}
+
+struct LatLongVisitor(Order);
+
+impl<'de> Visitor<'de> for LatLongVisitor {
+    type Value = LatLong;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Order::LatLon => {
+                f.write_str("an array [latitude, longitude] in decimal degrees or an object")
+            }
+            Order::LonLat => {
+                f.write_str("an array [longitude, latitude] in decimal degrees or an object")
+            }
+        }
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let first: f64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let second: f64 = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(3, &self));
+        }
+        match self.0 {
+            Order::LatLon => checked_lat_long(first, second),
+            Order::LonLat => checked_lat_long(second, first),
+        }
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut lat: Option<f64> = None;
+        let mut lng: Option<f64> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "lat" | "latitude" => {
+                    if lat.is_some() {
+                        return Err(de::Error::duplicate_field("lat"));
+                    }
+                    lat = Some(map.next_value()?);
+                }
+                "lon" | "lng" | "long" | "longitude" => {
+                    if lng.is_some() {
+                        return Err(de::Error::duplicate_field("lon"));
+                    }
+                    lng = Some(map.next_value()?);
+                }
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+        let lat = lat.ok_or_else(|| de::Error::missing_field("lat"))?;
+        let lng = lng.ok_or_else(|| de::Error::missing_field("lon"))?;
+        checked_lat_long(lat, lng)
+    }
+}
+
+/// Names of the fields accepted on deserialization.
+const FIELDS: &[&str] = &["lat", "latitude", "lon", "lng", "long", "longitude"];
+
+/// Names of the fields written on serialization, in order: non-self-describing formats read as many values.
+const SERIALIZED_FIELDS: &[&str] = &["latitude", "longitude"];
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::{
+        serde::{lat_lon, lon_lat},
+        LatLong,
+    };
+
+    fn to_lat_lon(p: LatLong) -> Value {
+        lat_lon::serialize(&p, serde_json::value::Serializer).unwrap()
+    }
+
+    fn to_lon_lat(p: LatLong) -> Value {
+        lon_lat::serialize(&p, serde_json::value::Serializer).unwrap()
+    }
+
+    fn from_lon_lat(v: Value) -> Result<LatLong, serde_json::Error> {
+        lon_lat::deserialize(v)
+    }
+
+    // serialize
+
+    #[test]
+    fn serialize_object() {
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        assert_eq!(
+            json!({"latitude": 48.8567, "longitude": 2.3508}),
+            serde_json::to_value(p).unwrap()
+        );
+        assert_eq!(
+            r#"{"latitude":48.8567,"longitude":2.3508}"#,
+            serde_json::to_string(&p).unwrap()
+        );
+    }
+
+    #[test]
+    fn serialize_lat_lon() {
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        assert_eq!(json!([48.8567, 2.3508]), to_lat_lon(p));
+    }
+
+    #[test]
+    fn serialize_lon_lat() {
+        let p = LatLong::from_degrees(48.8567, 2.3508);
+        assert_eq!(json!([2.3508, 48.8567]), to_lon_lat(p));
+    }
+
+    // deserialize
+
+    #[test]
+    fn deserialize_array() {
+        let p: LatLong = serde_json::from_value(json!([48.8567, 2.3508])).unwrap();
+        assert_eq!(LatLong::from_degrees(48.8567, 2.3508), p);
+
+        let p = from_lon_lat(json!([2.3508, 48.8567])).unwrap();
+        assert_eq!(LatLong::from_degrees(48.8567, 2.3508), p);
+
+        let p: LatLong = serde_json::from_value(json!([-90, 180])).unwrap();
+        assert_eq!(LatLong::from_degrees(-90.0, 180.0), p);
+    }
+
+    #[test]
+    fn deserialize_object() {
+        let expected = LatLong::from_degrees(48.8567, 2.3508);
+        let objects = vec![
+            json!({"lat": 48.8567, "lon": 2.3508}),
+            json!({"lon": 2.3508, "lat": 48.8567}),
+            json!({"latitude": 48.8567, "longitude": 2.3508}),
+            json!({"lat": 48.8567, "lng": 2.3508}),
+            json!({"lat": 48.8567, "long": 2.3508}),
+        ];
+        for o in objects {
+            let p: LatLong = serde_json::from_value(o.clone()).unwrap();
+            assert_eq!(expected, p);
+            assert_eq!(expected, from_lon_lat(o).unwrap());
+        }
+    }
+
+    #[test]
+    fn deserialize_out_of_range() {
+        let invalid = vec![
+            json!([90.1, 0.0]),
+            json!([-90.1, 0.0]),
+            json!([0.0, 180.1]),
+            json!([0.0, -180.1]),
+            json!({"lat": 91.0, "lon": 0.0}),
+            json!({"lat": 0.0, "lon": -181.0}),
+        ];
+        for v in invalid {
+            assert!(serde_json::from_value::<LatLong>(v).is_err());
+        }
+        // valid as [lat, lon] but not as [lon, lat].
+        assert!(serde_json::from_value::<LatLong>(json!([10.0, 100.0])).is_ok());
+        assert!(from_lon_lat(json!([10.0, 100.0])).is_err());
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let invalid = vec![
+            json!([]),
+            json!([48.8567]),
+            json!([48.8567, 2.3508, 0.0]),
+            json!(["48.8567", "2.3508"]),
+            json!({"lat": 48.8567}),
+            json!({"lon": 2.3508}),
+            json!({"lat": 48.8567, "lon": 2.3508, "alt": 0.0}),
+            json!({"lat": 48.8567, "latitude": 48.8567, "lon": 2.3508}),
+            json!(48.8567),
+        ];
+        for v in invalid {
+            assert!(serde_json::from_value::<LatLong>(v).is_err());
+        }
+    }
+
+    #[test]
+    fn deserialize_object_latitude_91() {
+        let e = serde_json::from_value::<LatLong>(json!({"latitude": 91.0, "longitude": 0.0}))
+            .unwrap_err();
+        assert!(e.to_string().contains("91"));
+        assert!(
+            serde_json::from_value::<LatLong>(json!({"latitude": 90.0, "longitude": 0.0})).is_ok()
+        );
+    }
+
+    #[test]
+    fn deserialize_error_message() {
+        let e = serde_json::from_str::<LatLong>("[95.0, 0.0]").unwrap_err();
+        assert!(e.to_string().contains("95"));
+    }
+
+    // round trip
+
+    #[test]
+    fn round_trip() {
+        let ps = vec![
+            LatLong::from_degrees(48.8567, 2.3508),
+            LatLong::from_degrees(-33.8688, 151.2093),
+            LatLong::from_degrees(90.0, -180.0),
+            LatLong::from_degrees(-90.0, 180.0),
+            LatLong::from_degrees(0.0, 0.0),
+        ];
+        for p in ps {
+            let s = serde_json::to_string(&p).unwrap();
+            assert_eq!(p, serde_json::from_str(&s).unwrap());
+            assert_eq!(p, lat_lon::deserialize(to_lat_lon(p)).unwrap());
+            assert_eq!(p, from_lon_lat(to_lon_lat(p)).unwrap());
+        }
+    }
+
+    #[test]
+    fn round_trip_non_self_describing() {
+        let options = bincode::DefaultOptions::new();
+        let p = LatLong::from_degrees(-33.8688, 151.2093);
+
+        let bytes = bincode::serialize(&p).unwrap();
+        // 2 decimal degrees, no field names.
+        assert_eq!(16, bytes.len());
+        assert_eq!(p, bincode::deserialize(&bytes).unwrap());
+
+        let mut bytes = Vec::new();
+        lat_lon::serialize(&p, &mut bincode::Serializer::new(&mut bytes, options)).unwrap();
+        let mut de = bincode::Deserializer::from_slice(&bytes, options);
+        assert_eq!(p, lat_lon::deserialize(&mut de).unwrap());
+
+        let mut bytes = Vec::new();
+        lon_lat::serialize(&p, &mut bincode::Serializer::new(&mut bytes, options)).unwrap();
+        let mut de = bincode::Deserializer::from_slice(&bytes, options);
+        assert_eq!(p, lon_lat::deserialize(&mut de).unwrap());
+
+        let bytes = bincode::serialize(&LatLong::from_degrees(95.0, 0.0)).unwrap();
+        assert!(bincode::deserialize::<LatLong>(&bytes).is_err());
+    }
+
+    #[test]
+    fn cross_format() {
+        // [lat, lon] serialized, read back as an object with named fields.
+        let p = LatLong::from_degrees(59.651944, 17.918611);
+        let v = to_lat_lon(p);
+        let o = json!({"lat": v[0], "lon": v[1]});
+        assert_eq!(p, serde_json::from_value(o.clone()).unwrap());
+
+        // object serialized, read back by the array helpers.
+        let o = serde_json::to_value(p).unwrap();
+        assert_eq!(p, lat_lon::deserialize(o.clone()).unwrap());
+        assert_eq!(p, from_lon_lat(o.clone()).unwrap());
+
+        // [lon, lat] serialized, read back as [lat, lon] after swapping.
+        let v = to_lon_lat(p);
+        assert_eq!(p, serde_json::from_value(json!([v[1], v[0]])).unwrap());
+        assert_eq!(p, from_lon_lat(o).unwrap());
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use crate::{impl_measurement, Length, Measurement};
+use crate::{Dimension, Length, Measurement};
 
 #[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Default)]
 /// A speed.
@@ -101,6 +101,8 @@ impl Speed {
 }
 
 impl Measurement for Speed {
+    const DIMENSION: Dimension = Dimension::Speed;
+
     fn from_default_unit(amount: f64) -> Self {
         Speed::from_metres_per_second(amount)
     }
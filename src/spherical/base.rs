@@ -1,4 +1,4 @@
-use crate::Vec3;
+use crate::{numbers::eq_zero, Vec3};
 
 /// epsilon below which expensive side is called.
 const TRIAGE_SIDE_EPS: f64 = 10.0 * f64::EPSILON;
@@ -57,6 +57,20 @@ pub(crate) fn exact_side(v0: Vec3, v1: Vec3, v2: Vec3) -> f64 {
     }
 }
 
+/// Returns the unit vector of the weighted sum of the given vectors, or `None` if the total weight is not
+/// positive or if the sum is effectively zero relative to the total weight.
+pub(crate) fn weighted_mean_vec3<I>(vs: I) -> Option<Vec3>
+where
+    I: Iterator<Item = (Vec3, f64)>,
+{
+    let (sum, total) = vs.fold((Vec3::ZERO, 0.0), |(s, t), (v, w)| (s + w * v, t + w));
+    if total.is_nan() || total <= 0.0 || eq_zero(sum.norm() / total) {
+        None
+    } else {
+        Some(sum.unit())
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
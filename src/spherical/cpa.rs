@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::{Length, NVector};
+
+/// The closest point of approach (CPA) between 2 vehicles: see [Sphere::cpa](crate::spherical::Sphere::cpa).
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Cpa {
+    time: Duration,
+    distance: Length,
+    ownship_position: NVector,
+    intruder_position: NVector,
+}
+
+impl Cpa {
+    pub(crate) fn new(
+        time: Duration,
+        distance: Length,
+        ownship_position: NVector,
+        intruder_position: NVector,
+    ) -> Self {
+        Cpa {
+            time,
+            distance,
+            ownship_position,
+            intruder_position,
+        }
+    }
+
+    /// Returns the time from now to the CPA.
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns the distance between the 2 vehicles at the CPA: the minimum separation.
+    #[inline]
+    pub fn distance(&self) -> Length {
+        self.distance
+    }
+
+    /// Returns the position of the ownship at the CPA.
+    #[inline]
+    pub fn ownship_position(&self) -> NVector {
+        self.ownship_position
+    }
+
+    /// Returns the position of the intruder at the CPA.
+    #[inline]
+    pub fn intruder_position(&self) -> NVector {
+        self.intruder_position
+    }
+}
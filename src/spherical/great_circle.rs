@@ -1,6 +1,19 @@
-use crate::{Angle, NVector, Vec3};
+use std::f64::consts::PI;
 
-use super::base::easting;
+use crate::{numbers::eq_zero, Angle, LatLong, Length, NVector, NavigationError, Vec3};
+
+use super::{base::easting, Sphere};
+
+/// Side of a position relative to a directed great circle: see [GreatCircle::side_of].
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash)]
+pub enum Side {
+    /// Left of the great circle (in its direction): negative cross-track distance.
+    Left,
+    /// Right of the great circle (in its direction): positive cross-track distance.
+    Right,
+    /// On the great circle, within the tolerance.
+    On,
+}
 
 /// A circle on the surface of a sphere which lies in a plane
 // passing through the sphere centre. Every two distinct and non-antipodal points
@@ -14,6 +27,12 @@ pub struct GreatCircle {
 }
 
 impl GreatCircle {
+    /// Sine of the smallest angle between 2 great circles for them to be considered distinct.
+    const INTERSECTIONS_MIN_SIN: f64 = 1e-12;
+
+    /// Tolerance used to decide whether a great circle is tangent to a parallel or passes by a pole.
+    const CROSSING_TANGENT_MAX_DIFF: f64 = 1e-12;
+
     /// Creates a great circle passing by both given positions (in this direction).
     ///
     /// Note: if both start and end positions are equal or the antipode of one another, then an
@@ -23,6 +42,34 @@ impl GreatCircle {
         GreatCircle { normal }
     }
 
+    /// Creates a great circle passing by both given positions (in this direction), if both positions define a
+    /// unique great circle - see [is_great_circle](crate::spherical::Sphere::is_great_circle).
+    ///
+    /// Returns [NavigationError::DegenerateGeometry] if both positions are equal, and
+    /// [NavigationError::AntipodalPositions] if both positions are the antipode of one another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{NavigationError, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// let p = NVector::from_lat_long_degrees(10.0, 20.0);
+    ///
+    /// assert!(GreatCircle::checked_new(p, NVector::from_lat_long_degrees(20.0, 20.0)).is_ok());
+    /// assert_eq!(Err(NavigationError::DegenerateGeometry), GreatCircle::checked_new(p, p));
+    /// assert_eq!(Err(NavigationError::AntipodalPositions), GreatCircle::checked_new(p, p.antipode()));
+    /// ```
+    pub fn checked_new(p1: NVector, p2: NVector) -> Result<Self, NavigationError> {
+        if Sphere::is_great_circle(p1, p2) {
+            Ok(Self::new(p1, p2))
+        } else if p1 == p2 {
+            Err(NavigationError::DegenerateGeometry)
+        } else {
+            Err(NavigationError::AntipodalPositions)
+        }
+    }
+
     /// Creates a great circle passing by the given position and heading on the given bearing.
     pub fn from_heading(p: NVector, bearing: Angle) -> Self {
         // easting.
@@ -36,15 +83,160 @@ impl GreatCircle {
         GreatCircle { normal }
     }
 
+    /// Creates the terminator for the given subsolar point (where the sun is at the zenith): the great circle
+    /// dividing the lit and dark hemispheres, whose normal is the subsolar point. See also
+    /// [is_lit](crate::spherical::Sphere::is_lit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// let subsolar = NVector::from_lat_long_degrees(23.0, 10.0);
+    /// let terminator = GreatCircle::terminator(subsolar);
+    ///
+    /// assert_eq!(subsolar.as_vec3(), terminator.normal());
+    /// ```
+    pub fn terminator(subsolar_point: NVector) -> Self {
+        GreatCircle {
+            normal: subsolar_point.as_vec3(),
+        }
+    }
+
+    /// Computes the great circle that best fits the given positions: the great circle which minimises the sum of the
+    /// squared (chord) distances from each position to its plane - i.e. the sum of the squared sines of the
+    /// cross-track angles, which for nearly-aligned positions is equivalent to minimising the sum of squared
+    /// cross-track distances.
+    ///
+    /// The normal of the returned great circle is the eigenvector associated with the smallest eigenvalue of
+    /// the scatter matrix of the n-vectors (sum of `n nᵀ`), oriented in the overall direction of travel from
+    /// the first to the last position.
+    ///
+    /// Returns `None` if less than 2 positions are given or if all given positions are equal or antipodal to
+    /// the first position (i.e. they do not define a unique great circle).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{NVector, Vec3};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// let ps = vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 20.0),
+    /// ];
+    ///
+    /// let gc = GreatCircle::best_fit(&ps).unwrap();
+    /// assert!((gc.normal() - Vec3::UNIT_Z).norm() < 1e-15);
+    /// ```
+    pub fn best_fit(ps: &[NVector]) -> Option<Self> {
+        let first = *ps.first()?;
+        if ps.len() < 2 || !ps.iter().any(|p| Sphere::is_great_circle(first, *p)) {
+            return None;
+        }
+
+        let mut scatter = [[0.0; 3]; 3];
+        for p in ps {
+            let v = p.as_vec3();
+            let c = [v.x(), v.y(), v.z()];
+            for (i, row) in scatter.iter_mut().enumerate() {
+                for (j, e) in row.iter_mut().enumerate() {
+                    *e += c[i] * c[j];
+                }
+            }
+        }
+        let normal = smallest_eigenvector(scatter).unit();
+
+        // direction of travel.
+        let dir = ps.windows(2).fold(Vec3::ZERO, |acc, w| {
+            acc + w[0].as_vec3().cross_prod(w[1].as_vec3())
+        });
+        if normal.dot_prod(dir) < 0.0 {
+            Some(GreatCircle { normal: -normal })
+        } else {
+            Some(GreatCircle { normal })
+        }
+    }
+
     /// Returns the vector normal to this great circle.
     #[inline]
     pub fn normal(&self) -> Vec3 {
         self.normal
     }
 
-    /// Computes the projection of the given position on this great circle. If the given position is strictly
-    /// "perpendicular" to this great circle, this method arbitrarily returns a position on the great circle (p
-    /// can be projected anywhere on the great circle).
+    /// Computes both intersections between this great circle and the given great circle: two distinct great
+    /// circles always intersect at two antipodal positions. The first returned position is the one given by the
+    /// cross product of the normal of this great circle with the normal of the given great circle, the second
+    /// is its antipode.
+    ///
+    /// Returns [None] if both great circles are equal or opposite (an infinity of intersections exist); see
+    /// also [cross_track_distance](crate::spherical::Sphere::cross_track_distance) to compute the distance
+    /// from a position to a great circle. Great circles are considered effectively equal or opposite when the
+    /// angle between both great circles (or its supplement) is less than about 1e-12 radians (a few micrometres
+    /// on the Earth): the intersections are then numerically meaningless.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// let equator = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(90.0));
+    /// let meridian = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::ZERO);
+    ///
+    /// let (i1, i2) = equator.intersections(meridian).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 0.0), LatLong::from_nvector(i1).round_d7());
+    /// assert_eq!(LatLong::from_degrees(0.0, 180.0), LatLong::from_nvector(i2).round_d7());
+    /// ```
+    pub fn intersections(&self, other: GreatCircle) -> Option<(NVector, NVector)> {
+        // sine of the angle between both great circles.
+        let sin = self.normal.cross_prod(other.normal).norm()
+            / (self.normal.norm() * other.normal.norm());
+        let i = self.normal.stable_cross_prod_unit(other.normal);
+        if i == Vec3::ZERO || sin.is_nan() || sin < Self::INTERSECTIONS_MIN_SIN {
+            // equal or opposite great circles: no unique intersection.
+            None
+        } else {
+            Some((NVector::new(i), NVector::new(-i)))
+        }
+    }
+
+    /// Computes both intersections between this great circle and the given great circle: see
+    /// [intersections](crate::spherical::GreatCircle::intersections).
+    ///
+    /// Returns [DegenerateGeometry](crate::NavigationError::DegenerateGeometry) if both great circles are
+    /// (effectively) equal or opposite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NavigationError, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// let p = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let equator = GreatCircle::from_heading(p, Angle::from_degrees(90.0));
+    ///
+    /// assert!(equator.checked_intersections(GreatCircle::from_heading(p, Angle::ZERO)).is_ok());
+    /// assert_eq!(
+    ///     Err(NavigationError::DegenerateGeometry),
+    ///     equator.checked_intersections(GreatCircle::from_heading(p, Angle::from_degrees(270.0)))
+    /// );
+    /// ```
+    pub fn checked_intersections(
+        &self,
+        other: GreatCircle,
+    ) -> Result<(NVector, NVector), NavigationError> {
+        self.intersections(other)
+            .ok_or(NavigationError::DegenerateGeometry)
+    }
+
+    /// Computes the projection of the given position on this great circle: the position on this great circle
+    /// which is the closest to the given position (the foot of the perpendicular great circle), at a cross-track
+    /// distance of zero. If the given position is strictly "perpendicular" to this great circle - i.e. it is one
+    /// of the poles of this great circle and is therefore equidistant from every position on it - this method
+    /// arbitrarily returns a position on the great circle (p can be projected anywhere on the great circle).
     ///
     /// # Examples
     ///
@@ -71,12 +263,683 @@ impl GreatCircle {
             Some(NVector::new(proj))
         }
     }
+
+    /// Computes the signed distance from the given position to this great circle, on a sphere of the given
+    /// radius: this is the [cross-track distance](crate::spherical::Sphere::cross_track_distance) - negative if the
+    /// position is left of this great circle, positive if it is right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector};
+    /// use jord::spherical::{GreatCircle, Sphere};
+    ///
+    /// // heading east along the equator.
+    /// let gc = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(90.0));
+    /// let p = NVector::from_lat_long_degrees(-1.0, 5.0);
+    ///
+    /// assert_eq!(
+    ///     Length::from_metres(111_194.94),
+    ///     gc.distance_to_point(p, Sphere::EARTH.radius()).round_cm()
+    /// );
+    /// ```
+    pub fn distance_to_point(&self, p: NVector, radius: Length) -> Length {
+        Sphere::new(radius).cross_track_distance(p, *self)
+    }
+
+    /// Determines whether the given position is right of (negative integer), left of (positive integer) or on
+    /// (zero) this great circle, in the direction given by its defining positions or heading.
+    ///
+    /// This is equivalent to [Sphere::side] but only requires a dot product with the precomputed normal of this
+    /// great circle: build the great circle once to classify many positions against the same path.
+    ///
+    /// This is the tolerance-free shortcut of [side_of](crate::spherical::GreatCircle::side_of): the position is
+    /// on this great circle only if its cross-track angle is effectively zero (within [f64::EPSILON] radians), and
+    /// `1`, `-1` and `0` correspond to [Side::Left], [Side::Right] and [Side::On] respectively. Use
+    /// [side_of](crate::spherical::GreatCircle::side_of) to choose the width of the band considered on the great
+    /// circle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// // heading east along the equator.
+    /// let gc = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(90.0));
+    ///
+    /// assert_eq!(1, gc.side(NVector::from_lat_long_degrees(1.0, 5.0)));
+    /// assert_eq!(-1, gc.side(NVector::from_lat_long_degrees(-1.0, 5.0)));
+    /// assert_eq!(0, gc.side(NVector::from_lat_long_degrees(0.0, 5.0)));
+    /// ```
+    pub fn side(&self, p: NVector) -> i8 {
+        let side = p.as_vec3().dot_prod(self.normal);
+        if eq_zero(side) {
+            0
+        } else if side < 0.0 {
+            -1
+        } else {
+            1
+        }
+    }
+
+    /// Determines on which side of this great circle - in the direction given by its defining positions or
+    /// heading - the given position is: [Side::On] if the absolute [cross-track
+    /// angle](crate::spherical::Sphere::cross_track_angle) of the position is at most the given tolerance,
+    /// otherwise [Side::Right] if the cross-track angle (and therefore the [cross-track
+    /// distance](crate::spherical::Sphere::cross_track_distance)) is positive and [Side::Left] if it is negative.
+    ///
+    /// This is computed from the normal of this great circle and is therefore not affected by the antimeridian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{GreatCircle, Side};
+    ///
+    /// // heading east across the antimeridian.
+    /// let gc = GreatCircle::new(
+    ///     NVector::from_lat_long_degrees(0.0, 170.0),
+    ///     NVector::from_lat_long_degrees(0.0, -170.0),
+    /// );
+    /// let tolerance = Angle::from_degrees(0.001);
+    ///
+    /// assert_eq!(Side::Left, gc.side_of(NVector::from_lat_long_degrees(1.0, -179.0), tolerance));
+    /// assert_eq!(Side::Right, gc.side_of(NVector::from_lat_long_degrees(-1.0, 179.0), tolerance));
+    /// assert_eq!(Side::On, gc.side_of(NVector::from_lat_long_degrees(0.0005, 180.0), tolerance));
+    /// ```
+    pub fn side_of(&self, p: NVector, tolerance: Angle) -> Side {
+        let xta = Sphere::cross_track_angle(p, *self);
+        if xta.as_radians().abs() <= tolerance.as_radians() {
+            Side::On
+        } else if xta > Angle::ZERO {
+            Side::Right
+        } else {
+            Side::Left
+        }
+    }
+
+    /// Computes the positions where this great circle reaches the given latitude, using Clairaut's relation: a
+    /// great circle whose normal has an angle `α` with the polar axis reaches a maximum latitude of `α`.
+    ///
+    /// Returns:
+    /// - an empty vector if the great circle never reaches the given latitude, or if this great circle is the
+    ///   equator and the given latitude is 0 (an infinity of crossings exist),
+    /// - a single position if the great circle is tangent to the parallel at the given latitude - i.e. the
+    ///   latitude is the maximum (or minimum) latitude of the great circle, or the latitude is ±90° and the
+    ///   great circle passes by the pole,
+    /// - otherwise 2 positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// // heading north-east from null island: maximum latitude is 45 degrees.
+    /// let gc = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(45.0));
+    ///
+    /// let crossings = gc.crossing_latitude(Angle::from_degrees(45.0));
+    /// assert_eq!(1, crossings.len());
+    /// assert_eq!(LatLong::from_degrees(45.0, 90.0), LatLong::from_nvector(crossings[0]).round_d7());
+    ///
+    /// assert_eq!(2, gc.crossing_latitude(Angle::from_degrees(30.0)).len());
+    /// assert!(gc.crossing_latitude(Angle::from_degrees(60.0)).is_empty());
+    /// ```
+    pub fn crossing_latitude(&self, latitude: Angle) -> Vec<NVector> {
+        let n = self.normal.unit();
+        let lat = latitude.as_radians();
+        // n · p = 0 with p = (cos(lat) cos(lon), cos(lat) sin(lon), sin(lat)) gives
+        // r cos(lat) cos(lon - lon_n) = -n_z sin(lat), where r = sqrt(n_x² + n_y²) and lon_n is the longitude
+        // of the normal.
+        let r = n.x().hypot(n.y());
+        let a = r * lat.cos();
+        let b = -n.z() * lat.sin();
+        if a.abs() < Self::CROSSING_TANGENT_MAX_DIFF {
+            // equator and latitude 0 (infinity of crossings), or pole.
+            return if b.abs() < Self::CROSSING_TANGENT_MAX_DIFF
+                && r >= Self::CROSSING_TANGENT_MAX_DIFF
+            {
+                vec![LatLong::new(latitude, Angle::ZERO).to_nvector()]
+            } else {
+                Vec::new()
+            };
+        }
+        let c = b / a;
+        let lon_n = n.y().atan2(n.x());
+        if c.abs() > 1.0 + Self::CROSSING_TANGENT_MAX_DIFF {
+            Vec::new()
+        } else if (c.abs() - 1.0).abs() <= Self::CROSSING_TANGENT_MAX_DIFF {
+            let lon = if c > 0.0 { lon_n } else { lon_n + PI };
+            vec![LatLong::new(latitude, Angle::from_radians(lon)).to_nvector()]
+        } else {
+            let d = c.acos();
+            [lon_n - d, lon_n + d]
+                .iter()
+                .map(|lon| LatLong::new(latitude, Angle::from_radians(*lon)).to_nvector())
+                .collect()
+        }
+    }
+
+    /// Computes the maximum latitude reached by this great circle - i.e. the latitude of its northernmost
+    /// vertex: by Clairaut's relation, `cos(latitude) * sin(bearing)` is constant along a great circle, and
+    /// the bearing at the vertex is ±90 degrees. The returned angle is within [0, 90] degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// let gc = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(45.0));
+    /// assert_eq!(Angle::from_degrees(45.0), gc.max_latitude().round_d7());
+    /// ```
+    pub fn max_latitude(&self) -> Angle {
+        let r = self.normal.x().hypot(self.normal.y());
+        Angle::from_radians(r.atan2(self.normal.z().abs()))
+    }
+
+    /// Computes the minimum latitude reached by this great circle - i.e. the latitude of its southernmost
+    /// vertex, which is the antipode of its northernmost vertex: this is always the opposite of the
+    /// [maximum latitude](crate::spherical::GreatCircle::max_latitude).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// let gc = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(45.0));
+    /// assert_eq!(Angle::from_degrees(-45.0), gc.min_latitude().round_d7());
+    /// ```
+    pub fn min_latitude(&self) -> Angle {
+        -self.max_latitude()
+    }
+
+    /// Computes the positions where this great circle crosses the meridian at the given longitude: a great
+    /// circle which is not itself a meridian crosses every meridian exactly once.
+    ///
+    /// Returns:
+    /// - an empty vector if this great circle contains the meridian at the given longitude (an infinity of
+    ///   crossings exist),
+    /// - both poles if this great circle is another meridian (the poles lie on every meridian),
+    /// - otherwise the single crossing position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::GreatCircle;
+    ///
+    /// // heading north-east from null island.
+    /// let gc = GreatCircle::from_heading(NVector::from_lat_long_degrees(0.0, 0.0), Angle::from_degrees(45.0));
+    ///
+    /// let crossings = gc.crossing_longitude(Angle::from_degrees(90.0));
+    /// assert_eq!(1, crossings.len());
+    /// assert_eq!(LatLong::from_degrees(45.0, 90.0), LatLong::from_nvector(crossings[0]).round_d7());
+    /// ```
+    pub fn crossing_longitude(&self, longitude: Angle) -> Vec<NVector> {
+        let lon = longitude.as_radians();
+        let (sin_lon, cos_lon) = lon.sin_cos();
+        let meridian = GreatCircle {
+            normal: Vec3::new(-sin_lon, cos_lon, 0.0),
+        };
+        match self.intersections(meridian) {
+            None => Vec::new(),
+            Some((i1, i2)) => {
+                // keep the intersection(s) on the half of the meridian circle at the given longitude.
+                let dir = Vec3::new(cos_lon, sin_lon, 0.0);
+                [i1, i2]
+                    .into_iter()
+                    .filter(|i| i.as_vec3().dot_prod(dir) >= -Self::CROSSING_TANGENT_MAX_DIFF)
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Returns the eigenvector associated with the smallest eigenvalue of the given symmetric matrix using
+/// the cyclic Jacobi eigenvalue algorithm.
+pub(crate) fn smallest_eigenvector(m: [[f64; 3]; 3]) -> Vec3 {
+    let mut a = m;
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..50 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        if off < 1e-30 {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+            // rotation annihilating a[p][q].
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (rp, rq) = (a[p], a[q]);
+            for (k, (apk, aqk)) in rp.iter().zip(rq.iter()).enumerate() {
+                a[p][k] = c * apk - s * aqk;
+                a[q][k] = s * apk + c * aqk;
+            }
+            for row in v.iter_mut() {
+                let (vkp, vkq) = (row[p], row[q]);
+                row[p] = c * vkp - s * vkq;
+                row[q] = s * vkp + c * vkq;
+            }
+        }
+    }
+    let mut i = 0;
+    for k in 1..3 {
+        if a[k][k] < a[i][i] {
+            i = k;
+        }
+    }
+    Vec3::new(v[0][i], v[1][i], v[2][i])
 }
 
 #[cfg(test)]
 mod tests {
 
-    use crate::{positions::assert_opt_nv_eq_d7, spherical::GreatCircle, Angle, NVector, Vec3};
+    use std::f64::consts::PI;
+
+    use crate::{
+        positions::assert_opt_nv_eq_d7,
+        spherical::{GreatCircle, Side, Sphere},
+        Angle, LatLong, Length, NVector, NavigationError, Vec3,
+    };
+
+    use super::smallest_eigenvector;
+
+    // checked_new
+
+    #[test]
+    fn checked_new() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        assert_eq!(
+            Ok(GreatCircle::new(p1, p2)),
+            GreatCircle::checked_new(p1, p2)
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            GreatCircle::checked_new(p1, p1)
+        );
+        assert_eq!(
+            Err(NavigationError::AntipodalPositions),
+            GreatCircle::checked_new(p1, p1.antipode())
+        );
+    }
+
+    // best_fit
+
+    fn sum_squared_xtd(ps: &[NVector], gc: GreatCircle) -> f64 {
+        ps.iter()
+            .map(|p| {
+                Sphere::EARTH
+                    .cross_track_distance(*p, gc)
+                    .as_metres()
+                    .powi(2)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn best_fit_not_enough_positions() {
+        assert!(GreatCircle::best_fit(&[]).is_none());
+        assert!(GreatCircle::best_fit(&[NVector::from_lat_long_degrees(1.0, 2.0)]).is_none());
+    }
+
+    #[test]
+    fn best_fit_undefined() {
+        let p = NVector::from_lat_long_degrees(1.0, 2.0);
+        assert!(GreatCircle::best_fit(&[p, p, p]).is_none());
+        assert!(GreatCircle::best_fit(&[p, p.antipode()]).is_none());
+    }
+
+    #[test]
+    fn best_fit_two_positions() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        let expected = GreatCircle::new(p1, p2).normal();
+        let actual = GreatCircle::best_fit(&[p1, p2]).unwrap().normal();
+        assert!((expected - actual).norm() < 1e-12);
+    }
+
+    #[test]
+    fn best_fit_exact() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        let ps: Vec<NVector> = (0..10)
+            .map(|i| Sphere::position_on_great_circle(p1, p2, Angle::from_degrees(i as f64)))
+            .collect();
+        let gc = GreatCircle::best_fit(&ps).unwrap();
+        assert!((GreatCircle::new(p1, p2).normal() - gc.normal()).norm() < 1e-12);
+        assert!(sum_squared_xtd(&ps, gc) < 1e-12);
+    }
+
+    #[test]
+    fn best_fit_direction() {
+        let ps = vec![
+            NVector::from_lat_long_degrees(0.0, 20.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 0.0),
+        ];
+        let gc = GreatCircle::best_fit(&ps).unwrap();
+        assert!((gc.normal() - Vec3::NEG_UNIT_Z).norm() < 1e-15);
+    }
+
+    #[test]
+    fn best_fit_noisy() {
+        // alternating north/south offsets of 0.01 degree along the equator.
+        let ps: Vec<NVector> = (0..11)
+            .map(|i| {
+                let lat = if i % 2 == 0 { 0.01 } else { -0.01 };
+                NVector::from_lat_long_degrees(lat, i as f64)
+            })
+            .collect();
+        let gc = GreatCircle::best_fit(&ps).unwrap();
+        assert!((gc.normal() - Vec3::UNIT_Z).norm() < 1e-3);
+        let residual = sum_squared_xtd(&ps, gc);
+        assert!(residual > 0.0);
+        // each position is ~1.1 km from the equator.
+        let max_xtd = Length::from_kilometres(1.2).as_metres();
+        assert!(residual < ps.len() as f64 * max_xtd * max_xtd);
+        // the fit is at least as good as the equator.
+        let equator = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(90.0),
+        );
+        assert!(residual <= sum_squared_xtd(&ps, equator));
+    }
+
+    // smallest_eigenvector
+
+    #[test]
+    fn smallest_eigenvector_diagonal() {
+        let m = [[3.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 2.0]];
+        assert_eq!(Vec3::UNIT_Y, smallest_eigenvector(m));
+    }
+
+    // intersections
+
+    #[test]
+    fn intersections_equal_or_opposite() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        let gc = GreatCircle::new(p1, p2);
+        assert!(gc.intersections(gc).is_none());
+        assert!(gc.intersections(GreatCircle::new(p2, p1)).is_none());
+    }
+
+    #[test]
+    fn intersections_effectively_equal() {
+        let p = NVector::from_lat_long_degrees(0.0, 0.0);
+        let equator = GreatCircle::from_heading(p, Angle::from_degrees(90.0));
+        let almost = GreatCircle::from_heading(p, Angle::from_radians(PI / 2.0 + 1e-14));
+        assert!(equator.intersections(almost).is_none());
+        let almost_opposite = GreatCircle::from_heading(p, Angle::from_radians(-PI / 2.0 + 1e-14));
+        assert!(equator.intersections(almost_opposite).is_none());
+    }
+
+    #[test]
+    fn intersections_equator_meridian() {
+        let equator = GreatCircle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        let meridian = GreatCircle::new(
+            NVector::from_lat_long_degrees(10.0, 25.0),
+            NVector::from_lat_long_degrees(20.0, 25.0),
+        );
+        let (i1, i2) = equator.intersections(meridian).unwrap();
+        assert_eq!(
+            LatLong::from_degrees(0.0, 25.0),
+            LatLong::from_nvector(i1).round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, -155.0),
+            LatLong::from_nvector(i2).round_d7()
+        );
+    }
+
+    #[test]
+    fn intersections_nearly_parallel() {
+        // great circles crossing at a very small angle (about 0.2 arcseconds).
+        let p = NVector::from_lat_long_degrees(0.0, 40.0);
+        let equator = GreatCircle::from_heading(p, Angle::from_degrees(90.0));
+        let almost = GreatCircle::from_heading(p, Angle::from_degrees(90.0 + 5e-5));
+        let (i1, i2) = equator.intersections(almost).unwrap();
+        assert_eq!(
+            LatLong::from_degrees(0.0, 40.0),
+            LatLong::from_nvector(i2).round_d7()
+        );
+        assert_eq!(i2.antipode(), i1);
+    }
+
+    #[test]
+    fn intersections() {
+        let x = NVector::from_lat_long_degrees(20.0, 30.0);
+        let gc1 = GreatCircle::new(x, NVector::from_lat_long_degrees(51.885, 0.235));
+        let gc2 = GreatCircle::from_heading(x, Angle::from_degrees(32.44));
+        let (i1, i2) = gc1.intersections(gc2).unwrap();
+        // gc1 and gc2 both pass by x: x is the second intersection given the orientation of the normals.
+        assert_opt_nv_eq_d7(x, Some(i2));
+        assert_eq!(i1.antipode(), i2);
+        // both intersections lie on both great circles.
+        for i in [i1, i2] {
+            assert!(gc1.normal().dot_prod(i.as_vec3()).abs() < 1e-15);
+            assert!(gc2.normal().dot_prod(i.as_vec3()).abs() < 1e-15);
+        }
+        // swapping great circles swaps intersections.
+        assert_eq!(Some((i2, i1)), gc2.intersections(gc1));
+    }
+
+    // crossing_latitude
+
+    #[test]
+    fn crossing_latitude_twice() {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(51.885, 0.235),
+            NVector::from_lat_long_degrees(48.269, 13.093),
+        );
+        let crossings = gc.crossing_latitude(Angle::from_degrees(50.0));
+        assert_eq!(2, crossings.len());
+        assert_ne!(
+            LatLong::from_nvector(crossings[0]).round_d7(),
+            LatLong::from_nvector(crossings[1]).round_d7()
+        );
+        for c in crossings {
+            assert!(gc.normal().dot_prod(c.as_vec3()).abs() < 1e-15);
+            assert_eq!(
+                Angle::from_degrees(50.0),
+                LatLong::from_nvector(c).latitude().round_d7()
+            );
+        }
+    }
+
+    #[test]
+    fn crossing_latitude_southern_hemisphere() {
+        // heading north-east from null island: minimum latitude is -45 degrees.
+        let gc = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(45.0),
+        );
+        let mut crossings: Vec<LatLong> = gc
+            .crossing_latitude(Angle::from_degrees(-30.0))
+            .iter()
+            .map(|c| LatLong::from_nvector(*c).round_d7())
+            .collect();
+        crossings.sort_by(|a, b| a.longitude().partial_cmp(&b.longitude()).unwrap());
+        // sin(lon) = tan(-30) / tan(45).
+        let lon = (-30.0f64).to_radians().tan().asin().to_degrees();
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(-30.0, -180.0 - lon).round_d7(),
+                LatLong::from_degrees(-30.0, lon).round_d7()
+            ],
+            crossings
+        );
+    }
+
+    #[test]
+    fn crossing_latitude_tangent() {
+        let gc = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(60.0, 20.0),
+            Angle::from_degrees(90.0),
+        );
+        assert_eq!(
+            vec![LatLong::from_degrees(60.0, 20.0)],
+            gc.crossing_latitude(Angle::from_degrees(60.0))
+                .iter()
+                .map(|c| LatLong::from_nvector(*c).round_d7())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![LatLong::from_degrees(-60.0, -160.0)],
+            gc.crossing_latitude(Angle::from_degrees(-60.0))
+                .iter()
+                .map(|c| LatLong::from_nvector(*c).round_d7())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn crossing_latitude_never_reached() {
+        let gc = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(60.0, 20.0),
+            Angle::from_degrees(90.0),
+        );
+        assert!(gc.crossing_latitude(Angle::from_degrees(60.001)).is_empty());
+        assert!(gc.crossing_latitude(Angle::from_degrees(-75.0)).is_empty());
+        assert!(gc.crossing_latitude(Angle::from_degrees(90.0)).is_empty());
+    }
+
+    #[test]
+    fn crossing_latitude_pole() {
+        let meridian =
+            GreatCircle::from_heading(NVector::from_lat_long_degrees(10.0, 20.0), Angle::ZERO);
+        assert_eq!(
+            vec![NVector::new(Vec3::UNIT_Z)],
+            meridian.crossing_latitude(Angle::from_degrees(90.0))
+        );
+        assert_eq!(
+            vec![NVector::new(Vec3::NEG_UNIT_Z)],
+            meridian.crossing_latitude(Angle::from_degrees(-90.0))
+        );
+    }
+
+    #[test]
+    fn crossing_latitude_equator() {
+        let equator = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(90.0),
+        );
+        assert!(equator.crossing_latitude(Angle::ZERO).is_empty());
+        assert!(equator
+            .crossing_latitude(Angle::from_degrees(1.0))
+            .is_empty());
+    }
+
+    // max_latitude
+
+    #[test]
+    fn max_latitude_equator() {
+        let equator = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Angle::from_degrees(90.0),
+        );
+        assert_eq!(Angle::ZERO, equator.max_latitude().round_d7());
+        assert_eq!(Angle::ZERO, equator.min_latitude().round_d7());
+    }
+
+    #[test]
+    fn max_latitude_meridian() {
+        let meridian = GreatCircle::new(
+            NVector::from_lat_long_degrees(10.0, 20.0),
+            NVector::from_lat_long_degrees(50.0, 20.0),
+        );
+        assert_eq!(
+            Angle::from_degrees(90.0),
+            meridian.max_latitude().round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(-90.0),
+            meridian.min_latitude().round_d7()
+        );
+    }
+
+    #[test]
+    fn max_latitude() {
+        // departing 40N on a bearing of 60 degrees: cos(max) = cos(40) * sin(60).
+        let gc = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(40.0, -74.0),
+            Angle::from_degrees(60.0),
+        );
+        let expected = (40.0f64.to_radians().cos() * 60.0f64.to_radians().sin())
+            .acos()
+            .to_degrees();
+        assert_eq!(
+            Angle::from_degrees(expected).round_d7(),
+            gc.max_latitude().round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(-expected).round_d7(),
+            gc.min_latitude().round_d7()
+        );
+        // the vertex is the single crossing of the maximum latitude.
+        assert_eq!(1, gc.crossing_latitude(gc.max_latitude()).len());
+        // same great circle, opposite direction.
+        let opposite = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(40.0, -74.0),
+            Angle::from_degrees(240.0),
+        );
+        assert_eq!(
+            gc.max_latitude().round_d7(),
+            opposite.max_latitude().round_d7()
+        );
+    }
+
+    // crossing_longitude
+
+    #[test]
+    fn crossing_longitude() {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(51.885, 0.235),
+            NVector::from_lat_long_degrees(48.269, 13.093),
+        );
+        for lon in [-170.0, -90.0, 0.0, 5.0, 90.0, 180.0] {
+            let crossings = gc.crossing_longitude(Angle::from_degrees(lon));
+            assert_eq!(1, crossings.len());
+            let c = crossings[0];
+            assert!(gc.normal().dot_prod(c.as_vec3()).abs() < 1e-15);
+            assert_eq!(
+                Angle::from_degrees(lon).round_d7(),
+                LatLong::from_nvector(c).longitude().round_d7()
+            );
+        }
+    }
+
+    #[test]
+    fn crossing_longitude_meridians() {
+        let meridian =
+            GreatCircle::from_heading(NVector::from_lat_long_degrees(10.0, 20.0), Angle::ZERO);
+        assert!(meridian
+            .crossing_longitude(Angle::from_degrees(20.0))
+            .is_empty());
+        assert!(meridian
+            .crossing_longitude(Angle::from_degrees(-160.0))
+            .is_empty());
+        let crossings = meridian.crossing_longitude(Angle::from_degrees(50.0));
+        assert_eq!(2, crossings.len());
+        assert!(crossings.contains(&NVector::new(Vec3::UNIT_Z)));
+        assert!(crossings.contains(&NVector::new(Vec3::NEG_UNIT_Z)));
+    }
 
     // projection
 
@@ -92,6 +955,203 @@ mod tests {
         );
     }
 
+    #[test]
+    fn projection_minimises_distance() {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(53.3206, -1.7297),
+            NVector::from_lat_long_degrees(53.1887, 0.1334),
+        );
+        let start = NVector::from_lat_long_degrees(53.3206, -1.7297);
+        let end = NVector::from_lat_long_degrees(53.1887, 0.1334);
+        for p in [
+            NVector::from_lat_long_degrees(53.2611, -0.7972),
+            NVector::from_lat_long_degrees(-20.0, 120.0),
+            NVector::from_lat_long_degrees(80.0, 45.0),
+        ] {
+            let proj = gc.projection(p).unwrap();
+            // on the great circle.
+            assert!(Sphere::EARTH.cross_track_distance(proj, gc).abs() < Length::from_metres(1e-6));
+            // closer than any position of a brute-force sweep of the great circle.
+            let d = Sphere::EARTH.distance(p, proj);
+            for i in 0..3600 {
+                let a = Angle::from_degrees(i as f64 / 10.0);
+                let c = Sphere::position_on_great_circle(start, end, a);
+                assert!(d <= Sphere::EARTH.distance(p, c) + Length::from_metres(1e-6));
+            }
+        }
+    }
+
+    #[test]
+    fn projection_pole() {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(0.0, -10.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        // the north pole is a pole of the equator: any position on the equator is a projection.
+        let proj = gc
+            .projection(NVector::from_lat_long_degrees(90.0, 0.0))
+            .unwrap();
+        assert_eq!(
+            Angle::ZERO,
+            LatLong::from_nvector(proj).latitude().round_d7()
+        );
+    }
+
+    // distance_to_point
+
+    #[test]
+    fn distance_to_point() {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(53.3206, -1.7297),
+            NVector::from_lat_long_degrees(53.1887, 0.1334),
+        );
+        let p = NVector::from_lat_long_degrees(53.2611, -0.7972);
+        assert_eq!(
+            Length::from_metres(-307.55),
+            gc.distance_to_point(p, Sphere::EARTH.radius()).round_mm()
+        );
+        assert_eq!(
+            Sphere::MOON.cross_track_distance(p, gc),
+            gc.distance_to_point(p, Sphere::MOON.radius())
+        );
+        // on the great circle.
+        assert_eq!(
+            Length::ZERO,
+            gc.distance_to_point(gc.projection(p).unwrap(), Sphere::EARTH.radius())
+                .round_mm()
+        );
+    }
+
+    // side
+
+    #[test]
+    fn side_reused_great_circle() {
+        let p1 = NVector::from_lat_long_degrees(55.4295, 13.82);
+        let p2 = NVector::from_lat_long_degrees(56.0465, 12.6945);
+        let gc = GreatCircle::checked_new(p1, p2).unwrap();
+        // the same great circle answers many queries consistently with the functions taking 2 positions.
+        for lat in (-89..90).step_by(7) {
+            for lng in (-180..180).step_by(11) {
+                let p = NVector::from_lat_long_degrees(lat as f64, lng as f64);
+                assert_eq!(Sphere::side(p, p1, p2), gc.side(p));
+                let xtd = Sphere::EARTH.cross_track_distance(p, gc);
+                assert_eq!(xtd.as_metres() < 0.0, gc.side(p) > 0, "{:?}: {:?}", p, xtd);
+            }
+        }
+    }
+
+    // side_of
+
+    #[test]
+    fn side_is_side_of_without_tolerance() {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(0.0, 170.0),
+            NVector::from_lat_long_degrees(10.0, -170.0),
+        );
+        let tolerance = Angle::from_radians(f64::EPSILON);
+        let to_side = |s: i8| match s {
+            1 => Side::Left,
+            -1 => Side::Right,
+            _ => Side::On,
+        };
+        for lat in (-80..=80).step_by(20) {
+            for lng in (-180..180).step_by(15) {
+                let p = NVector::from_lat_long_degrees(lat as f64, lng as f64);
+                assert_eq!(gc.side_of(p, tolerance), to_side(gc.side(p)));
+            }
+        }
+        let on = NVector::from_lat_long_degrees(0.0, 170.0);
+        assert_eq!(Side::On, to_side(gc.side(on)));
+        assert_eq!(Side::On, gc.side_of(on, tolerance));
+    }
+
+    fn assert_side_of(gc: GreatCircle, lat: f64, lng: f64, expected: Side) {
+        let p = NVector::from_lat_long_degrees(lat, lng);
+        let tolerance = Angle::from_degrees(1e-6);
+        assert_eq!(expected, gc.side_of(p, tolerance), "({}, {})", lat, lng);
+        let xtd = Sphere::EARTH.cross_track_distance(p, gc);
+        match expected {
+            Side::Left => assert!(xtd < Length::ZERO),
+            Side::Right => assert!(xtd > Length::ZERO),
+            Side::On => assert!(xtd.abs() < Length::from_metres(1.0)),
+        }
+    }
+
+    #[test]
+    fn side_of_northern_hemisphere() {
+        // heading north-east.
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            NVector::from_lat_long_degrees(50.0, 20.0),
+        );
+        assert_side_of(gc, 50.0, 10.0, Side::Left);
+        assert_side_of(gc, 45.0, 20.0, Side::Right);
+        assert_side_of(gc, 45.0, 10.0, Side::On);
+        assert_side_of(gc, 50.0, 20.0, Side::On);
+    }
+
+    #[test]
+    fn side_of_southern_hemisphere() {
+        // heading south-west.
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(-30.0, 150.0),
+            NVector::from_lat_long_degrees(-40.0, 140.0),
+        );
+        assert_side_of(gc, -40.0, 150.0, Side::Left);
+        assert_side_of(gc, -30.0, 140.0, Side::Right);
+        assert_side_of(gc, -30.0, 150.0, Side::On);
+    }
+
+    #[test]
+    fn side_of_antimeridian() {
+        // heading east across the antimeridian: the longitude difference changes sign.
+        let east = GreatCircle::new(
+            NVector::from_lat_long_degrees(10.0, 170.0),
+            NVector::from_lat_long_degrees(-10.0, -170.0),
+        );
+        assert_side_of(east, 5.0, 179.0, Side::Left);
+        assert_side_of(east, 1.0, -179.0, Side::Left);
+        assert_side_of(east, -1.0, 179.0, Side::Right);
+        assert_side_of(east, -5.0, -179.0, Side::Right);
+        assert_side_of(east, 0.0, 180.0, Side::On);
+
+        // heading west across the antimeridian.
+        let west = GreatCircle::new(
+            NVector::from_lat_long_degrees(-10.0, -170.0),
+            NVector::from_lat_long_degrees(10.0, 170.0),
+        );
+        assert_side_of(west, 5.0, 179.0, Side::Right);
+        assert_side_of(west, -1.0, 179.0, Side::Left);
+        assert_side_of(west, 0.0, -180.0, Side::On);
+    }
+
+    #[test]
+    fn side_of_tolerance() {
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        let p = NVector::from_lat_long_degrees(0.01, 5.0);
+        assert_eq!(Side::Left, gc.side_of(p, Angle::from_degrees(0.005)));
+        assert_eq!(Side::On, gc.side_of(p, Angle::from_degrees(0.02)));
+        let p = NVector::from_lat_long_degrees(-0.01, 5.0);
+        assert_eq!(Side::Right, gc.side_of(p, Angle::ZERO));
+        assert_eq!(
+            Side::On,
+            gc.side_of(NVector::from_lat_long_degrees(0.0, 5.0), Angle::ZERO)
+        );
+    }
+
+    #[test]
+    fn side_on_great_circle() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        let gc = GreatCircle::new(p1, p2);
+        assert_eq!(0, gc.side(p1));
+        assert_eq!(0, gc.side(p2));
+        assert_eq!(0, gc.side(Sphere::mean_position(&[p1, p2]).unwrap()));
+    }
+
     #[test]
     fn from_heading() {
         let null_island = NVector::from_lat_long_degrees(0.0, 0.0);
@@ -102,4 +1162,14 @@ mod tests {
         let n = d.norm();
         assert!(n.abs() < 1e-15);
     }
+
+    #[test]
+    fn from_heading_and_new_equal() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        let bearing = Sphere::initial_bearing(p1, p2);
+        let a = GreatCircle::from_heading(p1, bearing);
+        let e = GreatCircle::new(p1, p2);
+        assert!((e.normal() - a.normal()).norm() < 1e-15);
+    }
 }
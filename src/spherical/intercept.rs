@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::{Angle, NVector, Speed};
+
+/// The interception of a vehicle: see [Sphere::intercept](crate::spherical::Sphere::intercept) and
+/// [Sphere::intercept_at_time](crate::spherical::Sphere::intercept_at_time).
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Intercept {
+    time: Duration,
+    position: NVector,
+    bearing: Angle,
+    speed: Speed,
+}
+
+impl Intercept {
+    pub(crate) fn new(time: Duration, position: NVector, bearing: Angle, speed: Speed) -> Self {
+        Intercept {
+            time,
+            position,
+            bearing,
+            speed,
+        }
+    }
+
+    /// Returns the time from now to the interception.
+    #[inline]
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    /// Returns the position of the interception: the position of the intercepted vehicle at the
+    /// [time](crate::spherical::Intercept::time) of the interception.
+    #[inline]
+    pub fn position(&self) -> NVector {
+        self.position
+    }
+
+    /// Returns the initial bearing the interceptor must follow - along a great circle - to reach the
+    /// [position](crate::spherical::Intercept::position) of the interception, in compass angle: 0 if the
+    /// interceptor is already at the position of the interception.
+    #[inline]
+    pub fn bearing(&self) -> Angle {
+        self.bearing
+    }
+
+    /// Returns the speed the interceptor must maintain to reach the position of the interception at the time of
+    /// the interception.
+    #[inline]
+    pub fn speed(&self) -> Speed {
+        self.speed
+    }
+}
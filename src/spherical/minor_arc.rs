@@ -1,9 +1,14 @@
+use std::f64::consts::PI;
+
 use crate::{
     numbers::{eq_zero, gte},
-    Angle, NVector, Vec3,
+    Angle, Length, NVector, NavigationError, Vec3,
 };
 
-use super::base::{angle_radians_between, exact_side};
+use super::{
+    base::{angle_radians_between, exact_side},
+    Sphere,
+};
 
 /// Oriented minor arc of a great circle between two positions: shortest path between positions
 /// on a great circle.
@@ -15,6 +20,9 @@ pub struct MinorArc {
 }
 
 impl MinorArc {
+    /// Sine of the largest angle between 2 minor arcs for them to be considered collinear.
+    const COLLINEAR_MAX_SIN: f64 = 1e-12;
+
     /// Creates a new minor arc from the given start and end positions.
     ///
     /// Note: if both start and end positions are equal or the antipode of one another, then an
@@ -24,6 +32,35 @@ impl MinorArc {
         MinorArc { start, end, normal }
     }
 
+    /// Creates a new minor arc from the given start and end positions, if both positions define a unique
+    /// minor arc - see [is_great_circle](crate::spherical::Sphere::is_great_circle).
+    ///
+    /// Returns [NavigationError::DegenerateGeometry] if both positions are equal, and
+    /// [NavigationError::AntipodalPositions] if both positions are the antipode of one another - see also
+    /// [GreatCircle::checked_new](crate::spherical::GreatCircle::checked_new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{NavigationError, NVector};
+    /// use jord::spherical::MinorArc;
+    ///
+    /// let p = NVector::from_lat_long_degrees(10.0, 20.0);
+    ///
+    /// assert!(MinorArc::checked_new(p, NVector::from_lat_long_degrees(20.0, 20.0)).is_ok());
+    /// assert_eq!(Err(NavigationError::DegenerateGeometry), MinorArc::checked_new(p, p));
+    /// assert_eq!(Err(NavigationError::AntipodalPositions), MinorArc::checked_new(p, p.antipode()));
+    /// ```
+    pub fn checked_new(start: NVector, end: NVector) -> Result<Self, NavigationError> {
+        if Sphere::is_great_circle(start, end) {
+            Ok(Self::new(start, end))
+        } else if start == end {
+            Err(NavigationError::DegenerateGeometry)
+        } else {
+            Err(NavigationError::AntipodalPositions)
+        }
+    }
+
     /// Returns the start position of this minor arc.
     #[inline]
     pub fn start(&self) -> NVector {
@@ -42,8 +79,88 @@ impl MinorArc {
         self.normal
     }
 
+    /// Computes the angle subtended by this minor arc, which is also its length on the unit sphere: within
+    /// [0, 180] degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::MinorArc;
+    ///
+    /// let ma = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0)
+    /// );
+    /// assert_eq!(Angle::from_degrees(10.0), ma.length_angle().round_d7());
+    /// ```
+    pub fn length_angle(&self) -> Angle {
+        Sphere::angle(self.start, self.end)
+    }
+
+    /// Computes the length of this minor arc on a sphere of the given radius - see also
+    /// [Sphere::distance](crate::spherical::Sphere::distance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{MinorArc, Sphere};
+    ///
+    /// let ma = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0)
+    /// );
+    /// assert_eq!(Length::from_metres(111_194.94), ma.length(Sphere::EARTH.radius()).round_cm());
+    /// ```
+    pub fn length(&self, radius: Length) -> Length {
+        self.length_angle().arc_length(radius)
+    }
+
+    /// Computes the position at the given fraction of this minor arc: the start position if the fraction is 0,
+    /// the end position if the fraction is 1. Returns [None] if the given fraction is `< 0` or `> 1` - see also
+    /// [Sphere::interpolated_pos](crate::spherical::Sphere::interpolated_pos).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::MinorArc;
+    ///
+    /// let ma = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0)
+    /// );
+    /// assert_eq!(
+    ///     LatLong::from_degrees(0.0, 2.5),
+    ///     LatLong::from_nvector(ma.interpolate(0.25).unwrap()).round_d7()
+    /// );
+    /// assert!(ma.interpolate(1.5).is_none());
+    /// ```
+    pub fn interpolate(&self, f: f64) -> Option<NVector> {
+        if !(0.0..=1.0).contains(&f) {
+            None
+        } else if f == 0.0 {
+            Some(self.start)
+        } else if f == 1.0 {
+            Some(self.end)
+        } else {
+            let a = f * self.length_angle().as_radians();
+            // direction from start to end.
+            let dir = self.normal.cross_prod_unit(self.start.as_vec3());
+            let v = (self.start.as_vec3() * a.cos() + dir * a.sin()).unit();
+            Some(NVector::new(v))
+        }
+    }
+
     /// Computes the intersection point between this minor arc and the given minor arc, if there is an
-    /// intersection.
+    /// intersection: i.e. the point common to both minor arcs, start and end positions included (within floating
+    /// point precision).
+    ///
+    /// Minor arcs on the same great circle (collinear) - or effectively so: the sine of the angle between both
+    /// great circles is less than 1e-12 - intersect only if they share exactly one position: their common start
+    /// or end position is then returned. Collinear minor arcs overlapping along a portion of the great circle
+    /// have no unique intersection, and [None] is returned.
     ///
     /// # Examples
     ///
@@ -63,10 +180,46 @@ impl MinorArc {
     /// assert_eq!(i, Some(LatLong::from_degrees(0.0, 0.0).to_nvector()));
     /// ```
     pub fn intersection(&self, other: MinorArc) -> Option<NVector> {
+        self.checked_intersection(other).unwrap_or(None)
+    }
+
+    /// Computes the intersection point between this minor arc and the given minor arc, if there is an
+    /// intersection: see [intersection](crate::spherical::MinorArc::intersection).
+    ///
+    /// Returns [DegenerateGeometry](crate::NavigationError::DegenerateGeometry) if both minor arcs are
+    /// (effectively) on the same great circle and overlap along a portion of it (an infinity of intersections
+    /// exist), rather than [None].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{NavigationError, NVector};
+    /// use jord::spherical::MinorArc;
+    ///
+    /// let ma1 = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0)
+    /// );
+    /// let ma2 = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 20.0)
+    /// );
+    /// let ma3 = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(0.0, 5.0),
+    ///     NVector::from_lat_long_degrees(0.0, 20.0)
+    /// );
+    /// assert_eq!(Ok(Some(NVector::from_lat_long_degrees(0.0, 10.0))), ma1.checked_intersection(ma2));
+    /// assert_eq!(Err(NavigationError::DegenerateGeometry), ma1.checked_intersection(ma3));
+    /// ```
+    pub fn checked_intersection(
+        &self,
+        other: MinorArc,
+    ) -> Result<Option<NVector>, NavigationError> {
         let i = self.normal.stable_cross_prod_unit(other.normal);
-        if i == Vec3::ZERO {
-            // equal or opposite minor arcs: no intersection
-            None
+        if i == Vec3::ZERO || self.normal.cross_prod(other.normal).norm() < Self::COLLINEAR_MAX_SIN
+        {
+            // (effectively) collinear minor arcs.
+            self.collinear_intersection(other)
         } else {
             // select nearest intersection to start of first minor arc.
             let potential = if self.start.as_vec3().dot_prod(i) > 0.0 {
@@ -77,9 +230,9 @@ impl MinorArc {
             };
 
             if self.contains_vec3(potential) && other.contains_vec3(potential) {
-                Some(NVector::new(potential))
+                Ok(Some(NVector::new(potential)))
             } else {
-                None
+                Ok(None)
             }
         }
     }
@@ -118,6 +271,44 @@ impl MinorArc {
         }
     }
 
+    /// Computes the position on this minor arc which is the closest to the given position: the
+    /// [projection](crate::spherical::MinorArc::projection) of the given position if it is within this minor
+    /// arc, the nearest of the start and end positions otherwise - e.g. to snap a position to a leg of a route.
+    /// If the given position is strictly "perpendicular" to this minor arc (every position of the minor arc is
+    /// equidistant), this method arbitrarily returns the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    /// use jord::spherical::MinorArc;
+    ///
+    /// let ma = MinorArc::new(
+    ///     LatLong::from_degrees(0.0, -10.0).to_nvector(),
+    ///     LatLong::from_degrees(0.0, 10.0).to_nvector()
+    /// );
+    ///
+    /// let c = ma.closest_point(LatLong::from_degrees(1.0, 0.0).to_nvector());
+    /// assert_eq!(LatLong::from_degrees(0.0, 0.0), LatLong::from_nvector(c).round_d7());
+    ///
+    /// let c = ma.closest_point(LatLong::from_degrees(1.0, 15.0).to_nvector());
+    /// assert_eq!(LatLong::from_degrees(0.0, 10.0), LatLong::from_nvector(c).round_d7());
+    /// ```
+    pub fn closest_point(&self, p: NVector) -> NVector {
+        match self.projection(p) {
+            Some(proj) => proj,
+            None => {
+                let v = p.as_vec3();
+                // the nearest position has the greatest dot product.
+                if v.dot_prod(self.start.as_vec3()) >= v.dot_prod(self.end.as_vec3()) {
+                    self.start
+                } else {
+                    self.end
+                }
+            }
+        }
+    }
+
     /// Determines whether this minor arc contains the given point.
     ///
     /// ```
@@ -139,6 +330,46 @@ impl MinorArc {
         eq_zero(v.dot_prod(self.normal)) && self.contains_vec3(v)
     }
 
+    /// Determines whether the given point is on this minor arc within the given tolerance: i.e. whether the point
+    /// is within the given angle of an endpoint of this minor arc, or its cross-track angle is within the given
+    /// angle and its along-track angle is within [0, length of the minor arc]. If the start and end positions of
+    /// this minor arc are equal, this reduces to testing whether the point is within the given angle of the start.
+    ///
+    /// See also [Sphere::cross_track_angle](crate::spherical::Sphere::cross_track_angle) and
+    /// [Sphere::along_track_angle](crate::spherical::Sphere::along_track_angle).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::MinorArc;
+    ///
+    /// let ma = MinorArc::new(
+    ///     NVector::from_lat_long_degrees(0.0, -10.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0)
+    /// );
+    /// let tolerance = Angle::from_degrees(0.01);
+    ///
+    /// assert!(ma.contains_point_within(NVector::from_lat_long_degrees(0.005, 5.0), tolerance));
+    /// assert!(!ma.contains_point_within(NVector::from_lat_long_degrees(0.02, 5.0), tolerance));
+    /// assert!(!ma.contains_point_within(NVector::from_lat_long_degrees(0.0, 11.0), tolerance));
+    /// ```
+    pub fn contains_point_within(&self, p: NVector, tolerance: Angle) -> bool {
+        if Sphere::angle(self.start, p) <= tolerance || Sphere::angle(self.end, p) <= tolerance {
+            return true;
+        }
+        if self.start == self.end {
+            return false;
+        }
+        let v = p.as_vec3();
+        let cross_track = angle_radians_between(self.normal, v, None) - PI / 2.0;
+        if cross_track.abs() > tolerance.as_radians() {
+            return false;
+        }
+        let along_track = Sphere::along_track_angle(p, *self);
+        along_track >= Angle::ZERO && along_track <= Sphere::angle(self.start, self.end)
+    }
+
     /// Determines whether p if right of (negative integer), left of (positive integer) or on this
     /// minor arc (zero).
     ///
@@ -236,6 +467,31 @@ impl MinorArc {
         }
     }
 
+    /// Computes the unique point shared by this minor arc and the given minor arc, which are assumed to be on the
+    /// same great circle: [None] if they are disjoint, [DegenerateGeometry](NavigationError::DegenerateGeometry) if
+    /// they overlap along a portion of the great circle.
+    fn collinear_intersection(&self, other: MinorArc) -> Result<Option<NVector>, NavigationError> {
+        let candidates = [
+            (self.start, other.contains_vec3(self.start.as_vec3())),
+            (self.end, other.contains_vec3(self.end.as_vec3())),
+            (other.start, self.contains_vec3(other.start.as_vec3())),
+            (other.end, self.contains_vec3(other.end.as_vec3())),
+        ];
+        let mut common: Option<NVector> = None;
+        for (p, contained) in candidates {
+            if !contained {
+                continue;
+            }
+            match common {
+                None => common = Some(p),
+                Some(c) if eq_zero((c.as_vec3() - p.as_vec3()).norm()) => {}
+                // at least 2 distinct common points: overlapping minor arcs.
+                Some(_) => return Err(NavigationError::DegenerateGeometry),
+            }
+        }
+        Ok(common)
+    }
+
     /// Determines whether this minor arc contains the given point which is assumed to be on the great circle.
     fn contains_vec3(&self, v: Vec3) -> bool {
         // v is left of (normal, start)
@@ -254,9 +510,143 @@ mod tests {
     use crate::{
         positions::{assert_nv_eq_d7, assert_opt_nv_eq_d7},
         spherical::{GreatCircle, MinorArc, Sphere},
-        Angle, LatLong, Length, NVector, Vec3,
+        Angle, LatLong, Length, NVector, NavigationError, Vec3,
     };
 
+    // checked_new
+
+    #[test]
+    fn checked_new() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        assert_eq!(Ok(MinorArc::new(p1, p2)), MinorArc::checked_new(p1, p2));
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            MinorArc::checked_new(p1, p1)
+        );
+        assert_eq!(
+            Err(NavigationError::AntipodalPositions),
+            MinorArc::checked_new(p1, p1.antipode())
+        );
+    }
+
+    // length_angle, length
+
+    #[test]
+    fn length() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 20.0);
+        let ma = MinorArc::new(p1, p2);
+        assert_eq!(Sphere::angle(p1, p2), ma.length_angle());
+        assert_eq!(
+            Sphere::EARTH.distance(p1, p2),
+            ma.length(Sphere::EARTH.radius())
+        );
+        assert_eq!(ma.length_angle(), ma.opposite().length_angle());
+    }
+
+    #[test]
+    fn length_half_circle() {
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(90.0, 0.0),
+            NVector::from_lat_long_degrees(-90.0, 0.0),
+        );
+        assert_eq!(Angle::HALF_CIRCLE, ma.length_angle());
+    }
+
+    // interpolate
+
+    #[test]
+    fn interpolate_bounds() {
+        let p1 = NVector::from_lat_long_degrees(44.0, 44.0);
+        let p2 = NVector::from_lat_long_degrees(46.0, 46.0);
+        let ma = MinorArc::new(p1, p2);
+        assert_eq!(Some(p1), ma.interpolate(0.0));
+        assert_eq!(Some(p2), ma.interpolate(1.0));
+        assert!(ma.interpolate(-0.1).is_none());
+        assert!(ma.interpolate(1.1).is_none());
+    }
+
+    #[test]
+    fn interpolate_consistent_with_sphere() {
+        let p1 = NVector::from_lat_long_degrees(53.479444, -2.245278);
+        let p2 = NVector::from_lat_long_degrees(55.605833, 13.035833);
+        let ma = MinorArc::new(p1, p2);
+        for i in 1..10 {
+            let f = i as f64 / 10.0;
+            let expected = Sphere::interpolated_pos(p1, p2, f).unwrap();
+            assert_nv_eq_d7(expected, ma.interpolate(f).unwrap());
+            // on the minor arc.
+            assert!(ma.contains_point(ma.interpolate(f).unwrap()));
+        }
+    }
+
+    #[test]
+    fn interpolate_midpoint_equidistant() {
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(-30.0, 100.0),
+            NVector::from_lat_long_degrees(60.0, -150.0),
+        );
+        let m = ma.interpolate(0.5).unwrap();
+        let d1 = Sphere::EARTH.distance(ma.start(), m);
+        let d2 = Sphere::EARTH.distance(m, ma.end());
+        assert_eq!(d1.round_mm(), d2.round_mm());
+    }
+
+    // contains_point_within
+
+    #[test]
+    fn contains_point_within_endpoints() {
+        let start = NVector::from_lat_long_degrees(53.3206, -1.7297);
+        let end = NVector::from_lat_long_degrees(53.1887, 0.1334);
+        let ma = MinorArc::new(start, end);
+        assert!(ma.contains_point_within(start, Angle::ZERO));
+        assert!(ma.contains_point_within(end, Angle::ZERO));
+    }
+
+    #[test]
+    fn contains_point_within_tolerance() {
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        let tolerance = Angle::from_degrees(0.1);
+        assert!(ma.contains_point_within(NVector::from_lat_long_degrees(0.0, 5.0), tolerance));
+        assert!(ma.contains_point_within(NVector::from_lat_long_degrees(0.09, 5.0), tolerance));
+        assert!(ma.contains_point_within(NVector::from_lat_long_degrees(-0.09, 5.0), tolerance));
+        assert!(!ma.contains_point_within(NVector::from_lat_long_degrees(0.11, 5.0), tolerance));
+        // near an endpoint, but slightly beyond the minor arc.
+        assert!(ma.contains_point_within(NVector::from_lat_long_degrees(0.0, 10.05), tolerance));
+        assert!(ma.contains_point_within(NVector::from_lat_long_degrees(0.0, -0.05), tolerance));
+    }
+
+    #[test]
+    fn contains_point_within_beyond() {
+        let ma = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        let tolerance = Angle::from_degrees(0.1);
+        // on the great circle but outside the minor arc.
+        for lng in [-1.0, 10.5, 90.0, 180.0, -170.0] {
+            assert!(
+                !ma.contains_point_within(NVector::from_lat_long_degrees(0.0, lng), tolerance),
+                "{}",
+                lng
+            );
+        }
+    }
+
+    #[test]
+    fn contains_point_within_zero_length() {
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        let ma = MinorArc::new(p, p);
+        let tolerance = Angle::from_degrees(0.1);
+        assert!(ma.contains_point_within(p, tolerance));
+        assert!(ma.contains_point_within(NVector::from_lat_long_degrees(45.05, 45.0), tolerance));
+        assert!(!ma.contains_point_within(NVector::from_lat_long_degrees(45.2, 45.0), tolerance));
+    }
+
     // intersection
 
     #[test]
@@ -281,6 +671,75 @@ mod tests {
         assert!(arc1.intersection(arc2).is_none());
     }
 
+    #[test]
+    fn intersection_collinear_disjoint() {
+        let arc1 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        );
+        let arc2 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 20.0),
+            NVector::from_lat_long_degrees(0.0, 30.0),
+        );
+        assert!(arc1.intersection(arc2).is_none());
+        assert!(arc2.intersection(arc1).is_none());
+    }
+
+    #[test]
+    fn intersection_collinear_overlapping() {
+        let arc1 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 20.0),
+        );
+        let arc2 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(0.0, 30.0),
+        );
+        assert!(arc1.intersection(arc2).is_none());
+        assert!(arc2.intersection(arc1).is_none());
+        assert!(arc1.intersection(arc2.opposite()).is_none());
+        // one minor arc within the other.
+        let arc3 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            NVector::from_lat_long_degrees(0.0, 15.0),
+        );
+        assert!(arc1.intersection(arc3).is_none());
+        assert!(arc3.intersection(arc1).is_none());
+    }
+
+    #[test]
+    fn intersection_collinear_shared_endpoint() {
+        let shared = NVector::from_lat_long_degrees(10.0, 20.0);
+        let arc1 = MinorArc::new(NVector::from_lat_long_degrees(0.0, 20.0), shared);
+        let arc2 = MinorArc::new(shared, NVector::from_lat_long_degrees(30.0, 20.0));
+        assert_eq!(Some(shared), arc1.intersection(arc2));
+        assert_eq!(Some(shared), arc2.intersection(arc1));
+        // opposite directions.
+        assert_eq!(Some(shared), arc1.intersection(arc2.opposite()));
+        assert_eq!(Some(shared), arc1.opposite().intersection(arc2));
+    }
+
+    #[test]
+    fn intersection_effectively_collinear() {
+        let arc1 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 20.0),
+        );
+        let arc2 = MinorArc::new(
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(1e-14, 30.0),
+        );
+        assert!(arc1.intersection(arc2).is_none());
+    }
+
+    #[test]
+    fn intersection_shared_start() {
+        let shared = NVector::from_lat_long_degrees(10.0, 20.0);
+        let arc1 = MinorArc::new(shared, NVector::from_lat_long_degrees(0.0, 20.0));
+        let arc2 = MinorArc::new(shared, NVector::from_lat_long_degrees(15.0, 30.0));
+        assert_intersection(shared, arc1, arc2);
+    }
+
     #[test]
     fn intersection_arc_across_equator() {
         let arc1 = MinorArc::new(
@@ -619,6 +1078,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn projection_on_great_circle() {
+        let start = NVector::from_lat_long_degrees(53.3206, -1.7297);
+        let end = NVector::from_lat_long_degrees(53.1887, 0.1334);
+        let ma = MinorArc::new(start, end);
+        let p = ma
+            .projection(NVector::from_lat_long_degrees(53.2611, -0.7972))
+            .unwrap();
+        assert!(
+            Sphere::EARTH
+                .cross_track_distance(p, GreatCircle::new(start, end))
+                .abs()
+                < Length::from_metres(1e-6)
+        );
+    }
+
+    // closest_point
+
+    #[test]
+    fn closest_point_minimises_distance() {
+        let start = NVector::from_lat_long_degrees(53.3206, -1.7297);
+        let end = NVector::from_lat_long_degrees(53.1887, 0.1334);
+        let ma = MinorArc::new(start, end);
+        for p in [
+            // abeam.
+            NVector::from_lat_long_degrees(53.2611, -0.7972),
+            NVector::from_lat_long_degrees(52.0, -1.0),
+            // before start.
+            NVector::from_lat_long_degrees(53.5, -3.0),
+            // beyond end.
+            NVector::from_lat_long_degrees(53.0, 2.0),
+            // far away.
+            NVector::from_lat_long_degrees(-30.0, 100.0),
+        ] {
+            let c = ma.closest_point(p);
+            assert!(ma.contains_point_within(c, Angle::from_degrees(1e-9)));
+            // closer than any position of a brute-force sweep of the minor arc.
+            let d = Sphere::EARTH.distance(p, c);
+            for i in 0..=1000 {
+                let s = ma.interpolate(i as f64 / 1000.0).unwrap();
+                assert!(d <= Sphere::EARTH.distance(p, s) + Length::from_metres(1e-6));
+            }
+        }
+    }
+
+    #[test]
+    fn closest_point_endpoints() {
+        let start = NVector::from_lat_long_degrees(0.0, -10.0);
+        let end = NVector::from_lat_long_degrees(0.0, 10.0);
+        let ma = MinorArc::new(start, end);
+        assert_eq!(
+            start,
+            ma.closest_point(NVector::from_lat_long_degrees(5.0, -20.0))
+        );
+        assert_eq!(
+            end,
+            ma.closest_point(NVector::from_lat_long_degrees(-5.0, 20.0))
+        );
+        assert_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.0, 5.0),
+            ma.closest_point(NVector::from_lat_long_degrees(3.0, 5.0)),
+        );
+    }
+
+    #[test]
+    fn closest_point_pole() {
+        let start = NVector::from_lat_long_degrees(0.0, -10.0);
+        let end = NVector::from_lat_long_degrees(0.0, 10.0);
+        let c = MinorArc::new(start, end).closest_point(NVector::from_lat_long_degrees(90.0, 0.0));
+        assert_eq!(start, c);
+    }
+
     #[test]
     fn projection_nearly_perpendicular_null_island() {
         let start = NVector::from_lat_long_degrees(80.0, -90.0);
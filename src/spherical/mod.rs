@@ -1,22 +1,42 @@
 //! Geographical position calculations assuming a spherical model.
 
-mod base;
+pub(crate) mod base;
 
 mod cap;
 pub use cap::Cap;
 
+mod cpa;
+pub use cpa::Cpa;
+
 mod great_circle;
-pub use great_circle::GreatCircle;
+pub use great_circle::{GreatCircle, Side};
+
+mod intercept;
+pub use intercept::Intercept;
 
 mod minor_arc;
 pub use minor_arc::MinorArc;
 
+mod path;
+pub use path::{Leg, Path};
+
 mod rectangle;
 pub use rectangle::Rectangle;
 
+mod rhumb;
+
+mod route;
+pub use route::Route;
+
 mod sloop;
-pub use sloop::is_loop_clockwise;
 pub use sloop::Loop;
+pub use sloop::{ensure_loop_anticlockwise, is_loop_clockwise};
 
 mod sphere;
 pub use sphere::Sphere;
+
+mod triangle;
+pub use triangle::{SphericalTriangle, TriangleError};
+
+mod trilateration;
+pub use trilateration::Trilateration;
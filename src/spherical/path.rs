@@ -0,0 +1,644 @@
+use std::ops::Deref;
+
+use crate::{Angle, LatLong, Length};
+
+use super::{MinorArc, Rectangle, Sphere};
+
+/// A path: a sequence of positions joined by minor arcs.
+///
+/// [Path] dereferences to a slice of [LatLong], so that slice functions apply, and implements [FromIterator] so
+/// that it can be built with [collect](Iterator::collect).
+///
+/// # Examples
+///
+/// ```
+/// use jord::{LatLong, Length};
+/// use jord::spherical::{Path, Sphere};
+///
+/// let path: Path = (0..=2).map(|i| LatLong::from_degrees(0.0, i as f64)).collect();
+/// assert_eq!(3, path.len());
+/// assert_eq!(Length::from_metres(222_389.881), path.length(&Sphere::EARTH).round_mm());
+/// ```
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Path(Vec<LatLong>);
+
+impl Path {
+    /// Creates a new [Path] from the given positions.
+    pub fn new(positions: Vec<LatLong>) -> Self {
+        Self(positions)
+    }
+
+    /// Returns the positions of this path.
+    pub fn into_inner(self) -> Vec<LatLong> {
+        self.0
+    }
+
+    /// Computes the length of this path on the given sphere: the sum of the distances between consecutive
+    /// positions. The length of a path with less than 2 positions is zero.
+    pub fn length(&self, sphere: &Sphere) -> Length {
+        self.0
+            .windows(2)
+            .map(|w| sphere.distance(w[0].to_nvector(), w[1].to_nvector()))
+            .fold(Length::ZERO, |acc, d| acc + d)
+    }
+
+    /// Summarises each leg of this path - i.e. each pair of consecutive positions - on the given sphere: its
+    /// initial and final bearings, its distance and the distance travelled along the path at its end. The total
+    /// length of the path is the cumulative distance of the last leg. A path with less than 2 positions has no
+    /// leg.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length};
+    /// use jord::spherical::{Path, Sphere};
+    ///
+    /// let path = Path::new(vec![
+    ///     LatLong::from_degrees(0.0, 0.0),
+    ///     LatLong::from_degrees(0.0, 1.0),
+    ///     LatLong::from_degrees(1.0, 1.0),
+    /// ]);
+    /// let legs = path.legs(&Sphere::EARTH);
+    /// assert_eq!(2, legs.len());
+    /// assert_eq!(Angle::from_degrees(90.0), legs[0].initial_bearing().round_d7());
+    /// assert_eq!(Angle::ZERO, legs[1].initial_bearing().round_d7());
+    /// assert_eq!(path.length(&Sphere::EARTH), legs[1].cumulative_distance());
+    /// ```
+    pub fn legs(&self, sphere: &Sphere) -> Vec<Leg> {
+        let mut cumulative_distance = Length::ZERO;
+        self.0
+            .windows(2)
+            .map(|w| {
+                let (v1, v2) = (w[0].to_nvector(), w[1].to_nvector());
+                let distance = sphere.distance(v1, v2);
+                cumulative_distance = cumulative_distance + distance;
+                Leg {
+                    from: w[0],
+                    to: w[1],
+                    initial_bearing: Sphere::initial_bearing(v1, v2),
+                    final_bearing: Sphere::final_bearing(v1, v2),
+                    distance,
+                    cumulative_distance,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns a new path with [waypoints](crate::spherical::Sphere::waypoints) inserted between consecutive
+    /// positions of this path so that no two consecutive positions are further apart than the given spacing on
+    /// the given sphere. All positions of this path are kept, and at most 999,999 waypoints are inserted between
+    /// 2 consecutive positions: for a smaller spacing the waypoints are evenly spaced instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::{Path, Sphere};
+    ///
+    /// let path = Path::new(vec![LatLong::from_degrees(0.0, 0.0), LatLong::from_degrees(0.0, 1.0)]);
+    /// let dense = path.densify(Length::from_kilometres(50.0), &Sphere::EARTH);
+    /// assert_eq!(4, dense.len());
+    /// ```
+    pub fn densify(&self, spacing: Length, sphere: &Sphere) -> Path {
+        if self.0.len() < 2 {
+            return self.clone();
+        }
+        let mut res = Vec::with_capacity(self.0.len());
+        res.push(self.0[0]);
+        for w in self.0.windows(2) {
+            let wps = sphere.waypoints(w[0].to_nvector(), w[1].to_nvector(), spacing);
+            // first waypoint is the previous position; keep the original positions exactly.
+            let n = wps.len();
+            res.extend(wps[1..n - 1].iter().map(|wp| LatLong::from_nvector(*wp)));
+            res.push(w[1]);
+        }
+        Path(res)
+    }
+
+    /// Returns a new path with as few positions of this path as possible such that every removed position is
+    /// within the given tolerance of the returned path on the given sphere, using the Douglas-Peucker algorithm:
+    /// the distance from a position to a leg is the distance to the [closest
+    /// point](crate::spherical::MinorArc::closest_point) of the minor arc - i.e. the cross-track distance if the
+    /// position is abeam the leg, the distance to the nearest end of the leg otherwise.
+    ///
+    /// The first and last positions are always kept and consecutive duplicate positions are removed (unless
+    /// first and last). The algorithm is iterative and therefore suitable for very long paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::{Path, Sphere};
+    ///
+    /// let path = Path::new(vec![
+    ///     LatLong::from_degrees(0.0, 0.0),
+    ///     LatLong::from_degrees(0.0001, 0.5),
+    ///     LatLong::from_degrees(0.0, 1.0),
+    ///     LatLong::from_degrees(1.0, 1.0),
+    /// ]);
+    /// let simplified = path.simplify(Length::from_metres(100.0), &Sphere::EARTH);
+    /// assert_eq!(
+    ///     vec![LatLong::from_degrees(0.0, 0.0), LatLong::from_degrees(0.0, 1.0), LatLong::from_degrees(1.0, 1.0)],
+    ///     simplified.into_inner()
+    /// );
+    /// ```
+    pub fn simplify(&self, tolerance: Length, sphere: &Sphere) -> Path {
+        let len = self.0.len();
+        if len < 3 {
+            return self.clone();
+        }
+        let vs: Vec<_> = self.0.iter().map(|p| p.to_nvector()).collect();
+        let mut keep = vec![false; len];
+        keep[0] = true;
+        keep[len - 1] = true;
+        // explicit stack of (first, last) indices of the legs to simplify.
+        let mut stack = vec![(0, len - 1)];
+        while let Some((first, last)) = stack.pop() {
+            if last - first < 2 {
+                continue;
+            }
+            let (start, end) = (vs[first], vs[last]);
+            let arc = MinorArc::new(start, end);
+            let mut max = (first, Length::ZERO);
+            for (i, v) in vs.iter().enumerate().take(last).skip(first + 1) {
+                let d = if start == end {
+                    sphere.distance(*v, start)
+                } else {
+                    sphere.distance(*v, arc.closest_point(*v))
+                };
+                if d > max.1 {
+                    max = (i, d);
+                }
+            }
+            if max.1 > tolerance {
+                keep[max.0] = true;
+                stack.push((first, max.0));
+                stack.push((max.0, last));
+            }
+        }
+        self.0
+            .iter()
+            .zip(keep)
+            .filter_map(|(p, k)| if k { Some(*p) } else { None })
+            .collect()
+    }
+
+    /// Returns a new path without the positions within the given tolerance of the previous retained position on
+    /// the given sphere - e.g. to avoid zero-length legs whose bearing is undefined. The first and last positions
+    /// are always kept: the retained positions before the last position which are within the tolerance of the
+    /// last position are removed instead (unless first). A zero tolerance removes only duplicate positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::{Path, Sphere};
+    ///
+    /// let path = Path::new(vec![
+    ///     LatLong::from_degrees(0.0, 0.0),
+    ///     LatLong::from_degrees(0.0, 0.0),
+    ///     LatLong::from_degrees(0.0, 1.0),
+    ///     LatLong::from_degrees(0.0, 1.000001),
+    ///     LatLong::from_degrees(1.0, 1.0),
+    /// ]);
+    /// let deduped = path.dedup_consecutive(Length::from_metres(1.0), &Sphere::EARTH);
+    /// assert_eq!(
+    ///     vec![LatLong::from_degrees(0.0, 0.0), LatLong::from_degrees(0.0, 1.0), LatLong::from_degrees(1.0, 1.0)],
+    ///     deduped.into_inner()
+    /// );
+    /// ```
+    pub fn dedup_consecutive(&self, tolerance: Length, sphere: &Sphere) -> Path {
+        let len = self.0.len();
+        if len < 3 {
+            return self.clone();
+        }
+        let mut res: Vec<LatLong> = Vec::with_capacity(len);
+        res.push(self.0[0]);
+        let mut previous = self.0[0].to_nvector();
+        for p in &self.0[1..len - 1] {
+            let v = p.to_nvector();
+            if sphere.distance(previous, v) > tolerance {
+                res.push(*p);
+                previous = v;
+            }
+        }
+        let last = self.0[len - 1];
+        let v = last.to_nvector();
+        // the position before the one removed may also be within the tolerance of the last position.
+        while res.len() > 1 && sphere.distance(res[res.len() - 1].to_nvector(), v) <= tolerance {
+            res.pop();
+        }
+        res.push(last);
+        Path(res)
+    }
+
+    /// Returns the smallest [Rectangle] containing all the positions of this path - see
+    /// [Rectangle::from_points].
+    ///
+    /// Note: the minor arcs between positions may extend beyond the returned rectangle (e.g. towards the poles);
+    /// see [Rectangle::from_minor_arc] for a bound that includes each arc.
+    pub fn bounding_box(&self) -> Rectangle {
+        Rectangle::from_points(&self.0)
+    }
+}
+
+/// Summary of a leg of a [Path]: see [Path::legs].
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Leg {
+    from: LatLong,
+    to: LatLong,
+    initial_bearing: Angle,
+    final_bearing: Angle,
+    distance: Length,
+    cumulative_distance: Length,
+}
+
+impl Leg {
+    /// Returns the start position of this leg.
+    #[inline]
+    pub fn from(&self) -> LatLong {
+        self.from
+    }
+
+    /// Returns the end position of this leg.
+    #[inline]
+    pub fn to(&self) -> LatLong {
+        self.to
+    }
+
+    /// Returns the initial bearing of this leg - see [Sphere::initial_bearing].
+    #[inline]
+    pub fn initial_bearing(&self) -> Angle {
+        self.initial_bearing
+    }
+
+    /// Returns the final bearing of this leg - see [Sphere::final_bearing].
+    #[inline]
+    pub fn final_bearing(&self) -> Angle {
+        self.final_bearing
+    }
+
+    /// Returns the distance between the start and end positions of this leg.
+    #[inline]
+    pub fn distance(&self) -> Length {
+        self.distance
+    }
+
+    /// Returns the distance from the start of the path to the end position of this leg.
+    #[inline]
+    pub fn cumulative_distance(&self) -> Length {
+        self.cumulative_distance
+    }
+}
+
+impl Deref for Path {
+    type Target = [LatLong];
+
+    fn deref(&self) -> &[LatLong] {
+        &self.0
+    }
+}
+
+impl From<Vec<LatLong>> for Path {
+    fn from(positions: Vec<LatLong>) -> Self {
+        Self(positions)
+    }
+}
+
+impl FromIterator<LatLong> for Path {
+    fn from_iter<I: IntoIterator<Item = LatLong>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        spherical::{MinorArc, Path, Sphere},
+        Angle, LatLong, Length,
+    };
+
+    #[test]
+    fn collect_length() {
+        let path: Path = vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 1.0),
+            LatLong::from_degrees(1.0, 1.0),
+        ]
+        .into_iter()
+        .collect();
+        let e = Sphere::EARTH.distance(path[0].to_nvector(), path[1].to_nvector())
+            + Sphere::EARTH.distance(path[1].to_nvector(), path[2].to_nvector());
+        assert_eq!(e, path.length(&Sphere::EARTH));
+        assert_eq!(3, path.len());
+        assert_eq!(LatLong::from_degrees(1.0, 1.0), *path.last().unwrap());
+    }
+
+    #[test]
+    fn length_degenerate() {
+        assert_eq!(Length::ZERO, Path::default().length(&Sphere::EARTH));
+        let one: Path = std::iter::once(LatLong::from_degrees(10.0, 10.0)).collect();
+        assert_eq!(Length::ZERO, one.length(&Sphere::EARTH));
+    }
+
+    #[test]
+    fn collect_bounding_box() {
+        let path: Path = [(10.0, 170.0), (-10.0, -170.0), (5.0, 175.0)]
+            .iter()
+            .map(|(lat, lng)| LatLong::from_degrees(*lat, *lng))
+            .collect();
+        let bb = path.bounding_box();
+        assert_eq!(LatLong::from_degrees(10.0, -170.0), bb.north_east());
+        assert_eq!(LatLong::from_degrees(-10.0, 170.0), bb.south_west());
+        assert!(path.iter().all(|p| bb.contains_point(*p)));
+    }
+
+    #[test]
+    fn legs() {
+        let p1 = LatLong::from_degrees(53.3206, -1.7297);
+        let p2 = LatLong::from_degrees(53.1887, 0.1334);
+        let p3 = LatLong::from_degrees(52.205, 0.119);
+        let path = Path::new(vec![p1, p2, p3]);
+        let legs = path.legs(&Sphere::EARTH);
+        assert_eq!(2, legs.len());
+
+        assert_eq!(p1, legs[0].from());
+        assert_eq!(p2, legs[0].to());
+        assert_eq!(
+            Angle::from_degrees(96.0017325),
+            legs[0].initial_bearing().round_d7()
+        );
+        assert_eq!(
+            Length::from_metres(124_800.942),
+            legs[0].distance().round_mm()
+        );
+        assert_eq!(legs[0].distance(), legs[0].cumulative_distance());
+
+        assert_eq!(p2, legs[1].from());
+        assert_eq!(p3, legs[1].to());
+        let (v2, v3) = (p2.to_nvector(), p3.to_nvector());
+        assert_eq!(Sphere::initial_bearing(v2, v3), legs[1].initial_bearing());
+        assert_eq!(Sphere::final_bearing(v2, v3), legs[1].final_bearing());
+        assert_eq!(Sphere::EARTH.distance(v2, v3), legs[1].distance());
+
+        // totals add up.
+        assert_eq!(
+            legs[0].distance() + legs[1].distance(),
+            legs[1].cumulative_distance()
+        );
+        assert_eq!(path.length(&Sphere::EARTH), legs[1].cumulative_distance());
+    }
+
+    #[test]
+    fn legs_degenerate() {
+        assert!(Path::default().legs(&Sphere::EARTH).is_empty());
+        let one = Path::new(vec![LatLong::from_degrees(10.0, 10.0)]);
+        assert!(one.legs(&Sphere::EARTH).is_empty());
+    }
+
+    // simplify
+
+    /// Computes the distance from the given position to the given path.
+    fn distance_to_path(p: LatLong, path: &Path) -> Length {
+        let v = p.to_nvector();
+        path.windows(2)
+            .map(|w| {
+                let (s, e) = (w[0].to_nvector(), w[1].to_nvector());
+                if s == e {
+                    Sphere::EARTH.distance(v, s)
+                } else {
+                    Sphere::EARTH.distance(v, MinorArc::new(s, e).closest_point(v))
+                }
+            })
+            .fold(Length::MAX, |acc, d| if d < acc { d } else { acc })
+    }
+
+    #[test]
+    fn simplify_known_survivors() {
+        // a "U" whose sides deviate by about 11 metres from straight lines.
+        let path = Path::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0001, 0.5),
+            LatLong::from_degrees(0.0, 1.0),
+            LatLong::from_degrees(0.5, 0.9999),
+            LatLong::from_degrees(1.0, 1.0),
+            LatLong::from_degrees(0.9999, 0.5),
+            LatLong::from_degrees(1.0, 0.0),
+        ]);
+        let simplified = path.simplify(Length::from_metres(100.0), &Sphere::EARTH);
+        assert_eq!(
+            vec![path[0], path[2], path[4], path[6]],
+            simplified.into_inner()
+        );
+        // large tolerance: only the first and last positions survive.
+        let simplified = path.simplify(Length::from_kilometres(200.0), &Sphere::EARTH);
+        assert_eq!(vec![path[0], path[6]], simplified.into_inner());
+        // zero tolerance: every position off the simplified line survives.
+        let simplified = path.simplify(Length::ZERO, &Sphere::EARTH);
+        assert_eq!(path, simplified);
+    }
+
+    #[test]
+    fn simplify_degenerate() {
+        assert_eq!(
+            Path::default(),
+            Path::default().simplify(Length::ZERO, &Sphere::EARTH)
+        );
+        let two = Path::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 0.0),
+        ]);
+        assert_eq!(two, two.simplify(Length::ZERO, &Sphere::EARTH));
+    }
+
+    #[test]
+    fn simplify_duplicates() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.0, 1.0);
+        let p3 = LatLong::from_degrees(1.0, 1.0);
+        let path = Path::new(vec![p1, p1, p2, p2, p2, p3, p3]);
+        let simplified = path.simplify(Length::from_metres(1.0), &Sphere::EARTH);
+        assert_eq!(vec![p1, p2, p3], simplified.into_inner());
+    }
+
+    #[test]
+    fn simplify_closed() {
+        // first == last: distances are measured to the first position.
+        let p = LatLong::from_degrees(0.0, 0.0);
+        let path = Path::new(vec![
+            p,
+            LatLong::from_degrees(0.0, 1.0),
+            LatLong::from_degrees(1.0, 1.0),
+            LatLong::from_degrees(1.0, 0.0),
+            p,
+        ]);
+        let simplified = path.simplify(Length::from_metres(1.0), &Sphere::EARTH);
+        assert_eq!(path, simplified);
+    }
+
+    #[test]
+    fn simplify_within_tolerance() {
+        // xorshift: deterministic pseudo-random walk.
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5
+        };
+        let mut lat = 45.0;
+        let mut lng = 179.0;
+        let mut ps = Vec::new();
+        for _ in 0..2000 {
+            lat += next() * 0.01;
+            lng += 0.002 + next() * 0.01;
+            ps.push(LatLong::from_degrees(lat, (lng + 180.0) % 360.0 - 180.0));
+        }
+        let path = Path::new(ps);
+        let tolerance = Length::from_metres(250.0);
+        let simplified = path.simplify(tolerance, &Sphere::EARTH);
+        assert!(simplified.len() < path.len());
+        assert_eq!(path.first(), simplified.first());
+        assert_eq!(path.last(), simplified.last());
+        for p in path.iter() {
+            assert!(distance_to_path(*p, &simplified) <= tolerance);
+        }
+    }
+
+    #[test]
+    fn simplify_long_straight_path() {
+        let path: Path = (0..100_000)
+            .map(|i| LatLong::from_degrees(0.0, i as f64 / 1000.0))
+            .collect();
+        let simplified = path.simplify(Length::from_metres(1.0), &Sphere::EARTH);
+        assert_eq!(vec![path[0], path[99_999]], simplified.into_inner());
+    }
+
+    // dedup_consecutive
+
+    #[test]
+    fn dedup_consecutive_exact_duplicates() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.0, 1.0);
+        let p3 = LatLong::from_degrees(1.0, 1.0);
+        let path = Path::new(vec![p1, p1, p2, p2, p2, p3]);
+        assert_eq!(
+            vec![p1, p2, p3],
+            path.dedup_consecutive(Length::ZERO, &Sphere::EARTH)
+                .into_inner()
+        );
+    }
+
+    #[test]
+    fn dedup_consecutive_zero_tolerance_keeps_distinct() {
+        // ~ 1 millimetre apart.
+        let path = Path::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 0.00000001),
+            LatLong::from_degrees(0.0, 0.00000002),
+        ]);
+        assert_eq!(path, path.dedup_consecutive(Length::ZERO, &Sphere::EARTH));
+    }
+
+    #[test]
+    fn dedup_consecutive_near_duplicates() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        // ~ 0.11 and 0.22 metres from p1.
+        let n1 = LatLong::from_degrees(0.0, 0.000001);
+        let n2 = LatLong::from_degrees(0.0, 0.000002);
+        let p2 = LatLong::from_degrees(0.0, 1.0);
+        let p3 = LatLong::from_degrees(1.0, 1.0);
+        let path = Path::new(vec![p1, n1, n2, p2, p3]);
+        // distance to the previous retained position: n2 is within 0.25 m of p1.
+        assert_eq!(
+            vec![p1, p2, p3],
+            path.dedup_consecutive(Length::from_metres(0.25), &Sphere::EARTH)
+                .into_inner()
+        );
+        // n2 is 0.22 m from p1: kept with a 0.2 m tolerance.
+        assert_eq!(
+            vec![p1, n2, p2, p3],
+            path.dedup_consecutive(Length::from_metres(0.2), &Sphere::EARTH)
+                .into_inner()
+        );
+    }
+
+    #[test]
+    fn dedup_consecutive_keeps_first_and_last() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.0, 1.0);
+        let near_p2 = LatLong::from_degrees(0.0, 1.000001);
+        let path = Path::new(vec![p1, p2, near_p2]);
+        assert_eq!(
+            vec![p1, near_p2],
+            path.dedup_consecutive(Length::from_metres(1.0), &Sphere::EARTH)
+                .into_inner()
+        );
+        // all positions within the tolerance.
+        let near_p1 = LatLong::from_degrees(0.0, 0.000001);
+        let path = Path::new(vec![p1, near_p1, p1]);
+        assert_eq!(
+            vec![p1, p1],
+            path.dedup_consecutive(Length::from_metres(1.0), &Sphere::EARTH)
+                .into_inner()
+        );
+    }
+
+    #[test]
+    fn dedup_consecutive_near_duplicates_at_end() {
+        let p1 = LatLong::from_degrees(0.0, 0.0);
+        let p2 = LatLong::from_degrees(0.0, 1.0);
+        // ~ 0.22 metres from p2: retained with a 0.15 m tolerance.
+        let n1 = LatLong::from_degrees(0.0, 1.000002);
+        // ~ 0.11 metres from both p2 and n1.
+        let last = LatLong::from_degrees(0.0, 1.000001);
+        let path = Path::new(vec![p1, p2, n1, last]);
+        let deduped = path.dedup_consecutive(Length::from_metres(0.15), &Sphere::EARTH);
+        assert_eq!(vec![p1, last], deduped.clone().into_inner());
+        for w in deduped.windows(2) {
+            assert!(
+                Sphere::EARTH.distance(w[0].to_nvector(), w[1].to_nvector())
+                    > Length::from_metres(0.15)
+            );
+        }
+    }
+
+    #[test]
+    fn dedup_consecutive_preserves_distinct() {
+        let path = Path::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 1.0),
+            LatLong::from_degrees(1.0, 1.0),
+            LatLong::from_degrees(1.0, 0.0),
+        ]);
+        assert_eq!(
+            path,
+            path.dedup_consecutive(Length::from_kilometres(1.0), &Sphere::EARTH)
+        );
+        let two = Path::new(vec![LatLong::from_degrees(0.0, 0.0); 2]);
+        assert_eq!(two, two.dedup_consecutive(Length::ZERO, &Sphere::EARTH));
+    }
+
+    #[test]
+    fn densify() {
+        let path = Path::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 2.0),
+            LatLong::from_degrees(1.0, 2.0),
+        ]);
+        let spacing = Sphere::EARTH.distance(
+            LatLong::from_degrees(0.0, 0.0).to_nvector(),
+            LatLong::from_degrees(0.0, 0.5).to_nvector(),
+        );
+        let dense = path.densify(spacing, &Sphere::EARTH);
+        // 4 segments then 2 segments.
+        assert_eq!(7, dense.len());
+        assert_eq!(path[0], dense[0]);
+        assert_eq!(path[1], dense[4]);
+        assert_eq!(path[2], dense[6]);
+        assert_eq!(
+            path.length(&Sphere::EARTH).round_mm(),
+            dense.length(&Sphere::EARTH).round_mm()
+        );
+    }
+}
@@ -70,6 +70,46 @@ impl Rectangle {
         }
     }
 
+    /// Creates the smallest rectangle containing all the given points: the latitude interval spans from the
+    /// southernmost to the northernmost point, and the longitude interval is the smallest interval containing all
+    /// longitudes, which crosses the antimeridian if that yields a smaller longitude span.
+    ///
+    /// If any of the given points is a pole, the [polar closure](crate::spherical::Rectangle::polar_closure) of
+    /// the rectangle is returned. If no point is given, the [empty](crate::spherical::Rectangle::EMPTY)
+    /// rectangle is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::LatLong;
+    /// use jord::spherical::Rectangle;
+    ///
+    /// let r = Rectangle::from_points(&[
+    ///     LatLong::from_degrees(10.0, 170.0),
+    ///     LatLong::from_degrees(-10.0, -170.0),
+    ///     LatLong::from_degrees(5.0, 175.0),
+    /// ]);
+    ///
+    /// assert_eq!(LatLong::from_degrees(10.0, -170.0), r.north_east());
+    /// assert_eq!(LatLong::from_degrees(-10.0, 170.0), r.south_west());
+    /// assert!(r.contains_point(LatLong::from_degrees(0.0, 180.0)));
+    /// assert!(!r.contains_point(LatLong::from_degrees(0.0, 0.0)));
+    /// ```
+    pub fn from_points(ps: &[LatLong]) -> Self {
+        if ps.is_empty() {
+            return Self::EMPTY;
+        }
+        let mut lat = LatitudeInterval::EMPTY;
+        for p in ps {
+            lat.mut_union(LatitudeInterval::new(p.latitude(), p.latitude()));
+        }
+        let r = Self {
+            lat,
+            lng: LongitudeInterval::from_longitudes(ps.iter().map(|p| p.longitude()).collect()),
+        };
+        r.polar_closure()
+    }
+
     /// Compares the latitude intervalsof this rectangle and the given one: the [greater](Ordering::Greater) latitude interval is defined as
     /// the interval that is northernmost overall (including both low and high latitudes).
     pub fn cmp_by_latitude(&self, o: Self) -> Ordering {
@@ -180,6 +220,44 @@ impl Rectangle {
         self.lat.interior_contains_int(r.lat) && self.lng.interior_contains_int(r.lng)
     }
 
+    /// Determines whether this rectangle and the given rectangle have any point in common (boundaries included).
+    ///
+    /// Like [contains_point](crate::spherical::Rectangle::contains_point), this method compares latitude and
+    /// longitude intervals: two rectangles only touching a pole at different longitudes do not intersect unless
+    /// their [polar closure](crate::spherical::Rectangle::polar_closure) is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::Rectangle;
+    ///
+    /// let a = Rectangle::from_nesw(
+    ///     Angle::from_degrees(10.0),
+    ///     Angle::from_degrees(-170.0),
+    ///     Angle::from_degrees(-10.0),
+    ///     Angle::from_degrees(170.0),
+    /// );
+    /// let b = Rectangle::from_nesw(
+    ///     Angle::from_degrees(20.0),
+    ///     Angle::from_degrees(-160.0),
+    ///     Angle::ZERO,
+    ///     Angle::from_degrees(-175.0),
+    /// );
+    /// let c = Rectangle::from_nesw(
+    ///     Angle::from_degrees(20.0),
+    ///     Angle::from_degrees(20.0),
+    ///     Angle::ZERO,
+    ///     Angle::from_degrees(10.0),
+    /// );
+    ///
+    /// assert!(a.intersects(b));
+    /// assert!(!a.intersects(c));
+    /// ```
+    pub fn intersects(&self, o: Self) -> bool {
+        self.lat.intersects(o.lat) && self.lng.intersects(o.lng)
+    }
+
     /// Determines whether this rectangle is [full](crate::spherical::Rectangle::FULL).
     pub fn is_full(&self) -> bool {
         self.is_latitude_full() && self.is_longitude_full()
@@ -296,6 +374,69 @@ impl Rectangle {
         }
     }
 
+    /// Returns an iterator over a regular grid of points covering this rectangle: rows are iterated from south
+    /// to north and, within each row, points from west to east starting at the
+    /// [south-west](crate::spherical::Rectangle::south_west) corner. If this rectangle crosses the antimeridian,
+    /// longitudes wrap around from 180 to -180 degrees.
+    ///
+    /// Corners and steps are rounded to the nearest microarcsecond and each point is computed from its row and
+    /// column index, so that no floating point error accumulates along the grid. Points are included up to and
+    /// including the northern parallel and the eastern meridian, if they fall on the grid. A rectangle with a zero
+    /// extent yields exactly one point, and an empty rectangle yields no point.
+    ///
+    /// Returns [None] if either step is not positive once rounded to the nearest microarcsecond.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Rectangle;
+    ///
+    /// let r = Rectangle::from_nesw(
+    ///     Angle::from_degrees(1.0),
+    ///     Angle::from_degrees(-179.0),
+    ///     Angle::ZERO,
+    ///     Angle::from_degrees(179.0),
+    /// );
+    /// let ps: Vec<LatLong> = r.grid(Angle::from_degrees(1.0), Angle::from_degrees(1.0)).unwrap().collect();
+    /// assert_eq!(6, ps.len());
+    /// assert_eq!(LatLong::from_degrees(0.0, 179.0), ps[0]);
+    /// assert_eq!(LatLong::from_degrees(0.0, 180.0), ps[1]);
+    /// assert_eq!(LatLong::from_degrees(0.0, -179.0), ps[2]);
+    /// assert_eq!(LatLong::from_degrees(1.0, -179.0), ps[5]);
+    /// ```
+    pub fn grid(&self, lat_step: Angle, lng_step: Angle) -> Option<impl Iterator<Item = LatLong>> {
+        let lat_step = to_microarcseconds(lat_step);
+        let lng_step = to_microarcseconds(lng_step);
+        if lat_step <= 0 || lng_step <= 0 {
+            return None;
+        }
+        let lat_lo = to_microarcseconds(self.lat.lo);
+        let lng_lo = to_microarcseconds(self.lng.lo);
+        let (rows, cols) = if self.lat.is_empty() || self.lng.is_empty() {
+            (0, 0)
+        } else {
+            let rows = (to_microarcseconds(self.lat.hi) - lat_lo) / lat_step + 1;
+            let cols = if self.lng.is_full() {
+                // do not repeat the western meridian at 180 degrees.
+                (FULL_CIRCLE_MICROARCSECONDS - 1) / lng_step + 1
+            } else {
+                to_microarcseconds(self.lng.len()) / lng_step + 1
+            };
+            (rows, cols)
+        };
+        Some((0..rows).flat_map(move |i| {
+            let lat = from_microarcseconds(lat_lo + i * lat_step);
+            (0..cols).map(move |j| {
+                let mut lng = lng_lo + j * lng_step;
+                if lng > FULL_CIRCLE_MICROARCSECONDS / 2 {
+                    lng -= FULL_CIRCLE_MICROARCSECONDS;
+                }
+                LatLong::new(lat, from_microarcseconds(lng))
+            })
+        }))
+    }
+
     /// Returns the smallest rectangle containing the union of this rectangle and the given rectangle.
     pub fn union(&self, o: Self) -> Self {
         Rectangle {
@@ -315,6 +456,21 @@ impl Rectangle {
     }
 }
 
+/// Number of microarcseconds in a degree.
+const MICROARCSECONDS_PER_DEGREE: f64 = 3_600_000_000.0;
+
+/// Number of microarcseconds in a full circle.
+const FULL_CIRCLE_MICROARCSECONDS: i64 = 360 * 3_600_000_000;
+
+/// Rounds the given angle to the nearest whole number of microarcseconds; NaN is rounded to 0.
+fn to_microarcseconds(a: Angle) -> i64 {
+    (a.as_degrees() * MICROARCSECONDS_PER_DEGREE).round() as i64
+}
+
+fn from_microarcseconds(m: i64) -> Angle {
+    Angle::from_degrees(m as f64 / MICROARCSECONDS_PER_DEGREE)
+}
+
 /// latitude interval: {@link #lo} is assumed to be less than {@link #hi}, otherwise the interval is empty.
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
 struct LatitudeInterval {
@@ -394,6 +550,12 @@ impl LatitudeInterval {
         }
     }
 
+    /// Returns true if and only if this latitude interval and the given latitude interval have any latitude in
+    /// common.
+    fn intersects(&self, o: Self) -> bool {
+        !self.is_empty() && !o.is_empty() && o.lo <= self.hi && o.hi >= self.lo
+    }
+
     /// Returns an interval that has been expanded/shrinked on each side by the given amount.
     fn expand(&self, amount: Angle) -> Self {
         if self.is_empty() {
@@ -480,6 +642,31 @@ impl LongitudeInterval {
         }
     }
 
+    /// Returns the smallest longitude interval containing all the given longitudes: the interval is the
+    /// complement of the largest gap between consecutive longitudes.
+    fn from_longitudes(longitudes: Vec<Angle>) -> Self {
+        let mut lngs: Vec<Angle> = longitudes
+            .into_iter()
+            .map(Self::normalised_longitude)
+            .collect();
+        lngs.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let (first, last) = match (lngs.first(), lngs.last()) {
+            (Some(f), Some(l)) => (*f, *l),
+            _ => return Self::EMPTY,
+        };
+        // gap across the antimeridian: if it is the largest, the interval is not inverted.
+        let mut res = Self::new(first, last);
+        let mut max_gap = Self::positive_distance(last, first);
+        for w in lngs.windows(2) {
+            let gap = w[1] - w[0];
+            if gap > max_gap {
+                max_gap = gap;
+                res = Self::new(w[1], w[0]);
+            }
+        }
+        res
+    }
+
     /// Normalises the given longitude: if the given longitude is -180 degrees, 180 degrees is
     /// returned. This is done to workaround the discontinuity at the date line.
     fn normalised_longitude(longitude: Angle) -> Angle {
@@ -581,6 +768,19 @@ impl LongitudeInterval {
         (o.lo > self.lo && o.hi < self.hi) || self.is_full()
     }
 
+    /// Returns true if and only if this longitude interval and the given longitude interval have any longitude
+    /// in common.
+    fn intersects(&self, o: Self) -> bool {
+        if self.is_empty() || o.is_empty() {
+            false
+        } else if self.is_inverted() || o.is_inverted() {
+            // at least one interval contains the antimeridian.
+            (self.is_inverted() && o.is_inverted()) || o.lo <= self.hi || o.hi >= self.lo
+        } else {
+            o.lo <= self.hi && o.hi >= self.lo
+        }
+    }
+
     /// Returns true if this longitude interval is full.
     fn is_full(&self) -> bool {
         self.lo == Angle::NEG_HALF_CIRCLE && self.hi == Angle::HALF_CIRCLE
@@ -1216,7 +1416,7 @@ mod tests {
         for lat in -900..900 {
             let lat_f = lat as f64;
             let p = LatLong::from_degrees(lat_f / 10.0, 0.0);
-            if lat >= 0 && lat <= 100 {
+            if (0..=100).contains(&lat) {
                 assert!(actual.contains_point(p));
             } else {
                 assert!(!actual.contains_point(p));
@@ -1741,6 +1941,261 @@ mod tests {
         assert_eq!(e, expanded);
     }
 
+    // from_points
+
+    #[test]
+    fn from_points_empty() {
+        assert_eq!(Rectangle::EMPTY, Rectangle::from_points(&[]));
+    }
+
+    #[test]
+    fn from_points_single() {
+        let r = Rectangle::from_points(&[ll(10, 20)]);
+        assert_eq!(ll(10, 20), r.north_east());
+        assert_eq!(ll(10, 20), r.south_west());
+        assert!(r.contains_point(ll(10, 20)));
+    }
+
+    #[test]
+    fn from_points_nominal() {
+        let r = Rectangle::from_points(&[ll(10, 20), ll(-5, 40), ll(30, 25)]);
+        assert_eq!(ll(30, 40), r.north_east());
+        assert_eq!(ll(-5, 20), r.south_west());
+    }
+
+    #[test]
+    fn from_points_antimeridian() {
+        let r = Rectangle::from_points(&[ll(10, 170), ll(-10, -170), ll(0, 180)]);
+        assert_eq!(ll(10, -170), r.north_east());
+        assert_eq!(ll(-10, 170), r.south_west());
+        assert!(r.contains_point(ll(0, -180)));
+        assert!(!r.contains_point(ll(0, 0)));
+    }
+
+    #[test]
+    fn from_points_smallest_span() {
+        // a span from -100 to 100 (200 degrees) is larger than 100 to -100 (160 degrees).
+        let r = Rectangle::from_points(&[ll(0, -100), ll(0, 100)]);
+        assert!(r.contains_point(ll(0, 180)));
+        assert!(!r.contains_point(ll(0, 0)));
+
+        // a span from -70 to 70 (140 degrees) is smaller than 70 to -70 (220 degrees).
+        let r = Rectangle::from_points(&[ll(0, -70), ll(0, 70)]);
+        assert!(r.contains_point(ll(0, 0)));
+        assert!(!r.contains_point(ll(0, 180)));
+    }
+
+    #[test]
+    fn from_points_pole() {
+        let r = Rectangle::from_points(&[ll(80, 10), ll(90, 0)]);
+        assert!(r.is_longitude_full());
+        assert_eq!(Angle::from_degrees(80.0), r.south_west().latitude());
+        assert_eq!(Angle::QUARTER_CIRCLE, r.north_east().latitude());
+        assert!(r.contains_point(ll(90, 123)));
+        assert!(r.contains_point(ll(85, -170)));
+
+        let r = Rectangle::from_points(&[ll(-80, 10), ll(-90, 0)]);
+        assert!(r.is_longitude_full());
+        assert!(r.contains_point(ll(-90, 45)));
+    }
+
+    #[test]
+    fn from_points_brute_force() {
+        let lngs: Vec<i64> = vec![-180, -179, -120, -45, -1, 0, 1, 44, 90, 135, 178, 179, 180];
+        // every subset of 3 longitudes.
+        for (i, a) in lngs.iter().enumerate() {
+            for (j, b) in lngs.iter().enumerate().skip(i) {
+                for c in lngs.iter().skip(j) {
+                    let ps = vec![ll(-10, *a), ll(20, *b), ll(5, *c)];
+                    let r = Rectangle::from_points(&ps);
+                    for p in ps.iter() {
+                        assert!(r.contains_point(*p));
+                    }
+                    // the longitude span is the smallest: no rotation of the candidate intervals starting at a
+                    // point longitude yields a smaller span.
+                    let span = lng_span(r);
+                    for start in [*a, *b, *c] {
+                        let candidate = [*a, *b, *c]
+                            .iter()
+                            .map(|l| (l - start).rem_euclid(360))
+                            .max()
+                            .unwrap() as f64;
+                        assert!(span <= candidate + 1e-9);
+                    }
+                }
+            }
+        }
+    }
+
+    fn lng_span(r: Rectangle) -> f64 {
+        let d = r.north_east().longitude().as_degrees() - r.south_west().longitude().as_degrees();
+        if d < 0.0 {
+            d + 360.0
+        } else {
+            d
+        }
+    }
+
+    // intersects
+
+    #[test]
+    fn intersects_nominal() {
+        let a = nesw(20, 20, 10, 10);
+        assert!(a.intersects(nesw(30, 30, 15, 15)));
+        assert!(a.intersects(nesw(15, 15, 12, 12)));
+        // touching boundaries.
+        assert!(a.intersects(nesw(30, 30, 20, 20)));
+        // disjoint by latitude.
+        assert!(!a.intersects(nesw(40, 20, 30, 10)));
+        // disjoint by longitude.
+        assert!(!a.intersects(nesw(20, 40, 10, 30)));
+    }
+
+    #[test]
+    fn intersects_empty() {
+        let a = nesw(20, 20, 10, 10);
+        assert!(!a.intersects(Rectangle::EMPTY));
+        assert!(!Rectangle::EMPTY.intersects(a));
+        assert!(!Rectangle::EMPTY.intersects(Rectangle::EMPTY));
+        assert!(Rectangle::FULL.intersects(a));
+    }
+
+    #[test]
+    fn intersects_antimeridian() {
+        let a = nesw(10, -170, -10, 170);
+        assert!(a.intersects(nesw(10, -175, -10, 175)));
+        assert!(a.intersects(nesw(10, -160, -10, -175)));
+        assert!(a.intersects(nesw(10, 175, -10, 160)));
+        assert!(a.intersects(nesw(10, -160, -10, 160)));
+        assert!(!a.intersects(nesw(10, 160, -10, -160)));
+        assert!(!a.intersects(nesw(10, 10, -10, -10)));
+    }
+
+    #[test]
+    fn intersects_pole() {
+        let a = nesw(90, 10, 80, 0);
+        let b = nesw(90, 100, 80, 90);
+        assert!(!a.intersects(b));
+        assert!(a.polar_closure().intersects(b.polar_closure()));
+        assert!(!a.polar_closure().intersects(nesw(-80, 10, -90, 0)));
+    }
+
+    #[test]
+    fn intersects_brute_force() {
+        let bounds: Vec<(i64, i64)> = vec![
+            (-180, -170),
+            (170, -170),
+            (160, 180),
+            (-10, 10),
+            (10, -10),
+            (90, -90),
+            (-30, 30),
+            (5, 6),
+            (179, -179),
+        ];
+        let lats = [(-90, -80), (-10, 10), (0, 5), (80, 90), (-90, 90)];
+        let mut rs = Vec::new();
+        for (w, e) in bounds.iter() {
+            for (s, n) in lats.iter() {
+                rs.push(nesw(*n, *e, *s, *w));
+            }
+        }
+        for a in rs.iter() {
+            for b in rs.iter() {
+                // intervals intersect if and only if one contains an end of the other: checking all
+                // combinations of bounds is enough.
+                let expected = lats.iter().flat_map(|(s, n)| [*s, *n]).any(|lat| {
+                    bounds.iter().flat_map(|(w, e)| [*w, *e]).any(|lng| {
+                        let p = ll(lat, lng);
+                        a.contains_point(p) && b.contains_point(p)
+                    })
+                });
+                assert_eq!(expected, a.intersects(*b), "{:?} {:?}", a, b);
+                assert_eq!(a.intersects(*b), b.intersects(*a));
+            }
+        }
+    }
+
+    // grid
+
+    #[test]
+    fn grid_antimeridian_count() {
+        // 8 rows (-10 to 25 by 5) and 9 columns (170 to -170 by 2.5).
+        let r = nesw(25, -170, -10, 170);
+        let ps: Vec<LatLong> = r
+            .grid(Angle::from_degrees(5.0), Angle::from_degrees(2.5))
+            .unwrap()
+            .collect();
+        assert_eq!(8 * 9, ps.len());
+        assert_eq!(ll(-10, 170), ps[0]);
+        assert_eq!(ll(-10, 180), ps[4]);
+        assert_eq!(ll(-10, -170), ps[8]);
+        assert_eq!(ll(-5, 170), ps[9]);
+        assert_eq!(ll(25, -170), ps[ps.len() - 1]);
+        assert!(ps.iter().all(|p| r.contains_point(*p)));
+    }
+
+    #[test]
+    fn grid_no_accumulation() {
+        let r = nesw(1, 1, 0, 0);
+        let step = Angle::from_degrees(0.1);
+        let ps: Vec<LatLong> = r.grid(step, step).unwrap().collect();
+        assert_eq!(11 * 11, ps.len());
+        for (i, p) in ps.iter().enumerate() {
+            let e = LatLong::from_degrees((i / 11) as f64 / 10.0, (i % 11) as f64 / 10.0);
+            assert_eq!(e, *p);
+        }
+    }
+
+    #[test]
+    fn grid_full_longitude() {
+        let r = nesw(0, 180, 0, -180);
+        let ps: Vec<LatLong> = r
+            .grid(Angle::from_degrees(1.0), Angle::from_degrees(90.0))
+            .unwrap()
+            .collect();
+        assert_eq!(vec![ll(0, -180), ll(0, -90), ll(0, 0), ll(0, 90)], ps);
+    }
+
+    #[test]
+    fn grid_degenerate() {
+        let r = nesw(10, 20, 10, 20);
+        let ps: Vec<LatLong> = r
+            .grid(Angle::from_degrees(1.0), Angle::from_degrees(1.0))
+            .unwrap()
+            .collect();
+        assert_eq!(vec![ll(10, 20)], ps);
+        assert_eq!(
+            0,
+            Rectangle::EMPTY
+                .grid(Angle::from_degrees(1.0), Angle::from_degrees(1.0))
+                .unwrap()
+                .count()
+        );
+    }
+
+    #[test]
+    fn grid_invalid_steps() {
+        let r = nesw(10, 20, 0, 0);
+        assert!(r.grid(Angle::ZERO, Angle::from_degrees(1.0)).is_none());
+        assert!(r.grid(Angle::from_degrees(1.0), Angle::ZERO).is_none());
+        assert!(r
+            .grid(Angle::from_degrees(-1.0), Angle::from_degrees(1.0))
+            .is_none());
+        assert!(r
+            .grid(Angle::from_degrees(1.0), Angle::from_radians(f64::NAN))
+            .is_none());
+    }
+
+    fn nesw(n: i64, e: i64, s: i64, w: i64) -> Rectangle {
+        Rectangle::from_nesw(
+            Angle::from_degrees(n as f64),
+            Angle::from_degrees(e as f64),
+            Angle::from_degrees(s as f64),
+            Angle::from_degrees(w as f64),
+        )
+    }
+
     fn ll(lat: i64, lng: i64) -> LatLong {
         LatLong::from_degrees(lat as f64, lng as f64)
     }
@@ -0,0 +1,303 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use crate::{Angle, LatLong, Length, NVector};
+
+use super::Sphere;
+
+/// Below this difference of isometric latitude, a rhumb line is considered to follow a parallel (east-west
+/// leg): the ratio of the difference of latitude to the difference of isometric latitude is then the cosine
+/// of the latitude.
+const EAST_WEST_MAX_DIFF: f64 = 1e-12;
+
+impl Sphere {
+    /// Computes the distance along the [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line) (line of constant
+    /// bearing, or loxodrome) from the first position to the second position, following the shorter route in
+    /// longitude (i.e. crossing the antimeridian if shorter).
+    ///
+    /// A rhumb line is always at least as long as the great circle between both positions, but the difference
+    /// is small for short legs and at low latitudes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(50.3667, -4.1340).to_nvector();
+    /// let p2 = LatLong::from_degrees(42.3511, -71.0408).to_nvector();
+    ///
+    /// assert_eq!(Length::from_metres(5_197_983.0), Sphere::EARTH.rhumb_distance(p1, p2).round_m());
+    /// ```
+    pub fn rhumb_distance(&self, p1: NVector, p2: NVector) -> Length {
+        let (d_lat, d_lon, q) = rhumb_deltas(p1, p2);
+        let angle = d_lat.hypot(q * d_lon);
+        Angle::from_radians(angle).arc_length(self.radius())
+    }
+
+    /// Computes the (constant) bearing of the [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line) from the
+    /// first position to the second position, following the shorter route in longitude. The returned bearing is
+    /// within [0, 360) degrees; it is zero if both positions are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(50.3667, -4.1340).to_nvector();
+    /// let p2 = LatLong::from_degrees(42.3511, -71.0408).to_nvector();
+    ///
+    /// assert_eq!(Angle::from_degrees(260.1267451), Sphere::rhumb_bearing(p1, p2).round_d7());
+    /// ```
+    pub fn rhumb_bearing(p1: NVector, p2: NVector) -> Angle {
+        let ll1 = LatLong::from_nvector(p1);
+        let ll2 = LatLong::from_nvector(p2);
+        let d_psi = isometric_latitude(ll2.latitude()) - isometric_latitude(ll1.latitude());
+        let d_lon = shortest_longitude_diff(ll1, ll2);
+        if d_psi == 0.0 && d_lon == 0.0 {
+            Angle::ZERO
+        } else {
+            Angle::from_radians(d_lon.atan2(d_psi)).normalised()
+        }
+    }
+
+    /// Computes the position reached from the given position by travelling the given distance along the
+    /// [rhumb line](https://en.wikipedia.org/wiki/Rhumb_line) of the given bearing.
+    ///
+    /// If the rhumb line passes over a pole, the destination is reflected over it - as if continuing on the
+    /// opposite meridian - which is only meaningful for north-south legs since a rhumb line of any other
+    /// bearing spirals towards the pole without ever reaching it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p = LatLong::from_degrees(51.127, 1.338).to_nvector();
+    /// let d = Sphere::EARTH.rhumb_destination(p, Angle::from_degrees(116.7), Length::from_kilometres(40.31));
+    ///
+    /// assert_eq!(LatLong::from_degrees(50.96411, 1.85313), LatLong::from_nvector(d).round_d5());
+    /// ```
+    pub fn rhumb_destination(&self, p0: NVector, bearing: Angle, distance: Length) -> NVector {
+        let ll0 = LatLong::from_nvector(p0);
+        let angle = self.distance_to_angle(distance).as_radians();
+        let (sin_b, cos_b) = bearing.as_radians().sin_cos();
+        let lat1 = ll0.latitude().as_radians();
+        let mut lat2 = lat1 + angle * cos_b;
+        let mut lon1 = ll0.longitude().as_radians();
+        // past a pole: reflect onto the opposite meridian.
+        if lat2.abs() > FRAC_PI_2 {
+            lat2 = lat2.signum() * PI - lat2;
+            lon1 += PI;
+        }
+        let d_psi =
+            isometric_latitude(Angle::from_radians(lat2)) - isometric_latitude(ll0.latitude());
+        let q = if d_psi.abs() > EAST_WEST_MAX_DIFF {
+            (lat2 - lat1) / d_psi
+        } else {
+            lat1.cos()
+        };
+        let d_lon = if q == 0.0 { 0.0 } else { angle * sin_b / q };
+        let lon2 = wrap(lon1 + d_lon);
+        LatLong::new(Angle::from_radians(lat2), Angle::from_radians(lon2)).to_nvector()
+    }
+}
+
+/// Returns the difference of latitude, the (shortest) difference of longitude and the ratio of the difference of
+/// latitude to the difference of isometric latitude (all in radians) from p1 to p2.
+fn rhumb_deltas(p1: NVector, p2: NVector) -> (f64, f64, f64) {
+    let ll1 = LatLong::from_nvector(p1);
+    let ll2 = LatLong::from_nvector(p2);
+    let lat1 = ll1.latitude().as_radians();
+    let d_lat = ll2.latitude().as_radians() - lat1;
+    let d_psi = isometric_latitude(ll2.latitude()) - isometric_latitude(ll1.latitude());
+    // east-west legs: the ratio tends to cos(latitude).
+    let q = if d_psi.abs() > EAST_WEST_MAX_DIFF {
+        d_lat / d_psi
+    } else {
+        lat1.cos()
+    };
+    (d_lat, shortest_longitude_diff(ll1, ll2), q)
+}
+
+/// Returns the isometric latitude (meridional part on the unit sphere) of the given latitude: unbounded towards
+/// poles.
+fn isometric_latitude(lat: Angle) -> f64 {
+    (FRAC_PI_4 + lat.as_radians() / 2.0).tan().ln()
+}
+
+/// Returns the difference of longitude (in radians) from ll1 to ll2 in [-PI, PI).
+fn shortest_longitude_diff(ll1: LatLong, ll2: LatLong) -> f64 {
+    wrap(ll2.longitude().as_radians() - ll1.longitude().as_radians())
+}
+
+/// Wraps the given angle (in radians) to [-PI, PI).
+fn wrap(radians: f64) -> f64 {
+    (radians + PI).rem_euclid(2.0 * PI) - PI
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64::consts::PI;
+
+    use crate::{positions::assert_nv_eq_d7, spherical::Sphere, Angle, LatLong, Length, NVector};
+
+    // rhumb_distance
+
+    #[test]
+    fn rhumb_distance_same_position() {
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        assert_eq!(Length::ZERO, Sphere::EARTH.rhumb_distance(p, p));
+    }
+
+    #[test]
+    fn rhumb_distance_short_leg() {
+        // ~40 km leg: rhumb line and great circle are within 10 centimetres.
+        let p1 = NVector::from_lat_long_degrees(51.127, 1.338);
+        let p2 = NVector::from_lat_long_degrees(50.964, 1.853);
+        let rhumb = Sphere::EARTH.rhumb_distance(p1, p2);
+        let gc = Sphere::EARTH.distance(p1, p2);
+        assert!(rhumb >= gc);
+        assert!((rhumb - gc).as_metres() < 0.1);
+    }
+
+    #[test]
+    fn rhumb_distance_east_west_high_latitude() {
+        let p1 = NVector::from_lat_long_degrees(60.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(60.0, 90.0);
+        let rhumb = Sphere::EARTH.rhumb_distance(p1, p2);
+        // along the parallel.
+        let expected = Sphere::EARTH.radius().as_metres() * 60.0f64.to_radians().cos() * PI / 2.0;
+        assert!((rhumb.as_metres() - expected).abs() < 1e-6);
+        // substantially longer than the great circle.
+        let gc = Sphere::EARTH.distance(p1, p2);
+        assert!(rhumb.as_metres() > 1.05 * gc.as_metres());
+    }
+
+    #[test]
+    fn rhumb_distance_meridian() {
+        let p1 = NVector::from_lat_long_degrees(-10.0, 30.0);
+        let p2 = NVector::from_lat_long_degrees(50.0, 30.0);
+        assert_eq!(
+            Sphere::EARTH.distance(p1, p2).round_mm(),
+            Sphere::EARTH.rhumb_distance(p1, p2).round_mm()
+        );
+    }
+
+    #[test]
+    fn rhumb_distance_antimeridian() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 179.0);
+        let p2 = NVector::from_lat_long_degrees(11.0, -179.0);
+        let d = Sphere::EARTH.rhumb_distance(p1, p2);
+        // shorter route: 2 degrees of longitude, not 358.
+        assert!(d.as_kilometres() < 250.0);
+        assert_eq!(d, Sphere::EARTH.rhumb_distance(p2, p1));
+    }
+
+    // rhumb_bearing
+
+    #[test]
+    fn rhumb_bearing_same_position() {
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        assert_eq!(Angle::ZERO, Sphere::rhumb_bearing(p, p));
+    }
+
+    #[test]
+    fn rhumb_bearing_cardinal() {
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        for (lat, lon, b) in [
+            (50.0, 45.0, 0.0),
+            (45.0, 50.0, 90.0),
+            (40.0, 45.0, 180.0),
+            (45.0, 40.0, 270.0),
+        ] {
+            assert_eq!(
+                Angle::from_degrees(b),
+                Sphere::rhumb_bearing(p, NVector::from_lat_long_degrees(lat, lon)).round_d7()
+            );
+        }
+    }
+
+    #[test]
+    fn rhumb_bearing_antimeridian() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 179.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, -179.0);
+        assert_eq!(
+            Angle::from_degrees(90.0),
+            Sphere::rhumb_bearing(p1, p2).round_d7()
+        );
+        assert_eq!(
+            Angle::from_degrees(270.0),
+            Sphere::rhumb_bearing(p2, p1).round_d7()
+        );
+    }
+
+    // rhumb_destination
+
+    #[test]
+    fn rhumb_destination_zero_distance() {
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        assert_nv_eq_d7(
+            p,
+            Sphere::EARTH.rhumb_destination(p, Angle::from_degrees(33.0), Length::ZERO),
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_east_west() {
+        let p = NVector::from_lat_long_degrees(60.0, 0.0);
+        let d = Length::from_metres(
+            Sphere::EARTH.radius().as_metres() * 60.0f64.to_radians().cos() * PI / 2.0,
+        );
+        assert_nv_eq_d7(
+            NVector::from_lat_long_degrees(60.0, 90.0),
+            Sphere::EARTH.rhumb_destination(p, Angle::from_degrees(90.0), d),
+        );
+        assert_nv_eq_d7(
+            NVector::from_lat_long_degrees(60.0, -90.0),
+            Sphere::EARTH.rhumb_destination(p, Angle::from_degrees(270.0), d),
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_antimeridian() {
+        let p = NVector::from_lat_long_degrees(0.0, 179.0);
+        let d = Sphere::EARTH.rhumb_destination(
+            p,
+            Angle::from_degrees(90.0),
+            Angle::from_degrees(2.0).arc_length(Sphere::EARTH.radius()),
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, -179.0),
+            LatLong::from_nvector(d).round_d7()
+        );
+    }
+
+    #[test]
+    fn rhumb_destination_over_pole() {
+        let p = NVector::from_lat_long_degrees(80.0, 10.0);
+        let d = Sphere::EARTH.rhumb_destination(
+            p,
+            Angle::ZERO,
+            Angle::from_degrees(20.0).arc_length(Sphere::EARTH.radius()),
+        );
+        assert_nv_eq_d7(NVector::from_lat_long_degrees(80.0, -170.0), d);
+    }
+
+    #[test]
+    fn rhumb_destination_round_trip() {
+        let p1 = NVector::from_lat_long_degrees(50.3667, -4.1340);
+        for p2 in [
+            NVector::from_lat_long_degrees(42.3511, -71.0408),
+            NVector::from_lat_long_degrees(-33.8688, 151.2093),
+            NVector::from_lat_long_degrees(50.3667, 20.0),
+            NVector::from_lat_long_degrees(-10.0, -4.1340),
+        ] {
+            let b = Sphere::rhumb_bearing(p1, p2);
+            let d = Sphere::EARTH.rhumb_distance(p1, p2);
+            assert_nv_eq_d7(p2, Sphere::EARTH.rhumb_destination(p1, b, d));
+        }
+    }
+}
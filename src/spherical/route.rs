@@ -0,0 +1,568 @@
+use crate::{Angle, LatLong, Length, NVector, NavigationError};
+
+use super::Sphere;
+
+/// A route: an ordered sequence of waypoints joined by minor arcs, with the angular length of each leg
+/// precomputed so that lengths and positions along the route are cheap to query repeatedly - see also
+/// [Path](crate::spherical::Path).
+///
+/// A [Path](crate::spherical::Path) is a plain sequence of positions which computes everything on demand and
+/// produces new paths (e.g. densified or simplified); a route is immutable and caches the n-vector of each
+/// waypoint and the cumulative length to each waypoint, so that looking up a position by distance is a binary
+/// search rather than a walk along every leg. Build a route from a path with `Route::new(path.into_inner())`.
+///
+/// Lengths are computed on a sphere of the given radius (e.g. [Sphere::EARTH](crate::spherical::Sphere::EARTH)
+/// radius). Duplicate consecutive waypoints make zero-length legs which are skipped when looking up a position.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{LatLong, Length};
+/// use jord::spherical::{Route, Sphere};
+///
+/// let route = Route::new(vec![
+///     LatLong::from_degrees(0.0, 0.0),
+///     LatLong::from_degrees(0.0, 1.0),
+///     LatLong::from_degrees(1.0, 1.0),
+/// ]);
+/// let radius = Sphere::EARTH.radius();
+///
+/// assert_eq!(Length::from_metres(222_389.881), route.total_length(radius).round_mm());
+///
+/// // halfway along the second leg.
+/// let d = route.cumulative_lengths(radius)[1] + route.total_length(radius) / 4.0;
+/// assert_eq!(Some(1), route.leg_containing(d, radius));
+/// assert_eq!(
+///     Some(LatLong::from_degrees(0.5, 1.0)),
+///     route.position_at(d, radius).map(|p| p.round_d7())
+/// );
+/// ```
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Route {
+    waypoints: Vec<LatLong>,
+    nvectors: Vec<NVector>,
+    // angular length from the first waypoint to each waypoint.
+    cumulative_angles: Vec<f64>,
+}
+
+impl Route {
+    /// Creates a new route from the given waypoints.
+    pub fn new(waypoints: Vec<LatLong>) -> Self {
+        let nvectors: Vec<NVector> = waypoints.iter().map(|p| p.to_nvector()).collect();
+        let mut cumulative_angles = Vec::with_capacity(nvectors.len());
+        let mut total = 0.0;
+        for (i, v) in nvectors.iter().enumerate() {
+            if i > 0 {
+                total += Sphere::angle(nvectors[i - 1], *v).as_radians();
+            }
+            cumulative_angles.push(total);
+        }
+        Route {
+            waypoints,
+            nvectors,
+            cumulative_angles,
+        }
+    }
+
+    /// Returns the waypoints of this route.
+    #[inline]
+    pub fn waypoints(&self) -> &[LatLong] {
+        &self.waypoints
+    }
+
+    /// Returns the total length of this route on a sphere of the given radius: zero if this route has less than
+    /// 2 waypoints.
+    pub fn total_length(&self, radius: Length) -> Length {
+        self.cumulative_angles
+            .last()
+            .map_or(Length::ZERO, |a| Angle::from_radians(*a).arc_length(radius))
+    }
+
+    /// Returns the distance along this route from the first waypoint to each waypoint on a sphere of the given
+    /// radius: the first element is zero and the last is the [total length](crate::spherical::Route::total_length).
+    pub fn cumulative_lengths(&self, radius: Length) -> Vec<Length> {
+        self.cumulative_angles
+            .iter()
+            .map(|a| Angle::from_radians(*a).arc_length(radius))
+            .collect()
+    }
+
+    /// Returns the index of the leg - i.e. the leg from waypoint `i` to waypoint `i + 1` - containing the position
+    /// at the given distance along this route on a sphere of the given radius. A waypoint between 2 legs belongs
+    /// to the leg starting at the waypoint, except the last waypoint which belongs to the last leg. Zero-length
+    /// legs are never returned unless all legs are zero-length.
+    ///
+    /// Returns [None] if the distance is negative or greater than the total length, or if this route has less
+    /// than 2 waypoints.
+    pub fn leg_containing(&self, distance: Length, radius: Length) -> Option<usize> {
+        if self.waypoints.len() < 2 {
+            return None;
+        }
+        let d = self.central_angle(distance, radius)?;
+        let total = self.cumulative_angles[self.cumulative_angles.len() - 1];
+        if d == total {
+            // last leg which is not zero-length.
+            let end = self.cumulative_angles.partition_point(|a| *a < total);
+            Some(end.saturating_sub(1))
+        } else {
+            // first waypoint further than d: the leg before it is not zero-length.
+            let end = self.cumulative_angles.partition_point(|a| *a <= d);
+            Some(end - 1)
+        }
+    }
+
+    /// Computes the position at the given distance along this route on a sphere of the given radius, by
+    /// interpolating along the [leg containing](crate::spherical::Route::leg_containing) the distance.
+    ///
+    /// Returns [None] if the distance is negative or greater than the total length, or if this route is empty. The
+    /// position at distance zero along a route of a single waypoint is that waypoint.
+    pub fn position_at(&self, distance: Length, radius: Length) -> Option<LatLong> {
+        if self.waypoints.len() == 1 && distance == Length::ZERO {
+            return Some(self.waypoints[0]);
+        }
+        let leg = self.leg_containing(distance, radius)?;
+        let d = self.central_angle(distance, radius)?;
+        let start = self.cumulative_angles[leg];
+        let along = d - start;
+        if along <= 0.0 {
+            Some(self.waypoints[leg])
+        } else if d >= self.cumulative_angles[leg + 1] {
+            Some(self.waypoints[leg + 1])
+        } else {
+            let v = Sphere::position_on_great_circle(
+                self.nvectors[leg],
+                self.nvectors[leg + 1],
+                Angle::from_radians(along),
+            );
+            Some(LatLong::from_nvector(v))
+        }
+    }
+
+    /// Converts the given distance along this route to a central angle, or [None] if the distance is negative or
+    /// greater than the total length. The distance is compared to the total length in metres and the total length
+    /// maps to the total angle, so that it always maps to the last waypoint regardless of rounding in the
+    /// conversion to and from radians.
+    fn central_angle(&self, distance: Length, radius: Length) -> Option<f64> {
+        let total = *self.cumulative_angles.last()?;
+        let total_length = self.total_length(radius);
+        if distance < Length::ZERO || distance > total_length {
+            None
+        } else if distance == total_length {
+            Some(total)
+        } else {
+            Some(distance.to_central_angle(radius).as_radians().min(total))
+        }
+    }
+
+    /// Resamples this route on a sphere of the given radius: returns the positions every `spacing` along this
+    /// route starting at the first waypoint, followed by the last waypoint. The distance left over at the end of a
+    /// leg is carried over to the next leg, so the positions are evenly spaced along the route regardless of the
+    /// waypoints; only the distance between the last 2 positions may be shorter than the spacing. If the spacing
+    /// is greater than the total length, only the first and last waypoints are returned.
+    ///
+    /// Returns [OutOfRange](crate::NavigationError::OutOfRange) if the spacing or the radius is not strictly
+    /// positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    /// use jord::spherical::{Route, Sphere};
+    ///
+    /// let route = Route::new(vec![
+    ///     LatLong::from_degrees(0.0, 0.0),
+    ///     LatLong::from_degrees(0.0, 1.0),
+    ///     LatLong::from_degrees(0.0, 2.5),
+    /// ]);
+    /// let radius = Sphere::EARTH.radius();
+    /// let degree = route.total_length(radius) / 2.5;
+    ///
+    /// let positions: Vec<LatLong> = route
+    ///     .resample(degree * 0.75, radius)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|p| p.round_d7())
+    ///     .collect();
+    /// assert_eq!(
+    ///     vec![
+    ///         LatLong::from_degrees(0.0, 0.0),
+    ///         LatLong::from_degrees(0.0, 0.75),
+    ///         LatLong::from_degrees(0.0, 1.5),
+    ///         LatLong::from_degrees(0.0, 2.25),
+    ///         LatLong::from_degrees(0.0, 2.5),
+    ///     ],
+    ///     positions
+    /// );
+    /// ```
+    pub fn resample(
+        &self,
+        spacing: Length,
+        radius: Length,
+    ) -> Result<Vec<LatLong>, NavigationError> {
+        if spacing.as_metres().is_nan()
+            || spacing <= Length::ZERO
+            || radius.as_metres().is_nan()
+            || radius <= Length::ZERO
+        {
+            return Err(NavigationError::OutOfRange);
+        }
+        let n = self.waypoints.len();
+        if n < 2 {
+            return Ok(self.waypoints.clone());
+        }
+        let step = spacing.to_central_angle(radius).as_radians();
+        let total = self.cumulative_angles[n - 1];
+        let mut res = vec![self.waypoints[0]];
+        // next sample at k * step (rather than accumulating the step) to avoid drift over long routes.
+        let mut k = 1.0;
+        let mut leg = 0;
+        while k * step < total {
+            let d = k * step;
+            while self.cumulative_angles[leg + 1] <= d {
+                leg += 1;
+            }
+            let v = Sphere::position_on_great_circle(
+                self.nvectors[leg],
+                self.nvectors[leg + 1],
+                Angle::from_radians(d - self.cumulative_angles[leg]),
+            );
+            res.push(LatLong::from_nvector(v));
+            k += 1.0;
+        }
+        res.push(self.waypoints[n - 1]);
+        Ok(res)
+    }
+}
+
+impl From<Vec<LatLong>> for Route {
+    fn from(waypoints: Vec<LatLong>) -> Self {
+        Route::new(waypoints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        spherical::{Route, Sphere},
+        LatLong, Length, NavigationError,
+    };
+
+    fn radius() -> Length {
+        Length::from_metres(6_371_000.0)
+    }
+
+    // length of 1 degree of a great circle.
+    fn degree() -> Length {
+        radius() * PI / 180.0
+    }
+
+    fn equator_route() -> Route {
+        Route::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 1.0),
+            LatLong::from_degrees(0.0, 3.0),
+            LatLong::from_degrees(0.0, 6.0),
+        ])
+    }
+
+    #[test]
+    fn lengths() {
+        let route = equator_route();
+        assert_eq!(
+            (degree() * 6.0).round_mm(),
+            route.total_length(radius()).round_mm()
+        );
+        let cumulative: Vec<Length> = route
+            .cumulative_lengths(radius())
+            .iter()
+            .map(|l| l.round_mm())
+            .collect();
+        assert_eq!(
+            vec![
+                Length::ZERO,
+                degree().round_mm(),
+                (degree() * 3.0).round_mm(),
+                (degree() * 6.0).round_mm()
+            ],
+            cumulative
+        );
+    }
+
+    #[test]
+    fn leg_containing() {
+        let route = equator_route();
+        let r = radius();
+        assert_eq!(Some(0), route.leg_containing(Length::ZERO, r));
+        assert_eq!(Some(0), route.leg_containing(degree() * 0.5, r));
+        assert_eq!(Some(1), route.leg_containing(degree() * 2.0, r));
+        assert_eq!(Some(2), route.leg_containing(degree() * 5.0, r));
+        // waypoint between 2 legs: leg starting at the waypoint.
+        let cumulative = route.cumulative_lengths(r);
+        assert_eq!(Some(1), route.leg_containing(cumulative[1], r));
+        // end.
+        assert_eq!(Some(2), route.leg_containing(route.total_length(r), r));
+        // out of the route.
+        assert_eq!(None, route.leg_containing(-degree(), r));
+        assert_eq!(None, route.leg_containing(degree() * 7.0, r));
+    }
+
+    #[test]
+    fn position_at() {
+        let route = equator_route();
+        let r = radius();
+        let at = |d: Length| route.position_at(d, r).map(|p| p.round_d7());
+        assert_eq!(Some(LatLong::from_degrees(0.0, 0.0)), at(Length::ZERO));
+        assert_eq!(Some(LatLong::from_degrees(0.0, 0.5)), at(degree() * 0.5));
+        assert_eq!(Some(LatLong::from_degrees(0.0, 2.0)), at(degree() * 2.0));
+        assert_eq!(Some(LatLong::from_degrees(0.0, 4.5)), at(degree() * 4.5));
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, 6.0)),
+            at(route.total_length(r))
+        );
+        assert_eq!(None, at(degree() * 6.5));
+        assert_eq!(None, at(-degree()));
+    }
+
+    #[test]
+    fn position_at_total_length() {
+        // xorshift: deterministic pseudo-random routes.
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let r = radius();
+        for _ in 0..1000 {
+            let n = 2 + (next() * 4.0) as usize;
+            let ps: Vec<LatLong> = (0..n)
+                .map(|_| LatLong::from_degrees(next() * 180.0 - 90.0, next() * 360.0 - 180.0))
+                .collect();
+            let route = Route::new(ps.clone());
+            let total = route.total_length(r);
+            assert_eq!(Some(ps[n - 1]), route.position_at(total, r));
+            assert!(route.leg_containing(total, r).is_some());
+        }
+        let route = Route::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(10.0, 0.137),
+        ]);
+        assert_eq!(
+            Some(LatLong::from_degrees(10.0, 0.137)),
+            route.position_at(route.total_length(r), r)
+        );
+    }
+
+    #[test]
+    fn duplicate_waypoints() {
+        let route = Route::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 2.0),
+            LatLong::from_degrees(0.0, 2.0),
+            LatLong::from_degrees(0.0, 2.0),
+            LatLong::from_degrees(0.0, 3.0),
+            LatLong::from_degrees(0.0, 3.0),
+        ]);
+        let r = radius();
+        assert_eq!(
+            (degree() * 3.0).round_mm(),
+            route.total_length(r).round_mm()
+        );
+        let cumulative = route.cumulative_lengths(r);
+        assert_eq!(7, cumulative.len());
+        assert_eq!(cumulative[0], cumulative[1]);
+        assert_eq!(cumulative[2], cumulative[4]);
+        // zero-length legs are skipped.
+        assert_eq!(Some(1), route.leg_containing(Length::ZERO, r));
+        assert_eq!(Some(4), route.leg_containing(cumulative[2], r));
+        assert_eq!(Some(4), route.leg_containing(route.total_length(r), r));
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, 1.0)),
+            route.position_at(degree(), r).map(|p| p.round_d7())
+        );
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, 2.5)),
+            route.position_at(degree() * 2.5, r).map(|p| p.round_d7())
+        );
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, 3.0)),
+            route.position_at(route.total_length(r), r)
+        );
+    }
+
+    #[test]
+    fn antimeridian() {
+        let route = Route::new(vec![
+            LatLong::from_degrees(0.0, 178.0),
+            LatLong::from_degrees(0.0, -179.0),
+            LatLong::from_degrees(2.0, -179.0),
+        ]);
+        let r = radius();
+        assert_eq!(
+            (degree() * 5.0).round_mm(),
+            route.total_length(r).round_mm()
+        );
+        assert_eq!(
+            (degree() * 3.0).round_mm(),
+            route.cumulative_lengths(r)[1].round_mm()
+        );
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, 180.0)),
+            route.position_at(degree() * 2.0, r).map(|p| p.round_d7())
+        );
+        assert_eq!(
+            Some(LatLong::from_degrees(0.0, -179.5)),
+            route.position_at(degree() * 2.5, r).map(|p| p.round_d7())
+        );
+        assert_eq!(
+            Some(LatLong::from_degrees(1.0, -179.0)),
+            route.position_at(degree() * 4.0, r).map(|p| p.round_d7())
+        );
+    }
+
+    // resample
+
+    #[test]
+    fn resample_uniform_spacing_through_waypoints() {
+        // legs along the equator which are not multiples of the spacing.
+        let route = Route::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 0.3),
+            LatLong::from_degrees(0.0, 0.35),
+            LatLong::from_degrees(0.0, 0.35),
+            LatLong::from_degrees(0.0, 1.7),
+            LatLong::from_degrees(0.0, 2.0),
+        ]);
+        let r = radius();
+        let spacing = Length::from_kilometres(7.0);
+        let positions = route.resample(spacing, r).unwrap();
+        // 2 degrees is ~ 222.4 km.
+        assert_eq!(33, positions.len());
+        assert_eq!(LatLong::from_degrees(0.0, 0.0), positions[0]);
+        assert_eq!(LatLong::from_degrees(0.0, 2.0), positions[32]);
+        let spacings: Vec<Length> = positions
+            .windows(2)
+            .map(|w| {
+                let (a, b) = (w[0].to_nvector(), w[1].to_nvector());
+                Sphere::angle(a, b).arc_length(r)
+            })
+            .collect();
+        for s in &spacings[..spacings.len() - 1] {
+            assert_eq!(spacing.round_mm(), s.round_mm());
+        }
+        let last = spacings[spacings.len() - 1];
+        assert!(last > Length::ZERO && last < spacing);
+    }
+
+    #[test]
+    fn resample_with_bend() {
+        let route = Route::new(vec![
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_degrees(0.0, 1.0),
+            LatLong::from_degrees(1.0, 1.0),
+        ]);
+        let r = radius();
+        let positions: Vec<LatLong> = route
+            .resample(degree() * 0.4, r)
+            .unwrap()
+            .iter()
+            .map(|p| p.round_d7())
+            .collect();
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 0.0),
+                LatLong::from_degrees(0.0, 0.4),
+                LatLong::from_degrees(0.0, 0.8),
+                LatLong::from_degrees(0.2, 1.0),
+                LatLong::from_degrees(0.6, 1.0),
+                LatLong::from_degrees(1.0, 1.0),
+            ],
+            positions
+        );
+    }
+
+    #[test]
+    fn resample_spacing_larger_than_route() {
+        let route = equator_route();
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 0.0),
+                LatLong::from_degrees(0.0, 6.0)
+            ],
+            route.resample(degree() * 10.0, radius()).unwrap()
+        );
+    }
+
+    #[test]
+    fn resample_invalid_spacing() {
+        let route = equator_route();
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            route.resample(Length::ZERO, radius())
+        );
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            route.resample(-degree(), radius())
+        );
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            route.resample(Length::from_metres(f64::NAN), radius())
+        );
+    }
+
+    #[test]
+    fn resample_invalid_radius() {
+        let route = equator_route();
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            route.resample(degree(), Length::ZERO)
+        );
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            route.resample(degree(), -radius())
+        );
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            route.resample(degree(), Length::from_metres(f64::NAN))
+        );
+    }
+
+    #[test]
+    fn resample_degenerate() {
+        let p = LatLong::from_degrees(10.0, 20.0);
+        assert!(Route::default()
+            .resample(degree(), radius())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            vec![p],
+            Route::new(vec![p]).resample(degree(), radius()).unwrap()
+        );
+        assert_eq!(
+            vec![p, p],
+            Route::new(vec![p, p]).resample(degree(), radius()).unwrap()
+        );
+    }
+
+    #[test]
+    fn degenerate() {
+        let r = radius();
+        let empty = Route::default();
+        assert_eq!(Length::ZERO, empty.total_length(r));
+        assert!(empty.cumulative_lengths(r).is_empty());
+        assert_eq!(None, empty.leg_containing(Length::ZERO, r));
+        assert_eq!(None, empty.position_at(Length::ZERO, r));
+
+        let p = LatLong::from_degrees(10.0, 20.0);
+        let one = Route::new(vec![p]);
+        assert_eq!(Length::ZERO, one.total_length(r));
+        assert_eq!(vec![Length::ZERO], one.cumulative_lengths(r));
+        assert_eq!(None, one.leg_containing(Length::ZERO, r));
+        assert_eq!(Some(p), one.position_at(Length::ZERO, r));
+        assert_eq!(None, one.position_at(degree(), r));
+    }
+}
@@ -371,7 +371,12 @@ impl Loop {
     }
 
     /// Determines whether the **interior** of this loop contains the given point (i.e. excluding points which are
-    /// vertices or on an edge of this loop).
+    /// vertices or on an edge of this loop - see [any_edge_contains_point](crate::spherical::Loop::any_edge_contains_point)
+    /// to test those).
+    ///
+    /// The containment is determined by counting the crossings between the edges of this loop and the minor arc
+    /// joining the given point to a point known to be inside this loop: since this is computed with n-vectors,
+    /// loops spanning the antimeridian or enclosing a pole are supported.
     ///
     /// This function always returns false for [empty](crate::spherical::Loop::is_empty) loops, undefined for [non simple](crate::spherical::Loop::is_simple) loops.
     ///
@@ -660,6 +665,38 @@ pub fn is_loop_clockwise(vs: &[NVector]) -> bool {
     }
 }
 
+/// Ensures that the given vertices are in anti-clockwise order - e.g. as expected for the exterior ring of a
+/// GeoJSON polygon: reverses the order of the vertices if they are in clockwise order (see [is_loop_clockwise]).
+///
+/// The orientation is determined from the sum of the turn angles at each vertex on the sphere and is therefore
+/// not affected by the antimeridian or the poles.
+///
+/// - the loop can be explicity closed (first == last) or opened (first != last)
+/// - the vertices are left unchanged if less than 3 vertices are given
+///
+/// # Examples
+///
+/// ```
+/// use jord::NVector;
+/// use jord::spherical::{ensure_loop_anticlockwise, is_loop_clockwise};
+///
+/// let mut vs = vec![
+///     NVector::from_lat_long_degrees(40.0, 40.0),
+///     NVector::from_lat_long_degrees(10.0, 30.0),
+///     NVector::from_lat_long_degrees(20.0, 20.0),
+/// ];
+/// assert!(is_loop_clockwise(&vs));
+///
+/// ensure_loop_anticlockwise(&mut vs);
+/// assert!(!is_loop_clockwise(&vs));
+/// assert_eq!(NVector::from_lat_long_degrees(20.0, 20.0), vs[0]);
+/// ```
+pub fn ensure_loop_anticlockwise(vs: &mut [NVector]) {
+    if is_loop_clockwise(vs) {
+        vs.reverse();
+    }
+}
+
 #[derive(PartialEq, Clone, Copy, Debug)]
 enum Classification {
     Convex,
@@ -934,7 +971,7 @@ fn vec3_eq(a: Vec3, b: Vec3) -> bool {
 #[cfg(test)]
 mod tests {
     use crate::{
-        spherical::{is_loop_clockwise, Loop, Sphere},
+        spherical::{ensure_loop_anticlockwise, is_loop_clockwise, Loop, Sphere},
         Angle, LatLong, Length, NVector, Vec3,
     };
 
@@ -1007,7 +1044,7 @@ mod tests {
 
     #[test]
     fn new_triangle() {
-        assert_loop_invariants(&vec![
+        assert_loop_invariants(&[
             NVector::from_lat_long_degrees(20.0, 20.0),
             NVector::from_lat_long_degrees(10.0, 30.0),
             NVector::from_lat_long_degrees(40.0, 40.0),
@@ -1016,7 +1053,7 @@ mod tests {
 
     #[test]
     fn new_loop() {
-        assert_loop_invariants(&vec![
+        assert_loop_invariants(&[
             NVector::from_lat_long_degrees(-85.0, 10.0),
             NVector::from_lat_long_degrees(-85.0, 170.0),
             NVector::from_lat_long_degrees(-85.0, -170.0),
@@ -1069,7 +1106,7 @@ mod tests {
         assert_eq!(opened.len(), l2.num_vertices());
         assert_eq!(opened.len(), l3.num_vertices());
 
-        let e_it = if is_loop_clockwise(&opened) {
+        let e_it = if is_loop_clockwise(opened) {
             opened.iter()
         } else {
             rvs.iter()
@@ -1084,14 +1121,14 @@ mod tests {
 
     #[test]
     fn is_convex_concave() {
-        assert_convex(false, &vec![ystad(), hoor(), helsingborg(), kristianstad()]);
+        assert_convex(false, &[ystad(), hoor(), helsingborg(), kristianstad()]);
     }
 
     #[test]
     fn is_convex_concave_collinear_vertices() {
         assert_convex(
             false,
-            &vec![
+            &[
                 NVector::from_lat_long_degrees(10.0, 10.0),
                 NVector::from_lat_long_degrees(11.0, 10.0),
                 NVector::from_lat_long_degrees(12.0, 10.0),
@@ -1104,7 +1141,7 @@ mod tests {
 
     #[test]
     fn is_convex() {
-        assert_convex(true, &vec![ystad(), malmo(), helsingborg(), kristianstad()]);
+        assert_convex(true, &[ystad(), malmo(), helsingborg(), kristianstad()]);
     }
 
     fn assert_convex(e: bool, vs: &[NVector]) {
@@ -1179,7 +1216,7 @@ mod tests {
         let v1 = NVector::from_lat_long_degrees(20.0, 20.0);
         let v2 = NVector::from_lat_long_degrees(10.0, 30.0);
         let v3 = NVector::from_lat_long_degrees(40.0, 40.0);
-        let l = Loop::new(&vec![v1, v2, v3]);
+        let l = Loop::new(&[v1, v2, v3]);
         assert!(l.contains_point(inside));
         assert!(!l.contains_point(antipode));
     }
@@ -1190,7 +1227,7 @@ mod tests {
         let v1 = NVector::from_lat_long_degrees(10.0, 179.0);
         let v2 = NVector::from_lat_long_degrees(10.0, -150.0);
         let v3 = NVector::from_lat_long_degrees(-85.0, -150.0);
-        let l = Loop::new(&vec![v1, v2, v3]);
+        let l = Loop::new(&[v1, v2, v3]);
         assert!(!l.contains_point(position));
     }
 
@@ -1240,6 +1277,67 @@ mod tests {
         assert!(!l.contains_point(NVector::from_lat_long_degrees(90.0, 0.0)));
     }
 
+    #[test]
+    fn contains_point_antimeridian() {
+        let vertices: Vec<NVector> = vec![
+            NVector::from_lat_long_degrees(-20.0, 170.0),
+            NVector::from_lat_long_degrees(-20.0, -170.0),
+            NVector::from_lat_long_degrees(-10.0, -170.0),
+            NVector::from_lat_long_degrees(-10.0, 170.0),
+        ];
+        let l = Loop::new(&vertices);
+        assert!(l.contains_point(NVector::from_lat_long_degrees(-15.0, 180.0)));
+        assert!(l.contains_point(NVector::from_lat_long_degrees(-15.0, -180.0)));
+        assert!(l.contains_point(NVector::from_lat_long_degrees(-15.0, 175.0)));
+        assert!(l.contains_point(NVector::from_lat_long_degrees(-15.0, -175.0)));
+        // between the longitudes of the vertices but the "other way" around the globe.
+        assert!(!l.contains_point(NVector::from_lat_long_degrees(-15.0, 0.0)));
+        assert!(!l.contains_point(NVector::from_lat_long_degrees(-15.0, 165.0)));
+        assert!(!l.contains_point(NVector::from_lat_long_degrees(-15.0, -165.0)));
+        assert!(!l.contains_point(NVector::from_lat_long_degrees(-25.0, 180.0)));
+    }
+
+    #[test]
+    fn contains_point_polar_cap_many_vertices() {
+        let np = NVector::from_lat_long_degrees(90.0, 0.0);
+        let radius = Sphere::EARTH.radius() * Angle::from_degrees(5.0);
+        let mut vertices = Sphere::EARTH.circle_polygon(np, radius, 72);
+        // circle_polygon closes the polygon, loops are implicitly closed.
+        vertices.pop();
+        let l = Loop::new(&vertices);
+        assert!(l.contains_point(np));
+        for lng in [-180.0, -135.0, -90.0, -45.0, 0.0, 45.0, 90.0, 135.0, 180.0] {
+            assert!(l.contains_point(NVector::from_lat_long_degrees(86.0, lng)));
+            assert!(!l.contains_point(NVector::from_lat_long_degrees(84.0, lng)));
+        }
+        assert!(!l.contains_point(NVector::from_lat_long_degrees(-90.0, 0.0)));
+    }
+
+    #[test]
+    fn contains_point_near_edge() {
+        let vertices = vec![
+            NVector::from_lat_long_degrees(45.0, 1.0),
+            NVector::from_lat_long_degrees(45.0, 1.01),
+            NVector::from_lat_long_degrees(45.01, 1.01),
+            NVector::from_lat_long_degrees(45.01, 1.0),
+        ];
+        let l = Loop::new(&vertices);
+        // middle of the east edge (a meridian): 1 mm inside and 1 mm outside.
+        let m = Sphere::midpoint(vertices[1], vertices[2]).unwrap();
+        let inside = Sphere::EARTH.destination_pos(
+            m,
+            Angle::from_degrees(270.0),
+            Length::from_metres(0.001),
+        );
+        let outside =
+            Sphere::EARTH.destination_pos(m, Angle::from_degrees(90.0), Length::from_metres(0.001));
+        assert!(l.contains_point(inside));
+        assert!(!l.contains_point(outside));
+        // on the edge: not in the interior.
+        assert!(!l.contains_point(m));
+        assert!(l.any_edge_contains_point(m));
+    }
+
     #[test]
     fn contains_point_concave_polygon() {
         let vertices: Vec<NVector> = vec![malmo(), ystad(), kristianstad(), helsingborg(), lund()];
@@ -1280,7 +1378,7 @@ mod tests {
         // p is one arc microsecond east of v2: detected on both (v1, v2) and (v2, v3).
         let p = NVector::from_lat_long_degrees(0.0, one_mas);
 
-        let l = Loop::new(&vec![v1, v2, v3]);
+        let l = Loop::new(&[v1, v2, v3]);
 
         assert!(!l.contains_point(p));
         assert!(l.any_edge_contains_point(p));
@@ -1337,7 +1435,7 @@ mod tests {
 
     #[test]
     fn is_pos_within_distance_to_boundary_vertex() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(0.0, 0.0),
             NVector::from_lat_long_degrees(0.0, 10.0),
             NVector::from_lat_long_degrees(10.0, 10.0),
@@ -1351,19 +1449,17 @@ mod tests {
             Angle::from_degrees(315.0),
         ];
 
-        let mut i = 0;
-        for v in l.iter_vertices() {
+        for (i, v) in l.iter_vertices().enumerate() {
             let p = Sphere::EARTH.destination_pos(*v, bearings[i], Length::from_metres(10.0));
             let max = Sphere::angle(*v, p);
             assert!(l.is_pos_within_distance_to_boundary(p, max));
             assert!(!l.is_pos_within_distance_to_boundary(p, max - Angle::from_degrees(0.0001)));
-            i += 1;
         }
     }
 
     #[test]
     fn is_pos_within_distance_to_boundary_edge() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(0.0, 0.0),
             NVector::from_lat_long_degrees(0.0, 10.0),
             NVector::from_lat_long_degrees(10.0, 10.0),
@@ -1377,14 +1473,12 @@ mod tests {
             Angle::from_degrees(90.0),
         ];
 
-        let mut i = 0;
-        for e in l.iter_edges() {
-            let m = Sphere::mean_position(&vec![e.start(), e.end()]).unwrap();
+        for (i, e) in l.iter_edges().enumerate() {
+            let m = Sphere::mean_position(&[e.start(), e.end()]).unwrap();
             let p = Sphere::EARTH.destination_pos(m, bearings[i], Length::from_metres(10.0));
             let max = Sphere::angle(m, p);
             assert!(l.is_pos_within_distance_to_boundary(p, max));
             assert!(!l.is_pos_within_distance_to_boundary(p, max - Angle::from_degrees(0.0001)));
-            i += 1;
         }
     }
 
@@ -1399,7 +1493,7 @@ mod tests {
         let v4 = NVector::from_lat_long_degrees(20.0, 20.0);
 
         let expected = vec![(v0, v1, v2), (v4, v0, v2), (v2, v3, v4)];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4]);
     }
 
     #[test]
@@ -1419,12 +1513,12 @@ mod tests {
             (v6, v0, v4),
             (v4, v5, v6),
         ];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4, v5, v6]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4, v5, v6]);
     }
 
     #[test]
     fn triangulate_convex_6() {
-        let vs = &vec![
+        let vs = &[
             bangui(),
             juba(),
             narobi(),
@@ -1439,7 +1533,7 @@ mod tests {
             (kinshasa(), narobi(), dar_es_salaam()),
             (dar_es_salaam(), harare(), kinshasa()),
         ];
-        assert_loop_triangulation(&expected, &vs);
+        assert_loop_triangulation(&expected, vs);
     }
 
     #[test]
@@ -1488,7 +1582,7 @@ mod tests {
             (v1, v7, v8),
             (v1, v8, v9),
         ];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
     }
 
     #[test]
@@ -1514,12 +1608,12 @@ mod tests {
             (v9, v5, v7),
             (v7, v8, v9),
         ];
-        assert_loop_triangulation(&expected, &vec![v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
+        assert_loop_triangulation(&expected, &[v0, v1, v2, v3, v4, v5, v6, v7, v8, v9]);
     }
 
     #[test]
     fn triangulate_self_intersecting() {
-        let l = Loop::new(&vec![
+        let l = Loop::new(&[
             NVector::from_lat_long_degrees(-2.0, -2.0),
             NVector::from_lat_long_degrees(2.0, -2.0),
             NVector::from_lat_long_degrees(3.0, 0.0),
@@ -1548,8 +1642,106 @@ mod tests {
         );
     }
 
+    // is_loop_clockwise
+
+    #[test]
+    fn is_loop_clockwise_less_than_3_vertices() {
+        assert!(!is_loop_clockwise(&[]));
+        assert!(!is_loop_clockwise(&[bangui(), harare()]));
+    }
+
+    #[test]
+    fn is_loop_clockwise_clockwise() {
+        assert!(is_loop_clockwise(&[bangui(), juba(), narobi(), kinshasa()]));
+        assert!(is_loop_clockwise(&[bangui(), juba(), harare()]));
+    }
+
+    #[test]
+    fn is_loop_clockwise_anticlockwise() {
+        assert!(!is_loop_clockwise(&[
+            kinshasa(),
+            narobi(),
+            juba(),
+            bangui()
+        ]));
+        assert!(!is_loop_clockwise(&[harare(), juba(), bangui()]));
+    }
+
+    #[test]
+    fn is_loop_clockwise_antimeridian() {
+        let vs = [
+            NVector::from_lat_long_degrees(10.0, 170.0),
+            NVector::from_lat_long_degrees(10.0, -170.0),
+            NVector::from_lat_long_degrees(-10.0, -170.0),
+            NVector::from_lat_long_degrees(-10.0, 170.0),
+        ];
+        assert!(is_loop_clockwise(&vs));
+        let mut rvs = vs.to_vec();
+        rvs.reverse();
+        assert!(!is_loop_clockwise(&rvs));
+    }
+
+    #[test]
+    fn is_loop_clockwise_around_pole() {
+        // anti-clockwise seen from above the north pole: increasing longitudes.
+        let vs = [
+            NVector::from_lat_long_degrees(80.0, 0.0),
+            NVector::from_lat_long_degrees(80.0, 90.0),
+            NVector::from_lat_long_degrees(80.0, 180.0),
+            NVector::from_lat_long_degrees(80.0, -90.0),
+        ];
+        assert!(!is_loop_clockwise(&vs));
+        let mut rvs = vs.to_vec();
+        rvs.reverse();
+        assert!(is_loop_clockwise(&rvs));
+    }
+
+    // ensure_loop_anticlockwise
+
+    #[test]
+    fn ensure_loop_anticlockwise_clockwise() {
+        let mut vs = vec![bangui(), juba(), narobi(), kinshasa()];
+        ensure_loop_anticlockwise(&mut vs);
+        assert_eq!(vec![kinshasa(), narobi(), juba(), bangui()], vs);
+    }
+
+    #[test]
+    fn ensure_loop_anticlockwise_anticlockwise() {
+        let mut vs = vec![kinshasa(), narobi(), juba(), bangui()];
+        ensure_loop_anticlockwise(&mut vs);
+        assert_eq!(vec![kinshasa(), narobi(), juba(), bangui()], vs);
+    }
+
+    #[test]
+    fn ensure_loop_anticlockwise_closed() {
+        let mut vs = vec![bangui(), juba(), narobi(), kinshasa(), bangui()];
+        ensure_loop_anticlockwise(&mut vs);
+        assert_eq!(vec![bangui(), kinshasa(), narobi(), juba(), bangui()], vs);
+        assert!(!is_loop_clockwise(&vs));
+    }
+
+    #[test]
+    fn ensure_loop_anticlockwise_antimeridian() {
+        let mut vs = vec![
+            NVector::from_lat_long_degrees(10.0, 170.0),
+            NVector::from_lat_long_degrees(10.0, -170.0),
+            NVector::from_lat_long_degrees(-10.0, -170.0),
+            NVector::from_lat_long_degrees(-10.0, 170.0),
+        ];
+        ensure_loop_anticlockwise(&mut vs);
+        assert!(!is_loop_clockwise(&vs));
+        assert_eq!(NVector::from_lat_long_degrees(-10.0, 170.0), vs[0]);
+    }
+
+    #[test]
+    fn ensure_loop_anticlockwise_less_than_3_vertices() {
+        let mut vs = vec![bangui(), juba()];
+        ensure_loop_anticlockwise(&mut vs);
+        assert_eq!(vec![bangui(), juba()], vs);
+    }
+
     fn assert_loop_triangulation(e: &[(NVector, NVector, NVector)], vs: &[NVector]) {
-        assert_triangulation(e, &Loop::new(&vs));
+        assert_triangulation(e, &Loop::new(vs));
         let mut rvs = vs.to_vec();
         rvs.reverse();
         assert_triangulation(e, &Loop::new(&rvs));
@@ -1,13 +1,16 @@
 use std::{f64::consts::PI, time::Duration};
 
 use crate::{
-    numbers::eq_zero, surface::Surface, Angle, Cartesian3DVector, GeocentricPos, GeodeticPos,
-    LatLong, Length, Mat33, NVector, Speed, Vec3, Vehicle,
+    numbers::{eq_zero, gte},
+    surface::Surface,
+    Angle, Area, Cartesian3DVector, GeocentricPos, GeodeticPos, LatLong, Length, Mat33, NVector,
+    NavigationError, Speed, Vec3, Vehicle,
 };
 
 use super::{
-    base::{angle_radians_between, easting, exact_side},
-    GreatCircle, MinorArc,
+    base::{angle_radians_between, easting, exact_side, weighted_mean_vec3},
+    great_circle::smallest_eigenvector,
+    Cpa, GreatCircle, Intercept, Loop, MinorArc, Trilateration,
 };
 
 /// A sphere; for most use cases, a sphere is an acceptable approximation of the figure of a cellestial body (e.g. Earth).
@@ -61,13 +64,42 @@ impl Sphere {
     /// assert_eq!(Length::from_metres(62331.501), d.round_mm());
     /// ```
     pub fn along_track_distance(&self, p: NVector, ma: MinorArc) -> Length {
+        Self::along_track_angle(p, ma) * self.radius
+    }
+
+    /// Computes the signed angle from the start of the given minor arc to the projection of the given position on
+    /// the great circle of the minor arc: i.e. the [along-track distance](crate::spherical::Sphere::along_track_distance)
+    /// on the unit sphere. The angle is negative if the projection falls behind the start of the minor arc and is
+    /// greater than the length of the minor arc if the projection falls beyond its end.
+    ///
+    /// Together with the [cross-track angle](crate::spherical::Sphere::cross_track_angle), it reconstructs the angle
+    /// between the start of the minor arc and the given position: `cos(a) = cos(along-track) * cos(cross-track)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{MinorArc, Sphere};
+    ///
+    /// let ma = MinorArc::checked_new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0)
+    /// ).unwrap();
+    ///
+    /// let beyond_end = NVector::from_lat_long_degrees(1.0, 15.0);
+    /// assert_eq!(Angle::from_degrees(15.0), Sphere::along_track_angle(beyond_end, ma).round_d7());
+    ///
+    /// let behind_start = NVector::from_lat_long_degrees(-1.0, -5.0);
+    /// assert_eq!(Angle::from_degrees(-5.0), Sphere::along_track_angle(behind_start, ma).round_d7());
+    /// ```
+    pub fn along_track_angle(p: NVector, ma: MinorArc) -> Angle {
         let normal = ma.normal();
         let angle: f64 = angle_radians_between(
             ma.start().as_vec3(),
             normal.cross_prod(p.as_vec3()).cross_prod(normal),
             Some(normal),
         );
-        angle * self.radius
+        Angle::from_radians(angle)
     }
 
     /// Computes the angle between the two given positions, which is also equal to the distance
@@ -93,6 +125,12 @@ impl Sphere {
     /// Returns a negative length if the position is left of great circle, positive length if the position is right
     /// of great circle; the orientation of the great circle is therefore important.
     ///
+    /// Left and right are relative to the direction of travel along the great circle: from the first position
+    /// towards the second position for a great circle created with [GreatCircle::new], or along the given
+    /// bearing for a great circle created with [GreatCircle::from_heading] - i.e. for a northbound path, a position
+    /// east of the path is on the right (positive distance) and a position west of the path is on the left
+    /// (negative distance). The sign can be discarded with [Length::abs].
+    ///
     /// # Examples
     ///
     /// ```
@@ -107,8 +145,34 @@ impl Sphere {
     /// assert_eq!(Length::from_metres(-305.665), Sphere::EARTH.cross_track_distance(p, gc).round_mm());
     /// ```
     pub fn cross_track_distance(&self, p: NVector, gc: GreatCircle) -> Length {
+        Self::cross_track_angle(p, gc) * self.radius
+    }
+
+    /// Computes the signed angle from the given position to the given great circle: i.e. the
+    /// [cross-track distance](crate::spherical::Sphere::cross_track_distance) on the unit sphere.
+    /// Returns a negative angle if the position is left of great circle, positive angle if the position is right
+    /// of great circle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector};
+    /// use jord::spherical::{GreatCircle, Sphere};
+    ///
+    /// let gc = GreatCircle::checked_new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0)
+    /// ).unwrap();
+    ///
+    /// let north = NVector::from_lat_long_degrees(1.0, 5.0);
+    /// assert_eq!(Angle::from_degrees(-1.0), Sphere::cross_track_angle(north, gc).round_d7());
+    ///
+    /// let south = NVector::from_lat_long_degrees(-1.0, 5.0);
+    /// assert_eq!(Angle::from_degrees(1.0), Sphere::cross_track_angle(south, gc).round_d7());
+    /// ```
+    pub fn cross_track_angle(p: NVector, gc: GreatCircle) -> Angle {
         let angle = angle_radians_between(gc.normal(), p.as_vec3(), None);
-        (angle - (PI / 2.0)) * self.radius
+        Angle::from_radians(angle - (PI / 2.0))
     }
 
     /// Computes the destination position from the given position having travelled the given distance on the given
@@ -128,22 +192,159 @@ impl Sphere {
     /// assert_eq!(LatLong::from_degrees(45.0, 0.0), LatLong::from_nvector(dest).round_d7());
     /// ```
     pub fn destination_pos(&self, p0: NVector, bearing: Angle, distance: Length) -> NVector {
-        if distance == Length::ZERO {
+        let ta = Angle::from_radians(distance.as_metres() / self.radius.as_metres());
+        Self::destination_pos_angle(p0, bearing, ta)
+    }
+
+    /// Computes the destination position from the given position having travelled the given central angle (i.e.
+    /// the distance on the unit sphere) on the given initial bearing (compass angle): see
+    /// [destination_pos](crate::spherical::Sphere::destination_pos).
+    ///
+    /// The destination is computed by rotating the n-vector of the given position in the plane of the great
+    /// circle, which is stable at or near the poles and for any angle: a zero angle returns the given position
+    /// and an angle of 180 degrees returns the antipode of the given position, regardless of the bearing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p = LatLong::from_degrees(90.0, 0.0).to_nvector();
+    /// let dest = Sphere::destination_pos_angle(p, Angle::from_degrees(180.0), Angle::from_degrees(45.0));
+    ///
+    /// assert_eq!(LatLong::from_degrees(45.0, 0.0), LatLong::from_nvector(dest).round_d7());
+    /// ```
+    pub fn destination_pos_angle(p0: NVector, bearing: Angle, angle: Angle) -> NVector {
+        if angle == Angle::ZERO {
             p0
         } else {
-            // east direction vector at p
-            let ed = easting(p0.as_vec3());
-            // north direction vector at p
-            let nd = p0.as_vec3().cross_prod(ed);
             // central angle
-            let ta = distance.as_metres() / self.radius.as_metres();
-            let bearing_radians = bearing.as_radians();
-            // unit vector in the direction of the azimuth
-            let dir = nd * bearing_radians.cos() + ed * bearing_radians.sin();
+            let ta = angle.as_radians();
+            let dir = direction(p0.as_vec3(), bearing);
             NVector::new((p0.as_vec3() * ta.cos() + dir * ta.sin()).unit())
         }
     }
 
+    /// Computes a closed polygon approximating the circle of the given radius (distance) centred at the given
+    /// position: the vertices of the polygon are the [destinations](crate::spherical::Sphere::destination_pos)
+    /// from the centre at `max(3, segments)` evenly spaced bearings starting at 0 (clockwise), and the first vertex
+    /// is repeated at the end of the returned vector to close the polygon.
+    ///
+    /// Centres at (or near) a pole are supported: the polygon then surrounds the pole.
+    ///
+    /// See also [Cap::boundary](crate::spherical::Cap::boundary).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let centre = NVector::from_lat_long_degrees(55.6050, 13.0038);
+    /// let radius = Length::from_kilometres(10.0);
+    ///
+    /// let vs = Sphere::EARTH.circle_polygon(centre, radius, 36);
+    /// assert_eq!(37, vs.len());
+    /// assert_eq!(vs.first(), vs.last());
+    /// for v in vs {
+    ///     assert_eq!(radius, Sphere::EARTH.distance(centre, v).round_mm());
+    /// }
+    /// ```
+    pub fn circle_polygon(&self, centre: NVector, radius: Length, segments: usize) -> Vec<NVector> {
+        let n = segments.max(3);
+        let inc = Angle::FULL_CIRCLE / (n as f64);
+        let mut res = Vec::with_capacity(n + 1);
+        for i in 0..n {
+            res.push(self.destination_pos(centre, inc * (i as f64), radius));
+        }
+        res.push(res[0]);
+        res
+    }
+
+    /// Computes a route from `from` to `to` avoiding the circular exclusion zone of the given radius (distance)
+    /// centred at the given position: returns `[from, to]` if the minor arc between both positions does not enter
+    /// the zone, otherwise `[from, w, to]` where `w` is the intersection of the great circles from `from` and from
+    /// `to` tangent to the zone, on the side of the zone giving the shortest route. Both legs of the detour touch
+    /// the boundary of the zone.
+    ///
+    /// Returns:
+    /// - [OutOfRange](crate::NavigationError::OutOfRange) if either position is inside the zone or if the radius
+    ///   of the zone is not less than a quarter of the circumference of this sphere,
+    /// - [AntipodalPositions](crate::NavigationError::AntipodalPositions) if both positions are antipodal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{MinorArc, Sphere};
+    ///
+    /// let from = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let to = NVector::from_lat_long_degrees(0.0, 10.0);
+    /// let centre = NVector::from_lat_long_degrees(0.5, 5.0);
+    /// let radius = Length::from_kilometres(100.0);
+    ///
+    /// let route = Sphere::EARTH.route_avoiding(from, to, centre, radius).unwrap();
+    /// assert_eq!(3, route.len());
+    /// for leg in route.windows(2) {
+    ///     let closest = MinorArc::new(leg[0], leg[1]).closest_point(centre);
+    ///     assert!(Sphere::EARTH.distance(centre, closest).round_m() >= radius);
+    /// }
+    /// ```
+    pub fn route_avoiding(
+        &self,
+        from: NVector,
+        to: NVector,
+        centre: NVector,
+        radius: Length,
+    ) -> Result<Vec<NVector>, NavigationError> {
+        let r = self.distance_to_angle(radius).as_radians();
+        if r >= PI / 2.0 {
+            return Err(NavigationError::OutOfRange);
+        }
+        let d_from = Self::angle(from, centre).as_radians();
+        let d_to = Self::angle(to, centre).as_radians();
+        if d_from < r || d_to < r {
+            return Err(NavigationError::OutOfRange);
+        }
+        let arc = match MinorArc::checked_new(from, to) {
+            Ok(arc) => arc,
+            // equal positions, outside of the zone.
+            Err(NavigationError::DegenerateGeometry) => return Ok(vec![from, to]),
+            Err(e) => return Err(e),
+        };
+        if Self::angle(centre, arc.closest_point(centre)).as_radians() >= r {
+            return Ok(vec![from, to]);
+        }
+        // tangent great circle from p on the given side: right-angled spherical triangle (p, centre, tangent
+        // point), hence sin(r) = sin(d) * sin(alpha) where alpha is the angle at p.
+        let tangent = |p: NVector, d: f64, side: f64| {
+            let alpha = (r.sin() / d.sin()).min(1.0).asin();
+            let bearing = Self::initial_bearing(p, centre);
+            GreatCircle::from_heading(p, bearing + Angle::from_radians(side * alpha))
+        };
+        let mut best: Option<(NVector, Length)> = None;
+        for side in [-1.0, 1.0] {
+            // zone on the same side of both legs: turn one way at from and the other way at to.
+            let gc_from = tangent(from, d_from, side);
+            let gc_to = tangent(to, d_to, -side);
+            if let Some((i1, i2)) = gc_from.intersections(gc_to) {
+                // intersection on the side of the zone.
+                let w = if i1.as_vec3().dot_prod(centre.as_vec3()) >= 0.0 {
+                    i1
+                } else {
+                    i2
+                };
+                let length = self.distance(from, w) + self.distance(w, to);
+                if best.map_or(true, |(_, l)| length < l) {
+                    best = Some((w, length));
+                }
+            }
+        }
+        best.map(|(w, _)| vec![from, w, to])
+            .ok_or(NavigationError::DegenerateGeometry)
+    }
+
     /// Computes the surface distance on the great circle between the two given positions.
     ///
     /// # Examples
@@ -165,6 +366,60 @@ impl Sphere {
         Self::angle(p1, p2) * self.radius
     }
 
+    /// Computes the shortest distance from the given position to the given loop (e.g. "distance to coastline"):
+    /// zero if the position is inside the loop or on its boundary, otherwise the distance to the closest point
+    /// of any edge of the loop: either the [projection](crate::spherical::MinorArc::projection) of the position
+    /// on the edge, if any, or the closest vertex of the edge.
+    ///
+    /// Returns [Length::MAX] if the loop is [empty](crate::spherical::Loop::is_empty).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Length, NVector};
+    /// use jord::spherical::{Loop, Sphere};
+    ///
+    /// let l = Loop::new(&vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 10.0),
+    ///     NVector::from_lat_long_degrees(10.0, 0.0)
+    /// ]);
+    ///
+    /// assert_eq!(Length::ZERO, Sphere::EARTH.distance_to_loop(NVector::from_lat_long_degrees(5.0, 5.0), &l));
+    /// assert_eq!(
+    ///     Length::from_metres(111_194.94),
+    ///     Sphere::EARTH.distance_to_loop(NVector::from_lat_long_degrees(-1.0, 5.0), &l).round_cm()
+    /// );
+    /// ```
+    pub fn distance_to_loop(&self, p: NVector, l: &Loop) -> Length {
+        if l.is_empty() {
+            return Length::MAX;
+        }
+        if l.contains_point(p) {
+            return Length::ZERO;
+        }
+        let mut min = Angle::HALF_CIRCLE;
+        for e in l.iter_edges() {
+            let a = match e.projection(p) {
+                Some(proj) => Self::angle(p, proj),
+                None => {
+                    let a1 = Self::angle(p, e.start());
+                    let a2 = Self::angle(p, e.end());
+                    if a1 < a2 {
+                        a1
+                    } else {
+                        a2
+                    }
+                }
+            };
+            if a < min {
+                min = a;
+            }
+        }
+        min * self.radius
+    }
+
     /// Converts the given great circle distance to the equivalent central angle in the range [0, 180] degrees.
     ///
     /// The given distance is normalised to the range [0, `PI * Sphere::radius`].
@@ -205,6 +460,25 @@ impl Sphere {
         p1 != p2 && !p1.is_antipode_of(p2)
     }
 
+    /// Determines whether the given position is in the lit hemisphere - i.e. the hemisphere centred on the given
+    /// subsolar point (where the sun is at the zenith). Positions on the [terminator](crate::spherical::GreatCircle::terminator)
+    /// (given floating point precision) are considered lit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::NVector;
+    /// use jord::spherical::Sphere;
+    ///
+    /// let subsolar = NVector::from_lat_long_degrees(23.0, 10.0);
+    ///
+    /// assert!(Sphere::is_lit(NVector::from_lat_long_degrees(48.0, 2.0), subsolar));
+    /// assert!(!Sphere::is_lit(NVector::from_lat_long_degrees(-33.0, 151.0), subsolar));
+    /// ```
+    pub fn is_lit(p: NVector, subsolar_point: NVector) -> bool {
+        gte(p.as_vec3().dot_prod(subsolar_point.as_vec3()), 0.0)
+    }
+
     /// Computes the final bearing arriving at `p2` from `p1` in compass angle.
     /// Compass angles are clockwise angles from true north: 0 = north, 90 = east, 180 = south, 270 = west.
     /// The final bearing will differ from the initial bearing by varying degrees according to distance and latitude.
@@ -252,6 +526,97 @@ impl Sphere {
         }
     }
 
+    /// Computes both the [initial](crate::spherical::Sphere::checked_initial_bearing) bearing from `p1` and the
+    /// [final](crate::spherical::Sphere::checked_final_bearing) bearing arriving at `p2`, in compass angle in the
+    /// range [0, 360) degrees: this is cheaper than computing both separately since the great circle through both
+    /// positions is computed only once.
+    ///
+    /// Returns [None] if both positions are equal or the antipode of each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(0.0, 0.0).to_nvector();
+    /// let p2 = LatLong::from_degrees(45.0, 90.0).to_nvector();
+    /// let (initial, f) = Sphere::bearings(p1, p2).unwrap();
+    /// assert_eq!(Angle::from_degrees(45.0), initial.round_d7());
+    /// assert_eq!(Angle::from_degrees(90.0), f.round_d7());
+    /// ```
+    pub fn bearings(p1: NVector, p2: NVector) -> Option<(Angle, Angle)> {
+        if !Self::is_great_circle(p1, p2) {
+            None
+        } else {
+            let gc = p1.as_vec3().cross_prod(p2.as_vec3());
+            let initial = checked_bearing_radians_from_normal(p1, gc);
+            // initial bearing from p2 to p1 follows the opposite great circle.
+            let reverse = checked_bearing_radians_from_normal(p2, -gc);
+            Some((
+                Angle::from_radians(initial).normalised(),
+                Angle::from_radians(reverse + PI).normalised(),
+            ))
+        }
+    }
+
+    /// Computes the final bearing arriving at `p2` from `p1` in compass angle in the range [0, 360) degrees: the
+    /// reciprocal of the [initial bearing](crate::spherical::Sphere::checked_initial_bearing) from `p2` to `p1`.
+    /// Unlike [final_bearing](crate::spherical::Sphere::final_bearing), returns [None] if both positions are equal
+    /// or the antipode of each other, since the bearing is then undefined.
+    ///
+    /// By convention, the final bearing arriving at the north pole is 0 degrees and the final bearing arriving at
+    /// the south pole is 180 degrees, regardless of the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(0.0, 0.0).to_nvector();
+    /// let p2 = LatLong::from_degrees(0.0, 1.0).to_nvector();
+    /// assert_eq!(Some(Angle::from_degrees(90.0)), Sphere::checked_final_bearing(p1, p2));
+    /// assert_eq!(None, Sphere::checked_final_bearing(p1, p1));
+    ///
+    /// let np = LatLong::from_degrees(90.0, 0.0).to_nvector();
+    /// assert_eq!(Some(Angle::ZERO), Sphere::checked_final_bearing(p2, np));
+    /// ```
+    pub fn checked_final_bearing(p1: NVector, p2: NVector) -> Option<Angle> {
+        Self::checked_initial_bearing(p2, p1).map(|b| (b + Angle::HALF_CIRCLE).normalised())
+    }
+
+    /// Computes the initial bearing from `p1` to `p2` in compass angle in the range [0, 360) degrees; unlike
+    /// [initial_bearing](crate::spherical::Sphere::initial_bearing), returns [None] if both positions are equal or
+    /// the antipode of each other, since the bearing is then undefined.
+    ///
+    /// All directions from the north pole are south and all directions from the south pole are north: by
+    /// convention, the initial bearing from the north pole is therefore 180 degrees and the initial bearing from
+    /// the south pole is 0 degrees, regardless of the destination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = LatLong::from_degrees(0.0, 1.0).to_nvector();
+    /// let p2 = LatLong::from_degrees(0.0, 0.0).to_nvector();
+    /// assert_eq!(Some(Angle::from_degrees(270.0)), Sphere::checked_initial_bearing(p1, p2));
+    /// assert_eq!(None, Sphere::checked_initial_bearing(p1, p1));
+    /// assert_eq!(None, Sphere::checked_initial_bearing(p1, p1.antipode()));
+    ///
+    /// let np = LatLong::from_degrees(90.0, 0.0).to_nvector();
+    /// assert_eq!(Some(Angle::HALF_CIRCLE), Sphere::checked_initial_bearing(np, p1));
+    /// ```
+    pub fn checked_initial_bearing(p1: NVector, p2: NVector) -> Option<Angle> {
+        if !Self::is_great_circle(p1, p2) {
+            None
+        } else {
+            Some(Angle::from_radians(checked_initial_bearing_radians(p1, p2)).normalised())
+        }
+    }
+
     /// Returns the position at the given distance `a` from given point `p1` along the great circle `p1, p2`.
     ///
     /// ```
@@ -290,12 +655,259 @@ impl Sphere {
         }
     }
 
+    /// Computes the position at given fraction of the great circle arc from `p1` to `p2`. Unlike
+    /// [interpolated_pos](crate::spherical::Sphere::interpolated_pos), the fraction is not restricted to `[0, 1]`:
+    /// a fraction `< 0` or `> 1` extrapolates along the great circle, before `p1` or beyond `p2` respectively.
+    ///
+    /// Returns `p1` if both positions are equal, `p1` (respectively `p2`) exactly if the fraction is `0`
+    /// (respectively `1`), and `None` if both positions are the antipode of one another since the great circle
+    /// is then undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let p2 = NVector::from_lat_long_degrees(0.0, 10.0);
+    ///
+    /// let p = Sphere::extrapolated_pos(p1, p2, 1.5).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 15.0), LatLong::from_nvector(p).round_d7());
+    ///
+    /// let p = Sphere::extrapolated_pos(p1, p2, -0.5).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, -5.0), LatLong::from_nvector(p).round_d7());
+    ///
+    /// assert!(Sphere::extrapolated_pos(p1, p1.antipode(), 0.5).is_none());
+    /// ```
+    pub fn extrapolated_pos(p1: NVector, p2: NVector, f: f64) -> Option<NVector> {
+        if p1 == p2 || f == 0.0 {
+            Some(p1)
+        } else if f == 1.0 {
+            Some(p2)
+        } else if !Self::is_great_circle(p1, p2) {
+            None
+        } else {
+            let a = f * Self::angle(p1, p2);
+            Some(Self::position_on_great_circle(p1, p2, a))
+        }
+    }
+
+    /// Computes the position at the given time along the given track: a sequence of timestamped positions,
+    /// which must be sorted by increasing time. The position is interpolated along the great circle between
+    /// the 2 samples bracketing the given time by the time fraction.
+    ///
+    /// If the given time is before the first sample or after the last sample, returns `None` unless `extrapolate`
+    /// is `true`, in which case the position is extrapolated along the great circle of the first (respectively last)
+    /// 2 samples. Also returns `None` if the track is empty, or if extrapolation is requested with a single sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let track = vec![
+    ///     (Duration::from_secs(0), NVector::from_lat_long_degrees(0.0, 0.0)),
+    ///     (Duration::from_secs(10), NVector::from_lat_long_degrees(0.0, 1.0)),
+    /// ];
+    ///
+    /// let p = Sphere::interpolated_track_pos(&track, Duration::from_secs(5), false).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 0.5), LatLong::from_nvector(p).round_d7());
+    ///
+    /// assert!(Sphere::interpolated_track_pos(&track, Duration::from_secs(15), false).is_none());
+    ///
+    /// let p = Sphere::interpolated_track_pos(&track, Duration::from_secs(15), true).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, 1.5), LatLong::from_nvector(p).round_d7());
+    /// ```
+    pub fn interpolated_track_pos(
+        track: &[(Duration, NVector)],
+        at: Duration,
+        extrapolate: bool,
+    ) -> Option<NVector> {
+        let (first_t, _) = track.first()?;
+        let (last_t, last_p) = track.last()?;
+        let (s1, s2) = if at < *first_t || at > *last_t {
+            if !extrapolate || track.len() < 2 {
+                return None;
+            } else if at < *first_t {
+                (track[0], track[1])
+            } else {
+                (track[track.len() - 2], track[track.len() - 1])
+            }
+        } else {
+            // index of the first sample strictly after the given time.
+            let i = track.partition_point(|(t, _)| *t <= at);
+            if i == track.len() {
+                return Some(*last_p);
+            }
+            (track[i - 1], track[i])
+        };
+
+        let (t1, p1) = s1;
+        let (t2, p2) = s2;
+        let dt = t2.as_secs_f64() - t1.as_secs_f64();
+        if at == t1 || dt == 0.0 || p1 == p2 {
+            return Some(p1);
+        }
+        let f = (at.as_secs_f64() - t1.as_secs_f64()) / dt;
+        Some(Self::position_on_great_circle(
+            p1,
+            p2,
+            f * Self::angle(p1, p2),
+        ))
+    }
+
+    /// Computes waypoints along the great circle from `p1` to `p2` spaced by the given distance: the returned
+    /// waypoints start at `p1`, are then separated by `spacing` and end at `p2` - the last segment may therefore
+    /// be shorter than `spacing`.
+    ///
+    /// If `spacing` is greater than or equal to the distance between both positions, or is not strictly
+    /// positive, only `p1` and `p2` are returned. If both positions are the antipode of one another, the
+    /// waypoints are on an arbitrary great circle - see [is_great_circle](crate::spherical::Sphere::is_great_circle).
+    ///
+    /// At most 1,000,001 waypoints are returned: if `spacing` would divide the distance into more than 1,000,000
+    /// segments, the distance is instead divided into 1,000,000 segments of equal length (i.e. the spacing is
+    /// increased).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let p2 = NVector::from_lat_long_degrees(0.0, 2.5);
+    /// let spacing = Sphere::EARTH.distance(p1, NVector::from_lat_long_degrees(0.0, 1.0));
+    ///
+    /// let wps: Vec<LatLong> = Sphere::EARTH
+    ///     .waypoints(p1, p2, spacing)
+    ///     .iter()
+    ///     .map(|wp| LatLong::from_nvector(*wp).round_d7())
+    ///     .collect();
+    /// assert_eq!(
+    ///     vec![
+    ///         LatLong::from_degrees(0.0, 0.0),
+    ///         LatLong::from_degrees(0.0, 1.0),
+    ///         LatLong::from_degrees(0.0, 2.0),
+    ///         LatLong::from_degrees(0.0, 2.5)
+    ///     ],
+    ///     wps
+    /// );
+    /// ```
+    pub fn waypoints(&self, p1: NVector, p2: NVector, spacing: Length) -> Vec<NVector> {
+        let total = Self::angle(p1, p2).as_radians();
+        let step = spacing.as_metres() / self.radius.as_metres();
+        if step.is_nan() || step <= 0.0 || step >= total {
+            return vec![p1, p2];
+        }
+        let f = total / step;
+        let (segments, step) = if f > MAX_WAYPOINT_SEGMENTS as f64 {
+            (MAX_WAYPOINT_SEGMENTS, total / MAX_WAYPOINT_SEGMENTS as f64)
+        } else if (f - f.round()).abs() < 1e-9 {
+            // do not add a waypoint (almost) equal to p2 when the spacing evenly divides the distance.
+            (f.round() as usize, step)
+        } else {
+            (f.ceil() as usize, step)
+        };
+        let mut wps = Vec::with_capacity(segments + 1);
+        wps.push(p1);
+        for i in 1..segments {
+            let a = Angle::from_radians(i as f64 * step);
+            wps.push(Self::position_on_great_circle(p1, p2, a));
+        }
+        wps.push(p2);
+        wps
+    }
+
+    /// Computes the waypoints of an expanding square search pattern centred at the given position: starting at
+    /// `center`, the pattern consists of `legs` legs flown on the compass headings `initial_heading`,
+    /// `initial_heading + 90°`, `initial_heading + 180°`, ... (i.e. turning right by 90 degrees after each leg) and
+    /// whose lengths are `leg_spacing`, `leg_spacing`, `2 * leg_spacing`, `2 * leg_spacing`, `3 * leg_spacing`, ...
+    ///
+    /// The returned waypoints start at `center` and are followed by the end of each leg: `legs + 1` waypoints are
+    /// therefore returned. Each leg follows the great circle starting at the end of the previous leg on the
+    /// heading of the leg - see [destination_pos](crate::spherical::Sphere::destination_pos).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, Length, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let center = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let spacing = Sphere::EARTH.distance(center, NVector::from_lat_long_degrees(0.0, 1.0));
+    ///
+    /// let wps = Sphere::EARTH.expanding_square_pattern(center, spacing, 3, Angle::ZERO);
+    /// assert_eq!(4, wps.len());
+    /// assert_eq!(center, wps[0]);
+    /// assert_eq!(LatLong::from_degrees(1.0, 0.0), LatLong::from_nvector(wps[1]).round_d7());
+    /// assert_eq!(Angle::QUARTER_CIRCLE, Sphere::initial_bearing(wps[1], wps[2]).round_d7());
+    /// assert_eq!(Angle::HALF_CIRCLE, Sphere::initial_bearing(wps[2], wps[3]).round_d7());
+    /// ```
+    pub fn expanding_square_pattern(
+        &self,
+        center: NVector,
+        leg_spacing: Length,
+        legs: usize,
+        initial_heading: Angle,
+    ) -> Vec<NVector> {
+        let mut wps = Vec::with_capacity(legs + 1);
+        wps.push(center);
+        let mut p = center;
+        for i in 0..legs {
+            let heading = initial_heading + Angle::QUARTER_CIRCLE * (i as f64);
+            let length = leg_spacing * ((i / 2 + 1) as f64);
+            p = self.destination_pos(p, heading, length);
+            wps.push(p);
+        }
+        wps
+    }
+
+    /// Computes the midpoint of the 2 given positions: the position half-way along the great circle between both
+    /// positions. The midpoint is computed by normalising the sum of the n-vectors of both positions, which is
+    /// cheaper than [interpolated_pos](crate::spherical::Sphere::interpolated_pos) with a fraction of `0.5`. See
+    /// [mean_position](crate::spherical::Sphere::mean_position) for the generalisation to any number of positions.
+    ///
+    /// Returns `p1` if both positions are equal, and [None] if both positions are the antipode of one another
+    /// since an infinity of midpoints exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p1 = NVector::from_lat_long_degrees(0.0, 170.0);
+    /// let p2 = NVector::from_lat_long_degrees(0.0, -160.0);
+    ///
+    /// let m = Sphere::midpoint(p1, p2).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.0, -175.0), LatLong::from_nvector(m).round_d7());
+    ///
+    /// assert!(Sphere::midpoint(p1, p1.antipode()).is_none());
+    /// ```
+    pub fn midpoint(p1: NVector, p2: NVector) -> Option<NVector> {
+        if p1 == p2 {
+            Some(p1)
+        } else if p1.is_antipode_of(p2) {
+            None
+        } else {
+            Some(NVector::new((p1.as_vec3() + p2.as_vec3()).unit()))
+        }
+    }
+
     /// Computes the mean position of the given positions: the “center of gravity” of the given positions,
     /// which and can be compared to the centroid of a geometrical shape (n.b. other definitions of mean exist).
     ///
+    /// The mean position is computed by summing the n-vectors of the given positions and normalising the result:
+    /// unlike the arithmetic mean of latitudes and longitudes, it is not affected by the antimeridian or the poles.
+    /// The mean position of 2 positions is their [midpoint](crate::spherical::Sphere::midpoint).
+    ///
     /// The mean position is undefined if:
     /// - no position are given (i.e `ps` is empty), or
-    /// - any 2 given positions are the antipode of one another.
+    /// - any 2 given positions are the antipode of one another, or
+    /// - the sum of the n-vectors is effectively zero (e.g. 3 positions equally spaced on a great circle).
     ///
     /// # Examples
     ///
@@ -323,9 +935,7 @@ impl Sphere {
         } else if ps.len() == 1 {
             ps.first().cloned()
         } else {
-            let vs = ps.iter().map(|nv| nv.as_vec3()).collect::<Vec<_>>();
-            let m = Vec3::mean(&vs);
-            Some(NVector::new(m))
+            weighted_mean_vec3(ps.iter().map(|p| (p.as_vec3(), 1.0))).map(NVector::new)
         }
     }
 
@@ -398,37 +1008,529 @@ impl Sphere {
         Angle::from_radians(angle_radians_between(n1, n2, Some(b.as_vec3())))
     }
 
-    // kinematics
-
-    /// Calculates the position that the given vehicle will reach after the given time.
-    pub fn position_after(&self, vehicle: Vehicle, duration: Duration) -> NVector {
-        Sphere::EARTH.destination_pos(
-            vehicle.position(),
-            vehicle.bearing(),
-            vehicle.speed() * duration,
-        )
-    }
-
-    ///  Computes the time to the closest point of approach (CPA) between the two given vehicles: the time at which the
-    /// 2 vehicles will be the closest assuming they both maintain a constant course and heading.
+    /// Computes the signed change of course at waypoint `b` of a route from `a` to `c` via `b`: the angle from the
+    /// course at the end of the minor arc `a -> b` to the course at the start of the minor arc `b -> c`, within
+    /// (-180, 180] degrees. The angle is positive for a right turn (clockwise, like a bearing) and negative for a
+    /// left turn - i.e. the opposite of [turn](crate::spherical::Sphere::turn) - and 0 when going straight through.
+    /// A hairpin (`c` on the minor arc `a -> b`, e.g. `c == a`) is a turn of 180 degrees.
+    ///
+    /// Returns [NavigationError::DegenerateGeometry] if `a == b` or `b == c`, and
+    /// [NavigationError::AntipodalPositions] if `a` and `b` or `b` and `c` are the antipode of one another (the
+    /// course is then undefined).
     ///
     /// # Examples
     ///
     /// ```
-    /// use jord::{Angle, Length, NVector, Speed, Vehicle};
+    /// use jord::{Angle, NavigationError, NVector};
     /// use jord::spherical::Sphere;
     ///
-    /// let ownship = Vehicle::new(
-    ///     NVector::from_lat_long_degrees(20.0, -60.0),
-    ///     Angle::from_degrees(10.0),
-    ///     Speed::from_knots(15.0),
-    /// );
+    /// let a = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let b = NVector::from_lat_long_degrees(0.0, 10.0);
     ///
-    /// let intruder = Vehicle::new(
-    ///     NVector::from_lat_long_degrees(34.0, -50.0),
-    ///     Angle::from_degrees(220.0),
-    ///     Speed::from_knots(300.0),
-    /// );
+    /// // heading east, then south-east.
+    /// let c = NVector::from_lat_long_degrees(-10.0, 20.0);
+    /// assert!(Sphere::turn_angle(a, b, c).unwrap() > Angle::ZERO);
+    ///
+    /// // heading east, then north.
+    /// let c = NVector::from_lat_long_degrees(10.0, 10.0);
+    /// assert_eq!(Ok(Angle::from_degrees(-90.0)), Sphere::turn_angle(a, b, c).map(|t| t.round_d7()));
+    ///
+    /// assert_eq!(Err(NavigationError::DegenerateGeometry), Sphere::turn_angle(a, b, b));
+    /// ```
+    pub fn turn_angle(a: NVector, b: NVector, c: NVector) -> Result<Angle, NavigationError> {
+        for (p1, p2) in [(a, b), (b, c)] {
+            if p1 == p2 {
+                return Err(NavigationError::DegenerateGeometry);
+            }
+            if p1.is_antipode_of(p2) {
+                return Err(NavigationError::AntipodalPositions);
+            }
+        }
+        let turn = -Self::turn(a, b, c).as_radians();
+        if turn <= -PI {
+            Ok(Angle::HALF_CIRCLE)
+        } else {
+            Ok(Angle::from_radians(turn))
+        }
+    }
+
+    /// Computes the position fixed by the given bearings from 2 known positions (e.g. landmarks): the intersection
+    /// of the great circles defined by each position and bearing (compass angle) which lies ahead along both
+    /// bearings.
+    ///
+    /// Returns [DegenerateGeometry](crate::NavigationError::DegenerateGeometry) if the great circles are
+    /// (effectively) equal, or if no intersection lies ahead along both bearings within a quarter of a great
+    /// circle of both positions (e.g. the bearings diverge).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let a = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let b = NVector::from_lat_long_degrees(0.0, 1.0);
+    ///
+    /// let fix = Sphere::triangulate(a, Angle::from_degrees(45.0), b, Angle::from_degrees(315.0)).unwrap();
+    /// assert_eq!(LatLong::from_degrees(0.499981, 0.5), LatLong::from_nvector(fix).round_d6());
+    /// ```
+    pub fn triangulate(
+        a: NVector,
+        bearing_a: Angle,
+        b: NVector,
+        bearing_b: Angle,
+    ) -> Result<NVector, NavigationError> {
+        let gc_a = GreatCircle::from_heading(a, bearing_a);
+        let gc_b = GreatCircle::from_heading(b, bearing_b);
+        let (i1, i2) = gc_a
+            .intersections(gc_b)
+            .ok_or(NavigationError::DegenerateGeometry)?;
+        let dir_a = direction(a.as_vec3(), bearing_a);
+        let dir_b = direction(b.as_vec3(), bearing_b);
+        [i1, i2]
+            .into_iter()
+            .find(|i| {
+                let v = i.as_vec3();
+                v.dot_prod(dir_a) > 0.0
+                    && v.dot_prod(dir_b) > 0.0
+                    && v.dot_prod(a.as_vec3()) > 0.0
+                    && v.dot_prod(b.as_vec3()) > 0.0
+            })
+            .ok_or(NavigationError::DegenerateGeometry)
+    }
+
+    /// Computes the position fixed by the given bearings (compass angle) from known positions (e.g. landmarks): the
+    /// position minimising the sum of the squared sines of the cross-track angles to the great circles defined by
+    /// each position and bearing - i.e. approximately the total squared cross-track error. Of the 2 antipodal
+    /// solutions, the one ahead along the bearings is returned.
+    ///
+    /// With exactly 2 bearings this returns the same position as [triangulate](crate::spherical::Sphere::triangulate)
+    /// when the latter succeeds.
+    ///
+    /// Returns [DegenerateGeometry](crate::NavigationError::DegenerateGeometry) if less than 2 bearings are given or
+    /// if all great circles are (effectively) equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, LatLong, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p = NVector::from_lat_long_degrees(10.0, 10.0);
+    /// let landmarks = [
+    ///     NVector::from_lat_long_degrees(9.0, 9.0),
+    ///     NVector::from_lat_long_degrees(11.0, 9.5),
+    ///     NVector::from_lat_long_degrees(10.0, 11.0),
+    /// ];
+    /// let bearings: Vec<(NVector, Angle)> = landmarks
+    ///     .iter()
+    ///     .map(|l| (*l, Sphere::initial_bearing(*l, p)))
+    ///     .collect();
+    ///
+    /// let fix = Sphere::triangulate_least_squares(&bearings).unwrap();
+    /// assert_eq!(LatLong::from_degrees(10.0, 10.0), LatLong::from_nvector(fix).round_d7());
+    /// ```
+    pub fn triangulate_least_squares(
+        bearings: &[(NVector, Angle)],
+    ) -> Result<NVector, NavigationError> {
+        let gcs: Vec<GreatCircle> = bearings
+            .iter()
+            .map(|(p, b)| GreatCircle::from_heading(*p, *b))
+            .collect();
+        if !gcs
+            .iter()
+            .skip(1)
+            .any(|gc| gcs[0].intersections(*gc).is_some())
+        {
+            return Err(NavigationError::DegenerateGeometry);
+        }
+        // the minimum of sum((n . x)^2) for unit x is the eigenvector of the smallest eigenvalue of sum(n n^T).
+        let mut scatter = [[0.0; 3]; 3];
+        for gc in gcs.iter() {
+            let n = gc.normal().unit();
+            let c = [n.x(), n.y(), n.z()];
+            for (i, row) in scatter.iter_mut().enumerate() {
+                for (j, e) in row.iter_mut().enumerate() {
+                    *e += c[i] * c[j];
+                }
+            }
+        }
+        let x = smallest_eigenvector(scatter).unit();
+        let ahead: f64 = bearings
+            .iter()
+            .map(|(p, b)| x.dot_prod(direction(p.as_vec3(), *b)))
+            .sum();
+        if ahead < 0.0 {
+            Ok(NVector::new(-x))
+        } else {
+            Ok(NVector::new(x))
+        }
+    }
+
+    /// Computes the position fixed by the given distances (e.g. ranges measured by radar or DME) to known
+    /// positions: the position minimising the sum of the squared differences between the observed distances and
+    /// the surface distances to each known position, along with the root mean square of these differences - see
+    /// [Trilateration](crate::spherical::Trilateration).
+    ///
+    /// The position is first solved directly as the intersection of the small circles centred on each known
+    /// position - exactly when 3 consistent distances are given - and then refined by Gauss-Newton iterations
+    /// on the sphere.
+    ///
+    /// Returns [DegenerateGeometry](crate::NavigationError::DegenerateGeometry) if less than 3 distances are given
+    /// or if all known positions are on the same great circle (in which case 2 positions, mirrored across the great
+    /// circle, satisfy the distances equally well), or [NonConvergence](crate::NavigationError::NonConvergence)
+    /// if the refinement does not converge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let p = NVector::from_lat_long_degrees(10.0, 10.0);
+    /// let observations: Vec<(NVector, Length)> = [
+    ///     NVector::from_lat_long_degrees(9.0, 9.0),
+    ///     NVector::from_lat_long_degrees(11.0, 9.5),
+    ///     NVector::from_lat_long_degrees(10.0, 11.0),
+    /// ]
+    /// .iter()
+    /// .map(|b| (*b, Sphere::EARTH.distance(*b, p)))
+    /// .collect();
+    ///
+    /// let fix = Sphere::EARTH.trilaterate(&observations).unwrap();
+    /// assert_eq!(LatLong::from_degrees(10.0, 10.0), LatLong::from_nvector(fix.position()).round_d7());
+    /// assert_eq!(Length::ZERO, fix.residual().round_mm());
+    /// ```
+    pub fn trilaterate(
+        &self,
+        observations: &[(NVector, Length)],
+    ) -> Result<Trilateration, NavigationError> {
+        if observations.len() < 3 {
+            return Err(NavigationError::DegenerateGeometry);
+        }
+        let beacons: Vec<Vec3> = observations.iter().map(|(b, _)| b.as_vec3()).collect();
+        let angles: Vec<f64> = observations
+            .iter()
+            .map(|(_, d)| d.as_metres() / self.radius.as_metres())
+            .collect();
+
+        // the known positions are on the same great circle if they are all (effectively) orthogonal to the
+        // normal of the best fitting plane through the centre of the sphere.
+        let mut scatter = [[0.0; 3]; 3];
+        for b in beacons.iter() {
+            let c = [b.x(), b.y(), b.z()];
+            for (i, row) in scatter.iter_mut().enumerate() {
+                for (j, e) in row.iter_mut().enumerate() {
+                    *e += c[i] * c[j];
+                }
+            }
+        }
+        let normal = smallest_eigenvector(scatter).unit();
+        let off_plane = beacons
+            .iter()
+            .map(|b| b.dot_prod(normal).abs())
+            .fold(0.0, f64::max);
+        let spread = beacons
+            .iter()
+            .map(|b| (*b - beacons[0]).norm())
+            .fold(0.0, f64::max);
+        if off_plane <= 1e-9 * spread {
+            return Err(NavigationError::DegenerateGeometry);
+        }
+
+        // direct solution: p . b = cos(angle) for each known position b, in the least squares sense.
+        let rows = scatter.map(|r| Vec3::new(r[0], r[1], r[2]));
+        let rhs = beacons
+            .iter()
+            .zip(angles.iter())
+            .fold(Vec3::ZERO, |acc, (b, a)| acc + *b * a.cos());
+        let c0 = rows[1].cross_prod(rows[2]);
+        let c1 = rows[2].cross_prod(rows[0]);
+        let c2 = rows[0].cross_prod(rows[1]);
+        let det = rows[0].dot_prod(c0);
+        let mut p = ((c0 * rhs.x() + c1 * rhs.y() + c2 * rhs.z()) / det).unit();
+
+        // Gauss-Newton refinement in the tangent plane at the current estimate.
+        let mut converged = false;
+        for _ in 0..50 {
+            let ed = easting(p);
+            let nd = p.cross_prod(ed);
+            let (mut jee, mut jen, mut jnn, mut ge, mut gn) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for (b, a) in beacons.iter().zip(angles.iter()) {
+                let sin = p.cross_prod(*b).norm();
+                if sin < 1e-12 {
+                    // at the known position: the distance to it does not depend on the direction.
+                    continue;
+                }
+                let r = angle_radians_between(p, *b, None) - a;
+                let je = -ed.dot_prod(*b) / sin;
+                let jn = -nd.dot_prod(*b) / sin;
+                jee += je * je;
+                jen += je * jn;
+                jnn += jn * jn;
+                ge += je * r;
+                gn += jn * r;
+            }
+            let d = jee * jnn - jen * jen;
+            if eq_zero(d) {
+                return Err(NavigationError::DegenerateGeometry);
+            }
+            let de = (jen * gn - jnn * ge) / d;
+            let dn = (jen * ge - jee * gn) / d;
+            p = (p + ed * de + nd * dn).unit();
+            if de.hypot(dn) < 1e-14 {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(NavigationError::NonConvergence);
+        }
+
+        let sum_sq: f64 = beacons
+            .iter()
+            .zip(angles.iter())
+            .map(|(b, a)| {
+                let r = angle_radians_between(p, *b, None) - a;
+                r * r
+            })
+            .sum();
+        let rms = (sum_sq / beacons.len() as f64).sqrt();
+        Ok(Trilateration::new(NVector::new(p), self.radius * rms))
+    }
+
+    /// Computes the area of the spherical polygon whose vertices are given - consecutive
+    /// vertices being connected by minor arcs - regardless of the order (clockwise or anti-clockwise) of the
+    /// vertices. See [signed_polygon_area](crate::spherical::Sphere::signed_polygon_area) for details about the
+    /// method, the accepted vertices and the errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use jord::{Area, Length, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// // a triangle with three 90 degrees angles covers 1/8 of the sphere.
+    /// let vs = vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 90.0),
+    ///     NVector::from_lat_long_degrees(90.0, 0.0),
+    /// ];
+    ///
+    /// let s = Sphere::new(Length::from_metres(1.0));
+    /// let a = s.polygon_area(&vs).unwrap();
+    /// assert!((a - Area::from_square_metres(PI / 2.0)).abs() < Area::from_square_metres(1e-15));
+    /// ```
+    pub fn polygon_area(&self, vs: &[NVector]) -> Result<Area, NavigationError> {
+        self.signed_polygon_area(vs).map(|a| a.abs())
+    }
+
+    /// Computes the signed area of the spherical polygon whose vertices are given -
+    /// consecutive vertices being connected by minor arcs: the area is positive if the vertices are in
+    /// anti-clockwise order and negative if they are in clockwise order (see
+    /// [is_loop_clockwise](crate::spherical::is_loop_clockwise)).
+    ///
+    /// The area is computed from the spherical excess of the polygon, which is derived from the sum of the
+    /// turn angles at each vertex: it is therefore not affected by the antimeridian or the poles. Since any
+    /// polygon divides the sphere in 2 regions, the returned area is the one of the smallest region - i.e. at
+    /// most half of the sphere surface: this is the region to the left of the edges if the vertices are
+    /// anti-clockwise, or to the right otherwise.
+    ///
+    /// The polygon can be explicity closed (first == last) or opened (first != last). Returns:
+    /// - [NavigationError::DegenerateGeometry] if less than 3 vertices are given or if 2 consecutive vertices
+    ///   are equal,
+    /// - [NavigationError::AntipodalPositions] if 2 consecutive vertices are the antipode of one another (the
+    ///   edge connecting them is undefined).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Area, NavigationError, NVector};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let mut vs = vec![
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     NVector::from_lat_long_degrees(0.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 1.0),
+    ///     NVector::from_lat_long_degrees(1.0, 0.0),
+    /// ];
+    ///
+    /// // area (on Earth) of an anti-clockwise 1 degree "square".
+    /// let area = Sphere::EARTH.signed_polygon_area(&vs).unwrap();
+    /// assert_eq!(Area::from_square_kilometres(12_364.0), area.round_km2());
+    ///
+    /// vs.reverse();
+    /// let area = Sphere::EARTH.signed_polygon_area(&vs).unwrap();
+    /// assert_eq!(Area::from_square_kilometres(-12_364.0), area.round_km2());
+    ///
+    /// assert_eq!(
+    ///     Err(NavigationError::DegenerateGeometry),
+    ///     Sphere::EARTH.signed_polygon_area(&vs[0..2])
+    /// );
+    /// ```
+    pub fn signed_polygon_area(&self, vs: &[NVector]) -> Result<Area, NavigationError> {
+        let opened = if vs.len() > 1 && vs.first() == vs.last() {
+            &vs[..vs.len() - 1]
+        } else {
+            vs
+        };
+        let len = opened.len();
+        if len < 3 {
+            return Err(NavigationError::DegenerateGeometry);
+        }
+        for i in 0..len {
+            let cur = opened[i];
+            let next = opened[(i + 1) % len];
+            if cur == next {
+                return Err(NavigationError::DegenerateGeometry);
+            }
+            if cur == next.antipode() {
+                return Err(NavigationError::AntipodalPositions);
+            }
+        }
+
+        // for a polygon with anti-clockwise vertices, the sum of the turn angles is 2 * PI minus the spherical
+        // excess; for clockwise vertices, the sum of the turn angles is the spherical excess minus 2 * PI.
+        let mut turn = 0.0;
+        for i in 0..len {
+            let prev = opened[(i + len - 1) % len];
+            let cur = opened[i];
+            let next = opened[(i + 1) % len];
+            turn += Sphere::turn(prev, cur, next).as_radians();
+        }
+        let excess = if turn >= 0.0 {
+            2.0 * PI - turn
+        } else {
+            -2.0 * PI - turn
+        };
+        Ok(excess * (self.radius * self.radius))
+    }
+
+    // kinematics
+
+    /// Calculates the position that the given vehicle will reach after the given time, following the great circle
+    /// defined by its position and bearing at its speed: this is the [destination](crate::spherical::Sphere::destination_pos)
+    /// from the position of the vehicle on its bearing at a distance of speed x time.
+    ///
+    /// [Duration] cannot be negative: to project backwards along the same great circle, use the vehicle
+    /// [with](crate::Vehicle::with_bearing) the reciprocal bearing.
+    pub fn position_after(&self, vehicle: Vehicle, duration: Duration) -> NVector {
+        self.destination_pos(
+            vehicle.position(),
+            vehicle.bearing(),
+            vehicle.speed() * duration,
+        )
+    }
+
+    /// Calculates the positions reached by dead reckoning from the given position along the given ordered legs,
+    /// each given by its duration, course (compass angle) and speed: each leg follows the great circle from the
+    /// position reached at the end of the previous leg on its own initial course - see
+    /// [position_after](crate::spherical::Sphere::position_after).
+    ///
+    /// The returned positions are the given position followed by the position reached at the end of each leg
+    /// (the last position being the final dead reckoning position); legs of zero duration or at zero speed leave
+    /// the position unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use jord::{Angle, LatLong, NVector, Speed};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let start = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let hour = Duration::from_secs(3_600);
+    /// let legs = [
+    ///     (hour, Angle::from_degrees(90.0), Speed::from_knots(60.0)),
+    ///     (hour, Angle::ZERO, Speed::from_knots(60.0)),
+    /// ];
+    /// let positions = Sphere::EARTH.dead_reckon(start, &legs);
+    ///
+    /// assert_eq!(3, positions.len());
+    /// assert_eq!(start, positions[0]);
+    /// // 1 nautical mile is ~ 1 minute of arc.
+    /// assert_eq!(
+    ///     LatLong::from_degrees(0.99933, 0.99933),
+    ///     LatLong::from_nvector(positions[2]).round_d5()
+    /// );
+    /// ```
+    pub fn dead_reckon(&self, start: NVector, legs: &[(Duration, Angle, Speed)]) -> Vec<NVector> {
+        let mut positions = Vec::with_capacity(legs.len() + 1);
+        positions.push(start);
+        let mut p = start;
+        for (duration, course, speed) in legs {
+            p = self.destination_pos(p, *course, *speed * *duration);
+            positions.push(p);
+        }
+        positions
+    }
+
+    /// Calculates the positions that the given vehicle will reach after each of the given times: this is
+    /// equivalent to calling [position_after](crate::spherical::Sphere::position_after) for each time, but the
+    /// great circle followed by the vehicle is computed only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use jord::{Angle, LatLong, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let vehicle = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(0.0, 0.0),
+    ///     Angle::from_degrees(90.0),
+    ///     Speed::from_knots(60.0),
+    /// );
+    /// let times = [Duration::ZERO, Duration::from_secs(3_600), Duration::from_secs(7_200)];
+    /// let positions = Sphere::EARTH.positions_after(vehicle, &times);
+    ///
+    /// assert_eq!(3, positions.len());
+    /// assert_eq!(vehicle.position(), positions[0]);
+    /// // 1 nautical mile is ~ 1 minute of arc.
+    /// assert_eq!(
+    ///     LatLong::from_degrees(0.0, 0.99933),
+    ///     LatLong::from_nvector(positions[1]).round_d5()
+    /// );
+    /// ```
+    pub fn positions_after(&self, vehicle: Vehicle, times: &[Duration]) -> Vec<NVector> {
+        let p0 = vehicle.position();
+        let v0 = p0.as_vec3();
+        let dir = direction(v0, vehicle.bearing());
+        let w = vehicle.speed().as_metres_per_second() / self.radius.as_metres();
+        times
+            .iter()
+            .map(|t| {
+                let ta = w * t.as_secs_f64();
+                if ta == 0.0 {
+                    p0
+                } else {
+                    NVector::new((v0 * ta.cos() + dir * ta.sin()).unit())
+                }
+            })
+            .collect()
+    }
+
+    ///  Computes the time to the closest point of approach (CPA) between the two given vehicles: the time at which the
+    /// 2 vehicles will be the closest assuming they both maintain a constant course and heading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let ownship = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(20.0, -60.0),
+    ///     Angle::from_degrees(10.0),
+    ///     Speed::from_knots(15.0),
+    /// );
+    ///
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(34.0, -50.0),
+    ///     Angle::from_degrees(220.0),
+    ///     Speed::from_knots(300.0),
+    /// );
     ///
     /// let opt_time_at_cpa = Sphere::EARTH.time_to_cpa(ownship, intruder);
     /// assert!(opt_time_at_cpa.is_some());
@@ -473,6 +1575,44 @@ impl Sphere {
         hours_to_cpa.filter(|h| h >= &0.0).map(hours_to_duration)
     }
 
+    /// Computes the closest point of approach (CPA) between the two given vehicles assuming they both maintain a
+    /// constant course and speed: the [time to CPA](crate::spherical::Sphere::time_to_cpa), the distance between
+    /// the 2 vehicles at that time (i.e. their minimum separation) and their positions at that time.
+    ///
+    /// Returns [None] if the CPA is in the past (i.e. the vehicles are moving apart and their current separation
+    /// is the minimum separation from now on) or if there is no single CPA (e.g. vehicles following each other at
+    /// the same speed along the same great circle, whose separation remains constant).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let ownship = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(20.0, -60.0),
+    ///     Angle::from_degrees(10.0),
+    ///     Speed::from_knots(15.0),
+    /// );
+    ///
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(34.0, -50.0),
+    ///     Angle::from_degrees(220.0),
+    ///     Speed::from_knots(300.0),
+    /// );
+    ///
+    /// let cpa = Sphere::EARTH.cpa(ownship, intruder).unwrap();
+    /// assert_eq!(113_961_40, cpa.time().as_millis());
+    /// assert_eq!(Length::from_metres(124_232.0), cpa.distance().round_m());
+    /// ```
+    pub fn cpa(&self, ownship: Vehicle, intruder: Vehicle) -> Option<Cpa> {
+        let time = self.time_to_cpa(ownship, intruder)?;
+        let after = |v: Vehicle| self.destination_pos(v.position(), v.bearing(), v.speed() * time);
+        let own = after(ownship);
+        let int = after(intruder);
+        Some(Cpa::new(time, self.distance(own, int), own, int))
+    }
+
     /// Calculates the maximum time required by an interceptor at the given position to intercept the given intruder: i.e. the interceptor is
     /// travelling at the minimum speed required to achieve intercept.
     ///
@@ -591,6 +1731,90 @@ impl Sphere {
             Some(Duration::from_secs_f64(t_intercept_secs))
         }
     }
+
+    /// Computes the interception of the given intruder by an interceptor at the given position and travelling at
+    /// the given speed along a great circle: the [time to intercept](crate::spherical::Sphere::time_to_intercept),
+    /// the position of the interception and the bearing the interceptor must follow.
+    ///
+    /// Returns [None] if the intercept is impossible (e.g. the intruder is faster and moving away from the
+    /// interceptor).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, Length, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let interceptor_pos = NVector::from_lat_long_degrees(20.0, -60.0);
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(34.0, -50.0),
+    ///     Angle::from_degrees(220.0),
+    ///     Speed::from_knots(600.0)
+    /// );
+    ///
+    /// let intercept = Sphere::EARTH.intercept(interceptor_pos, Speed::from_knots(700.0), intruder).unwrap();
+    /// assert_eq!(2_764_688, intercept.time().as_millis());
+    /// assert_eq!(26.0, intercept.bearing().as_degrees().round());
+    /// assert_eq!(700.0, intercept.speed().as_knots().round());
+    /// ```
+    pub fn intercept(
+        &self,
+        interceptor_pos: NVector,
+        interceptor_speed: Speed,
+        intruder: Vehicle,
+    ) -> Option<Intercept> {
+        let time = self.time_to_intercept(interceptor_pos, interceptor_speed, intruder)?;
+        self.intercept_at_time(interceptor_pos, intruder, time)
+    }
+
+    /// Computes the interception of the given intruder at the given time by an interceptor at the given position
+    /// and travelling along a great circle: the position of the intruder at that time, and the bearing and the
+    /// speed the interceptor must follow to reach it at that time.
+    ///
+    /// Returns [None] if the time is zero and the interceptor is not at the position of the intruder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use jord::{Angle, NVector, Speed, Vehicle};
+    /// use jord::spherical::Sphere;
+    ///
+    /// let interceptor_pos = NVector::from_lat_long_degrees(0.0, 0.0);
+    /// let intruder = Vehicle::new(
+    ///     NVector::from_lat_long_degrees(1.0, 0.0),
+    ///     Angle::from_degrees(90.0),
+    ///     Speed::from_knots(60.0)
+    /// );
+    ///
+    /// let intercept = Sphere::EARTH.intercept_at_time(interceptor_pos, intruder, Duration::from_secs(3_600)).unwrap();
+    /// assert_eq!(45.0, intercept.bearing().as_degrees().round());
+    /// assert_eq!(85.0, intercept.speed().as_knots().round());
+    /// ```
+    pub fn intercept_at_time(
+        &self,
+        interceptor_pos: NVector,
+        intruder: Vehicle,
+        time: Duration,
+    ) -> Option<Intercept> {
+        let position = self.destination_pos(
+            intruder.position(),
+            intruder.bearing(),
+            intruder.speed() * time,
+        );
+        let distance = self.distance(interceptor_pos, position);
+        let speed = if time.is_zero() {
+            if distance == Length::ZERO {
+                Speed::ZERO
+            } else {
+                return None;
+            }
+        } else {
+            distance / time
+        };
+        let bearing = Sphere::initial_bearing(interceptor_pos, position);
+        Some(Intercept::new(time, position, bearing, speed))
+    }
 }
 
 impl Surface for Sphere {
@@ -605,6 +1829,9 @@ impl Surface for Sphere {
     }
 }
 
+// maximum number of segments between the waypoints returned by Sphere::waypoints.
+const MAX_WAYPOINT_SEGMENTS: usize = 1_000_000;
+
 // nanoseconds in one hour.
 const NANOS_PER_HOUR: f64 = 3_600.0 * 1_000.0 * 1_000_000.0;
 
@@ -612,26 +1839,50 @@ fn final_bearing_radians(v1: NVector, v2: NVector) -> f64 {
     initial_bearing_radians(v2, v1) + PI
 }
 
+/// Initial bearing from v1 to v2 in radians, using the pole convention of
+/// [Sphere::checked_initial_bearing]; v1 and v2 must define a unique great circle.
+fn checked_initial_bearing_radians(v1: NVector, v2: NVector) -> f64 {
+    let gc = v1.as_vec3().cross_prod(v2.as_vec3());
+    checked_bearing_radians_from_normal(v1, gc)
+}
+
+/// Bearing at v of the great circle of the given normal (oriented so that v x destination gives the normal), in
+/// radians, using the pole convention of [Sphere::checked_initial_bearing].
+fn checked_bearing_radians_from_normal(v: NVector, gc: Vec3) -> f64 {
+    let z = v.as_vec3().z();
+    if z == 1.0 {
+        PI
+    } else if z == -1.0 {
+        0.0
+    } else {
+        bearing_radians_from_normal(v, gc)
+    }
+}
+
 fn initial_bearing_radians(v1: NVector, v2: NVector) -> f64 {
     // great circle through v1 & v2.
     let gc1 = v1.as_vec3().cross_prod(v2.as_vec3());
+    bearing_radians_from_normal(v1, gc1)
+}
 
-    // this is equivalent to -easting(v1), but avoids the creation of
+/// Bearing at v of the great circle of the given normal in radians.
+fn bearing_radians_from_normal(v: NVector, gc1: Vec3) -> f64 {
+    // this is equivalent to -easting(v), but avoids the creation of
     // an intermediate Vec3.
-    // -y if at pole or great circle through v1 & north pole (v x [0, 0, 1])
-    let gc2 = if v1.as_vec3().z().abs() == 1.0 {
+    // -y if at pole or great circle through v & north pole (v x [0, 0, 1])
+    let gc2 = if v.as_vec3().z().abs() == 1.0 {
         Vec3::NEG_UNIT_Y
     } else {
-        Vec3::new(v1.as_vec3().y(), -v1.as_vec3().x(), 0.0)
+        Vec3::new(v.as_vec3().y(), -v.as_vec3().x(), 0.0)
     };
-    angle_radians_between(gc1, gc2, Some(v1.as_vec3()))
+    angle_radians_between(gc1, gc2, Some(v.as_vec3()))
 }
 
 /// Determines if the given vector contains antipodal positions.
 fn contains_antipodal(ps: &[NVector]) -> bool {
     for p in ps {
         let a = p.antipode();
-        let found = ps.iter().any(|&o| o == a);
+        let found = ps.contains(&a);
         if found {
             return true;
         }
@@ -662,6 +1913,16 @@ where
     None
 }
 
+/// Returns the unit vector tangent to the sphere at the given position in the direction of the given bearing.
+fn direction(v: Vec3, bearing: Angle) -> Vec3 {
+    // east direction vector at v
+    let ed = easting(v);
+    // north direction vector at v
+    let nd = v.cross_prod(ed);
+    let bearing_radians = bearing.as_radians();
+    nd * bearing_radians.cos() + ed * bearing_radians.sin()
+}
+
 fn course(vehicle: Vehicle) -> Vec3 {
     let ll = LatLong::from_nvector(vehicle.position());
     let lat_rads = ll.latitude().as_radians();
@@ -766,7 +2027,7 @@ where
         let cosw2t = (w2 * ti_secs).cos();
         let sinw2t = (w2 * ti_secs).sin();
         let v10dv2dt = -w2 * (v10v20 * sinw2t - v10c2 * cosw2t);
-        let v10d2v2dt2 = (-1.0 * w2 * w2) * (v10v20 * cosw2t + v10c2 * sinw2t);
+        let v10d2v2dt2 = (-w2 * w2) * (v10v20 * cosw2t + v10c2 * sinw2t);
         let si = sep(ti_secs);
         // if separation = 0, intercept takes place at ti_secs.
         if si == 0.0 {
@@ -820,8 +2081,9 @@ mod tests {
 
     use crate::{
         positions::{assert_nv_eq_d7, assert_opt_nv_eq_d7},
-        spherical::{GreatCircle, MinorArc, Sphere},
-        Angle, GeocentricPos, GeodeticPos, LatLong, Length, NVector, Speed, Surface, Vec3, Vehicle,
+        spherical::{GreatCircle, Loop, MinorArc, Sphere},
+        Angle, Area, GeocentricPos, GeodeticPos, LatLong, Length, NVector, NavigationError, Speed,
+        Surface, Vec3, Vehicle,
     };
 
     use super::newton_raphson;
@@ -857,35 +2119,351 @@ mod tests {
         assert_eq!(Length::ZERO, a.round_mm());
     }
 
-    // cross_track_distance
-
     #[test]
-    fn cross_track_distance_negative() {
-        let p = NVector::from_lat_long_degrees(53.2611, -0.7972);
-        let gc = GreatCircle::from_heading(
-            NVector::from_lat_long_degrees(53.3206, -1.7297),
-            Angle::from_degrees(96.0),
+    fn along_track_distance_beyond_end() {
+        let ma = MinorArc::checked_new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+        )
+        .unwrap();
+        let p = NVector::from_lat_long_degrees(2.0, 12.5);
+        assert_eq!(
+            (Sphere::EARTH.radius() * Angle::from_degrees(12.5)).round_mm(),
+            Sphere::EARTH.along_track_distance(p, ma).round_mm()
         );
-        let a = Sphere::EARTH.cross_track_distance(p, gc);
-        assert_eq!(Length::from_metres(-305.665), a.round_mm());
     }
 
     #[test]
-    fn cross_track_distance_positive() {
-        let p = NVector::from_lat_long_degrees(53.2611, -1.7972);
-        let gc = GreatCircle::from_heading(
-            NVector::from_lat_long_degrees(53.3206, -1.7297),
-            Angle::from_degrees(96.0),
+    fn along_track_angle_meridian() {
+        // travelling south along the 30th meridian east.
+        let ma = MinorArc::checked_new(
+            NVector::from_lat_long_degrees(10.0, 30.0),
+            NVector::from_lat_long_degrees(0.0, 30.0),
+        )
+        .unwrap();
+        let p = NVector::from_lat_long_degrees(25.0, 30.0);
+        assert_eq!(
+            Angle::from_degrees(-15.0),
+            Sphere::along_track_angle(p, ma).round_d7()
+        );
+        let p = NVector::from_lat_long_degrees(-5.0, 30.0);
+        assert_eq!(
+            Angle::from_degrees(15.0),
+            Sphere::along_track_angle(p, ma).round_d7()
         );
-        let a = Sphere::EARTH.cross_track_distance(p, gc);
-        assert_eq!(Length::from_metres(7047.043), a.round_mm());
     }
 
-    // destination.
-
     #[test]
-    fn destination_pos_across_date_line() {
-        let p = NVector::from_lat_long_degrees(0.0, 154.0);
+    fn along_track_angle_consistent_with_cross_track_angle() {
+        let start = NVector::from_lat_long_degrees(53.3206, -1.7297);
+        let end = NVector::from_lat_long_degrees(53.1887, 0.1334);
+        let ma = MinorArc::checked_new(start, end).unwrap();
+        let gc = GreatCircle::checked_new(start, end).unwrap();
+        for p in [
+            NVector::from_lat_long_degrees(53.2611, -0.7972),
+            NVector::from_lat_long_degrees(54.0, 2.0),
+            NVector::from_lat_long_degrees(50.0, -5.0),
+        ] {
+            let at = Sphere::along_track_angle(p, ma).as_radians();
+            let xt = Sphere::cross_track_angle(p, gc).as_radians();
+            let a = Sphere::angle(start, p).as_radians();
+            assert!((a.cos() - at.cos() * xt.cos()).abs() < 1e-12);
+        }
+    }
+
+    // circle_polygon
+
+    #[test]
+    fn circle_polygon() {
+        let centre = NVector::from_lat_long_degrees(-33.0, 151.0);
+        let radius = Length::from_nautical_miles(25.0);
+        let vs = Sphere::EARTH.circle_polygon(centre, radius, 72);
+        assert_eq!(73, vs.len());
+        assert_eq!(vs[0], vs[72]);
+        for v in vs.iter() {
+            assert_eq!(
+                radius.round_mm(),
+                Sphere::EARTH.distance(centre, *v).round_mm()
+            );
+        }
+        // clockwise, starting north.
+        assert!(
+            Sphere::initial_bearing(centre, vs[0])
+                .as_radians_normalised()
+                .abs()
+                < 1e-12
+        );
+        assert_eq!(
+            Angle::from_degrees(5.0),
+            Sphere::initial_bearing(centre, vs[1]).round_d7()
+        );
+    }
+
+    #[test]
+    fn circle_polygon_min_segments() {
+        let centre = NVector::from_lat_long_degrees(0.0, 0.0);
+        let vs = Sphere::EARTH.circle_polygon(centre, Length::from_kilometres(1.0), 1);
+        assert_eq!(4, vs.len());
+        assert_eq!(vs[0], vs[3]);
+    }
+
+    #[test]
+    fn circle_polygon_north_pole() {
+        let centre = NVector::from_lat_long_degrees(90.0, 0.0);
+        let radius = Sphere::EARTH.radius() * Angle::from_degrees(1.0);
+        let vs = Sphere::EARTH.circle_polygon(centre, radius, 4);
+        assert_eq!(5, vs.len());
+        assert_eq!(vs[0], vs[4]);
+        let lls: Vec<LatLong> = vs
+            .iter()
+            .map(|v| LatLong::from_nvector(*v).round_d7())
+            .collect();
+        for ll in lls.iter() {
+            assert_eq!(Angle::from_degrees(89.0), ll.latitude());
+        }
+        // the polygon surrounds the pole: consecutive vertices are 90 degrees of longitude apart.
+        for w in lls.windows(2) {
+            let dl = (w[1].longitude() - w[0].longitude())
+                .as_radians_normalised()
+                .abs();
+            assert_eq!(
+                Angle::QUARTER_CIRCLE.round_d7(),
+                Angle::from_radians(dl).round_d7()
+            );
+        }
+    }
+
+    // route_avoiding
+
+    fn assert_clears_zone(route: &[NVector], centre: NVector, radius: Length) {
+        for leg in route.windows(2) {
+            let closest = MinorArc::new(leg[0], leg[1]).closest_point(centre);
+            let d = Sphere::EARTH.distance(centre, closest);
+            assert!(
+                d.round_mm() >= radius.round_mm(),
+                "leg enters zone: {:?} < {:?}",
+                d,
+                radius
+            );
+        }
+    }
+
+    #[test]
+    fn route_avoiding_clear() {
+        let from = NVector::from_lat_long_degrees(0.0, 0.0);
+        let to = NVector::from_lat_long_degrees(0.0, 10.0);
+        let centre = NVector::from_lat_long_degrees(2.0, 5.0);
+        let route = Sphere::EARTH
+            .route_avoiding(from, to, centre, Length::from_kilometres(100.0))
+            .unwrap();
+        assert_eq!(vec![from, to], route);
+        // zone beyond the end of the arc.
+        let centre = NVector::from_lat_long_degrees(0.0, 12.0);
+        let route = Sphere::EARTH
+            .route_avoiding(from, to, centre, Length::from_kilometres(100.0))
+            .unwrap();
+        assert_eq!(vec![from, to], route);
+    }
+
+    #[test]
+    fn route_avoiding_blocked_symmetric() {
+        // zone centred on the route: the detour goes round either side, symmetrically.
+        let from = NVector::from_lat_long_degrees(0.0, -5.0);
+        let to = NVector::from_lat_long_degrees(0.0, 5.0);
+        let centre = NVector::from_lat_long_degrees(0.0, 0.0);
+        let radius = Length::from_kilometres(200.0);
+        let route = Sphere::EARTH
+            .route_avoiding(from, to, centre, radius)
+            .unwrap();
+        assert_eq!(3, route.len());
+        assert_eq!(from, route[0]);
+        assert_eq!(to, route[2]);
+        let w = LatLong::from_nvector(route[1]);
+        assert_eq!(0.0, w.longitude().round_d7().as_degrees().abs());
+        // tangent legs: the waypoint is further than the radius from the centre, and both legs touch the zone.
+        assert!(Sphere::EARTH.distance(centre, route[1]) > radius);
+        assert_clears_zone(&route, centre, radius);
+        for leg in route.windows(2) {
+            let closest = MinorArc::new(leg[0], leg[1]).closest_point(centre);
+            assert_eq!(
+                radius.round_m(),
+                Sphere::EARTH.distance(centre, closest).round_m()
+            );
+        }
+    }
+
+    #[test]
+    fn route_avoiding_blocked_shortest_side() {
+        let from = NVector::from_lat_long_degrees(50.0, -20.0);
+        let to = NVector::from_lat_long_degrees(52.0, 0.0);
+        // zone just south of the route.
+        let mid = Sphere::midpoint(from, to).unwrap();
+        let centre = Sphere::EARTH.destination_pos(
+            mid,
+            Sphere::initial_bearing(mid, to) + Angle::QUARTER_CIRCLE,
+            Length::from_kilometres(20.0),
+        );
+        let radius = Length::from_kilometres(100.0);
+        let route = Sphere::EARTH
+            .route_avoiding(from, to, centre, radius)
+            .unwrap();
+        assert_eq!(3, route.len());
+        assert_clears_zone(&route, centre, radius);
+        // the detour goes north of the zone (left of the route).
+        assert_eq!(
+            1,
+            Sphere::side(route[1], from, to),
+            "waypoint should be left of the direct route"
+        );
+        let direct = Sphere::EARTH.distance(from, to);
+        let detour = Sphere::EARTH.distance(from, route[1]) + Sphere::EARTH.distance(route[1], to);
+        assert!(detour > direct);
+    }
+
+    #[test]
+    fn route_avoiding_errors() {
+        let from = NVector::from_lat_long_degrees(0.0, 0.0);
+        let to = NVector::from_lat_long_degrees(0.0, 10.0);
+        let radius = Length::from_kilometres(100.0);
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            Sphere::EARTH.route_avoiding(from, to, from, radius)
+        );
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            Sphere::EARTH.route_avoiding(
+                from,
+                to,
+                NVector::from_lat_long_degrees(0.1, 10.0),
+                radius
+            )
+        );
+        assert_eq!(
+            Err(NavigationError::OutOfRange),
+            Sphere::EARTH.route_avoiding(
+                from,
+                to,
+                NVector::from_lat_long_degrees(60.0, 100.0),
+                Sphere::EARTH.radius() * Angle::QUARTER_CIRCLE
+            )
+        );
+        assert_eq!(
+            Err(NavigationError::AntipodalPositions),
+            Sphere::EARTH.route_avoiding(
+                from,
+                from.antipode(),
+                NVector::from_lat_long_degrees(0.0, 90.0),
+                radius
+            )
+        );
+        // same positions outside of the zone.
+        assert_eq!(
+            Ok(vec![from, from]),
+            Sphere::EARTH.route_avoiding(
+                from,
+                from,
+                NVector::from_lat_long_degrees(0.0, 10.0),
+                radius
+            )
+        );
+    }
+
+    // cross_track_distance
+
+    #[test]
+    fn cross_track_distance_negative() {
+        let p = NVector::from_lat_long_degrees(53.2611, -0.7972);
+        let gc = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(53.3206, -1.7297),
+            Angle::from_degrees(96.0),
+        );
+        let a = Sphere::EARTH.cross_track_distance(p, gc);
+        assert_eq!(Length::from_metres(-305.665), a.round_mm());
+    }
+
+    #[test]
+    fn cross_track_distance_positive() {
+        let p = NVector::from_lat_long_degrees(53.2611, -1.7972);
+        let gc = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(53.3206, -1.7297),
+            Angle::from_degrees(96.0),
+        );
+        let a = Sphere::EARTH.cross_track_distance(p, gc);
+        assert_eq!(Length::from_metres(7047.043), a.round_mm());
+    }
+
+    #[test]
+    fn cross_track_distance_northbound() {
+        let gc = GreatCircle::from_heading(NVector::from_lat_long_degrees(45.0, 10.0), Angle::ZERO);
+        let r = Sphere::EARTH.radius();
+        // 1 degree east of the meridian: right.
+        let right =
+            Sphere::EARTH.cross_track_distance(NVector::from_lat_long_degrees(0.0, 11.0), gc);
+        assert_eq!(
+            Angle::from_degrees(1.0).arc_length(r).round_mm(),
+            right.round_mm()
+        );
+        // 1 degree west of the meridian: left.
+        let left = Sphere::EARTH.cross_track_distance(NVector::from_lat_long_degrees(0.0, 9.0), gc);
+        assert_eq!(-right.round_mm(), left.round_mm());
+        assert_eq!(right.round_mm(), left.abs().round_mm());
+        // reversing the direction of travel flips the sign.
+        let southbound = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(45.0, 10.0),
+            Angle::HALF_CIRCLE,
+        );
+        assert_eq!(
+            left.round_mm(),
+            Sphere::EARTH
+                .cross_track_distance(NVector::from_lat_long_degrees(0.0, 11.0), southbound)
+                .round_mm()
+        );
+    }
+
+    #[test]
+    fn cross_track_angle_known_value() {
+        let p = NVector::from_lat_long_degrees(53.2611, -0.7972);
+        let gc = GreatCircle::from_heading(
+            NVector::from_lat_long_degrees(53.3206, -1.7297),
+            Angle::from_degrees(96.0),
+        );
+        assert_eq!(
+            Length::from_metres(-305.665),
+            (Sphere::cross_track_angle(p, gc) * Sphere::EARTH.radius()).round_mm()
+        );
+    }
+
+    #[test]
+    fn cross_track_angle_sign_convention() {
+        // travelling north along the prime meridian: east is right, west is left.
+        let gc = GreatCircle::checked_new(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+        )
+        .unwrap();
+        assert_eq!(
+            Angle::from_degrees(2.0),
+            Sphere::cross_track_angle(NVector::from_lat_long_degrees(0.0, 2.0), gc).round_d7()
+        );
+        assert!(
+            Sphere::cross_track_angle(NVector::from_lat_long_degrees(5.0, -2.0), gc) < Angle::ZERO
+        );
+        assert_eq!(
+            Angle::ZERO,
+            Sphere::cross_track_angle(NVector::from_lat_long_degrees(45.0, 0.0), gc).round_d7()
+        );
+        // beyond the pole, the great circle is travelled southward: west is right.
+        assert_eq!(
+            Angle::from_degrees(30.0),
+            Sphere::cross_track_angle(NVector::from_lat_long_degrees(0.0, 150.0), gc).round_d7()
+        );
+    }
+
+    // destination.
+
+    #[test]
+    fn destination_pos_across_date_line() {
+        let p = NVector::from_lat_long_degrees(0.0, 154.0);
         let actual = Sphere::EARTH.destination_pos(
             p,
             Angle::from_degrees(90.0),
@@ -950,6 +2528,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn destination_pos_angle_zero() {
+        let p = NVector::from_lat_long_degrees(89.999, -179.5);
+        assert_eq!(
+            p,
+            Sphere::destination_pos_angle(p, Angle::from_degrees(33.0), Angle::ZERO)
+        );
+    }
+
+    #[test]
+    fn destination_pos_half_circle_is_antipode() {
+        for p in [
+            NVector::from_lat_long_degrees(55.6050, 13.0038),
+            NVector::from_lat_long_degrees(90.0, 0.0),
+            NVector::from_lat_long_degrees(-0.5, 180.0),
+        ] {
+            for b in (0..360).step_by(15) {
+                let d = Sphere::destination_pos_angle(
+                    p,
+                    Angle::from_degrees(b as f64),
+                    Angle::HALF_CIRCLE,
+                );
+                assert!(Sphere::angle(p.antipode(), d).as_radians() < 1e-15);
+                let d = Sphere::EARTH.destination_pos(
+                    p,
+                    Angle::from_degrees(b as f64),
+                    Sphere::EARTH.radius() * PI,
+                );
+                assert!(Sphere::angle(p.antipode(), d).as_radians() < 1e-15);
+            }
+        }
+    }
+
+    #[test]
+    fn destination_pos_round_trip() {
+        // deterministic samples: latitudes, longitudes, bearings and distances up to 179 degrees.
+        for i in 0..500 {
+            let f = i as f64;
+            let lat = ((f * 37.0) % 170.0) - 85.0;
+            let lng = ((f * 53.0) % 360.0) - 180.0;
+            let bearing = Angle::from_degrees((f * 71.0) % 360.0);
+            let angle = Angle::from_degrees(0.5 + (f * 29.0) % 178.5);
+            let p = NVector::from_lat_long_degrees(lat, lng);
+            let d = Sphere::destination_pos_angle(p, bearing, angle);
+            let ll = LatLong::from_nvector(d);
+            assert!(ll.longitude().as_degrees().abs() <= 180.0);
+            assert!((Sphere::angle(p, d) - angle).as_radians().abs() < 1e-12);
+            let b = Sphere::checked_initial_bearing(p, d).unwrap();
+            assert!((b - bearing).as_radians_normalised().abs() < 1e-9);
+        }
+    }
+
     // distance.
 
     #[test]
@@ -985,6 +2615,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn distance_london_paris() {
+        let london = NVector::from_lat_long_degrees(51.5074, -0.1278);
+        let paris = NVector::from_lat_long_degrees(48.8566, 2.3522);
+        assert_eq!(
+            Length::from_metres(343_556.103),
+            Sphere::EARTH.distance(london, paris).round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_antipodes() {
+        let p = NVector::from_lat_long_degrees(48.8566, 2.3522);
+        assert_eq!(Angle::HALF_CIRCLE, Sphere::angle(p, p.antipode()));
+        assert_eq!(
+            (Sphere::EARTH.radius() * PI).round_mm(),
+            Sphere::EARTH.distance(p, p.antipode()).round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_near_identical() {
+        let p1 = NVector::from_lat_long_degrees(51.5074, -0.1278);
+        let p2 =
+            Sphere::EARTH.destination_pos(p1, Angle::from_degrees(30.0), Length::from_metres(0.01));
+        assert_eq!(
+            Length::from_metres(0.01),
+            Sphere::EARTH.distance(p1, p2).round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_symmetric() {
+        let p1 = NVector::from_lat_long_degrees(-33.8688, 151.2093);
+        let p2 = NVector::from_lat_long_degrees(40.7128, -74.006);
+        assert_eq!(Sphere::angle(p1, p2), Sphere::angle(p2, p1));
+        assert_eq!(
+            Sphere::EARTH.distance(p1, p2),
+            Sphere::EARTH.distance(p2, p1)
+        );
+    }
+
     #[test]
     fn distance_transitivity() {
         let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
@@ -1000,6 +2672,7 @@ mod tests {
     fn distance_zero() {
         let p = NVector::from_lat_long_degrees(50.066389, -5.714722);
         assert_eq!(Length::ZERO, Sphere::EARTH.distance(p, p));
+        assert_eq!(Angle::ZERO, Sphere::angle(p, p));
     }
 
     /// final_bearing.
@@ -1059,6 +2732,174 @@ mod tests {
         );
     }
 
+    // is_lit
+
+    #[test]
+    fn is_lit_subsolar_point_and_antipode() {
+        let subsolar = NVector::from_lat_long_degrees(-23.44, 135.0);
+        assert!(Sphere::is_lit(subsolar, subsolar));
+        assert!(!Sphere::is_lit(subsolar.antipode(), subsolar));
+    }
+
+    #[test]
+    fn is_lit_terminator() {
+        let subsolar = NVector::from_lat_long_degrees(-23.44, 135.0);
+        let terminator = GreatCircle::terminator(subsolar);
+        // positions on the terminator are lit, positions just off the terminator are lit or dark.
+        let p = terminator
+            .projection(NVector::from_lat_long_degrees(10.0, 20.0))
+            .unwrap();
+        assert!(Sphere::is_lit(p, subsolar));
+        let bearing = Sphere::initial_bearing(p, subsolar);
+        let lit = Sphere::EARTH.destination_pos(p, bearing, Length::from_metres(1.0));
+        let dark = Sphere::EARTH.destination_pos(p, bearing, Length::from_metres(-1.0));
+        assert!(Sphere::is_lit(lit, subsolar));
+        assert!(!Sphere::is_lit(dark, subsolar));
+    }
+
+    #[test]
+    fn is_lit_equinox() {
+        // at the equinox, the subsolar point is on the equator and both poles are on the terminator.
+        let subsolar = NVector::from_lat_long_degrees(0.0, 0.0);
+        assert!(Sphere::is_lit(
+            NVector::from_lat_long_degrees(90.0, 0.0),
+            subsolar
+        ));
+        assert!(Sphere::is_lit(
+            NVector::from_lat_long_degrees(-90.0, 0.0),
+            subsolar
+        ));
+        assert!(Sphere::is_lit(
+            NVector::from_lat_long_degrees(45.0, 89.0),
+            subsolar
+        ));
+        assert!(!Sphere::is_lit(
+            NVector::from_lat_long_degrees(45.0, 91.0),
+            subsolar
+        ));
+    }
+
+    // checked_initial_bearing
+
+    #[test]
+    fn checked_initial_bearing_undefined() {
+        let p = NVector::from_lat_long_degrees(50.0, -18.0);
+        assert_eq!(None, Sphere::checked_initial_bearing(p, p));
+        assert_eq!(None, Sphere::checked_initial_bearing(p, p.antipode()));
+        let np = NVector::from_lat_long_degrees(90.0, 0.0);
+        let sp = NVector::from_lat_long_degrees(-90.0, 0.0);
+        assert_eq!(None, Sphere::checked_initial_bearing(np, sp));
+        assert_eq!(None, Sphere::checked_initial_bearing(sp, np));
+    }
+
+    #[test]
+    fn checked_initial_bearing_from_pole() {
+        let np = NVector::from_lat_long_degrees(90.0, 0.0);
+        let sp = NVector::from_lat_long_degrees(-90.0, 0.0);
+        for lng in [-180.0, -170.0, -90.0, 0.0, 45.0, 154.0, 180.0] {
+            let p = NVector::from_lat_long_degrees(10.0, lng);
+            assert_eq!(
+                Some(Angle::HALF_CIRCLE),
+                Sphere::checked_initial_bearing(np, p)
+            );
+            assert_eq!(Some(Angle::ZERO), Sphere::checked_initial_bearing(sp, p));
+        }
+    }
+
+    #[test]
+    fn checked_initial_bearing_to_pole() {
+        let np = NVector::from_lat_long_degrees(90.0, 0.0);
+        let sp = NVector::from_lat_long_degrees(-90.0, 0.0);
+        for lng in [-170.0, -90.0, 0.0, 45.0, 180.0] {
+            let p = NVector::from_lat_long_degrees(10.0, lng);
+            assert_eq!(Some(Angle::ZERO), Sphere::checked_initial_bearing(p, np));
+            assert_eq!(
+                Some(Angle::HALF_CIRCLE),
+                Sphere::checked_initial_bearing(p, sp)
+            );
+        }
+    }
+
+    #[test]
+    fn checked_initial_bearing_reciprocal_of_final() {
+        let ps = [
+            NVector::from_lat_long_degrees(50.066389, -5.714722),
+            NVector::from_lat_long_degrees(58.643889, -3.07),
+            NVector::from_lat_long_degrees(-33.8688, 151.2093),
+            NVector::from_lat_long_degrees(0.0, 179.0),
+        ];
+        for a in ps {
+            for b in ps {
+                if a == b {
+                    continue;
+                }
+                let i = Sphere::checked_initial_bearing(a, b).unwrap();
+                let f = Sphere::final_bearing(b, a);
+                assert_eq!(
+                    i.round_d7(),
+                    (f + Angle::HALF_CIRCLE).normalised().round_d7()
+                );
+                assert!(i >= Angle::ZERO && i < Angle::FULL_CIRCLE);
+            }
+        }
+    }
+
+    // checked_final_bearing
+
+    #[test]
+    fn checked_final_bearing_undefined() {
+        let p = NVector::from_lat_long_degrees(50.0, -18.0);
+        assert_eq!(None, Sphere::checked_final_bearing(p, p));
+        assert_eq!(None, Sphere::checked_final_bearing(p, p.antipode()));
+        assert_eq!(None, Sphere::bearings(p, p));
+        assert_eq!(None, Sphere::bearings(p, p.antipode()));
+    }
+
+    #[test]
+    fn checked_final_bearing_poles() {
+        let np = NVector::from_lat_long_degrees(90.0, 0.0);
+        let sp = NVector::from_lat_long_degrees(-90.0, 0.0);
+        for lng in [-170.0, -90.0, 0.0, 45.0, 180.0] {
+            let p = NVector::from_lat_long_degrees(10.0, lng);
+            assert_eq!(Some(Angle::ZERO), Sphere::checked_final_bearing(p, np));
+            assert_eq!(
+                Some(Angle::HALF_CIRCLE),
+                Sphere::checked_final_bearing(p, sp)
+            );
+            assert_eq!(
+                Some(Angle::HALF_CIRCLE),
+                Sphere::checked_final_bearing(np, p)
+            );
+            assert_eq!(Some(Angle::ZERO), Sphere::checked_final_bearing(sp, p));
+        }
+    }
+
+    #[test]
+    fn bearings_consistent() {
+        let ps = [
+            NVector::from_lat_long_degrees(50.066389, -5.714722),
+            NVector::from_lat_long_degrees(58.643889, -3.07),
+            NVector::from_lat_long_degrees(-33.8688, 151.2093),
+            NVector::from_lat_long_degrees(0.0, 179.0),
+            NVector::from_lat_long_degrees(90.0, 0.0),
+            NVector::from_lat_long_degrees(-90.0, 0.0),
+        ];
+        for a in ps {
+            for b in ps {
+                if !Sphere::is_great_circle(a, b) {
+                    continue;
+                }
+                let (i, f) = Sphere::bearings(a, b).unwrap();
+                assert_eq!(Sphere::checked_initial_bearing(a, b), Some(i));
+                assert_eq!(
+                    Sphere::checked_final_bearing(a, b).map(|b| b.round_d7()),
+                    Some(f.round_d7())
+                );
+                assert!(f >= Angle::ZERO && f < Angle::FULL_CIRCLE);
+            }
+        }
+    }
+
     // initial_bearing
 
     #[test]
@@ -1234,64 +3075,366 @@ mod tests {
         assert_opt_nv_eq_d7(expected, actual);
     }
 
-    // mean
+    // expanding_square_pattern
 
     #[test]
-    fn mean_antipodal() {
-        let p = NVector::from_lat_long_degrees(0.0, 0.0);
-        assert!(Sphere::mean_position(&vec!(p, p.antipode())).is_none());
+    fn expanding_square_pattern_first_legs() {
+        let center = NVector::from_lat_long_degrees(45.0, 10.0);
+        let spacing = Length::from_metres(1852.0);
+        let heading = Angle::from_degrees(30.0);
+        let wps = Sphere::EARTH.expanding_square_pattern(center, spacing, 6, heading);
+        assert_eq!(7, wps.len());
+        assert_eq!(center, wps[0]);
+        for i in 0..6 {
+            let expected_heading = (heading + Angle::QUARTER_CIRCLE * (i as f64)).normalised();
+            let expected_length = spacing * ((i / 2 + 1) as f64);
+            assert_eq!(
+                expected_heading.round_d7(),
+                Sphere::initial_bearing(wps[i], wps[i + 1]).round_d7()
+            );
+            assert_eq!(
+                expected_length.round_mm(),
+                Sphere::EARTH.distance(wps[i], wps[i + 1]).round_mm()
+            );
+        }
     }
 
     #[test]
-    fn mean_empty() {
-        let vs: Vec<NVector> = Vec::new();
-        assert!(Sphere::mean_position(&vs).is_none());
+    fn expanding_square_pattern_no_leg() {
+        let center = NVector::from_lat_long_degrees(45.0, 10.0);
+        assert_eq!(
+            vec![center],
+            Sphere::EARTH.expanding_square_pattern(
+                center,
+                Length::from_metres(1000.0),
+                0,
+                Angle::ZERO
+            )
+        );
     }
 
     #[test]
-    fn mean_test() {
-        let vs = vec![
-            NVector::from_lat_long_degrees(10.0, 10.0),
-            NVector::from_lat_long_degrees(10.0, -10.0),
-            NVector::from_lat_long_degrees(-10.0, -10.0),
-            NVector::from_lat_long_degrees(-10.0, 10.0),
-        ];
+    fn expanding_square_pattern_symmetric_extents() {
+        let center = NVector::from_lat_long_degrees(0.0, 0.0);
+        let spacing = Length::from_metres(1000.0);
+        let wps = Sphere::EARTH.expanding_square_pattern(center, spacing, 8, Angle::ZERO);
+        let lls: Vec<LatLong> = wps.iter().map(|wp| LatLong::from_nvector(*wp)).collect();
+        let max_lat = lls
+            .iter()
+            .map(|ll| ll.latitude().as_degrees())
+            .fold(f64::MIN, f64::max);
+        let min_lat = lls
+            .iter()
+            .map(|ll| ll.latitude().as_degrees())
+            .fold(f64::MAX, f64::min);
+        let max_lng = lls
+            .iter()
+            .map(|ll| ll.longitude().as_degrees())
+            .fold(f64::MIN, f64::max);
+        let min_lng = lls
+            .iter()
+            .map(|ll| ll.longitude().as_degrees())
+            .fold(f64::MAX, f64::min);
+        // after 8 legs the pattern extends by 2 leg spacings in every direction.
+        let two_legs = Sphere::EARTH.distance_to_angle(spacing * 2.0).as_degrees();
+        assert!((max_lat - two_legs).abs() < 1e-6);
+        assert!((min_lat + two_legs).abs() < 1e-6);
+        assert!((max_lng - two_legs).abs() < 1e-6);
+        assert!((min_lng + two_legs).abs() < 1e-6);
+    }
 
-        assert_opt_nv_eq_d7(
-            NVector::from_lat_long_degrees(0.0, 0.0),
-            Sphere::mean_position(&vs),
-        );
+    // extrapolated
+
+    #[test]
+    fn extrapolated_pos_antipodal() {
+        let p = NVector::from_lat_long_degrees(45.0, 10.0);
+        assert!(Sphere::extrapolated_pos(p, p.antipode(), 0.5).is_none());
+        assert!(Sphere::extrapolated_pos(p, p.antipode(), 2.0).is_none());
     }
 
     #[test]
-    fn mean_one() {
-        assert_eq!(
-            Some(NVector::from_lat_long_degrees(0.0, 0.0)),
-            Sphere::mean_position(&vec!(NVector::from_lat_long_degrees(0.0, 0.0)))
-        );
+    fn extrapolated_pos_equal() {
+        let p = NVector::from_lat_long_degrees(45.0, 10.0);
+        assert_eq!(Some(p), Sphere::extrapolated_pos(p, p, 3.0));
     }
 
-    // side
+    #[test]
+    fn extrapolated_pos_exact_ends() {
+        let p1 = NVector::from_lat_long_degrees(54.0, 154.0);
+        let p2 = NVector::from_lat_long_degrees(55.0, -175.0);
+        assert_eq!(Some(p1), Sphere::extrapolated_pos(p1, p2, 0.0));
+        assert_eq!(Some(p2), Sphere::extrapolated_pos(p1, p2, 1.0));
+    }
 
     #[test]
-    fn side_collinear() {
-        assert_eq!(
-            0,
-            Sphere::side(
-                NVector::from_lat_long_degrees(0.0, 0.0),
-                NVector::from_lat_long_degrees(45.0, 0.0),
-                NVector::from_lat_long_degrees(90.0, 0.0)
-            )
-        );
+    fn extrapolated_pos_nearly_equal() {
+        let p1 = NVector::from_lat_long_degrees(45.0, 10.0);
+        let p2 = NVector::from_lat_long_degrees(45.0, 10.000000001);
+        let p = Sphere::extrapolated_pos(p1, p2, 0.5).unwrap();
+        assert_nv_eq_d7(p1, p);
+        assert!(Sphere::angle(p1, p) <= Sphere::angle(p1, p2));
     }
 
     #[test]
-    fn side_equal() {
-        let v1 = NVector::new(Vec3::new_unit(1.0, 2.0, 3.0));
-        // largest component is z, orthogonal vector in x-z plan.
-        assert_eq!(0, Sphere::side(NVector::new(Vec3::UNIT_Y), v1, v1));
-        assert_eq!(
-            -1,
+    fn extrapolated_pos_matches_interpolated() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 20.0);
+        let p2 = NVector::from_lat_long_degrees(-30.0, 50.0);
+        for f in [0.1, 0.25, 0.5, 0.9] {
+            assert_eq!(
+                Sphere::interpolated_pos(p1, p2, f),
+                Sphere::extrapolated_pos(p1, p2, f)
+            );
+        }
+    }
+
+    #[test]
+    fn extrapolated_pos_outside() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, 10.0);
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.0, 30.0),
+            Sphere::extrapolated_pos(p1, p2, 3.0),
+        );
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.0, -20.0),
+            Sphere::extrapolated_pos(p1, p2, -2.0),
+        );
+        let p = Sphere::extrapolated_pos(p1, p2, 1.3).unwrap();
+        assert_eq!(0, Sphere::side(p, p1, p2));
+    }
+
+    // interpolated_track_pos
+
+    fn track() -> Vec<(Duration, NVector)> {
+        vec![
+            (
+                Duration::from_secs(10),
+                NVector::from_lat_long_degrees(0.0, 0.0),
+            ),
+            (
+                Duration::from_secs(20),
+                NVector::from_lat_long_degrees(0.0, 1.0),
+            ),
+            (
+                Duration::from_secs(40),
+                NVector::from_lat_long_degrees(1.0, 1.0),
+            ),
+        ]
+    }
+
+    #[test]
+    fn interpolated_track_pos_empty() {
+        assert!(Sphere::interpolated_track_pos(&[], Duration::ZERO, false).is_none());
+        assert!(Sphere::interpolated_track_pos(&[], Duration::ZERO, true).is_none());
+    }
+
+    #[test]
+    fn interpolated_track_pos_exact_sample() {
+        let t = track();
+        for (ts, p) in t.iter() {
+            assert_eq!(Some(*p), Sphere::interpolated_track_pos(&t, *ts, false));
+        }
+    }
+
+    #[test]
+    fn interpolated_track_pos_mid_interval() {
+        let t = track();
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.0, 0.5),
+            Sphere::interpolated_track_pos(&t, Duration::from_secs(15), false),
+        );
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.25, 1.0),
+            Sphere::interpolated_track_pos(&t, Duration::from_secs(25), false),
+        );
+    }
+
+    #[test]
+    fn interpolated_track_pos_out_of_range() {
+        let t = track();
+        assert!(Sphere::interpolated_track_pos(&t, Duration::from_secs(9), false).is_none());
+        assert!(Sphere::interpolated_track_pos(&t, Duration::from_secs(41), false).is_none());
+    }
+
+    #[test]
+    fn interpolated_track_pos_extrapolated() {
+        let t = track();
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.0, -0.5),
+            Sphere::interpolated_track_pos(&t, Duration::from_secs(5), true),
+        );
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(1.5, 1.0),
+            Sphere::interpolated_track_pos(&t, Duration::from_secs(50), true),
+        );
+    }
+
+    #[test]
+    fn interpolated_track_pos_single_sample() {
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        let t = vec![(Duration::from_secs(1), p)];
+        assert_eq!(
+            Some(p),
+            Sphere::interpolated_track_pos(&t, Duration::from_secs(1), false)
+        );
+        assert!(Sphere::interpolated_track_pos(&t, Duration::from_secs(2), true).is_none());
+    }
+
+    #[test]
+    fn interpolated_track_pos_stationary() {
+        let p = NVector::from_lat_long_degrees(45.0, 45.0);
+        let t = vec![(Duration::from_secs(1), p), (Duration::from_secs(2), p)];
+        assert_eq!(
+            Some(p),
+            Sphere::interpolated_track_pos(&t, Duration::from_millis(1500), false)
+        );
+    }
+
+    // midpoint
+
+    #[test]
+    fn midpoint_antipodal() {
+        let p = NVector::from_lat_long_degrees(45.0, 10.0);
+        assert!(Sphere::midpoint(p, p.antipode()).is_none());
+    }
+
+    #[test]
+    fn midpoint_equal() {
+        let p = NVector::from_lat_long_degrees(45.0, 10.0);
+        assert_eq!(Some(p), Sphere::midpoint(p, p));
+    }
+
+    #[test]
+    fn midpoint_meridian() {
+        let m = Sphere::midpoint(
+            NVector::from_lat_long_degrees(80.0, 0.0),
+            NVector::from_lat_long_degrees(80.0, 180.0),
+        )
+        .unwrap();
+        assert!(Sphere::angle(NVector::new(Vec3::UNIT_Z), m).as_radians() < 1e-12);
+    }
+
+    #[test]
+    fn midpoint_consistent() {
+        let p1 = NVector::from_lat_long_degrees(54.0, 154.0);
+        let p2 = NVector::from_lat_long_degrees(-33.0, -70.0);
+        let m = Sphere::midpoint(p1, p2).unwrap();
+        assert_opt_nv_eq_d7(m, Sphere::interpolated_pos(p1, p2, 0.5));
+        assert_opt_nv_eq_d7(m, Sphere::mean_position(&[p1, p2]));
+        assert_opt_nv_eq_d7(m, Sphere::midpoint(p2, p1));
+        assert_eq!(
+            Sphere::angle(p1, m).round_d7(),
+            Sphere::angle(m, p2).round_d7()
+        );
+    }
+
+    // mean
+
+    #[test]
+    fn mean_antipodal() {
+        let p = NVector::from_lat_long_degrees(0.0, 0.0);
+        assert!(Sphere::mean_position(&[p, p.antipode()]).is_none());
+    }
+
+    #[test]
+    fn mean_empty() {
+        let vs: Vec<NVector> = Vec::new();
+        assert!(Sphere::mean_position(&vs).is_none());
+    }
+
+    #[test]
+    fn mean_test() {
+        let vs = vec![
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, -10.0),
+            NVector::from_lat_long_degrees(-10.0, -10.0),
+            NVector::from_lat_long_degrees(-10.0, 10.0),
+        ];
+
+        assert_opt_nv_eq_d7(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            Sphere::mean_position(&vs),
+        );
+    }
+
+    #[test]
+    fn mean_zero_resultant() {
+        // no antipodal positions but the n-vectors sum to zero.
+        let vs = vec![
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 120.0),
+            NVector::from_lat_long_degrees(0.0, -120.0),
+        ];
+        assert!(Sphere::mean_position(&vs).is_none());
+    }
+
+    #[test]
+    fn mean_antimeridian() {
+        let vs = vec![
+            NVector::from_lat_long_degrees(10.0, 179.0),
+            NVector::from_lat_long_degrees(-10.0, 179.0),
+            NVector::from_lat_long_degrees(10.0, -179.0),
+            NVector::from_lat_long_degrees(-10.0, -179.0),
+        ];
+        let m = Sphere::mean_position(&vs).unwrap();
+        let e = NVector::from_lat_long_degrees(0.0, 180.0);
+        assert!(Sphere::angle(e, m).as_radians() < 1e-12);
+    }
+
+    #[test]
+    fn mean_antimeridian_asymmetric() {
+        let vs = vec![
+            NVector::from_lat_long_degrees(0.0, 179.0),
+            NVector::from_lat_long_degrees(0.0, -178.0),
+            NVector::from_lat_long_degrees(0.0, -178.0),
+        ];
+        let m = LatLong::from_nvector(Sphere::mean_position(&vs).unwrap());
+        assert_eq!(Angle::ZERO, m.latitude().round_d7());
+        // arithmetic mean of the longitudes would be ~ -59 degrees.
+        assert!((m.longitude().as_degrees() + 179.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn mean_around_pole() {
+        let vs = vec![
+            NVector::from_lat_long_degrees(80.0, 0.0),
+            NVector::from_lat_long_degrees(80.0, 90.0),
+            NVector::from_lat_long_degrees(80.0, 180.0),
+            NVector::from_lat_long_degrees(80.0, -90.0),
+        ];
+        let m = Sphere::mean_position(&vs).unwrap();
+        assert!(Sphere::angle(NVector::new(Vec3::UNIT_Z), m).as_radians() < 1e-12);
+    }
+
+    #[test]
+    fn mean_one() {
+        assert_eq!(
+            Some(NVector::from_lat_long_degrees(0.0, 0.0)),
+            Sphere::mean_position(&[NVector::from_lat_long_degrees(0.0, 0.0)])
+        );
+    }
+
+    // side
+
+    #[test]
+    fn side_collinear() {
+        assert_eq!(
+            0,
+            Sphere::side(
+                NVector::from_lat_long_degrees(0.0, 0.0),
+                NVector::from_lat_long_degrees(45.0, 0.0),
+                NVector::from_lat_long_degrees(90.0, 0.0)
+            )
+        );
+    }
+
+    #[test]
+    fn side_equal() {
+        let v1 = NVector::new(Vec3::new_unit(1.0, 2.0, 3.0));
+        // largest component is z, orthogonal vector in x-z plan.
+        assert_eq!(0, Sphere::side(NVector::new(Vec3::UNIT_Y), v1, v1));
+        assert_eq!(
+            -1,
             Sphere::side(NVector::new(Vec3::new_unit(1.0, -3.0, 0.0)), v1, v1)
         );
         assert_eq!(
@@ -1339,36 +3482,628 @@ mod tests {
         assert_eq!(1, Sphere::side(left, v1, v2));
     }
 
-    // turn
+    // turn
+
+    #[test]
+    fn turn_collinear() {
+        let actual = Sphere::turn(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(45.0, 0.0),
+            NVector::from_lat_long_degrees(90.0, 0.0),
+        );
+        assert_eq!(Angle::ZERO, actual);
+    }
+
+    #[test]
+    fn turn_left() {
+        let actual = Sphere::turn(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(45.0, 0.0),
+            NVector::from_lat_long_degrees(60.0, -10.0),
+        );
+        assert_eq!(Angle::from_radians(0.3175226173130951), actual);
+    }
+
+    #[test]
+    fn turn_right() {
+        let actual = Sphere::turn(
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(45.0, 0.0),
+            NVector::from_lat_long_degrees(60.0, 10.0),
+        );
+        assert_eq!(Angle::from_radians(-0.3175226173130951), actual);
+    }
+
+    // turn_angle
+
+    #[test]
+    fn turn_angle_degenerate() {
+        let a = NVector::from_lat_long_degrees(10.0, 20.0);
+        let b = NVector::from_lat_long_degrees(15.0, 25.0);
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::turn_angle(a, a, b)
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::turn_angle(a, b, b)
+        );
+        assert_eq!(
+            Err(NavigationError::AntipodalPositions),
+            Sphere::turn_angle(a, a.antipode(), b)
+        );
+        assert_eq!(
+            Err(NavigationError::AntipodalPositions),
+            Sphere::turn_angle(a, b, b.antipode())
+        );
+    }
+
+    #[test]
+    fn turn_angle_straight() {
+        let a = NVector::from_lat_long_degrees(53.3206, -1.7297);
+        let c = NVector::from_lat_long_degrees(53.1887, 0.1334);
+        let b = Sphere::midpoint(a, c).unwrap();
+        let t = Sphere::turn_angle(a, b, c).unwrap();
+        assert!(t.as_degrees().abs() < 1e-9);
+    }
+
+    #[test]
+    fn turn_angle_hairpin() {
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        let b = NVector::from_lat_long_degrees(0.0, 10.0);
+        assert_eq!(Ok(Angle::HALF_CIRCLE), Sphere::turn_angle(a, b, a));
+        // nearly a hairpin on either side.
+        let right = Sphere::turn_angle(a, b, NVector::from_lat_long_degrees(-0.001, 0.0)).unwrap();
+        assert!(right.as_degrees() > 179.9 && right.as_degrees() < 180.0);
+        let left = Sphere::turn_angle(a, b, NVector::from_lat_long_degrees(0.001, 0.0)).unwrap();
+        assert!(left.as_degrees() < -179.9 && left.as_degrees() > -180.0);
+    }
+
+    #[test]
+    fn turn_angle_consistent_with_bearings() {
+        let a = NVector::from_lat_long_degrees(45.0, 0.0);
+        let b = NVector::from_lat_long_degrees(50.0, 5.0);
+        let c = NVector::from_lat_long_degrees(48.0, 15.0);
+        let t = Sphere::turn_angle(a, b, c).unwrap();
+        let expected = Sphere::initial_bearing(b, c) - Sphere::final_bearing(a, b);
+        assert_eq!(expected.round_d7(), t.round_d7());
+        assert!(t > Angle::ZERO);
+    }
+
+    #[test]
+    fn turn_angle_mirrored() {
+        // mirroring across the meridian of b flips the sign.
+        let (a, b, c) = ((40.0, -3.0), (42.0, 1.0), (41.0, 6.0));
+        let nv = |(lat, lng): (f64, f64)| NVector::from_lat_long_degrees(lat, lng);
+        let mirror = |(lat, lng): (f64, f64)| NVector::from_lat_long_degrees(lat, 2.0 - lng);
+        let t = Sphere::turn_angle(nv(a), nv(b), nv(c)).unwrap();
+        let m = Sphere::turn_angle(mirror(a), mirror(b), mirror(c)).unwrap();
+        assert!(t > Angle::ZERO);
+        assert_eq!((-t).round_d7(), m.round_d7());
+    }
+
+    // triangulate
+
+    fn bearings_to(p: NVector, landmarks: &[NVector]) -> Vec<(NVector, Angle)> {
+        landmarks
+            .iter()
+            .map(|l| (*l, Sphere::initial_bearing(*l, p)))
+            .collect()
+    }
+
+    #[test]
+    fn triangulate_known_position() {
+        let p = NVector::from_lat_long_degrees(50.1, -4.2);
+        let a = NVector::from_lat_long_degrees(50.3, -4.5);
+        let b = NVector::from_lat_long_degrees(50.2, -3.8);
+        let fix = Sphere::triangulate(
+            a,
+            Sphere::initial_bearing(a, p),
+            b,
+            Sphere::initial_bearing(b, p),
+        );
+        assert_opt_nv_eq_d7(p, fix.ok());
+    }
+
+    #[test]
+    fn triangulate_ahead_of_both() {
+        // both bearings towards the north: the fix is north of the landmarks, not at the antipode.
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        let b = NVector::from_lat_long_degrees(0.0, 2.0);
+        let fix = Sphere::triangulate(a, Angle::from_degrees(45.0), b, Angle::from_degrees(315.0))
+            .unwrap();
+        let ll = LatLong::from_nvector(fix);
+        assert!(ll.latitude() > Angle::ZERO);
+        assert_eq!(Angle::from_degrees(1.0), ll.longitude().round_d7());
+        // the reciprocal bearings fix south of the landmarks.
+        let fix = Sphere::triangulate(a, Angle::from_degrees(135.0), b, Angle::from_degrees(225.0))
+            .unwrap();
+        assert!(LatLong::from_nvector(fix).latitude() < Angle::ZERO);
+    }
+
+    #[test]
+    fn triangulate_diverging() {
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        let b = NVector::from_lat_long_degrees(0.0, 2.0);
+        // a looks north-west, b north-east: the great circles only cross behind both landmarks or at the far side
+        // of the Earth.
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::triangulate(a, Angle::from_degrees(315.0), b, Angle::from_degrees(45.0))
+        );
+        // one bearing ahead, the other away from the crossing.
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::triangulate(a, Angle::from_degrees(45.0), b, Angle::from_degrees(135.0))
+        );
+    }
+
+    #[test]
+    fn triangulate_parallel() {
+        // same great circle.
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        let b = NVector::from_lat_long_degrees(10.0, 0.0);
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::triangulate(a, Angle::ZERO, b, Angle::ZERO)
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::triangulate(a, Angle::ZERO, b, Angle::HALF_CIRCLE)
+        );
+    }
+
+    #[test]
+    fn triangulate_least_squares_exact_bearings() {
+        let p = NVector::from_lat_long_degrees(-35.0, 150.0);
+        let landmarks = [
+            NVector::from_lat_long_degrees(-34.5, 149.0),
+            NVector::from_lat_long_degrees(-35.5, 149.5),
+            NVector::from_lat_long_degrees(-34.8, 151.0),
+            NVector::from_lat_long_degrees(-36.0, 150.5),
+        ];
+        let bearings = bearings_to(p, &landmarks);
+        assert_opt_nv_eq_d7(p, Sphere::triangulate_least_squares(&bearings).ok());
+        // 2 bearings: same as triangulate.
+        assert_eq!(
+            LatLong::from_nvector(
+                Sphere::triangulate(bearings[0].0, bearings[0].1, bearings[1].0, bearings[1].1)
+                    .unwrap()
+            )
+            .round_d7(),
+            LatLong::from_nvector(Sphere::triangulate_least_squares(&bearings[..2]).unwrap())
+                .round_d7()
+        );
+    }
+
+    #[test]
+    fn triangulate_least_squares_noisy_bearings() {
+        let p = NVector::from_lat_long_degrees(50.0, 0.0);
+        let landmarks = [
+            NVector::from_lat_long_degrees(50.2, -0.2),
+            NVector::from_lat_long_degrees(50.2, 0.2),
+            NVector::from_lat_long_degrees(49.8, 0.1),
+        ];
+        // bearings off by up to 1 degree: a "cocked hat".
+        let errors = [1.0, -0.5, 0.7];
+        let bearings: Vec<(NVector, Angle)> = bearings_to(p, &landmarks)
+            .iter()
+            .zip(errors)
+            .map(|((l, b), e)| (*l, *b + Angle::from_degrees(e)))
+            .collect();
+        let fix = Sphere::triangulate_least_squares(&bearings).unwrap();
+        assert!(Sphere::EARTH.distance(p, fix) < Length::from_kilometres(1.0));
+        // the fix minimises the total squared cross-track error: moving it increases the error.
+        let error = |x: NVector| -> f64 {
+            bearings
+                .iter()
+                .map(|(l, b)| {
+                    Sphere::cross_track_angle(x, GreatCircle::from_heading(*l, *b))
+                        .as_radians()
+                        .powi(2)
+                })
+                .sum()
+        };
+        let e = error(fix);
+        for b in (0..8).map(|i| Angle::from_degrees(i as f64 * 45.0)) {
+            let moved = Sphere::EARTH.destination_pos(fix, b, Length::from_metres(50.0));
+            assert!(error(moved) > e);
+        }
+    }
+
+    #[test]
+    fn triangulate_least_squares_degenerate() {
+        let a = NVector::from_lat_long_degrees(0.0, 0.0);
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::triangulate_least_squares(&[])
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::triangulate_least_squares(&[(a, Angle::ZERO)])
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::triangulate_least_squares(&[
+                (a, Angle::ZERO),
+                (
+                    NVector::from_lat_long_degrees(10.0, 0.0),
+                    Angle::HALF_CIRCLE
+                )
+            ])
+        );
+    }
+
+    // trilaterate
+
+    fn distances_to(p: NVector, beacons: &[NVector]) -> Vec<(NVector, Length)> {
+        beacons
+            .iter()
+            .map(|b| (*b, Sphere::EARTH.distance(*b, p)))
+            .collect()
+    }
+
+    #[test]
+    fn trilaterate_exact() {
+        let cases = [
+            (
+                NVector::from_lat_long_degrees(51.5, -0.1),
+                vec![
+                    NVector::from_lat_long_degrees(51.6, -0.3),
+                    NVector::from_lat_long_degrees(51.55, 0.1),
+                    NVector::from_lat_long_degrees(51.4, -0.05),
+                ],
+            ),
+            // beacons a few hundred metres apart.
+            (
+                NVector::from_lat_long_degrees(-33.86, 151.2),
+                vec![
+                    NVector::from_lat_long_degrees(-33.862, 151.203),
+                    NVector::from_lat_long_degrees(-33.857, 151.201),
+                    NVector::from_lat_long_degrees(-33.861, 151.197),
+                ],
+            ),
+            // across the antimeridian, close to the pole.
+            (
+                NVector::from_lat_long_degrees(88.0, 180.0),
+                vec![
+                    NVector::from_lat_long_degrees(87.0, 170.0),
+                    NVector::from_lat_long_degrees(89.0, -170.0),
+                    NVector::from_lat_long_degrees(86.5, -175.0),
+                    NVector::from_lat_long_degrees(88.5, 100.0),
+                ],
+            ),
+            // at one of the beacons.
+            (
+                NVector::from_lat_long_degrees(10.0, 10.0),
+                vec![
+                    NVector::from_lat_long_degrees(10.0, 10.0),
+                    NVector::from_lat_long_degrees(11.0, 10.0),
+                    NVector::from_lat_long_degrees(10.0, 11.0),
+                ],
+            ),
+        ];
+        for (p, beacons) in cases {
+            let fix = Sphere::EARTH
+                .trilaterate(&distances_to(p, &beacons))
+                .unwrap();
+            assert_nv_eq_d7(p, fix.position());
+            assert!(fix.residual() < Length::from_metres(1e-3));
+        }
+    }
+
+    #[test]
+    fn trilaterate_noisy() {
+        let p = NVector::from_lat_long_degrees(45.0, 5.0);
+        let beacons = [
+            NVector::from_lat_long_degrees(45.3, 4.8),
+            NVector::from_lat_long_degrees(45.1, 5.4),
+            NVector::from_lat_long_degrees(44.7, 5.1),
+            NVector::from_lat_long_degrees(44.9, 4.6),
+            NVector::from_lat_long_degrees(45.2, 5.2),
+        ];
+        // distances off by up to 50 metres.
+        let errors = [30.0, -50.0, 10.0, 45.0, -20.0];
+        let observations: Vec<(NVector, Length)> = distances_to(p, &beacons)
+            .iter()
+            .zip(errors)
+            .map(|((b, d), e)| (*b, *d + Length::from_metres(e)))
+            .collect();
+        let fix = Sphere::EARTH.trilaterate(&observations).unwrap();
+        assert!(Sphere::EARTH.distance(p, fix.position()) < Length::from_metres(50.0));
+        assert!(fix.residual() > Length::from_metres(1.0));
+        assert!(fix.residual() < Length::from_metres(50.0));
+        // the fix minimises the sum of the squared residuals: moving it increases the sum.
+        let error = |x: NVector| -> f64 {
+            observations
+                .iter()
+                .map(|(b, d)| (Sphere::EARTH.distance(x, *b) - *d).as_metres().powi(2))
+                .sum()
+        };
+        let e = error(fix.position());
+        assert_eq!(
+            Length::from_metres((e / beacons.len() as f64).sqrt()).round_mm(),
+            fix.residual().round_mm()
+        );
+        for b in (0..8).map(|i| Angle::from_degrees(i as f64 * 45.0)) {
+            let moved = Sphere::EARTH.destination_pos(fix.position(), b, Length::from_metres(1.0));
+            assert!(error(moved) > e);
+        }
+    }
+
+    #[test]
+    fn trilaterate_degenerate() {
+        let p = NVector::from_lat_long_degrees(1.0, 1.0);
+        // less than 3 observations.
+        let two = distances_to(
+            p,
+            &[
+                NVector::from_lat_long_degrees(0.0, 0.0),
+                NVector::from_lat_long_degrees(2.0, 0.0),
+            ],
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::EARTH.trilaterate(&two)
+        );
+        // all beacons on the equator: (1, 1) and (-1, 1) are equally good.
+        let equator = distances_to(
+            p,
+            &[
+                NVector::from_lat_long_degrees(0.0, 0.0),
+                NVector::from_lat_long_degrees(0.0, 1.5),
+                NVector::from_lat_long_degrees(0.0, 3.0),
+                NVector::from_lat_long_degrees(0.0, -2.0),
+            ],
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::EARTH.trilaterate(&equator)
+        );
+        // all beacons on an oblique great circle.
+        let gc = GreatCircle::new(
+            NVector::from_lat_long_degrees(-10.0, -20.0),
+            NVector::from_lat_long_degrees(30.0, 40.0),
+        );
+        let beacons: Vec<NVector> = (0..4)
+            .map(|i| {
+                Sphere::EARTH.destination_pos(
+                    NVector::from_lat_long_degrees(-10.0, -20.0),
+                    Sphere::initial_bearing(
+                        NVector::from_lat_long_degrees(-10.0, -20.0),
+                        NVector::from_lat_long_degrees(30.0, 40.0),
+                    ),
+                    Length::from_kilometres(i as f64 * 500.0),
+                )
+            })
+            .collect();
+        assert!(beacons
+            .iter()
+            .all(|b| Sphere::cross_track_angle(*b, gc).as_degrees().abs() < 1e-12));
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::EARTH.trilaterate(&distances_to(p, &beacons))
+        );
+    }
+
+    // polygon_area
+
+    #[test]
+    fn polygon_area_degenerate() {
+        let p1 = NVector::from_lat_long_degrees(10.0, 20.0);
+        let p2 = NVector::from_lat_long_degrees(15.0, 25.0);
+        let p3 = NVector::from_lat_long_degrees(5.0, 30.0);
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::EARTH.polygon_area(&[])
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::EARTH.polygon_area(&[p1, p2])
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::EARTH.polygon_area(&[p1, p2, p1])
+        );
+        assert_eq!(
+            Err(NavigationError::DegenerateGeometry),
+            Sphere::EARTH.polygon_area(&[p1, p2, p2, p3])
+        );
+        assert_eq!(
+            Err(NavigationError::AntipodalPositions),
+            Sphere::EARTH.polygon_area(&[p1, p2, p2.antipode(), p3])
+        );
+    }
+
+    #[test]
+    fn polygon_area_octant() {
+        let s = Sphere::new(Length::from_metres(1.0));
+        let vs = vec![
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 90.0),
+            NVector::from_lat_long_degrees(90.0, 0.0),
+        ];
+        let expected = Area::from_square_metres(4.0 * PI / 8.0);
+        let tolerance = Area::from_square_metres(1e-15);
+        assert!((s.signed_polygon_area(&vs).unwrap() - expected).abs() < tolerance);
+        let mut rvs = vs.clone();
+        rvs.reverse();
+        assert!((s.signed_polygon_area(&rvs).unwrap() + expected).abs() < tolerance);
+        assert!((s.polygon_area(&rvs).unwrap() - expected).abs() < tolerance);
+    }
+
+    #[test]
+    fn polygon_area_closed() {
+        let vs = vec![
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            NVector::from_lat_long_degrees(1.0, 1.0),
+            NVector::from_lat_long_degrees(1.0, 0.0),
+        ];
+        let mut closed = vs.clone();
+        closed.push(vs[0]);
+        assert_eq!(
+            Sphere::EARTH.signed_polygon_area(&vs),
+            Sphere::EARTH.signed_polygon_area(&closed)
+        );
+    }
+
+    #[test]
+    fn polygon_area_antimeridian() {
+        let vs = vec![
+            NVector::from_lat_long_degrees(-1.0, 179.0),
+            NVector::from_lat_long_degrees(-1.0, -179.0),
+            NVector::from_lat_long_degrees(1.0, -179.0),
+            NVector::from_lat_long_degrees(1.0, 179.0),
+        ];
+        // same polygon centred on the prime meridian.
+        let shifted = vec![
+            NVector::from_lat_long_degrees(-1.0, -1.0),
+            NVector::from_lat_long_degrees(-1.0, 1.0),
+            NVector::from_lat_long_degrees(1.0, 1.0),
+            NVector::from_lat_long_degrees(1.0, -1.0),
+        ];
+        let a = Sphere::EARTH.signed_polygon_area(&vs).unwrap();
+        let e = Sphere::EARTH.signed_polygon_area(&shifted).unwrap();
+        assert!((a - e).abs() < Area::from_square_metres(1.0));
+        // about 2 x 2 degrees.
+        assert_eq!(Area::from_square_kilometres(49_460.0), a.round_km2());
+    }
+
+    #[test]
+    fn polygon_area_pole() {
+        // cap above 80 degrees of latitude approximated by a polygon of 360 vertices.
+        let vs = (0..360)
+            .map(|i| NVector::from_lat_long_degrees(80.0, i as f64))
+            .collect::<Vec<_>>();
+        let s = Sphere::new(Length::from_metres(1.0));
+        let cap = Area::from_square_metres(2.0 * PI * (1.0 - 80.0f64.to_radians().sin()));
+        // minor arcs bulge towards the pole: the polygon is slightly smaller than the cap.
+        let a = s.signed_polygon_area(&vs).unwrap();
+        assert!(a < cap);
+        assert!((cap - a) / cap < 1e-4);
+
+        // same around the south pole: vertices are clockwise.
+        let vs = (0..360)
+            .map(|i| NVector::from_lat_long_degrees(-80.0, i as f64))
+            .collect::<Vec<_>>();
+        let a = s.signed_polygon_area(&vs).unwrap();
+        assert!(a < Area::ZERO);
+        assert!((cap - a.abs()) / cap < 1e-4);
+    }
+
+    #[test]
+    fn polygon_area_wyoming() {
+        // Wyoming is bounded by 41N, 45N, 104.05W and 111.05W: 253,335 km² (US Census Bureau).
+        let vs = vec![
+            NVector::from_lat_long_degrees(41.0, -111.05),
+            NVector::from_lat_long_degrees(41.0, -104.05),
+            NVector::from_lat_long_degrees(45.0, -104.05),
+            NVector::from_lat_long_degrees(45.0, -111.05),
+        ];
+        let a = Sphere::EARTH.polygon_area(&vs).unwrap();
+        let e = Area::from_square_kilometres(253_335.0);
+        assert!((a - e).abs() / e < 0.01);
+    }
+
+    // waypoints
+
+    fn waypoints_d7(p1: NVector, p2: NVector, spacing: Length) -> Vec<LatLong> {
+        Sphere::EARTH
+            .waypoints(p1, p2, spacing)
+            .iter()
+            .map(|wp| LatLong::from_nvector(*wp).round_d7())
+            .collect()
+    }
 
     #[test]
-    fn turn_collinear() {
-        let actual = Sphere::turn(
-            NVector::from_lat_long_degrees(0.0, 0.0),
-            NVector::from_lat_long_degrees(45.0, 0.0),
-            NVector::from_lat_long_degrees(90.0, 0.0),
+    fn waypoints_even_division() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, 3.0);
+        let spacing = Sphere::EARTH.distance(p1, p2) / 3.0;
+        assert_eq!(
+            vec![
+                LatLong::from_degrees(0.0, 0.0),
+                LatLong::from_degrees(0.0, 1.0),
+                LatLong::from_degrees(0.0, 2.0),
+                LatLong::from_degrees(0.0, 3.0)
+            ],
+            waypoints_d7(p1, p2, spacing)
         );
-        assert_eq!(Angle::ZERO, actual);
     }
 
     #[test]
-    fn turn_left() {
-        let actual = Sphere::turn(
-            NVector::from_lat_long_degrees(0.0, 0.0),
-            NVector::from_lat_long_degrees(45.0, 0.0),
-            NVector::from_lat_long_degrees(60.0, -10.0),
+    fn waypoints_uneven_division() {
+        let p1 = NVector::from_lat_long_degrees(51.5074, -0.1278);
+        let p2 = NVector::from_lat_long_degrees(40.6892, -74.0445);
+        // 100 nautical miles.
+        let spacing = Length::from_metres(185_200.0);
+        let wps = Sphere::EARTH.waypoints(p1, p2, spacing);
+        let total = Sphere::EARTH.distance(p1, p2);
+        assert_eq!(
+            (total.as_metres() / spacing.as_metres()).ceil() as usize + 1,
+            wps.len()
         );
-        assert_eq!(Angle::from_radians(0.3175226173130951), actual);
+        assert_eq!(p1, wps[0]);
+        assert_eq!(p2, wps[wps.len() - 1]);
+        for w in wps[..wps.len() - 1].windows(2) {
+            let d = Sphere::EARTH.distance(w[0], w[1]);
+            assert_eq!(spacing.round_mm(), d.round_mm());
+            // on the great circle.
+            let xtd = Sphere::EARTH.cross_track_distance(w[1], GreatCircle::new(p1, p2));
+            assert!(xtd.as_metres().abs() < 1e-6);
+        }
+        let last = Sphere::EARTH.distance(wps[wps.len() - 2], p2);
+        assert!(last < spacing);
+        assert!(last > Length::ZERO);
     }
 
     #[test]
-    fn turn_right() {
-        let actual = Sphere::turn(
-            NVector::from_lat_long_degrees(0.0, 0.0),
-            NVector::from_lat_long_degrees(45.0, 0.0),
-            NVector::from_lat_long_degrees(60.0, 10.0),
+    fn waypoints_spacing_too_large() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, 1.0);
+        let d = Sphere::EARTH.distance(p1, p2);
+        assert_eq!(vec![p1, p2], Sphere::EARTH.waypoints(p1, p2, d));
+        assert_eq!(
+            vec![p1, p2],
+            Sphere::EARTH.waypoints(p1, p2, d + Length::from_metres(1.0))
+        );
+    }
+
+    #[test]
+    fn waypoints_invalid_spacing() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, 1.0);
+        assert_eq!(vec![p1, p2], Sphere::EARTH.waypoints(p1, p2, Length::ZERO));
+        assert_eq!(
+            vec![p1, p2],
+            Sphere::EARTH.waypoints(p1, p2, Length::from_metres(-1.0))
+        );
+        assert_eq!(
+            vec![p1, p2],
+            Sphere::EARTH.waypoints(p1, p2, Length::from_metres(f64::NAN))
+        );
+    }
+
+    #[test]
+    fn waypoints_tiny_spacing() {
+        let p1 = NVector::from_lat_long_degrees(0.0, 0.0);
+        let p2 = NVector::from_lat_long_degrees(0.0, 2.5);
+        let wps = Sphere::EARTH.waypoints(p1, p2, Length::from_metres(1e-9));
+        assert_eq!(1_000_001, wps.len());
+        assert_eq!(p1, wps[0]);
+        assert_eq!(p2, wps[1_000_000]);
+        let segment = Sphere::EARTH.distance(p1, p2) / 1e6;
+        assert_eq!(
+            segment.round_mm(),
+            Sphere::EARTH.distance(wps[0], wps[1]).round_mm()
+        );
+        assert_eq!(
+            segment.round_mm(),
+            Sphere::EARTH
+                .distance(wps[999_999], wps[1_000_000])
+                .round_mm()
         );
-        assert_eq!(Angle::from_radians(-0.3175226173130951), actual);
     }
 
     // newton_raphson
@@ -1522,7 +4257,7 @@ mod tests {
         );
 
         assert_time_to_cpa(
-            Duration::from_millis(113_961_40),
+            Duration::from_millis(11_396_140),
             Sphere::EARTH.time_to_cpa(ownship, intruder),
         );
     }
@@ -1581,6 +4316,126 @@ mod tests {
         assert!(Sphere::EARTH.time_to_cpa(ownship, intruder).is_none());
     }
 
+    // CPA
+
+    #[test]
+    fn cpa_head_on_along_equator() {
+        let ownship = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, -0.1),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.1),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(400.0),
+        );
+        let cpa = Sphere::EARTH.cpa(ownship, intruder).unwrap();
+        assert_eq!(Length::ZERO, cpa.distance().round_m());
+        // time to CPA is computed to the millisecond: ~ 0.2 metres at 400 knots.
+        assert_eq!(
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_nvector(cpa.ownship_position()).round_d5()
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, 0.0),
+            LatLong::from_nvector(cpa.intruder_position()).round_d5()
+        );
+    }
+
+    #[test]
+    fn cpa_symmetric_offset_head_on() {
+        // symmetric about (0, 0): the vehicles are abeam each other when crossing the prime meridian.
+        let ownship = Vehicle::new(
+            NVector::from_lat_long_degrees(0.01, -0.2),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(300.0),
+        );
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(-0.01, 0.2),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(300.0),
+        );
+        let cpa = Sphere::EARTH.cpa(ownship, intruder).unwrap();
+        let own = LatLong::from_nvector(cpa.ownship_position());
+        let int = LatLong::from_nvector(cpa.intruder_position());
+        assert_eq!(0.0, own.longitude().round_d5().as_degrees().abs());
+        assert_eq!(0.0, int.longitude().round_d5().as_degrees().abs());
+        assert_eq!(own.latitude().round_d7(), -int.latitude().round_d7());
+        assert_eq!(
+            cpa.distance().round_m(),
+            Sphere::EARTH
+                .distance(cpa.ownship_position(), cpa.intruder_position())
+                .round_m()
+        );
+        // both vehicles travelled ~ 0.2 degrees.
+        let travelled = Speed::from_knots(300.0) * cpa.time();
+        let expected = Angle::from_degrees(0.2) * Sphere::EARTH.radius();
+        assert!((travelled - expected).abs() < Length::from_metres(10.0));
+    }
+
+    #[test]
+    fn cpa_planar_approximation() {
+        // short range: CPA on the sphere matches the planar CPA in a local east-north frame.
+        let own_p = LatLong::from_degrees(45.0, 5.0);
+        let int_p = LatLong::from_degrees(45.05, 5.08);
+        let own_bearing = Angle::from_degrees(60.0);
+        let int_bearing = Angle::from_degrees(200.0);
+        let own_speed = Speed::from_metres_per_second(12.0);
+        let int_speed = Speed::from_metres_per_second(9.0);
+
+        let ownship = Vehicle::new(own_p.to_nvector(), own_bearing, own_speed);
+        let intruder = Vehicle::new(int_p.to_nvector(), int_bearing, int_speed);
+        let cpa = Sphere::EARTH.cpa(ownship, intruder).unwrap();
+
+        let r = Sphere::EARTH.radius().as_metres();
+        let lat0 = own_p.latitude().as_radians();
+        let dx = (int_p.longitude() - own_p.longitude()).as_radians() * r * lat0.cos();
+        let dy = (int_p.latitude() - own_p.latitude()).as_radians() * r;
+        let velocity = |b: Angle, s: Speed| {
+            let v = s.as_metres_per_second();
+            (v * b.as_radians().sin(), v * b.as_radians().cos())
+        };
+        let (own_vx, own_vy) = velocity(own_bearing, own_speed);
+        let (int_vx, int_vy) = velocity(int_bearing, int_speed);
+        let (vx, vy) = (int_vx - own_vx, int_vy - own_vy);
+        let t = -(dx * vx + dy * vy) / (vx * vx + vy * vy);
+        let d = ((dx + vx * t).powi(2) + (dy + vy * t).powi(2)).sqrt();
+
+        assert!((cpa.time().as_secs_f64() - t).abs() < 1.0);
+        assert!((cpa.distance().as_metres() - d).abs() < 5.0);
+    }
+
+    #[test]
+    fn cpa_in_the_past() {
+        let ownship = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 0.1),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, -0.1),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(400.0),
+        );
+        assert!(Sphere::EARTH.cpa(ownship, intruder).is_none());
+    }
+
+    #[test]
+    fn cpa_constant_separation() {
+        let ownship = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.1),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(400.0),
+        );
+        assert!(Sphere::EARTH.cpa(ownship, intruder).is_none());
+    }
+
     fn assert_time_to_cpa(expected: Duration, actual: Option<Duration>) {
         assert!(actual.is_some());
         let a_ms = actual.unwrap().as_millis() as i128;
@@ -1687,6 +4542,398 @@ mod tests {
         assert_eq!(2_764_688, opt_time.unwrap().as_millis());
     }
 
+    // position_after, positions_after
+
+    #[test]
+    fn position_after_is_destination() {
+        let vehicle = Vehicle::new(
+            NVector::from_lat_long_degrees(45.0, -3.0),
+            Angle::from_degrees(37.0),
+            Speed::from_knots(450.0),
+        );
+        let t = Duration::from_secs(5_400);
+        assert_nv_eq_d7(
+            Sphere::EARTH.destination_pos(
+                vehicle.position(),
+                vehicle.bearing(),
+                Length::from_nautical_miles(675.0),
+            ),
+            Sphere::EARTH.position_after(vehicle, t),
+        );
+        // radius of the sphere is used.
+        assert_nv_eq_d7(
+            Sphere::MOON.destination_pos(
+                vehicle.position(),
+                vehicle.bearing(),
+                Length::from_nautical_miles(675.0),
+            ),
+            Sphere::MOON.position_after(vehicle, t),
+        );
+    }
+
+    #[test]
+    fn positions_after_consistent_with_position_after() {
+        let vehicle = Vehicle::new(
+            NVector::from_lat_long_degrees(-33.9, 18.4),
+            Angle::from_degrees(250.0),
+            Speed::from_knots(12.0),
+        );
+        let times: Vec<Duration> = (0..50).map(|i| Duration::from_secs(i * 1_800)).collect();
+        let positions = Sphere::EARTH.positions_after(vehicle, &times);
+        assert_eq!(times.len(), positions.len());
+        assert_eq!(vehicle.position(), positions[0]);
+        for (t, p) in times.iter().zip(positions) {
+            assert_nv_eq_d7(Sphere::EARTH.position_after(vehicle, *t), p);
+        }
+    }
+
+    #[test]
+    fn positions_after_linear_in_time() {
+        let vehicle = Vehicle::new(
+            NVector::from_lat_long_degrees(60.0, 5.0),
+            Angle::from_degrees(300.0),
+            Speed::from_knots(20.0),
+        );
+        let times: Vec<Duration> = (0..10).map(|i| Duration::from_secs(i * 60)).collect();
+        let positions = Sphere::EARTH.positions_after(vehicle, &times);
+        // distance travelled in each minute: 1/3 nautical mile.
+        let expected = Length::from_nautical_miles(20.0 / 60.0);
+        for w in positions.windows(2) {
+            assert_eq!(
+                expected.round_mm(),
+                Sphere::EARTH.distance(w[0], w[1]).round_mm()
+            );
+        }
+        // all positions on the same great circle.
+        let gc = GreatCircle::new(positions[0], positions[9]);
+        for p in &positions[1..9] {
+            assert!(Sphere::EARTH.cross_track_distance(*p, gc).abs() < Length::from_metres(0.001));
+        }
+    }
+
+    #[test]
+    fn positions_after_stationary() {
+        let p = NVector::from_lat_long_degrees(1.0, 2.0);
+        let vehicle = Vehicle::new(p, Angle::ZERO, Speed::ZERO);
+        assert_eq!(
+            vec![p, p],
+            Sphere::EARTH.positions_after(vehicle, &[Duration::ZERO, Duration::from_secs(60)])
+        );
+        assert!(Sphere::EARTH.positions_after(vehicle, &[]).is_empty());
+    }
+
+    // dead_reckon
+
+    #[test]
+    fn dead_reckon_chains_legs() {
+        let start = NVector::from_lat_long_degrees(50.0, -5.0);
+        let legs = [
+            (
+                Duration::from_secs(1_800),
+                Angle::from_degrees(225.0),
+                Speed::from_knots(12.0),
+            ),
+            (
+                Duration::from_secs(5_400),
+                Angle::from_degrees(270.0),
+                Speed::from_knots(8.0),
+            ),
+            (
+                Duration::from_secs(600),
+                Angle::from_degrees(10.0),
+                Speed::from_knots(20.0),
+            ),
+        ];
+        let positions = Sphere::EARTH.dead_reckon(start, &legs);
+        assert_eq!(legs.len() + 1, positions.len());
+        assert_eq!(start, positions[0]);
+        for (i, (d, c, s)) in legs.iter().enumerate() {
+            let vehicle = Vehicle::new(positions[i], *c, *s);
+            assert_eq!(Sphere::EARTH.position_after(vehicle, *d), positions[i + 1]);
+        }
+    }
+
+    #[test]
+    fn dead_reckon_square_circuit() {
+        // 10 kilometres north, east, south then west at 10 metres per second.
+        let start = NVector::from_lat_long_degrees(45.0, 10.0);
+        let legs: Vec<(Duration, Angle, Speed)> = [0.0, 90.0, 180.0, 270.0]
+            .iter()
+            .map(|c| {
+                (
+                    Duration::from_secs(1_000),
+                    Angle::from_degrees(*c),
+                    Speed::from_metres_per_second(10.0),
+                )
+            })
+            .collect();
+        let positions = Sphere::EARTH.dead_reckon(start, &legs);
+        assert_eq!(5, positions.len());
+        for w in positions.windows(2) {
+            assert_eq!(
+                Length::from_kilometres(10.0).round_mm(),
+                Sphere::EARTH.distance(w[0], w[1]).round_mm()
+            );
+        }
+        // the circuit does not quite close on the sphere (meridians converge), but the closure error is
+        // below 0.1% of the 40 kilometres travelled.
+        let closure = Sphere::EARTH.distance(start, positions[4]);
+        assert!(closure > Length::from_metres(1.0));
+        assert!(closure < Length::from_metres(40.0));
+    }
+
+    #[test]
+    fn dead_reckon_unchanged() {
+        let start = NVector::from_lat_long_degrees(-20.0, 30.0);
+        let legs = [
+            (
+                Duration::ZERO,
+                Angle::from_degrees(45.0),
+                Speed::from_knots(10.0),
+            ),
+            (
+                Duration::from_secs(3_600),
+                Angle::from_degrees(45.0),
+                Speed::ZERO,
+            ),
+        ];
+        assert_eq!(
+            vec![start, start, start],
+            Sphere::EARTH.dead_reckon(start, &legs)
+        );
+        assert_eq!(vec![start], Sphere::EARTH.dead_reckon(start, &[]));
+    }
+
+    // intercept
+
+    #[test]
+    fn intercept_stationary_intruder() {
+        let interceptor_pos = NVector::from_lat_long_degrees(0.0, 0.0);
+        let intruder_pos = NVector::from_lat_long_degrees(0.0, 1.0);
+        let intruder = Vehicle::new(intruder_pos, Angle::ZERO, Speed::ZERO);
+        let speed = Speed::from_knots(60.0);
+        let intercept = Sphere::EARTH
+            .intercept(interceptor_pos, speed, intruder)
+            .unwrap();
+        let expected = Sphere::EARTH
+            .distance(interceptor_pos, intruder_pos)
+            .as_nautical_miles()
+            / 60.0;
+        assert_eq!(
+            (expected * 3_600.0).round(),
+            intercept.time().as_secs_f64().round()
+        );
+        assert_nv_eq_d7(intruder_pos, intercept.position());
+        assert_eq!(Angle::from_degrees(90.0), intercept.bearing().round_d7());
+    }
+
+    #[test]
+    fn intercept_intruder_heading_at_interceptor() {
+        let interceptor_pos = NVector::from_lat_long_degrees(0.0, 0.0);
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(270.0),
+            Speed::from_knots(20.0),
+        );
+        let intercept = Sphere::EARTH
+            .intercept(interceptor_pos, Speed::from_knots(40.0), intruder)
+            .unwrap();
+        // closing at 60 knots, the interception takes place 2/3 of the way.
+        let distance = Sphere::EARTH.distance(interceptor_pos, intruder.position());
+        assert_eq!(
+            (distance.as_nautical_miles() / 60.0 * 3_600.0).round(),
+            intercept.time().as_secs_f64().round()
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, 2.0 / 3.0).round_d5(),
+            LatLong::from_nvector(intercept.position()).round_d5()
+        );
+        assert_eq!(Angle::from_degrees(90.0), intercept.bearing().round_d7());
+    }
+
+    #[test]
+    fn intercept_intruder_heading_away() {
+        let interceptor_pos = NVector::from_lat_long_degrees(0.0, 0.0);
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(20.0),
+        );
+        // faster and opening: impossible.
+        assert!(Sphere::EARTH
+            .intercept(interceptor_pos, Speed::from_knots(10.0), intruder)
+            .is_none());
+        // slower: closing at 20 knots.
+        let intercept = Sphere::EARTH
+            .intercept(interceptor_pos, Speed::from_knots(40.0), intruder)
+            .unwrap();
+        let distance = Sphere::EARTH.distance(interceptor_pos, intruder.position());
+        assert_eq!(
+            (distance.as_nautical_miles() / 20.0 * 3_600.0).round(),
+            intercept.time().as_secs_f64().round()
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, 2.0).round_d5(),
+            LatLong::from_nvector(intercept.position()).round_d5()
+        );
+    }
+
+    #[test]
+    fn intercept_consistent() {
+        let interceptor_pos = NVector::from_lat_long_degrees(20.0, -60.0);
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(34.0, -50.0),
+            Angle::from_degrees(220.0),
+            Speed::from_knots(600.0),
+        );
+        let speed = Speed::from_knots(700.0);
+        let intercept = Sphere::EARTH
+            .intercept(interceptor_pos, speed, intruder)
+            .unwrap();
+        // the interceptor reaches the intruder following the bearing at the speed.
+        let interceptor = Vehicle::new(interceptor_pos, intercept.bearing(), speed);
+        let p = Sphere::EARTH.destination_pos(
+            interceptor.position(),
+            interceptor.bearing(),
+            speed * intercept.time(),
+        );
+        assert!(Sphere::EARTH.distance(p, intercept.position()) < Length::from_metres(1.0));
+        assert_eq!(
+            speed.as_knots().round(),
+            intercept.speed().as_knots().round()
+        );
+    }
+
+    #[test]
+    fn intercept_beyond_half_circumference() {
+        // the intruder moves to the other side of the Earth, the interceptor goes the other way round.
+        let interceptor_pos = NVector::from_lat_long_degrees(0.0, 0.0);
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 170.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(100.0),
+        );
+        let intercept = Sphere::EARTH
+            .intercept(interceptor_pos, Speed::from_knots(300.0), intruder)
+            .unwrap();
+        // going west, 190 degrees apart closing at 400 knots: the intruder travels 47.5 degrees.
+        assert_eq!(Angle::from_degrees(270.0), intercept.bearing().round_d7());
+        let ll = LatLong::from_nvector(intercept.position()).round_d5();
+        assert_eq!(LatLong::from_degrees(0.0, -142.5), ll);
+    }
+
+    #[test]
+    fn intercept_at_time() {
+        let interceptor_pos = NVector::from_lat_long_degrees(0.0, 0.0);
+        let intruder = Vehicle::new(
+            NVector::from_lat_long_degrees(0.0, 1.0),
+            Angle::from_degrees(90.0),
+            Speed::from_knots(60.0),
+        );
+        let intercept = Sphere::EARTH
+            .intercept_at_time(interceptor_pos, intruder, Duration::from_secs(3_600))
+            .unwrap();
+        // 1 hour at 60 knots: 1 nautical mile = 1 minute of arc.
+        let lng = 1.0
+            + Length::from_nautical_miles(60.0)
+                .to_central_angle(Sphere::EARTH.radius())
+                .as_degrees();
+        assert_eq!(
+            LatLong::from_degrees(0.0, lng).round_d7(),
+            LatLong::from_nvector(intercept.position()).round_d7()
+        );
+        assert_eq!(Angle::from_degrees(90.0), intercept.bearing().round_d7());
+        let d = Sphere::EARTH.distance(interceptor_pos, intercept.position());
+        assert_eq!(
+            (d / Duration::from_secs(3_600)).as_knots().round(),
+            intercept.speed().as_knots().round()
+        );
+    }
+
+    #[test]
+    fn intercept_at_time_zero() {
+        let p = NVector::from_lat_long_degrees(10.0, 20.0);
+        let intruder = Vehicle::new(p, Angle::ZERO, Speed::from_knots(10.0));
+        let intercept = Sphere::EARTH
+            .intercept_at_time(p, intruder, Duration::ZERO)
+            .unwrap();
+        assert_eq!(Speed::ZERO, intercept.speed());
+        assert_eq!(p, intercept.position());
+        assert!(Sphere::EARTH
+            .intercept_at_time(
+                NVector::from_lat_long_degrees(0.0, 0.0),
+                intruder,
+                Duration::ZERO
+            )
+            .is_none());
+    }
+
+    // distance_to_loop
+
+    fn square() -> Loop {
+        Loop::new(&[
+            NVector::from_lat_long_degrees(0.0, 0.0),
+            NVector::from_lat_long_degrees(0.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 10.0),
+            NVector::from_lat_long_degrees(10.0, 0.0),
+        ])
+    }
+
+    #[test]
+    fn distance_to_loop_inside() {
+        let l = square();
+        assert_eq!(
+            Length::ZERO,
+            Sphere::EARTH.distance_to_loop(NVector::from_lat_long_degrees(5.0, 5.0), &l)
+        );
+        // on a vertex and on an edge.
+        assert_eq!(
+            Length::ZERO,
+            Sphere::EARTH
+                .distance_to_loop(NVector::from_lat_long_degrees(0.0, 0.0), &l)
+                .round_mm()
+        );
+        assert_eq!(
+            Length::ZERO,
+            Sphere::EARTH
+                .distance_to_loop(NVector::from_lat_long_degrees(0.0, 5.0), &l)
+                .round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_to_loop_outside_edge() {
+        let l = square();
+        // just south of the southern edge (the equator).
+        let p = NVector::from_lat_long_degrees(-0.001, 5.0);
+        let e = Sphere::EARTH.distance(p, NVector::from_lat_long_degrees(0.0, 5.0));
+        assert_eq!(
+            e.round_mm(),
+            Sphere::EARTH.distance_to_loop(p, &l).round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_to_loop_nearest_vertex() {
+        let l = square();
+        // south-west of the south-west vertex: cannot be projected on either adjacent edge.
+        let p = NVector::from_lat_long_degrees(-1.0, -1.0);
+        let v = NVector::from_lat_long_degrees(0.0, 0.0);
+        assert_eq!(
+            Sphere::EARTH.distance(p, v).round_mm(),
+            Sphere::EARTH.distance_to_loop(p, &l).round_mm()
+        );
+    }
+
+    #[test]
+    fn distance_to_loop_empty() {
+        assert_eq!(
+            Length::MAX,
+            Sphere::EARTH
+                .distance_to_loop(NVector::from_lat_long_degrees(0.0, 0.0), &Loop::new(&[]))
+        );
+    }
+
     // distance_to_angle
     #[test]
     fn distance_to_angle() {
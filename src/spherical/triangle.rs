@@ -0,0 +1,632 @@
+use std::{cmp::Ordering, f64::consts::PI, fmt};
+
+use crate::Angle;
+
+/// Error returned when a [SphericalTriangle] cannot be solved from the given elements.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum TriangleError {
+    /// A side is not within (0, 180) degrees.
+    SideOutOfRange,
+    /// An angle is not within (0, 180) degrees.
+    AngleOutOfRange,
+    /// The sides violate the spherical triangle inequalities: each side must be less than the sum of the 2 others,
+    /// and the sum of the sides must be less than 360 degrees.
+    InvalidSides,
+    /// The angles violate the spherical triangle inequalities: the sum of the angles must be greater than 180
+    /// degrees, and each angle increased by 180 degrees must be greater than the sum of the 2 others.
+    InvalidAngles,
+    /// No spherical triangle has the given elements (e.g. the side opposite the given angle is too short to
+    /// reach the third side).
+    NoSolution,
+}
+
+impl fmt::Display for TriangleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TriangleError::SideOutOfRange => write!(f, "side not within (0, 180) degrees"),
+            TriangleError::AngleOutOfRange => write!(f, "angle not within (0, 180) degrees"),
+            TriangleError::InvalidSides => {
+                write!(f, "sides violate the spherical triangle inequalities")
+            }
+            TriangleError::InvalidAngles => {
+                write!(f, "angles violate the spherical triangle inequalities")
+            }
+            TriangleError::NoSolution => write!(f, "no spherical triangle has the given elements"),
+        }
+    }
+}
+
+impl std::error::Error for TriangleError {}
+
+/// A spherical triangle on the unit sphere: 3 sides (great circle arcs, given as the angle they subtend at the
+/// centre of the sphere) and 3 angles, where the angle `A` is opposite the side `a`, `B` opposite `b` and `C`
+/// opposite `c`.
+///
+/// A [SphericalTriangle] is obtained by solving it from 3 of its elements using one of the `solve_*`
+/// functions; all sides and angles are within (0, 180) degrees (Eulerian triangle).
+///
+/// # Examples
+///
+/// ```
+/// use jord::{Angle, NVector};
+/// use jord::spherical::{Sphere, SphericalTriangle};
+///
+/// // New York (40N, 74W) to London (51.5N, 0W): triangle formed with the North Pole, where the angle at
+/// // the North Pole (C) is the difference of longitude and the angle at New York (A) is the initial course.
+/// let t = SphericalTriangle::solve_sas(
+///     Angle::from_degrees(90.0 - 51.5), // co-latitude of London (opposite New York)
+///     Angle::from_degrees(74.0),        // difference of longitude
+///     Angle::from_degrees(90.0 - 40.0), // co-latitude of New York (opposite London)
+/// )
+/// .unwrap();
+///
+/// let new_york = NVector::from_lat_long_degrees(40.0, -74.0);
+/// let london = NVector::from_lat_long_degrees(51.5, 0.0);
+/// assert_eq!(Sphere::angle(new_york, london).round_d7(), t.side_c().round_d7());
+/// assert_eq!(Sphere::initial_bearing(new_york, london).round_d7(), t.angle_a().round_d7());
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct SphericalTriangle {
+    side_a: Angle,
+    side_b: Angle,
+    side_c: Angle,
+    angle_a: Angle,
+    angle_b: Angle,
+    angle_c: Angle,
+}
+
+impl SphericalTriangle {
+    /// Solves the spherical triangle given its 3 sides (SSS), using the spherical law of cosines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::SphericalTriangle;
+    ///
+    /// // octant: all sides and angles are right angles.
+    /// let right = Angle::from_degrees(90.0);
+    /// let t = SphericalTriangle::solve_sss(right, right, right).unwrap();
+    /// assert_eq!(right, t.angle_a().round_d7());
+    /// assert_eq!(right, t.angle_b().round_d7());
+    /// assert_eq!(right, t.angle_c().round_d7());
+    /// ```
+    pub fn solve_sss(
+        side_a: Angle,
+        side_b: Angle,
+        side_c: Angle,
+    ) -> Result<SphericalTriangle, TriangleError> {
+        let (a, b, c) = (
+            check_side(side_a)?,
+            check_side(side_b)?,
+            check_side(side_c)?,
+        );
+        if a >= b + c || b >= a + c || c >= a + b || a + b + c >= 2.0 * PI {
+            return Err(TriangleError::InvalidSides);
+        }
+        Ok(Self::from_radians(
+            [a, b, c],
+            [
+                opposite_angle(a, b, c),
+                opposite_angle(b, c, a),
+                opposite_angle(c, a, b),
+            ],
+        ))
+    }
+
+    /// Solves the spherical triangle given its 3 angles (AAA) - on a sphere, the angles determine the size of
+    /// the triangle - using the polar triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::SphericalTriangle;
+    ///
+    /// let right = Angle::from_degrees(90.0);
+    /// let t = SphericalTriangle::solve_aaa(right, right, right).unwrap();
+    /// assert_eq!(right, t.side_a().round_d7());
+    /// ```
+    pub fn solve_aaa(
+        angle_a: Angle,
+        angle_b: Angle,
+        angle_c: Angle,
+    ) -> Result<SphericalTriangle, TriangleError> {
+        let (a, b, c) = (
+            check_angle(angle_a)?,
+            check_angle(angle_b)?,
+            check_angle(angle_c)?,
+        );
+        // the sides of the polar triangle are the supplements of the angles.
+        Self::solve_sss(
+            Angle::from_radians(PI - a),
+            Angle::from_radians(PI - b),
+            Angle::from_radians(PI - c),
+        )
+        .map(|t| t.polar())
+        .map_err(|_| TriangleError::InvalidAngles)
+    }
+
+    /// Solves the spherical triangle given 2 sides and the included angle (SAS): sides `a` and `b` and angle
+    /// `C`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::SphericalTriangle;
+    ///
+    /// let right = Angle::from_degrees(90.0);
+    /// let t = SphericalTriangle::solve_sas(right, Angle::from_degrees(45.0), right).unwrap();
+    /// assert_eq!(Angle::from_degrees(45.0), t.side_c().round_d7());
+    /// assert_eq!(right, t.angle_a().round_d7());
+    /// ```
+    pub fn solve_sas(
+        side_a: Angle,
+        angle_c: Angle,
+        side_b: Angle,
+    ) -> Result<SphericalTriangle, TriangleError> {
+        let (a, b) = (check_side(side_a)?, check_side(side_b)?);
+        let cc = check_angle(angle_c)?;
+        let (sin_a, cos_a) = a.sin_cos();
+        let (sin_b, cos_b) = b.sin_cos();
+        let (sin_cc, cos_cc) = cc.sin_cos();
+        let c = (cos_a * cos_b + sin_a * sin_b * cos_cc)
+            .clamp(-1.0, 1.0)
+            .acos();
+        // four-part formula.
+        let aa = (sin_a * sin_cc).atan2(cos_a * sin_b - sin_a * cos_b * cos_cc);
+        let bb = (sin_b * sin_cc).atan2(cos_b * sin_a - sin_b * cos_a * cos_cc);
+        Ok(Self::from_radians([a, b, c], [aa, bb, cc]))
+    }
+
+    /// Solves the spherical triangle given 2 angles and the included side (ASA): angles `A` and `B` and side
+    /// `c`, using the polar triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::SphericalTriangle;
+    ///
+    /// let right = Angle::from_degrees(90.0);
+    /// let t = SphericalTriangle::solve_asa(right, Angle::from_degrees(45.0), right).unwrap();
+    /// assert_eq!(Angle::from_degrees(45.0), t.angle_c().round_d7());
+    /// assert_eq!(right, t.side_a().round_d7());
+    /// ```
+    pub fn solve_asa(
+        angle_a: Angle,
+        side_c: Angle,
+        angle_b: Angle,
+    ) -> Result<SphericalTriangle, TriangleError> {
+        let (aa, bb) = (check_angle(angle_a)?, check_angle(angle_b)?);
+        let c = check_side(side_c)?;
+        Self::solve_sas(
+            Angle::from_radians(PI - aa),
+            Angle::from_radians(PI - c),
+            Angle::from_radians(PI - bb),
+        )
+        .map(|t| t.polar())
+    }
+
+    /// Solves the spherical triangle given 2 sides and the angle opposite the first one (SSA): sides `a` and
+    /// `b` and angle `A`.
+    ///
+    /// This is the ambiguous case: the returned vector contains either 1 or 2 triangles (ordered by
+    /// increasing side `c`). Returns [TriangleError::NoSolution] if no triangle has the given elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::SphericalTriangle;
+    ///
+    /// let ts = SphericalTriangle::solve_ssa(
+    ///     Angle::from_degrees(50.0),
+    ///     Angle::from_degrees(60.0),
+    ///     Angle::from_degrees(60.0),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(2, ts.len());
+    /// ```
+    pub fn solve_ssa(
+        side_a: Angle,
+        side_b: Angle,
+        angle_a: Angle,
+    ) -> Result<Vec<SphericalTriangle>, TriangleError> {
+        let (a, b) = (check_side(side_a)?, check_side(side_b)?);
+        let aa = check_angle(angle_a)?;
+        // law of cosines: cos(a) = cos(b) cos(c) + sin(b) sin(c) cos(A) = r cos(c - phi).
+        let (sin_b, cos_b) = b.sin_cos();
+        let r = cos_b.hypot(sin_b * aa.cos());
+        let phi = (sin_b * aa.cos()).atan2(cos_b);
+        let cos_d = a.cos() / r;
+        if cos_d.abs() > 1.0 {
+            return Err(TriangleError::NoSolution);
+        }
+        let d = cos_d.acos();
+        let mut cs = vec![phi - d];
+        if d > 0.0 {
+            cs.push(phi + d);
+        }
+        let mut ts: Vec<SphericalTriangle> = cs
+            .into_iter()
+            // c is only known modulo 2 * PI.
+            .map(|c| (c + PI).rem_euclid(2.0 * PI) - PI)
+            .filter(|c| *c > 0.0 && *c < PI)
+            .filter_map(|c| Self::solve_sss(side_a, side_b, Angle::from_radians(c)).ok())
+            .collect();
+        if ts.is_empty() {
+            Err(TriangleError::NoSolution)
+        } else {
+            ts.sort_by(|t1, t2| t1.side_c.partial_cmp(&t2.side_c).unwrap_or(Ordering::Equal));
+            Ok(ts)
+        }
+    }
+
+    /// Solves the spherical triangle given 2 angles and the side opposite the first one (AAS): angles `A` and
+    /// `B` and side `a`, using the polar triangle.
+    ///
+    /// Like [SSA](crate::spherical::SphericalTriangle::solve_ssa), this case is ambiguous on a sphere: the
+    /// returned vector contains either 1 or 2 triangles. Returns [TriangleError::NoSolution] if no triangle has
+    /// the given elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::Angle;
+    /// use jord::spherical::SphericalTriangle;
+    ///
+    /// let right = Angle::from_degrees(90.0);
+    /// let ts = SphericalTriangle::solve_aas(right, right, right).unwrap();
+    /// assert_eq!(1, ts.len());
+    /// assert_eq!(right, ts[0].side_b().round_d7());
+    /// ```
+    pub fn solve_aas(
+        angle_a: Angle,
+        angle_b: Angle,
+        side_a: Angle,
+    ) -> Result<Vec<SphericalTriangle>, TriangleError> {
+        let (aa, bb) = (check_angle(angle_a)?, check_angle(angle_b)?);
+        let a = check_side(side_a)?;
+        Self::solve_ssa(
+            Angle::from_radians(PI - aa),
+            Angle::from_radians(PI - bb),
+            Angle::from_radians(PI - a),
+        )
+        .map(|ts| ts.iter().map(|t| t.polar()).collect())
+    }
+
+    /// Returns the side `a` - opposite angle `A`.
+    #[inline]
+    pub fn side_a(&self) -> Angle {
+        self.side_a
+    }
+
+    /// Returns the side `b` - opposite angle `B`.
+    #[inline]
+    pub fn side_b(&self) -> Angle {
+        self.side_b
+    }
+
+    /// Returns the side `c` - opposite angle `C`.
+    #[inline]
+    pub fn side_c(&self) -> Angle {
+        self.side_c
+    }
+
+    /// Returns the angle `A` - opposite side `a`.
+    #[inline]
+    pub fn angle_a(&self) -> Angle {
+        self.angle_a
+    }
+
+    /// Returns the angle `B` - opposite side `b`.
+    #[inline]
+    pub fn angle_b(&self) -> Angle {
+        self.angle_b
+    }
+
+    /// Returns the angle `C` - opposite side `c`.
+    #[inline]
+    pub fn angle_c(&self) -> Angle {
+        self.angle_c
+    }
+
+    /// Returns the polar triangle of this triangle: its sides are the supplements of the angles of this
+    /// triangle and its angles are the supplements of the sides of this triangle.
+    fn polar(&self) -> Self {
+        let s = |a: Angle| PI - a.as_radians();
+        Self::from_radians(
+            [s(self.angle_a), s(self.angle_b), s(self.angle_c)],
+            [s(self.side_a), s(self.side_b), s(self.side_c)],
+        )
+    }
+
+    fn from_radians(sides: [f64; 3], angles: [f64; 3]) -> Self {
+        Self {
+            side_a: Angle::from_radians(sides[0]),
+            side_b: Angle::from_radians(sides[1]),
+            side_c: Angle::from_radians(sides[2]),
+            angle_a: Angle::from_radians(angles[0]),
+            angle_b: Angle::from_radians(angles[1]),
+            angle_c: Angle::from_radians(angles[2]),
+        }
+    }
+}
+
+/// Returns the given side in radians if within (0, PI).
+fn check_side(side: Angle) -> Result<f64, TriangleError> {
+    let r = side.as_radians();
+    if r > 0.0 && r < PI {
+        Ok(r)
+    } else {
+        Err(TriangleError::SideOutOfRange)
+    }
+}
+
+/// Returns the given angle in radians if within (0, PI).
+fn check_angle(angle: Angle) -> Result<f64, TriangleError> {
+    let r = angle.as_radians();
+    if r > 0.0 && r < PI {
+        Ok(r)
+    } else {
+        Err(TriangleError::AngleOutOfRange)
+    }
+}
+
+/// Returns the angle opposite side a given the 3 sides (law of cosines).
+fn opposite_angle(a: f64, b: f64, c: f64) -> f64 {
+    ((a.cos() - b.cos() * c.cos()) / (b.sin() * c.sin()))
+        .clamp(-1.0, 1.0)
+        .acos()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::f64::consts::PI;
+
+    use crate::{
+        spherical::{Sphere, SphericalTriangle, TriangleError},
+        Angle, NVector,
+    };
+
+    fn deg(d: f64) -> Angle {
+        Angle::from_degrees(d)
+    }
+
+    /// triangle with vertices at Stockholm (A), Rome (B) and Madrid (C).
+    fn expected() -> SphericalTriangle {
+        let a = NVector::from_lat_long_degrees(59.3293, 18.0686);
+        let b = NVector::from_lat_long_degrees(41.9028, 12.4964);
+        let c = NVector::from_lat_long_degrees(40.4168, -3.7038);
+        let angle = |p: NVector, q: NVector, r: NVector| {
+            Angle::from_radians(PI - Sphere::turn(p, q, r).as_radians().abs())
+        };
+        SphericalTriangle {
+            side_a: Sphere::angle(b, c),
+            side_b: Sphere::angle(a, c),
+            side_c: Sphere::angle(a, b),
+            angle_a: angle(c, a, b),
+            angle_b: angle(a, b, c),
+            angle_c: angle(b, c, a),
+        }
+    }
+
+    fn assert_triangle_eq(e: SphericalTriangle, a: SphericalTriangle) {
+        assert_eq!(e.side_a().round_d7(), a.side_a().round_d7());
+        assert_eq!(e.side_b().round_d7(), a.side_b().round_d7());
+        assert_eq!(e.side_c().round_d7(), a.side_c().round_d7());
+        assert_eq!(e.angle_a().round_d7(), a.angle_a().round_d7());
+        assert_eq!(e.angle_b().round_d7(), a.angle_b().round_d7());
+        assert_eq!(e.angle_c().round_d7(), a.angle_c().round_d7());
+    }
+
+    // solve_sss
+
+    #[test]
+    fn solve_sss() {
+        let e = expected();
+        assert_triangle_eq(
+            e,
+            SphericalTriangle::solve_sss(e.side_a(), e.side_b(), e.side_c()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn solve_sss_right_triangle() {
+        // Napier's rules: cos(c) = cos(a) cos(b) when C is a right angle.
+        let c = (30.0f64.to_radians().cos() * 40.0f64.to_radians().cos()).acos();
+        let t = SphericalTriangle::solve_sss(deg(30.0), deg(40.0), Angle::from_radians(c)).unwrap();
+        assert_eq!(deg(90.0), t.angle_c().round_d7());
+    }
+
+    #[test]
+    fn solve_sss_invalid() {
+        assert_eq!(
+            Err(TriangleError::SideOutOfRange),
+            SphericalTriangle::solve_sss(Angle::ZERO, deg(10.0), deg(10.0))
+        );
+        assert_eq!(
+            Err(TriangleError::SideOutOfRange),
+            SphericalTriangle::solve_sss(deg(10.0), deg(180.0), deg(10.0))
+        );
+        assert_eq!(
+            Err(TriangleError::InvalidSides),
+            SphericalTriangle::solve_sss(deg(10.0), deg(20.0), deg(30.0))
+        );
+        assert_eq!(
+            Err(TriangleError::InvalidSides),
+            SphericalTriangle::solve_sss(deg(50.0), deg(20.0), deg(20.0))
+        );
+        assert_eq!(
+            Err(TriangleError::InvalidSides),
+            SphericalTriangle::solve_sss(deg(120.0), deg(120.0), deg(120.0))
+        );
+    }
+
+    // solve_aaa
+
+    #[test]
+    fn solve_aaa() {
+        let e = expected();
+        assert_triangle_eq(
+            e,
+            SphericalTriangle::solve_aaa(e.angle_a(), e.angle_b(), e.angle_c()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn solve_aaa_invalid() {
+        assert_eq!(
+            Err(TriangleError::AngleOutOfRange),
+            SphericalTriangle::solve_aaa(deg(-10.0), deg(100.0), deg(100.0))
+        );
+        // sum of angles not greater than 180 degrees: planar triangle.
+        assert_eq!(
+            Err(TriangleError::InvalidAngles),
+            SphericalTriangle::solve_aaa(deg(60.0), deg(60.0), deg(60.0))
+        );
+        assert_eq!(
+            Err(TriangleError::InvalidAngles),
+            SphericalTriangle::solve_aaa(deg(170.0), deg(170.0), deg(10.0))
+        );
+    }
+
+    // solve_sas
+
+    #[test]
+    fn solve_sas() {
+        let e = expected();
+        assert_triangle_eq(
+            e,
+            SphericalTriangle::solve_sas(e.side_a(), e.angle_c(), e.side_b()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn solve_sas_navigation() {
+        // great circle sailing from New York (40N, 74W) to London (51.5N, 0W).
+        let t =
+            SphericalTriangle::solve_sas(deg(90.0 - 51.5), deg(74.0), deg(90.0 - 40.0)).unwrap();
+        let new_york = NVector::from_lat_long_degrees(40.0, -74.0);
+        let london = NVector::from_lat_long_degrees(51.5, 0.0);
+        assert_eq!(
+            Sphere::angle(new_york, london).round_d7(),
+            t.side_c().round_d7()
+        );
+        assert_eq!(
+            Sphere::initial_bearing(new_york, london).round_d7(),
+            t.angle_a().round_d7()
+        );
+        assert_eq!(
+            Sphere::final_bearing(new_york, london).round_d7(),
+            (deg(180.0) - t.angle_b()).round_d7()
+        );
+    }
+
+    #[test]
+    fn solve_sas_published_example() {
+        // Aviation Formulary (Ed Williams), great circle from LAX (33°57'N, 118°24'W) to JFK (40°38'N, 73°47'W):
+        // distance 0.623585 radians (about 2144 nautical miles) on an initial true course of 1.150035 radians
+        // (about 66 degrees).
+        let dms = |d: i64, m: i64| Angle::from_dms(d, m, 0.0).unwrap();
+        let t = SphericalTriangle::solve_sas(
+            dms(49, 22), // co-latitude of JFK.
+            dms(44, 37), // difference of longitude.
+            dms(56, 3),  // co-latitude of LAX.
+        )
+        .unwrap();
+        assert_eq!(0.623585, (t.side_c().as_radians() * 1e6).round() / 1e6);
+        assert_eq!(1.150035, (t.angle_a().as_radians() * 1e6).round() / 1e6);
+        assert_eq!(2144.0, (t.side_c().as_degrees() * 60.0).round());
+        assert_eq!(66.0, t.angle_a().as_degrees().round());
+    }
+
+    #[test]
+    fn solve_sas_invalid() {
+        assert_eq!(
+            Err(TriangleError::AngleOutOfRange),
+            SphericalTriangle::solve_sas(deg(10.0), deg(180.0), deg(10.0))
+        );
+        assert_eq!(
+            Err(TriangleError::SideOutOfRange),
+            SphericalTriangle::solve_sas(deg(10.0), deg(90.0), deg(-10.0))
+        );
+    }
+
+    // solve_asa
+
+    #[test]
+    fn solve_asa() {
+        let e = expected();
+        assert_triangle_eq(
+            e,
+            SphericalTriangle::solve_asa(e.angle_a(), e.side_c(), e.angle_b()).unwrap(),
+        );
+    }
+
+    // solve_ssa
+
+    #[test]
+    fn solve_ssa_two_solutions() {
+        let e = expected();
+        let ts = SphericalTriangle::solve_ssa(e.side_a(), e.side_b(), e.angle_a()).unwrap();
+        assert_eq!(2, ts.len());
+        assert!(ts[0].side_c() < ts[1].side_c());
+        assert!(ts
+            .iter()
+            .any(|t| t.side_c().round_d7() == e.side_c().round_d7()));
+        for t in ts {
+            assert_eq!(e.side_a().round_d7(), t.side_a().round_d7());
+            assert_eq!(e.side_b().round_d7(), t.side_b().round_d7());
+            assert_eq!(e.angle_a().round_d7(), t.angle_a().round_d7());
+        }
+    }
+
+    #[test]
+    fn solve_ssa_one_solution() {
+        // side opposite the given angle is larger than the other side.
+        let e = expected();
+        let ts = SphericalTriangle::solve_ssa(e.side_b(), e.side_a(), e.angle_b()).unwrap();
+        assert_eq!(1, ts.len());
+        assert_triangle_eq(
+            SphericalTriangle::solve_sss(e.side_b(), e.side_a(), e.side_c()).unwrap(),
+            ts[0],
+        );
+    }
+
+    #[test]
+    fn solve_ssa_no_solution() {
+        assert_eq!(
+            Err(TriangleError::NoSolution),
+            SphericalTriangle::solve_ssa(deg(40.0), deg(50.0), deg(60.0))
+        );
+    }
+
+    // solve_aas
+
+    #[test]
+    fn solve_aas() {
+        let e = expected();
+        let ts = SphericalTriangle::solve_aas(e.angle_a(), e.angle_b(), e.side_a()).unwrap();
+        assert!(!ts.is_empty());
+        assert!(ts
+            .iter()
+            .any(|t| t.side_c().round_d7() == e.side_c().round_d7()));
+        for t in ts {
+            assert_eq!(e.angle_a().round_d7(), t.angle_a().round_d7());
+            assert_eq!(e.angle_b().round_d7(), t.angle_b().round_d7());
+            assert_eq!(e.side_a().round_d7(), t.side_a().round_d7());
+        }
+    }
+
+    #[test]
+    fn solve_aas_no_solution() {
+        assert_eq!(
+            Err(TriangleError::NoSolution),
+            SphericalTriangle::solve_aas(deg(140.0), deg(130.0), deg(120.0))
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{Length, NVector};
+
+/// A position fixed from distances to known positions: see
+/// [Sphere::trilaterate](crate::spherical::Sphere::trilaterate).
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct Trilateration {
+    position: NVector,
+    residual: Length,
+}
+
+impl Trilateration {
+    pub(crate) fn new(position: NVector, residual: Length) -> Self {
+        Trilateration { position, residual }
+    }
+
+    /// Returns the fixed position.
+    #[inline]
+    pub fn position(&self) -> NVector {
+        self.position
+    }
+
+    /// Returns the root mean square of the differences between the observed distances and the distances from
+    /// the [fixed position](crate::spherical::Trilateration::position) to each known position: zero if all
+    /// observations are consistent, larger as the observations disagree - i.e. an indication of the quality of
+    /// the fix.
+    #[inline]
+    pub fn residual(&self) -> Length {
+        self.residual
+    }
+}
@@ -0,0 +1,298 @@
+use std::f64::consts::PI;
+
+use crate::{spherical::Rectangle, web_mercator::MAX_LATITUDE, Angle, LatLong};
+
+/// Maximum zoom level of a [Tile].
+const MAX_ZOOM: u8 = 22;
+
+/// Size in pixels of the side of a [Tile].
+const TILE_SIZE: f64 = 256.0;
+
+/// A [slippy map tile](https://wiki.openstreetmap.org/wiki/Slippy_map_tilenames) as used by OpenStreetMap-style
+/// tile servers: at zoom level `z` the Web Mercator projection of the world is divided into `2^z` by `2^z` tiles,
+/// numbered from west (x = 0) to east and from north (y = 0) to south.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub struct Tile {
+    zoom: u8,
+    x: u32,
+    y: u32,
+}
+
+impl Tile {
+    /// Creates a new [Tile] from the given zoom level and tile coordinates. Returns [None] if the zoom level is
+    /// greater than 22 or if either coordinate is not in [0, 2^zoom).
+    pub fn new(zoom: u8, x: u32, y: u32) -> Option<Self> {
+        if zoom > MAX_ZOOM || x >= tiles(zoom) || y >= tiles(zoom) {
+            None
+        } else {
+            Some(Tile { zoom, x, y })
+        }
+    }
+
+    /// Returns the zoom level of this tile.
+    #[inline]
+    pub fn zoom(&self) -> u8 {
+        self.zoom
+    }
+
+    /// Returns the x (west to east) coordinate of this tile.
+    #[inline]
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// Returns the y (north to south) coordinate of this tile.
+    #[inline]
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    /// Returns the north-west corner of this tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Tile};
+    ///
+    /// let tile = Tile::new(10, 511, 340).unwrap();
+    /// assert_eq!(LatLong::from_degrees(51.61802, -0.35156), tile.nw_corner().round_d5());
+    /// ```
+    pub fn nw_corner(&self) -> LatLong {
+        LatLong::new(
+            tile_latitude(self.y, self.zoom),
+            tile_longitude(self.x, self.zoom),
+        )
+    }
+
+    /// Returns the rectangle bounding this tile: the north-west corner of this tile and the north-west corner of
+    /// the tile south-east of this tile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Tile};
+    ///
+    /// let bounds = Tile::new(10, 511, 340).unwrap().bounds();
+    /// assert_eq!(LatLong::from_degrees(51.61802, 0.0), bounds.north_east().round_d5());
+    /// assert_eq!(LatLong::from_degrees(51.39921, -0.35156), bounds.south_west().round_d5());
+    /// ```
+    pub fn bounds(&self) -> Rectangle {
+        Rectangle::from_nesw(
+            tile_latitude(self.y, self.zoom),
+            tile_longitude(self.x + 1, self.zoom),
+            tile_latitude(self.y + 1, self.zoom),
+            tile_longitude(self.x, self.zoom),
+        )
+    }
+}
+
+impl LatLong {
+    /// Returns the [Tile] containing this [LatLong] at the given zoom level. The zoom level is clamped to the
+    /// range [0, 22] and latitudes are clamped to the range [-85.0511, 85.0511] degrees, beyond which the Web
+    /// Mercator projection is not defined.
+    ///
+    /// This [LatLong] is assumed to have a longitude in [-180, 180] degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Tile};
+    ///
+    /// let london = LatLong::from_degrees(51.5074, -0.1278);
+    /// assert_eq!(Tile::new(10, 511, 340).unwrap(), london.to_tile(10));
+    /// assert_eq!(Tile::new(0, 0, 0).unwrap(), london.to_tile(0));
+    /// ```
+    pub fn to_tile(&self, zoom: u8) -> Tile {
+        self.to_tile_pixel(zoom).0
+    }
+
+    /// Returns the [Tile] containing this [LatLong] at the given zoom level - see [LatLong::to_tile] - and the
+    /// offset in pixels of this [LatLong] within the tile, from its north-west corner, assuming 256 by 256 pixels
+    /// tiles: (tile, pixel x in [0, 256), pixel y in [0, 256)). Only positions on the eastern edge (antimeridian) or
+    /// southern edge of the tiling have a pixel offset of 256.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Tile};
+    ///
+    /// let (tile, px, py) = LatLong::from_degrees(51.5074, -0.1278).to_tile_pixel(10);
+    /// assert_eq!(Tile::new(10, 511, 340).unwrap(), tile);
+    /// assert_eq!(162, px as u8);
+    /// assert_eq!(129, py as u8);
+    /// ```
+    pub fn to_tile_pixel(&self, zoom: u8) -> (Tile, f64, f64) {
+        let zoom = zoom.min(MAX_ZOOM);
+        let n = tiles(zoom);
+        let lat = self
+            .latitude()
+            .as_degrees()
+            .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+            .to_radians();
+        let lng = self.longitude().as_degrees();
+
+        let fx = (lng + 180.0) / 360.0 * n as f64;
+        let fy = (1.0 - (lat.tan() + 1.0 / lat.cos()).ln() / PI) / 2.0 * n as f64;
+        let (x, px) = tile_and_pixel(fx, n);
+        let (y, py) = tile_and_pixel(fy, n);
+        (Tile { zoom, x, y }, px, py)
+    }
+}
+
+/// Returns the number of tiles along each axis at the given zoom level.
+fn tiles(zoom: u8) -> u32 {
+    1 << zoom
+}
+
+/// Returns the tile index and pixel offset within that tile of the given fractional tile coordinate, clamped
+/// to the given number of tiles.
+fn tile_and_pixel(f: f64, n: u32) -> (u32, f64) {
+    if f <= 0.0 {
+        (0, 0.0)
+    } else if f >= n as f64 {
+        // eastern or southern edge of the last tile.
+        (n - 1, TILE_SIZE)
+    } else {
+        let t = f.floor();
+        (t as u32, (f - t) * TILE_SIZE)
+    }
+}
+
+/// Returns the latitude of the northern edge of tiles with the given y coordinate.
+fn tile_latitude(y: u32, zoom: u8) -> Angle {
+    let n = tiles(zoom) as f64;
+    Angle::from_radians((PI * (1.0 - 2.0 * y as f64 / n)).sinh().atan())
+}
+
+/// Returns the longitude of the western edge of tiles with the given x coordinate.
+fn tile_longitude(x: u32, zoom: u8) -> Angle {
+    let n = tiles(zoom) as f64;
+    Angle::from_degrees(x as f64 / n * 360.0 - 180.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, LatLong, Tile};
+
+    fn tile(zoom: u8, x: u32, y: u32) -> Tile {
+        Tile::new(zoom, x, y).unwrap()
+    }
+
+    // new
+
+    #[test]
+    fn new_out_of_range() {
+        assert!(Tile::new(0, 0, 0).is_some());
+        assert!(Tile::new(0, 1, 0).is_none());
+        assert!(Tile::new(0, 0, 1).is_none());
+        assert!(Tile::new(10, 1023, 1023).is_some());
+        assert!(Tile::new(10, 1024, 0).is_none());
+        assert!(Tile::new(22, 0, 0).is_some());
+        assert!(Tile::new(23, 0, 0).is_none());
+    }
+
+    // to_tile
+
+    #[test]
+    fn to_tile_landmarks() {
+        let london = LatLong::from_degrees(51.5074, -0.1278);
+        assert_eq!(tile(0, 0, 0), london.to_tile(0));
+        assert_eq!(tile(1, 0, 0), london.to_tile(1));
+        assert_eq!(tile(10, 511, 340), london.to_tile(10));
+        assert_eq!(tile(16, 32744, 21792), london.to_tile(16));
+
+        let eiffel_tower = LatLong::from_degrees(48.8584, 2.2945);
+        assert_eq!(tile(1, 1, 0), eiffel_tower.to_tile(1));
+        assert_eq!(tile(10, 518, 352), eiffel_tower.to_tile(10));
+        assert_eq!(tile(16, 33185, 22545), eiffel_tower.to_tile(16));
+        assert_eq!(tile(22, 2123884, 1442927), eiffel_tower.to_tile(22));
+
+        let statue_of_liberty = LatLong::from_degrees(40.6892, -74.0445);
+        assert_eq!(tile(10, 301, 385), statue_of_liberty.to_tile(10));
+        assert_eq!(tile(16, 19288, 24645), statue_of_liberty.to_tile(16));
+
+        let sydney_opera_house = LatLong::from_degrees(-33.8568, 151.2153);
+        assert_eq!(tile(1, 1, 1), sydney_opera_house.to_tile(1));
+        assert_eq!(tile(10, 942, 614), sydney_opera_house.to_tile(10));
+        assert_eq!(tile(16, 60295, 39325), sydney_opera_house.to_tile(16));
+    }
+
+    #[test]
+    fn to_tile_clamped() {
+        let p = LatLong::from_degrees(48.8584, 2.2945);
+        assert_eq!(p.to_tile(22), p.to_tile(30));
+        assert_eq!(
+            tile(3, 0, 0),
+            LatLong::from_degrees(90.0, -180.0).to_tile(3)
+        );
+        assert_eq!(
+            tile(3, 7, 7),
+            LatLong::from_degrees(-90.0, 180.0).to_tile(3)
+        );
+        assert_eq!(
+            tile(3, 0, 0),
+            LatLong::from_degrees(86.0, -179.0).to_tile(3)
+        );
+    }
+
+    // to_tile_pixel
+
+    #[test]
+    fn to_tile_pixel() {
+        let (t, px, py) = LatLong::from_degrees(48.8584, 2.2945).to_tile_pixel(16);
+        assert_eq!(tile(16, 33185, 22545), t);
+        assert!((px - 179.4503).abs() < 1e-3);
+        assert!((py - 191.3292).abs() < 1e-3);
+
+        let (t, px, py) = LatLong::from_degrees(0.0, 0.0).to_tile_pixel(0);
+        assert_eq!(tile(0, 0, 0), t);
+        assert!((px - 128.0).abs() < 1e-9);
+        assert!((py - 128.0).abs() < 1e-9);
+    }
+
+    // nw_corner & bounds
+
+    #[test]
+    fn nw_corner() {
+        assert_eq!(
+            LatLong::from_degrees(85.0511288, -180.0),
+            tile(0, 0, 0).nw_corner().round_d7()
+        );
+        assert_eq!(
+            LatLong::from_degrees(0.0, 0.0),
+            tile(1, 1, 1).nw_corner().round_d7()
+        );
+    }
+
+    #[test]
+    fn bounds_contain_position() {
+        let ps = vec![
+            LatLong::from_degrees(51.5074, -0.1278),
+            LatLong::from_degrees(48.8584, 2.2945),
+            LatLong::from_degrees(40.6892, -74.0445),
+            LatLong::from_degrees(-33.8568, 151.2153),
+        ];
+        for p in ps {
+            for zoom in 0..=22 {
+                let t = p.to_tile(zoom);
+                assert!(t.bounds().contains_point(p));
+                let b = t.bounds();
+                assert_eq!(
+                    t.nw_corner(),
+                    LatLong::new(b.north_east().latitude(), b.south_west().longitude())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bounds_world() {
+        let b = tile(0, 0, 0).bounds();
+        assert_eq!(Angle::from_degrees(-180.0), b.south_west().longitude());
+        assert_eq!(Angle::from_degrees(180.0), b.north_east().longitude());
+        assert_eq!(
+            Angle::from_degrees(-85.0511288),
+            b.south_west().latitude().round_d7()
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use crate::{Angle, NVector, Speed};
+use std::{f64::consts::PI, fmt, time::Duration};
+
+use crate::{Angle, AngularVelocity, NVector, Speed};
 
 /// The state of a vehicle: its horizontal position and velocity (bearing and speed).
 #[derive(PartialEq, Clone, Copy, Debug, Default)]
@@ -8,16 +10,88 @@ pub struct Vehicle {
     speed: Speed,
 }
 
+/// Error returned when creating or updating a [Vehicle] with an invalid velocity.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum VehicleError {
+    /// The speed is negative or not a number.
+    InvalidSpeed,
+}
+
+impl fmt::Display for VehicleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VehicleError::InvalidSpeed => write!(
+                f,
+                "invalid vehicle speed: expected a positive number or zero"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for VehicleError {}
+
 impl Vehicle {
-    /// Creates a [Vehicle] from given horizontal position and velocity (bearing and speed).
+    /// Creates a [Vehicle] from given horizontal position and velocity (bearing and speed). The bearing is
+    /// normalised to the range [0, 360) degrees.
+    ///
+    /// Note: this method does not check the given speed, see [Vehicle::checked_new].
     pub fn new(position: NVector, bearing: Angle, speed: Speed) -> Self {
         Self {
             position,
-            bearing,
+            bearing: bearing.normalised(),
             speed,
         }
     }
 
+    /// Creates a [Vehicle] from given horizontal position and velocity (bearing and speed), if the given speed is
+    /// positive (or zero). The bearing is normalised to the range [0, 360) degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector, Speed, Vehicle, VehicleError};
+    ///
+    /// let p = NVector::from_lat_long_degrees(20.0, 30.0);
+    ///
+    /// let v = Vehicle::checked_new(p, Angle::from_degrees(-90.0), Speed::from_knots(400.0)).unwrap();
+    /// assert_eq!(Angle::from_degrees(270.0), v.bearing());
+    ///
+    /// assert_eq!(
+    ///     Err(VehicleError::InvalidSpeed),
+    ///     Vehicle::checked_new(p, Angle::ZERO, Speed::from_knots(-1.0))
+    /// );
+    /// ```
+    pub fn checked_new(
+        position: NVector,
+        bearing: Angle,
+        speed: Speed,
+    ) -> Result<Self, VehicleError> {
+        Self::check_speed(speed)?;
+        Ok(Self::new(position, bearing, speed))
+    }
+
+    /// Returns a copy of this vehicle with the given bearing - normalised to the range [0, 360) degrees.
+    pub fn with_bearing(&self, bearing: Angle) -> Self {
+        Self::new(self.position, bearing, self.speed)
+    }
+
+    /// Returns a copy of this vehicle with the given speed, if the given speed is positive (or zero).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{Angle, NVector, Speed, Vehicle, VehicleError};
+    ///
+    /// let v = Vehicle::new(NVector::from_lat_long_degrees(20.0, 30.0), Angle::ZERO, Speed::ZERO);
+    ///
+    /// assert_eq!(Ok(Speed::from_knots(400.0)), v.with_speed(Speed::from_knots(400.0)).map(|v| v.speed()));
+    /// assert_eq!(Err(VehicleError::InvalidSpeed), v.with_speed(Speed::from_knots(-400.0)));
+    /// ```
+    pub fn with_speed(&self, speed: Speed) -> Result<Self, VehicleError> {
+        Self::check_speed(speed)?;
+        Ok(Self::new(self.position, self.bearing, speed))
+    }
+
     /// Returns the horizontal position of this vehicle.
     #[inline]
     pub fn position(&self) -> NVector {
@@ -35,4 +109,214 @@ impl Vehicle {
     pub fn speed(&self) -> Speed {
         self.speed
     }
+
+    /// Returns the constant rate of turn required for this vehicle to change from its current bearing to the
+    /// given target bearing over the given duration, turning in the shorter direction: a positive rate of turn
+    /// is a turn to the right (clockwise) and a negative rate of turn is a turn to the left (anticlockwise).
+    ///
+    /// A full reversal (i.e. the target bearing is 180 degrees from the current bearing) is always performed as
+    /// a turn to the right.
+    ///
+    /// Returns [None] if the given duration is zero: no finite rate of turn changes the bearing instantly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use jord::{Angle, AngularVelocity, NVector, Speed, Vehicle};
+    ///
+    /// let v = Vehicle::new(NVector::from_lat_long_degrees(20.0, 30.0), Angle::from_degrees(350.0), Speed::ZERO);
+    ///
+    /// let rot = v.rate_of_turn_to_reach(Angle::from_degrees(20.0), Duration::from_secs(10));
+    /// assert_eq!(Some(3.0), rot.map(|r| r.as_degrees_per_second().round()));
+    ///
+    /// let rot = v.rate_of_turn_to_reach(Angle::from_degrees(320.0), Duration::from_secs(10));
+    /// assert_eq!(Some(-3.0), rot.map(|r| r.as_degrees_per_second().round()));
+    /// ```
+    pub fn rate_of_turn_to_reach(
+        &self,
+        target_bearing: Angle,
+        over: Duration,
+    ) -> Option<AngularVelocity> {
+        if over.is_zero() {
+            return None;
+        }
+        let turn = (target_bearing - self.bearing).as_radians_normalised();
+        // a reversal is always a turn to the right, even if rounding errors made it slightly less than -180.
+        let turn = if (turn.abs() - PI).abs() < 1e-12 {
+            PI
+        } else {
+            turn
+        };
+        Some(Angle::from_radians(turn) / over)
+    }
+
+    fn check_speed(speed: Speed) -> Result<(), VehicleError> {
+        if speed.as_metres_per_second() >= 0.0 {
+            Ok(())
+        } else {
+            Err(VehicleError::InvalidSpeed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{Angle, NVector, Speed, Vehicle, VehicleError};
+
+    fn p() -> NVector {
+        NVector::from_lat_long_degrees(20.0, 30.0)
+    }
+
+    // new
+
+    #[test]
+    fn new_normalises_bearing() {
+        assert_eq!(
+            Angle::from_degrees(270.0),
+            Vehicle::new(p(), Angle::from_degrees(-90.0), Speed::ZERO).bearing()
+        );
+        assert_eq!(
+            Angle::ZERO,
+            Vehicle::new(p(), Angle::FULL_CIRCLE, Speed::ZERO).bearing()
+        );
+        assert_eq!(
+            Angle::from_degrees(45.0).round_d7(),
+            Vehicle::new(p(), Angle::from_degrees(405.0), Speed::ZERO)
+                .bearing()
+                .round_d7()
+        );
+    }
+
+    // checked_new
+
+    #[test]
+    fn checked_new() {
+        let v = Vehicle::checked_new(p(), Angle::from_degrees(-90.0), Speed::from_knots(400.0));
+        assert_eq!(
+            Ok(Vehicle::new(
+                p(),
+                Angle::from_degrees(270.0),
+                Speed::from_knots(400.0)
+            )),
+            v
+        );
+        assert!(Vehicle::checked_new(p(), Angle::ZERO, Speed::ZERO).is_ok());
+    }
+
+    #[test]
+    fn checked_new_invalid_speed() {
+        assert_eq!(
+            Err(VehicleError::InvalidSpeed),
+            Vehicle::checked_new(p(), Angle::ZERO, Speed::from_knots(-1.0))
+        );
+        assert_eq!(
+            Err(VehicleError::InvalidSpeed),
+            Vehicle::checked_new(p(), Angle::ZERO, Speed::from_metres_per_second(f64::NAN))
+        );
+    }
+
+    // rate_of_turn_to_reach
+
+    #[test]
+    fn rate_of_turn_to_reach_right() {
+        let v = Vehicle::new(p(), Angle::from_degrees(10.0), Speed::ZERO);
+        let rot = v
+            .rate_of_turn_to_reach(Angle::from_degrees(40.0), Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(
+            Angle::from_degrees(0.5).round_d7(),
+            Angle::from_radians(rot.as_radians_per_second()).round_d7()
+        );
+    }
+
+    #[test]
+    fn rate_of_turn_to_reach_left_across_north() {
+        let v = Vehicle::new(p(), Angle::from_degrees(10.0), Speed::ZERO);
+        let rot = v
+            .rate_of_turn_to_reach(Angle::from_degrees(340.0), Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(
+            Angle::from_degrees(-3.0).round_d7(),
+            Angle::from_radians(rot.as_radians_per_second()).round_d7()
+        );
+    }
+
+    #[test]
+    fn rate_of_turn_to_reach_reversal() {
+        for b in [0.0, 90.0, 200.0, 359.0] {
+            let v = Vehicle::new(p(), Angle::from_degrees(b), Speed::ZERO);
+            let rot = v
+                .rate_of_turn_to_reach(Angle::from_degrees(b + 180.0), Duration::from_secs(180))
+                .unwrap();
+            assert_eq!(
+                Angle::from_degrees(1.0).round_d7(),
+                Angle::from_radians(rot.as_radians_per_second()).round_d7()
+            );
+            let rot = v
+                .rate_of_turn_to_reach(Angle::from_degrees(b - 180.0), Duration::from_secs(180))
+                .unwrap();
+            assert_eq!(
+                Angle::from_degrees(1.0).round_d7(),
+                Angle::from_radians(rot.as_radians_per_second()).round_d7()
+            );
+        }
+    }
+
+    #[test]
+    fn rate_of_turn_to_reach_same_bearing() {
+        let v = Vehicle::new(p(), Angle::from_degrees(10.0), Speed::ZERO);
+        assert_eq!(
+            0.0,
+            v.rate_of_turn_to_reach(Angle::from_degrees(370.0), Duration::from_secs(10))
+                .unwrap()
+                .as_radians_per_second()
+                .abs()
+                .round()
+        );
+    }
+
+    #[test]
+    fn rate_of_turn_to_reach_zero_duration() {
+        let v = Vehicle::new(p(), Angle::from_degrees(10.0), Speed::ZERO);
+        assert_eq!(
+            None,
+            v.rate_of_turn_to_reach(Angle::from_degrees(40.0), Duration::ZERO)
+        );
+        assert_eq!(
+            None,
+            v.rate_of_turn_to_reach(Angle::from_degrees(10.0), Duration::ZERO)
+        );
+    }
+
+    // with_bearing
+
+    #[test]
+    fn with_bearing() {
+        let v = Vehicle::new(p(), Angle::ZERO, Speed::from_knots(400.0));
+        let a = v.with_bearing(Angle::from_degrees(-10.0));
+        assert_eq!(
+            Angle::from_degrees(350.0).round_d7(),
+            a.bearing().round_d7()
+        );
+        assert_eq!(v.position(), a.position());
+        assert_eq!(v.speed(), a.speed());
+    }
+
+    // with_speed
+
+    #[test]
+    fn with_speed() {
+        let v = Vehicle::new(p(), Angle::from_degrees(10.0), Speed::ZERO);
+        let a = v.with_speed(Speed::from_knots(400.0)).unwrap();
+        assert_eq!(Speed::from_knots(400.0), a.speed());
+        assert_eq!(v.position(), a.position());
+        assert_eq!(v.bearing(), a.bearing());
+        assert_eq!(
+            Err(VehicleError::InvalidSpeed),
+            v.with_speed(Speed::from_knots(-400.0))
+        );
+    }
 }
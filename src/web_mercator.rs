@@ -0,0 +1,153 @@
+use std::f64::consts::FRAC_PI_4;
+
+use crate::{Angle, LatLong, Length};
+
+/// Maximum latitude (in degrees) of the Web Mercator projection: `atan(sinh(π))`, at which the projected world
+/// is square.
+pub(crate) const MAX_LATITUDE: f64 = 85.0511287798066;
+
+/// Radius of the sphere used by the Web Mercator projection: the semi-major axis of the WGS84 ellipsoid.
+const RADIUS: f64 = 6_378_137.0;
+
+impl LatLong {
+    /// Projects this [LatLong] onto the [Web Mercator](https://epsg.io/3857) (EPSG:3857, "Pseudo-Mercator")
+    /// plane: returns (easting, northing), or [None] if the latitude of this [LatLong] is beyond
+    /// [-85.0511, 85.0511] degrees, where the projection is not defined.
+    ///
+    /// This is the spherical form of the Mercator projection applied to geodetic coordinates, as used by web
+    /// maps; it is neither conformal nor equal-area on the ellipsoid and its metres are not ground metres (the
+    /// scale error reaches 1 / cos(latitude)): do not use it for measurement.
+    ///
+    /// This [LatLong] is assumed to have a longitude in [-180, 180] degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    ///
+    /// let (x, y) = LatLong::from_degrees(0.0, 180.0).to_web_mercator().unwrap();
+    /// assert_eq!(Length::from_metres(20037508.343), x.round_mm());
+    /// assert_eq!(Length::ZERO, y.round_mm());
+    ///
+    /// assert!(LatLong::from_degrees(90.0, 0.0).to_web_mercator().is_none());
+    /// ```
+    pub fn to_web_mercator(&self) -> Option<(Length, Length)> {
+        let lat = self.latitude();
+        if lat.as_degrees().abs() > MAX_LATITUDE {
+            return None;
+        }
+        let x = RADIUS * self.longitude().as_radians();
+        let y = RADIUS * (FRAC_PI_4 + lat.as_radians() / 2.0).tan().ln();
+        Some((Length::from_metres(x), Length::from_metres(y)))
+    }
+
+    /// Returns the [LatLong] projected at the given easting and northing on the [Web Mercator](https://epsg.io/3857)
+    /// (EPSG:3857) plane - the inverse of [LatLong::to_web_mercator].
+    ///
+    /// As with [LatLong::to_web_mercator] this is the spherical form of the Mercator projection: do not use it for
+    /// measurement.
+    ///
+    /// The easting is assumed to be in [-20037508.34, 20037508.34] metres.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jord::{LatLong, Length};
+    ///
+    /// let p = LatLong::from_web_mercator(Length::from_metres(20037508.342789244), Length::ZERO);
+    /// assert_eq!(LatLong::from_degrees(0.0, 180.0), p.round_d7());
+    /// ```
+    pub fn from_web_mercator(easting: Length, northing: Length) -> LatLong {
+        let lat = (northing.as_metres() / RADIUS).sinh().atan();
+        let lng = easting.as_metres() / RADIUS;
+        LatLong::new(Angle::from_radians(lat), Angle::from_radians(lng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, LatLong, Length};
+
+    fn assert_metres_eq(expected: f64, actual: Length, tolerance: f64) {
+        assert!(
+            (expected - actual.as_metres()).abs() < tolerance,
+            "expected {} but got {}",
+            expected,
+            actual.as_metres()
+        );
+    }
+
+    // to_web_mercator
+
+    #[test]
+    fn to_web_mercator_epsg() {
+        // EPSG Guidance Note 7-2, Popular Visualisation Pseudo Mercator example.
+        let p = LatLong::new(
+            Angle::from_dms(24, 22, 54.433).unwrap(),
+            Angle::from_dms(-100, 20, 0.0).unwrap(),
+        );
+        let (x, y) = p.to_web_mercator().unwrap();
+        assert_metres_eq(-11_169_055.58, x, 0.005);
+        assert_metres_eq(2_800_000.00, y, 0.005);
+
+        // EPSG:3857 projected bounds.
+        let (x, y) = LatLong::from_degrees(85.0511287798066, -180.0)
+            .to_web_mercator()
+            .unwrap();
+        assert_metres_eq(-20_037_508.34, x, 0.005);
+        assert_metres_eq(20_037_508.34, y, 0.005);
+
+        let (x, y) = LatLong::from_degrees(0.0, 0.0).to_web_mercator().unwrap();
+        assert_eq!(Length::ZERO, x);
+        assert_metres_eq(0.0, y, 1e-9);
+    }
+
+    #[test]
+    fn to_web_mercator_out_of_range() {
+        assert!(LatLong::from_degrees(85.06, 0.0)
+            .to_web_mercator()
+            .is_none());
+        assert!(LatLong::from_degrees(-85.06, 0.0)
+            .to_web_mercator()
+            .is_none());
+        assert!(LatLong::from_degrees(90.0, 0.0).to_web_mercator().is_none());
+        assert!(LatLong::from_degrees(-90.0, 0.0)
+            .to_web_mercator()
+            .is_none());
+    }
+
+    // from_web_mercator
+
+    #[test]
+    fn from_web_mercator_epsg() {
+        let p = LatLong::from_web_mercator(
+            Length::from_metres(-11_169_055.58),
+            Length::from_metres(2_800_000.00),
+        );
+        let expected = LatLong::new(
+            Angle::from_dms(24, 22, 54.433).unwrap(),
+            Angle::from_dms(-100, 20, 0.0).unwrap(),
+        );
+        // 1 cm on the projected plane.
+        assert!((expected.latitude() - p.latitude()).as_degrees().abs() < 1e-7);
+        assert!((expected.longitude() - p.longitude()).as_degrees().abs() < 1e-7);
+    }
+
+    #[test]
+    fn from_web_mercator_round_trip() {
+        let microarcsecond = 1.0 / 3_600_000_000.0;
+        let mut lat = -85.05;
+        while lat <= 85.05 {
+            let mut lng = -180.0;
+            while lng <= 180.0 {
+                let p = LatLong::from_degrees(lat, lng);
+                let (x, y) = p.to_web_mercator().unwrap();
+                let a = LatLong::from_web_mercator(x, y);
+                assert!((p.latitude() - a.latitude()).as_degrees().abs() < microarcsecond);
+                assert!((p.longitude() - a.longitude()).as_degrees().abs() < microarcsecond);
+                lng += 7.5;
+            }
+            lat += 0.35;
+        }
+    }
+}
@@ -0,0 +1,478 @@
+//! Reading and writing of positions as [Well-Known Text](https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry)
+//! (WKT) geometries: `POINT` and `LINESTRING`, optionally with a Z coordinate.
+//!
+//! WKT positions are ordered x (longitude) first, then y (latitude) and optionally z (height in metres): all
+//! functions of this module take care of this ordering, e.g. Paris (48.8567°N, 2.3508°E) is written
+//! `POINT (2.3508 48.8567)`.
+//!
+//! Coordinates are written in decimal degrees rounded to 9 decimal places (about 0.1 millimetre at the equator).
+
+use std::fmt;
+
+use crate::{Angle, GeodeticPos, LatLong, Length};
+
+/// Error returned when a WKT geometry cannot be parsed.
+#[derive(PartialEq, Clone, Debug)]
+pub enum WktError {
+    /// The geometry type (e.g. `MULTIPOLYGON` or `POINT M`) is not supported.
+    Unsupported(String),
+    /// The geometry is supported but is not of the expected type (`POINT` or `LINESTRING`).
+    UnexpectedType(&'static str),
+    /// A coordinate is not a finite number.
+    InvalidNumber(String),
+    /// The text is not a well-formed WKT geometry.
+    InvalidFormat,
+    /// The latitude (in degrees) is outside of [-90, 90].
+    LatitudeOutOfRange(f64),
+    /// The longitude (in degrees) is outside of [-180, 180].
+    LongitudeOutOfRange(f64),
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WktError::Unsupported(t) => write!(f, "unsupported WKT geometry type: {}", t),
+            WktError::UnexpectedType(t) => write!(f, "expected a WKT {} geometry", t),
+            WktError::InvalidNumber(n) => write!(f, "invalid WKT coordinate: {:?}", n),
+            WktError::InvalidFormat => write!(f, "invalid WKT geometry"),
+            WktError::LatitudeOutOfRange(lat) => {
+                write!(f, "latitude out of range [-90, 90]: {}", lat)
+            }
+            WktError::LongitudeOutOfRange(lng) => {
+                write!(f, "longitude out of range [-180, 180]: {}", lng)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WktError {}
+
+/// Supported geometry types.
+const POINT: &str = "POINT";
+const LINESTRING: &str = "LINESTRING";
+
+/// Returns the WKT `POINT` of the given position.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::wkt;
+///
+/// assert_eq!("POINT (2.3508 48.8567)", wkt::point(LatLong::from_degrees(48.8567, 2.3508)));
+/// ```
+pub fn point(p: LatLong) -> String {
+    format!("{} ({})", POINT, position(p))
+}
+
+/// Returns the WKT `POINT Z` of the given position: the height is written as the z coordinate in metres.
+///
+/// # Examples
+///
+/// ```
+/// use jord::{GeodeticPos, Length, NVector};
+/// use jord::wkt;
+///
+/// let p = GeodeticPos::new(NVector::from_lat_long_degrees(48.8567, 2.3508), Length::from_metres(35.0));
+/// assert_eq!("POINT Z (2.3508 48.8567 35)", wkt::point_with_height(p));
+/// ```
+pub fn point_with_height(p: GeodeticPos) -> String {
+    let ll = LatLong::from_nvector(p.horizontal_position());
+    format!("{} Z ({} {})", POINT, position(ll), p.height().as_metres())
+}
+
+/// Returns the WKT `LINESTRING` of the given positions; `LINESTRING EMPTY` if no position is given.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::wkt;
+///
+/// let ps = vec![LatLong::from_degrees(48.8567, 2.3508), LatLong::from_degrees(51.5074, -0.1278)];
+/// assert_eq!("LINESTRING (2.3508 48.8567, -0.1278 51.5074)", wkt::line_string(&ps));
+/// assert_eq!("LINESTRING EMPTY", wkt::line_string(&[]));
+/// ```
+pub fn line_string(ps: &[LatLong]) -> String {
+    if ps.is_empty() {
+        format!("{} EMPTY", LINESTRING)
+    } else {
+        let coordinates: Vec<String> = ps.iter().map(|p| position(*p)).collect();
+        format!("{} ({})", LINESTRING, coordinates.join(", "))
+    }
+}
+
+/// Parses the given WKT `POINT`; returns `None` for `POINT EMPTY`. The z coordinate - if any - is ignored.
+///
+/// Keywords are case-insensitive, surplus whitespace is ignored and coordinates may use
+/// scientific notation.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::wkt::{self, WktError};
+///
+/// assert_eq!(Ok(Some(LatLong::from_degrees(48.8567, 2.3508))), wkt::parse_point("POINT (2.3508 48.8567)"));
+/// assert_eq!(Ok(None), wkt::parse_point("point empty"));
+/// assert_eq!(
+///     Err(WktError::Unsupported("MULTIPOLYGON".to_string())),
+///     wkt::parse_point("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))")
+/// );
+/// ```
+pub fn parse_point(s: &str) -> Result<Option<LatLong>, WktError> {
+    parse_single(s).map(|o| o.map(|(p, _)| p))
+}
+
+/// Parses the given WKT `POINT`; returns `None` for `POINT EMPTY`. The height is 0 if the point has no
+/// z coordinate.
+pub fn parse_point_with_height(s: &str) -> Result<Option<GeodeticPos>, WktError> {
+    parse_single(s).map(|o| o.map(|(p, h)| GeodeticPos::new(p.to_nvector(), h)))
+}
+
+/// Parses the given WKT `LINESTRING`; returns an empty vector for `LINESTRING EMPTY`. The z coordinates - if
+/// any - are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use jord::LatLong;
+/// use jord::wkt;
+///
+/// let ps = wkt::parse_line_string("LINESTRING(2.3508 48.8567,-1.278E-1 5.15074e1)").unwrap();
+/// assert_eq!(
+///     vec![LatLong::from_degrees(48.8567, 2.3508), LatLong::from_degrees(51.5074, -0.1278)],
+///     ps
+/// );
+/// ```
+pub fn parse_line_string(s: &str) -> Result<Vec<LatLong>, WktError> {
+    Ok(parse_geometry(s, LINESTRING)?
+        .into_iter()
+        .map(|(p, _)| p)
+        .collect())
+}
+
+/// Parses the given WKT `POINT`: returns its position and height if not empty.
+fn parse_single(s: &str) -> Result<Option<(LatLong, Length)>, WktError> {
+    let ps = parse_geometry(s, POINT)?;
+    match ps.as_slice() {
+        [] => Ok(None),
+        [p] => Ok(Some(*p)),
+        _ => Err(WktError::InvalidFormat),
+    }
+}
+
+/// Returns the WKT position - longitude latitude - of the given position.
+fn position(p: LatLong) -> String {
+    format!("{} {}", round_d9(p.longitude()), round_d9(p.latitude()))
+}
+
+/// Returns the given angle in decimal degrees rounded to 9 decimal places.
+fn round_d9(a: Angle) -> f64 {
+    (a.as_degrees() * 1e9).round() / 1e9
+}
+
+/// Parses the given WKT geometry which must be of the given type and returns its positions (empty if the
+/// geometry is `EMPTY`).
+fn parse_geometry(s: &str, expected: &'static str) -> Result<Vec<(LatLong, Length)>, WktError> {
+    let s = s.trim();
+    let (keyword, rest) = split_word(s);
+    if keyword.is_empty() {
+        return Err(WktError::InvalidFormat);
+    }
+    let keyword = keyword.to_ascii_uppercase();
+
+    // geometry type, optionally directly followed by the dimension, e.g. POINTZ.
+    let (geometry, mut z) = if let Some(dim) = keyword.strip_prefix(LINESTRING) {
+        (LINESTRING, dim)
+    } else if let Some(dim) = keyword.strip_prefix(POINT) {
+        (POINT, dim)
+    } else {
+        return Err(WktError::Unsupported(keyword));
+    };
+    match z {
+        "" | "Z" => {}
+        _ => return Err(WktError::Unsupported(keyword)),
+    }
+    if geometry != expected {
+        return Err(WktError::UnexpectedType(expected));
+    }
+
+    let (word, mut rest) = split_word(rest.trim_start());
+    let mut word = word.to_ascii_uppercase();
+    if z.is_empty() && !word.is_empty() {
+        match word.as_str() {
+            "Z" => {
+                z = "Z";
+                let (w, r) = split_word(rest.trim_start());
+                word = w.to_ascii_uppercase();
+                rest = r;
+            }
+            "M" | "ZM" => return Err(WktError::Unsupported(format!("{} {}", geometry, word))),
+            _ => {}
+        }
+    }
+    let with_z = z == "Z";
+
+    match word.as_str() {
+        "EMPTY" if rest.trim().is_empty() => return Ok(Vec::new()),
+        "" => {}
+        _ => return Err(WktError::InvalidFormat),
+    }
+
+    let inner = rest
+        .trim()
+        .strip_prefix('(')
+        .and_then(|r| r.strip_suffix(')'))
+        .ok_or(WktError::InvalidFormat)?;
+    if inner.contains('(') || inner.contains(')') {
+        return Err(WktError::InvalidFormat);
+    }
+
+    inner
+        .split(',')
+        .map(|c| parse_position(c, with_z))
+        .collect()
+}
+
+/// Splits the given text into a leading word made of ASCII letters and the remainder.
+fn split_word(s: &str) -> (&str, &str) {
+    let end = s
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Parses the given WKT position: `x y` or `x y z` (x is the longitude, y the latitude, z the height).
+fn parse_position(s: &str, with_z: bool) -> Result<(LatLong, Length), WktError> {
+    let cs: Vec<f64> = s
+        .split_whitespace()
+        .map(|n| {
+            n.parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or_else(|| WktError::InvalidNumber(n.to_string()))
+        })
+        .collect::<Result<_, _>>()?;
+    if (with_z && cs.len() != 3) || (!with_z && cs.len() != 2 && cs.len() != 3) {
+        return Err(WktError::InvalidFormat);
+    }
+    let (lng, lat) = (cs[0], cs[1]);
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(WktError::LatitudeOutOfRange(lat));
+    }
+    if !(-180.0..=180.0).contains(&lng) {
+        return Err(WktError::LongitudeOutOfRange(lng));
+    }
+    let h = cs.get(2).copied().unwrap_or(0.0);
+    Ok((LatLong::from_degrees(lat, lng), Length::from_metres(h)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GeodeticPos, LatLong, Length, NVector};
+
+    use super::{
+        line_string, parse_line_string, parse_point, parse_point_with_height, point,
+        point_with_height, WktError,
+    };
+
+    fn paris() -> LatLong {
+        LatLong::from_degrees(48.8567, 2.3508)
+    }
+
+    fn london() -> LatLong {
+        LatLong::from_degrees(51.5074, -0.1278)
+    }
+
+    // point
+
+    #[test]
+    fn point_lon_lat_order() {
+        assert_eq!("POINT (2.3508 48.8567)", point(paris()));
+    }
+
+    #[test]
+    fn point_with_height_z() {
+        let p = GeodeticPos::new(
+            NVector::from_lat_long_degrees(48.8567, 2.3508),
+            Length::from_metres(-12.5),
+        );
+        assert_eq!("POINT Z (2.3508 48.8567 -12.5)", point_with_height(p));
+    }
+
+    // line_string
+
+    #[test]
+    fn line_string_lon_lat_order() {
+        assert_eq!(
+            "LINESTRING (2.3508 48.8567, -0.1278 51.5074)",
+            line_string(&[paris(), london()])
+        );
+    }
+
+    // parse_point
+
+    #[test]
+    fn parse_point_round_trip() {
+        let ps = vec![
+            paris(),
+            london(),
+            LatLong::from_degrees(-33.8688, 151.2093),
+            LatLong::from_degrees(90.0, 180.0),
+            LatLong::from_degrees(-90.0, -180.0),
+        ];
+        for p in ps {
+            assert_eq!(
+                p.round_d7(),
+                parse_point(&point(p)).unwrap().unwrap().round_d7()
+            );
+        }
+    }
+
+    #[test]
+    fn parse_point_messy() {
+        let expected = Ok(Some(paris()));
+        assert_eq!(expected, parse_point("POINT(2.3508 48.8567)"));
+        assert_eq!(expected, parse_point("  point  (  2.3508    48.8567 )  "));
+        assert_eq!(expected, parse_point("Point\t(\n2.3508\t48.8567\n)"));
+        assert_eq!(expected, parse_point("POINT (2.3508e0 4.88567E+1)"));
+        assert_eq!(expected, parse_point("POINT (+2.3508 48856.7e-3)"));
+        assert_eq!(expected, parse_point("POINT Z (2.3508 48.8567 35)"));
+        assert_eq!(expected, parse_point("POINTZ(2.3508 48.8567 35)"));
+    }
+
+    #[test]
+    fn parse_point_with_height_z() {
+        let p = parse_point_with_height("POINT Z (2.3508 48.8567 35.5)")
+            .unwrap()
+            .unwrap();
+        assert_eq!(Length::from_metres(35.5), p.height());
+        let p = parse_point_with_height("POINT (2.3508 48.8567 1e2)")
+            .unwrap()
+            .unwrap();
+        assert_eq!(Length::from_metres(100.0), p.height());
+        let p = parse_point_with_height("POINT (2.3508 48.8567)")
+            .unwrap()
+            .unwrap();
+        assert_eq!(Length::ZERO, p.height());
+    }
+
+    #[test]
+    fn parse_point_empty() {
+        assert_eq!(Ok(None), parse_point("POINT EMPTY"));
+        assert_eq!(Ok(None), parse_point(" point  Z  empty "));
+    }
+
+    #[test]
+    fn parse_point_unsupported() {
+        assert_eq!(
+            Err(WktError::Unsupported("MULTIPOLYGON".to_string())),
+            parse_point("MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)))")
+        );
+        assert_eq!(
+            Err(WktError::Unsupported("POLYGON".to_string())),
+            parse_point("polygon ((0 0, 1 0, 1 1, 0 0))")
+        );
+        assert_eq!(
+            Err(WktError::Unsupported("GEOMETRYCOLLECTION".to_string())),
+            parse_point("GEOMETRYCOLLECTION (POINT (1 2))")
+        );
+        assert_eq!(
+            Err(WktError::Unsupported("POINT M".to_string())),
+            parse_point("POINT M (1 2 3)")
+        );
+        assert_eq!(
+            Err(WktError::Unsupported("POINTZM".to_string())),
+            parse_point("POINTZM (1 2 3 4)")
+        );
+    }
+
+    #[test]
+    fn parse_point_unexpected_type() {
+        assert_eq!(
+            Err(WktError::UnexpectedType("POINT")),
+            parse_point("LINESTRING (1 2, 3 4)")
+        );
+    }
+
+    #[test]
+    fn parse_point_invalid() {
+        assert_eq!(Err(WktError::InvalidFormat), parse_point(""));
+        assert_eq!(Err(WktError::InvalidFormat), parse_point("(1 2)"));
+        assert_eq!(Err(WktError::InvalidFormat), parse_point("POINT"));
+        assert_eq!(Err(WktError::InvalidFormat), parse_point("POINT (1 2"));
+        assert_eq!(Err(WktError::InvalidFormat), parse_point("POINT (1 2) x"));
+        assert_eq!(Err(WktError::InvalidFormat), parse_point("POINT (1)"));
+        assert_eq!(
+            Err(WktError::InvalidFormat),
+            parse_point("POINT (1 2, 3 4)")
+        );
+        assert_eq!(Err(WktError::InvalidFormat), parse_point("POINT Z (1 2)"));
+        assert_eq!(
+            Err(WktError::InvalidFormat),
+            parse_point("POINT EMPTY (1 2)")
+        );
+        // decimal comma.
+        assert_eq!(Err(WktError::InvalidFormat), parse_point("POINT (1,5 2)"));
+        assert_eq!(
+            Err(WktError::InvalidNumber("x".to_string())),
+            parse_point("POINT (x 2)")
+        );
+        assert_eq!(
+            Err(WktError::InvalidNumber("NaN".to_string())),
+            parse_point("POINT (NaN 2)")
+        );
+    }
+
+    #[test]
+    fn parse_point_out_of_range() {
+        // latitude and longitude swapped.
+        assert_eq!(
+            Err(WktError::LatitudeOutOfRange(122.3508)),
+            parse_point("POINT (48.8567 122.3508)")
+        );
+        assert_eq!(
+            Err(WktError::LongitudeOutOfRange(180.5)),
+            parse_point("POINT (180.5 48.8567)")
+        );
+    }
+
+    // parse_line_string
+
+    #[test]
+    fn parse_line_string_round_trip() {
+        let ps = vec![paris(), london()];
+        assert_eq!(Ok(ps.clone()), parse_line_string(&line_string(&ps)));
+        assert_eq!(Ok(vec![]), parse_line_string(&line_string(&[])));
+    }
+
+    #[test]
+    fn parse_line_string_messy() {
+        assert_eq!(
+            Ok(vec![paris(), london()]),
+            parse_line_string("linestring z(2.3508 48.8567 1 ,  -0.1278   51.5074 2.0E1 )")
+        );
+        assert_eq!(Ok(vec![]), parse_line_string("LineString Empty"));
+    }
+
+    #[test]
+    fn parse_line_string_invalid() {
+        assert_eq!(
+            Err(WktError::InvalidFormat),
+            parse_line_string("LINESTRING (1 2,, 3 4)")
+        );
+        assert_eq!(
+            Err(WktError::InvalidFormat),
+            parse_line_string("LINESTRING ((1 2, 3 4))")
+        );
+        assert_eq!(
+            Err(WktError::UnexpectedType("LINESTRING")),
+            parse_line_string("POINT (1 2)")
+        );
+        assert_eq!(
+            Err(WktError::Unsupported("MULTILINESTRING".to_string())),
+            parse_line_string("MULTILINESTRING ((1 2, 3 4))")
+        );
+    }
+}